@@ -23,6 +23,8 @@ fn setup_mock_token(env: &Env, client: &TokenFactoryClient, creator: &Address) -
         clawback_enabled: true,
             freeze_enabled: false,
             is_paused: false,
+            max_burn_bps: None,
+            official: false,
         
         };
     
@@ -174,6 +176,8 @@ fn test_admin_burn_clawback_disabled() {
         clawback_enabled: false,
             freeze_enabled: false,
             is_paused: false,
+            max_burn_bps: None,
+            official: false,
         
         };
     storage::set_token_info_by_address(&env, &token_address, &token_info);
@@ -257,6 +261,8 @@ fn test_set_clawback_enable() {
         clawback_enabled: false,
             freeze_enabled: false,
             is_paused: false,
+            max_burn_bps: None,
+            official: false,
         
         };
     storage::set_token_info_by_address(&env, &token_address, &token_info);
@@ -33,6 +33,8 @@ fn setup_factory(test_env: &TestEnv) {
         is_paused: false,
         clawback_enabled: false,
         freeze_enabled: false,
+        max_burn_bps: None,
+        official: false,
     };
     storage::set_token_info(&test_env.env, 0, &token_info);
     storage::increment_token_count(&test_env.env).unwrap();
@@ -65,6 +65,8 @@ fn create_test_token(env: &Env, contract_id: &Address, supply: i128, max: Option
             clawback_enabled: false,
             freeze_enabled: false,
             is_paused: false,
+            max_burn_bps: None,
+            official: false,
         };
         storage::set_token_info(env, 0, &token_info);
         storage::set_balance(env, 0, &token_info.creator, supply);
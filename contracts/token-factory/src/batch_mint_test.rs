@@ -47,6 +47,8 @@ fn setup_token(
         clawback_enabled: false,
             freeze_enabled: false,
             is_paused: false,
+            max_burn_bps: None,
+            official: false,
         
         };
 
@@ -34,6 +34,7 @@ pub const MAX_BATCH_SIZE: u32 = 50;
 /// * `InvalidParameters`   – Empty batch.
 /// * `InsufficientFee`     – `total_fee_payment` is below the required total.
 /// * `InvalidTokenParams`  – Any token fails parameter validation.
+/// * `CreatorNotAllowed`   – Allowlist mode is on and `creator` is not listed.
 pub fn batch_reveal(
     env: &Env,
     creator: Address,
@@ -43,8 +44,10 @@ pub fn batch_reveal(
     if storage::is_paused(env) {
         return Err(Error::ContractPaused);
     }
+    storage::ensure_not_killed(env)?;
 
     creator.require_auth();
+    storage::check_creator_allowed(env, &creator)?;
 
     let batch_len = tokens.len();
     if batch_len == 0 {
@@ -56,18 +59,30 @@ pub fn batch_reveal(
 
     // ── Phase 1: validate all params and accumulate required fee ──────────
     let base_fee = storage::get_base_fee(env);
-    let metadata_fee = storage::get_metadata_fee(env);
+    let metadata_fee = if storage::is_metadata_fee_waived_at_creation(env) {
+        0
+    } else {
+        storage::get_metadata_fee(env)
+    };
+    let min_fee = storage::get_min_creation_fee(env);
+    let exempt = storage::is_fee_exempt(env, &creator);
 
     let mut required_fee: i128 = 0;
     for token in tokens.iter() {
         validate_token_params(env, &token)?;
-        let token_fee = if token.metadata_uri.is_some() {
-            base_fee
-                .checked_add(metadata_fee)
-                .ok_or(Error::ArithmeticError)?
-        } else {
-            base_fee
-        };
+        storage::check_metadata_required(env, &token.metadata_uri)?;
+        let token_fee = storage::round_fee_up(
+            env,
+            if token.metadata_uri.is_some() {
+                base_fee
+                    .checked_add(metadata_fee)
+                    .ok_or(Error::ArithmeticError)?
+            } else {
+                base_fee
+            }
+            .max(min_fee),
+        );
+        let token_fee = if exempt { 0 } else { token_fee };
         required_fee = required_fee
             .checked_add(token_fee)
             .ok_or(Error::ArithmeticError)?;
@@ -76,6 +91,7 @@ pub fn batch_reveal(
     if total_fee_payment < required_fee {
         return Err(Error::InsufficientFee);
     }
+    storage::check_registry_capacity(env, batch_len)?;
 
     // ── Phase 2: write state (all validations passed) ─────────────────────
     // Read token count once to avoid N storage reads.
@@ -99,6 +115,9 @@ pub fn batch_reveal(
         .instance()
         .set(&crate::types::DataKey::TokenCount, &new_count);
 
+    if !exempt {
+        crate::treasury::add_collected_fee(env, total_fee_payment)?;
+    }
     crate::events::emit_batch_tokens_created(env, &creator, batch_len);
 
     Ok(indices)
@@ -138,6 +157,7 @@ pub fn batch_settle(
     if storage::is_paused(env) {
         return Err(Error::ContractPaused);
     }
+    storage::ensure_not_killed(env)?;
 
     creator.require_auth();
 
@@ -152,7 +172,7 @@ pub fn batch_settle(
     // Load token info once.
     let token_info = storage::get_token_info(env, token_index).ok_or(Error::TokenNotFound)?;
 
-    if token_info.creator != creator {
+    if !storage::can_mint(env, &token_info, token_index, &creator) {
         return Err(Error::Unauthorized);
     }
     if storage::is_token_paused(env, token_index) {
@@ -197,17 +217,19 @@ fn validate_token_params(env: &Env, params: &TokenCreationParams) -> Result<(),
     if params.name.len() == 0 || params.name.len() > 32 {
         return Err(Error::InvalidTokenParams);
     }
-    if params.symbol.len() == 0 || params.symbol.len() > 12 {
+    let symbol = crate::token_creation::normalize_symbol(env, &params.symbol);
+    if symbol.is_empty() || symbol.len() > 12 {
         return Err(Error::InvalidTokenParams);
     }
     if params.decimals > 18 {
         return Err(Error::InvalidTokenParams);
     }
-    if params.initial_supply <= 0 {
+    if params.initial_supply < 0 {
         return Err(Error::InvalidTokenParams);
     }
+    crate::token_creation::validate_initial_supply_bounds(env, params.initial_supply)?;
+    crate::token_creation::validate_min_decimals(env, params.decimals)?;
     crate::mint::validate_max_supply_at_creation(params.initial_supply, params.max_supply)?;
-    let _ = env; // env available for future validation
     Ok(())
 }
 
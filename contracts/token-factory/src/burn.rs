@@ -1,10 +1,45 @@
 use crate::storage;
-use crate::types::Error;
-use soroban_sdk::{symbol_short, Address, Env};
+use crate::types::{
+    BatchBurnOutcome, BurnMode, BurnRecord, BurnRecordPage, BurnSchedule, BurnScheduleStatus,
+    BurnSummary, Error, RoundingMode, SupplyEvent, SupplyEventRef,
+};
+use soroban_sdk::address_payload::AddressPayload;
+use soroban_sdk::xdr::ToXdr;
+use soroban_sdk::{Address, Bytes, BytesN, Env, Vec};
 
 const MAX_BATCH_BURN: u32 = 100;
+const MAX_RECENT_BURN_RECORDS: u32 = 50;
+const MAX_TOTAL_BURNED_QUERY: u32 = 45;
+const MAX_REINITIALIZE_BATCH: u32 = 100;
+const MAX_VELOCITY_WINDOW_DAYS: u32 = 90;
+const MAX_DAY_FEED_SCAN: u32 = 90;
+const MAX_LARGE_BURN_SCAN: u32 = 90;
+const MAX_TOKEN_LEDGER_PAGE: u32 = 45;
 
 pub fn burn(env: &Env, caller: Address, token_index: u32, amount: i128) -> Result<(), Error> {
+    burn_own(env, caller, token_index, amount, None)
+}
+
+/// Burn from the caller's own balance, tagging the burn-log record with
+/// an integrator-supplied `correlation_id` that can later be looked up via
+/// [`get_burn_by_correlation`]. Reusing an id is rejected.
+pub fn burn_with_correlation(
+    env: &Env,
+    caller: Address,
+    token_index: u32,
+    amount: i128,
+    correlation_id: u64,
+) -> Result<(), Error> {
+    burn_own(env, caller, token_index, amount, Some(correlation_id))
+}
+
+fn burn_own(
+    env: &Env,
+    caller: Address,
+    token_index: u32,
+    amount: i128,
+    correlation_id: Option<u64>,
+) -> Result<(), Error> {
     caller.require_auth();
     validate_amount(amount)?;
 
@@ -15,38 +50,17 @@ pub fn burn(env: &Env, caller: Address, token_index: u32, amount: i128) -> Resul
         return Err(Error::TokenPaused);
     }
 
-    let balance = storage::get_balance(env, token_index, &caller);
-    if balance < amount {
-        return Err(Error::InsufficientBalance);
+    // Compliance freeze check
+    if storage::is_token_frozen(env, token_index) {
+        return Err(Error::TokenFrozen);
     }
+    storage::ensure_not_retired(env, token_index)?;
+    storage::check_burn_age(env, &info)?;
 
-    let new_balance = balance.checked_sub(amount).ok_or(Error::ArithmeticError)?;
-    let new_supply = info
-        .total_supply
-        .checked_sub(amount)
-        .ok_or(Error::ArithmeticError)?;
-
-    storage::set_balance(env, token_index, &caller, new_balance);
-    info.total_supply = new_supply;
-    info.total_burned = info
-        .total_burned
-        .checked_add(amount)
-        .ok_or(Error::ArithmeticError)?;
-    info.burn_count = info
-        .burn_count
-        .checked_add(1)
-        .ok_or(Error::ArithmeticError)?;
-    storage::set_token_info(env, token_index, &info);
-
-    // 8. Emit event — after state is fully committed
-    storage::increment_burn_count(env, token_index);
-    storage::add_total_burned(env, token_index, amount);
-
-    // Record snapshots for historical queries
-    let _ = crate::snapshot::record_balance_snapshot(env, token_index, &caller, new_balance);
-    let _ = crate::snapshot::record_supply_snapshot(env, token_index, new_supply);
+    let new_supply = apply_burn(env, token_index, &mut info, &caller, &caller, amount, correlation_id)?;
 
-    emit_burn_event(env, token_index, &caller, amount, new_supply);
+    // Emit event — after state is fully committed
+    crate::events::emit_burn(env, token_index, &caller, amount, new_supply);
     Ok(())
 }
 
@@ -65,7 +79,301 @@ pub fn admin_burn(
     }
 
     validate_amount(amount)?;
-    validate_address(&holder)?;
+    validate_address(env, &holder)?;
+
+    let mut info = storage::get_token_info(env, token_index).ok_or(Error::TokenNotFound)?;
+
+    // Token-level pause check
+    if storage::is_token_paused(env, token_index) {
+        return Err(Error::TokenPaused);
+    }
+
+    // Compliance freeze check
+    if storage::is_token_frozen(env, token_index) {
+        return Err(Error::TokenFrozen);
+    }
+    storage::ensure_not_retired(env, token_index)?;
+
+    let new_supply = apply_burn(env, token_index, &mut info, &holder, &admin, amount, None)?;
+
+    // Emit event with both admin and holder for auditability
+    crate::events::emit_holder_burn(env, token_index, &admin, &holder, amount, new_supply);
+    Ok(())
+}
+
+/// Dry-run a burn of `amount` from `from` executed by `burned_by`.
+///
+/// `burned_by == from` is checked as the holder's own `burn`; any other
+/// executor is checked as an `admin_burn` and must be the admin. Only the
+/// executor's identity is compared, no authorization is required. Returns
+/// the first error the real call would hit, in the same order, and writes
+/// nothing.
+pub fn can_burn(
+    env: &Env,
+    token_index: u32,
+    from: &Address,
+    amount: i128,
+    burned_by: &Address,
+) -> Result<(), Error> {
+    if burned_by != from {
+        if *burned_by != storage::try_get_admin(env)? {
+            return Err(Error::Unauthorized);
+        }
+        validate_amount(amount)?;
+        validate_address(env, from)?;
+    } else {
+        validate_amount(amount)?;
+    }
+
+    let info = storage::get_token_info(env, token_index).ok_or(Error::TokenNotFound)?;
+
+    if storage::is_token_paused(env, token_index) {
+        return Err(Error::TokenPaused);
+    }
+    if storage::is_token_frozen(env, token_index) {
+        return Err(Error::TokenFrozen);
+    }
+    storage::ensure_not_retired(env, token_index)?;
+    if burned_by == from {
+        storage::check_burn_age(env, &info)?;
+    }
+
+    // Read-only mirror of the checks in `apply_burn`
+    storage::ensure_not_killed(env)?;
+    validate_address(env, from)?;
+    storage::check_burner_allowed(env, token_index, burned_by)?;
+    check_burn_cap(&info, amount)?;
+    if storage::get_balance(env, token_index, from) < amount {
+        return Err(Error::InsufficientBalance);
+    }
+    if let BurnMode::SendToDead(dead) = storage::get_burn_mode(env, token_index) {
+        if *from == dead {
+            return Err(Error::InvalidParameters);
+        }
+    }
+    Ok(())
+}
+
+/// Atomically burn from one holder and mint to another (admin only).
+///
+/// Used for consolidation-style tokenomics. The burn is applied first, so
+/// the mint is validated against the post-burn supply and the token's
+/// `max_supply` cap. Any failure — including a cap violation on the mint
+/// leg — aborts the invocation and Soroban rolls back the burn as well.
+///
+/// Returns the token's total supply after both legs.
+pub fn rebase(
+    env: &Env,
+    admin: Address,
+    token_index: u32,
+    from: Address,
+    burn_amount: i128,
+    to: Address,
+    mint_amount: i128,
+) -> Result<i128, Error> {
+    admin.require_auth();
+
+    let current_admin = storage::get_admin(env);
+    if admin != current_admin {
+        return Err(Error::Unauthorized);
+    }
+
+    validate_amount(burn_amount)?;
+    validate_amount(mint_amount)?;
+
+    let mut info = storage::get_token_info(env, token_index).ok_or(Error::TokenNotFound)?;
+
+    // Token-level pause check
+    if storage::is_token_paused(env, token_index) {
+        return Err(Error::TokenPaused);
+    }
+
+    // Compliance freeze check
+    if storage::is_token_frozen(env, token_index) {
+        return Err(Error::TokenFrozen);
+    }
+    storage::ensure_not_retired(env, token_index)?;
+
+    // Burn leg
+    let supply_after_burn = apply_burn(env, token_index, &mut info, &from, &admin, burn_amount, None)?;
+    crate::events::emit_holder_burn(env, token_index, &admin, &from, burn_amount, supply_after_burn);
+
+    // Mint leg — enforces max_supply against the post-burn supply
+    crate::mint::mint(env, token_index, &to, mint_amount)?;
+
+    let new_supply = storage::get_token_info(env, token_index)
+        .ok_or(Error::TokenNotFound)?
+        .total_supply;
+
+    crate::events::emit_rebase(
+        env,
+        token_index,
+        &admin,
+        &from,
+        burn_amount,
+        &to,
+        mint_amount,
+        new_supply,
+    );
+    Ok(new_supply)
+}
+
+/// Schedule a burn of `amount` from `from` that unlocks at `unlock_time`
+/// (admin only). Returns the schedule id.
+pub fn schedule_burn(
+    env: &Env,
+    admin: Address,
+    token_index: u32,
+    from: Address,
+    amount: i128,
+    unlock_time: u64,
+) -> Result<u64, Error> {
+    storage::require_admin(env, &admin)?;
+    storage::ensure_not_killed(env)?;
+    if storage::is_paused(env) {
+        return Err(Error::ContractPaused);
+    }
+
+    validate_amount(amount)?;
+    validate_address(env, &from)?;
+    storage::get_token_info(env, token_index).ok_or(Error::TokenNotFound)?;
+    storage::ensure_not_retired(env, token_index)?;
+
+    let now = env.ledger().timestamp();
+    if unlock_time <= now {
+        return Err(Error::InvalidParameters);
+    }
+
+    let id = storage::increment_burn_schedule_id(env);
+    storage::set_burn_schedule(
+        env,
+        &BurnSchedule {
+            id,
+            token_index,
+            from,
+            amount,
+            unlock_time,
+            created_at: now,
+            executed_at: None,
+            creator: admin,
+            status: BurnScheduleStatus::Pending,
+        },
+    );
+    storage::add_burn_schedule_by_token(env, token_index, id);
+    Ok(id)
+}
+
+/// Fail unless schedule `id` exists and is still pending.
+fn pending_burn_schedule(env: &Env, id: u64) -> Result<BurnSchedule, Error> {
+    let schedule = storage::get_burn_schedule(env, id).ok_or(Error::BurnScheduleNotFound)?;
+    match schedule.status {
+        BurnScheduleStatus::Pending => Ok(schedule),
+        BurnScheduleStatus::Executed => Err(Error::BurnScheduleAlreadyExecuted),
+        BurnScheduleStatus::Cancelled => Err(Error::BurnCancelled),
+    }
+}
+
+/// Execute a pending schedule once its unlock time has passed.
+///
+/// Anyone may trigger execution; the burn is attributed to the schedule's
+/// creator and goes through the same checks as `admin_burn`.
+pub fn execute_burn_schedule(env: &Env, executor: Address, id: u64) -> Result<(), Error> {
+    executor.require_auth();
+
+    let mut schedule = pending_burn_schedule(env, id)?;
+    let now = env.ledger().timestamp();
+    if now < schedule.unlock_time {
+        return Err(Error::BurnScheduleLocked);
+    }
+
+    let token_index = schedule.token_index;
+    let mut info = storage::get_token_info(env, token_index).ok_or(Error::TokenNotFound)?;
+
+    // Token-level pause check
+    if storage::is_token_paused(env, token_index) {
+        return Err(Error::TokenPaused);
+    }
+
+    // Compliance freeze check
+    if storage::is_token_frozen(env, token_index) {
+        return Err(Error::TokenFrozen);
+    }
+    storage::ensure_not_retired(env, token_index)?;
+
+    let new_supply = apply_burn(
+        env,
+        token_index,
+        &mut info,
+        &schedule.from,
+        &schedule.creator,
+        schedule.amount,
+        None,
+    )?;
+
+    schedule.status = BurnScheduleStatus::Executed;
+    schedule.executed_at = Some(now);
+    storage::set_burn_schedule(env, &schedule);
+
+    crate::events::emit_holder_burn(
+        env,
+        token_index,
+        &schedule.creator,
+        &schedule.from,
+        schedule.amount,
+        new_supply,
+    );
+    Ok(())
+}
+
+/// Cancel a pending schedule (its creator or the admin).
+///
+/// A cancelled schedule can never execute; `execute_burn_schedule` rejects
+/// it with `Error::BurnCancelled`.
+pub fn cancel_scheduled_burn(env: &Env, index: u64, caller: Address) -> Result<(), Error> {
+    caller.require_auth();
+    storage::ensure_not_killed(env)?;
+
+    let mut schedule = pending_burn_schedule(env, index)?;
+    if caller != schedule.creator && caller != storage::try_get_admin(env)? {
+        return Err(Error::Unauthorized);
+    }
+
+    schedule.status = BurnScheduleStatus::Cancelled;
+    storage::set_burn_schedule(env, &schedule);
+    Ok(())
+}
+
+/// Burn on behalf of `from` using an off-chain ed25519 signature.
+///
+/// Lets a relayer submit the transaction without `from` signing it. The
+/// signature must cover [`burn_signature_payload`] for the current nonce of
+/// `from`, which is consumed so the same signature cannot be replayed.
+///
+/// Signature verification is performed by the host: `ed25519_verify` has no
+/// error return and traps on a bad signature, and all state (including the
+/// consumed nonce) is rolled back. The trap is a host error, which the
+/// client maps through `From<soroban_sdk::Error>` to
+/// `Error::InvalidParameters`.
+pub fn burn_with_sig(
+    env: &Env,
+    token_index: u32,
+    from: Address,
+    amount: i128,
+    nonce: u64,
+    signature: BytesN<64>,
+) -> Result<(), Error> {
+    validate_amount(amount)?;
+
+    // Only ed25519 accounts have a key we can verify against
+    let public_key = match from.to_payload() {
+        Some(AddressPayload::AccountIdPublicKeyEd25519(key)) => key,
+        _ => return Err(Error::InvalidSignature),
+    };
+
+    storage::consume_nonce(env, &from, nonce)?;
+
+    let payload = burn_signature_payload(env, token_index, &from, amount, nonce);
+    env.crypto().ed25519_verify(&public_key, &payload, &signature);
 
     let mut info = storage::get_token_info(env, token_index).ok_or(Error::TokenNotFound)?;
 
@@ -74,38 +382,187 @@ pub fn admin_burn(
         return Err(Error::TokenPaused);
     }
 
-    let balance = storage::get_balance(env, token_index, &holder);
+    // Compliance freeze check
+    if storage::is_token_frozen(env, token_index) {
+        return Err(Error::TokenFrozen);
+    }
+    storage::ensure_not_retired(env, token_index)?;
+    storage::check_burn_age(env, &info)?;
+
+    let new_supply = apply_burn(env, token_index, &mut info, &from, &from, amount, None)?;
+
+    crate::events::emit_burn(env, token_index, &from, amount, new_supply);
+    Ok(())
+}
+
+/// Message an account signs to authorize `burn_with_sig`.
+///
+/// Binds the factory address so a signature cannot be replayed against a
+/// different deployment.
+pub fn burn_signature_payload(
+    env: &Env,
+    token_index: u32,
+    from: &Address,
+    amount: i128,
+    nonce: u64,
+) -> Bytes {
+    (
+        env.current_contract_address(),
+        token_index,
+        from.clone(),
+        amount,
+        nonce,
+    )
+        .to_xdr(env)
+}
+
+/// Debit `holder` and update every burn aggregate for a single burn.
+///
+/// Shared by all single-holder burn paths so balances, `TokenInfo`
+/// counters, the per-token burn keys, snapshots and the global burn log
+/// stay in lockstep. Returns the new total supply.
+///
+/// A `correlation_id` is mapped to the burn-log record it produced; it is
+/// not mapped when the per-token record cap suppressed the record.
+fn apply_burn(
+    env: &Env,
+    token_index: u32,
+    info: &mut crate::types::TokenInfo,
+    holder: &Address,
+    burned_by: &Address,
+    amount: i128,
+    correlation_id: Option<u64>,
+) -> Result<i128, Error> {
+    // Entry points validate first; re-checked here so no caller can reach
+    // the writes below with a zero amount and log a no-op BurnRecord.
+    storage::ensure_not_killed(env)?;
+    validate_amount(amount)?;
+    validate_address(env, holder)?;
+    storage::check_burner_allowed(env, token_index, burned_by)?;
+    if let Some(id) = correlation_id {
+        if storage::get_burn_correlation(env, id).is_some() {
+            return Err(Error::InvalidParameters);
+        }
+    }
+    check_burn_cap(info, amount)?;
+
+    let balance = storage::get_balance(env, token_index, holder);
     if balance < amount {
         return Err(Error::InsufficientBalance);
     }
 
+    // Everything fallible runs before the first write: lenient batches carry
+    // on after a failed entry, so a half-applied burn must not persist.
     let new_balance = balance.checked_sub(amount).ok_or(Error::ArithmeticError)?;
-    let new_supply = info
-        .total_supply
-        .checked_sub(amount)
-        .ok_or(Error::ArithmeticError)?;
-
-    storage::set_balance(env, token_index, &holder, new_balance);
-    info.total_supply = new_supply;
-    info.total_burned = info
+    let dead = dead_destination(env, token_index, holder, amount)?;
+    let new_supply = if dead.is_none() {
+        info.total_supply
+            .checked_sub(amount)
+            .ok_or(Error::ArithmeticError)?
+    } else {
+        info.total_supply
+    };
+    let total_burned = info
         .total_burned
         .checked_add(amount)
         .ok_or(Error::ArithmeticError)?;
-    info.burn_count = info
+    let burn_count = info
         .burn_count
         .checked_add(1)
         .ok_or(Error::ArithmeticError)?;
-    storage::set_token_info(env, token_index, &info);
+    storage::check_burn_counters(env, token_index, holder, burned_by, amount, dead.is_some())?;
+
+    if let Some((dead, dead_balance)) = dead {
+        credit_dead(env, token_index, &dead, dead_balance, amount)?;
+    }
+    storage::set_balance(env, token_index, holder, new_balance);
+    info.total_supply = new_supply;
+    info.total_burned = total_burned;
+    info.burn_count = burn_count;
+    storage::set_token_info(env, token_index, info);
 
-    // 8. Emit event with both admin and holder for auditability
-    storage::increment_burn_count(env, token_index);
-    storage::add_total_burned(env, token_index, amount);
+    storage::increment_burn_count(env, token_index)?;
+    storage::add_total_burned(env, token_index, amount)?;
+    storage::add_global_burn_count(env, 1)?;
+    storage::record_token_burn_stats(env, token_index, amount)?;
+    storage::credit_account_burned(env, token_index, holder, burned_by, amount)?;
+    storage::freeze_metadata_on_burn(env, token_index);
 
     // Record snapshots for historical queries
-    let _ = crate::snapshot::record_balance_snapshot(env, token_index, &holder, new_balance);
+    let _ = crate::snapshot::record_balance_snapshot(env, token_index, holder, new_balance);
     let _ = crate::snapshot::record_supply_snapshot(env, token_index, new_supply);
 
-    emit_admin_burn_event(env, token_index, &admin, &holder, amount, new_supply);
+    let record = BurnRecord {
+        token_index,
+        from: holder.clone(),
+        burned_by: burned_by.clone(),
+        amount,
+        timestamp: env.ledger().timestamp(),
+    };
+    storage::update_largest_burn(env, &record);
+    let record_index = storage::add_burn_record(env, &record)?;
+    if let (Some(id), Some(index)) = (correlation_id, record_index) {
+        storage::set_burn_correlation(env, id, index);
+    }
+
+    Ok(new_supply)
+}
+
+/// Send a burned `amount` wherever the token's [`BurnMode`] says.
+///
+/// Returns `true` when the caller should take `amount` off `total_supply`.
+/// In `SendToDead` mode the dead address is credited here instead.
+fn route_burned(
+    env: &Env,
+    token_index: u32,
+    holder: &Address,
+    amount: i128,
+) -> Result<bool, Error> {
+    match dead_destination(env, token_index, holder, amount)? {
+        Some((dead, dead_balance)) => {
+            credit_dead(env, token_index, &dead, dead_balance, amount)?;
+            Ok(false)
+        }
+        None => Ok(true),
+    }
+}
+
+/// Dead address and its post-burn balance for a `SendToDead` token, or
+/// `None` when the burn leaves supply.
+///
+/// Burning from the dead address itself is rejected: it would count as
+/// burned while moving nothing. Reads only, so callers can run it before
+/// their first write.
+fn dead_destination(
+    env: &Env,
+    token_index: u32,
+    holder: &Address,
+    amount: i128,
+) -> Result<Option<(Address, i128)>, Error> {
+    match storage::get_burn_mode(env, token_index) {
+        BurnMode::ReduceSupply => Ok(None),
+        BurnMode::SendToDead(dead) => {
+            if *holder == dead {
+                return Err(Error::InvalidParameters);
+            }
+            let dead_balance = storage::get_balance(env, token_index, &dead)
+                .checked_add(amount)
+                .ok_or(Error::ArithmeticError)?;
+            Ok(Some((dead, dead_balance)))
+        }
+    }
+}
+
+/// Credit a burn resolved by [`dead_destination`] to the dead address.
+fn credit_dead(
+    env: &Env,
+    token_index: u32,
+    dead: &Address,
+    dead_balance: i128,
+    amount: i128,
+) -> Result<(), Error> {
+    storage::add_dead_burned(env, token_index, amount)?;
+    storage::set_balance(env, token_index, dead, dead_balance);
     Ok(())
 }
 
@@ -116,6 +573,7 @@ pub fn batch_burn(
     burns: soroban_sdk::Vec<(Address, i128)>,
 ) -> Result<(), Error> {
     admin.require_auth();
+    storage::ensure_not_killed(env)?;
 
     let current_admin = storage::get_admin(env);
     if admin != current_admin {
@@ -136,13 +594,21 @@ pub fn batch_burn(
         return Err(Error::TokenPaused);
     }
 
+    // Compliance freeze check
+    if storage::is_token_frozen(env, token_index) {
+        return Err(Error::TokenFrozen);
+    }
+    storage::ensure_not_retired(env, token_index)?;
+
     // Single pass: validation and mutation combined for gas efficiency
     // Soroban automatically rolls back state changes if the transaction fails
     let mut total_burn: i128 = 0;
+    // Portion of total_burn that leaves supply (all of it unless SendToDead)
+    let mut supply_burn: i128 = 0;
     for i in 0..burns.len() {
         let (ref holder, amount) = burns.get(i).unwrap();
         validate_amount(amount)?;
-        validate_address(holder)?;
+        validate_address(env, holder)?;
 
         let balance = storage::get_balance(env, token_index, holder);
         if balance < amount {
@@ -150,20 +616,37 @@ pub fn batch_burn(
         }
         
         let new_balance = balance.checked_sub(amount).ok_or(Error::ArithmeticError)?;
+        if route_burned(env, token_index, holder, amount)? {
+            supply_burn = supply_burn
+                .checked_add(amount)
+                .ok_or(Error::ArithmeticError)?;
+        }
         storage::set_balance(env, token_index, holder, new_balance);
+        storage::record_token_burn_stats(env, token_index, amount)?;
+        storage::credit_account_burned(env, token_index, holder, &admin, amount)?;
+        let record = BurnRecord {
+            token_index,
+            from: holder.clone(),
+            burned_by: admin.clone(),
+            amount,
+            timestamp: env.ledger().timestamp(),
+        };
+        storage::update_largest_burn(env, &record);
+        storage::add_burn_record(env, &record)?;
         
         total_burn = total_burn
             .checked_add(amount)
             .ok_or(Error::ArithmeticError)?;
     }
 
-    if info.total_supply < total_burn {
+    if info.total_supply < supply_burn {
         return Err(Error::InsufficientBalance);
     }
+    check_burn_cap(&info, total_burn)?;
 
     let new_supply = info
         .total_supply
-        .checked_sub(total_burn)
+        .checked_sub(supply_burn)
         .ok_or(Error::ArithmeticError)?;
     info.total_supply = new_supply;
     info.total_burned = info
@@ -176,9 +659,11 @@ pub fn batch_burn(
         .ok_or(Error::ArithmeticError)?;
     storage::set_token_info(env, token_index, &info);
     storage::increment_burn_count(env, token_index)?;
-    storage::add_total_burned(env, token_index, total_burn);
+    storage::add_total_burned(env, token_index, total_burn)?;
+    storage::add_global_burn_count(env, burns.len())?;
+    storage::freeze_metadata_on_burn(env, token_index);
 
-    emit_batch_burn_event(
+    crate::events::emit_batch_burn(
         env,
         token_index,
         &admin,
@@ -189,108 +674,3376 @@ pub fn batch_burn(
     Ok(())
 }
 
-pub fn get_burn_count(env: &Env, token_index: u32) -> u32 {
-    storage::get_burn_count(env, token_index)
-}
+/// Best-effort variant of [`batch_burn`].
+///
+/// Authorization, batch size and token-level checks (unknown, paused or
+/// frozen token) still fail the whole call. Past that point each entry is
+/// applied independently through the single-burn path: an entry that fails
+/// (bad amount or address, insufficient balance, burn cap) is skipped and
+/// reported, while every other entry is committed.
+///
+/// This gives up `batch_burn`'s all-or-nothing guarantee; callers must
+/// inspect the report rather than assuming the batch applied as a unit.
+///
+/// Returns one `(input_index, outcome)` pair per entry, in input order.
+pub fn batch_burn_lenient(
+    env: &Env,
+    admin: Address,
+    token_index: u32,
+    burns: Vec<(Address, i128)>,
+) -> Result<Vec<(u32, BatchBurnOutcome)>, Error> {
+    admin.require_auth();
+    storage::ensure_not_killed(env)?;
 
-pub fn get_balance(env: &Env, token_index: u32, holder: &Address) -> i128 {
-    storage::get_balance(env, token_index, holder)
-}
+    let current_admin = storage::get_admin(env);
+    if admin != current_admin {
+        return Err(Error::Unauthorized);
+    }
 
-fn validate_amount(amount: i128) -> Result<(), Error> {
-    if amount <= 0 {
+    if burns.len() > MAX_BATCH_BURN {
+        return Err(Error::BatchTooLarge);
+    }
+    if burns.is_empty() {
         return Err(Error::InvalidParameters);
     }
-    Ok(())
-}
 
-fn validate_address(addr: &Address) -> Result<(), Error> {
-    let _ = addr;
-    Ok(())
-}
+    let mut info = storage::get_token_info(env, token_index).ok_or(Error::TokenNotFound)?;
 
-// ─────────────────────────────────────────────
-//  Event emission
-// ─────────────────────────────────────────────
+    // Token-level pause check
+    if storage::is_token_paused(env, token_index) {
+        return Err(Error::TokenPaused);
+    }
 
-/// Emit burn event (v1)
-///
-/// **Schema Version**: 1
-/// **Event Name**: burn_v1
-///
-/// **Topics** (indexed):
-/// - Event name: "burn_v1"
-/// - token_index: u32 - The token index
+    // Compliance freeze check
+    if storage::is_token_frozen(env, token_index) {
+        return Err(Error::TokenFrozen);
+    }
+    storage::ensure_not_retired(env, token_index)?;
+
+    let mut report = Vec::new(env);
+    let mut applied: u32 = 0;
+    let mut total_burn: i128 = 0;
+    for i in 0..burns.len() {
+        let (holder, amount) = burns.get(i).unwrap();
+        let outcome = validate_amount(amount)
+            .and_then(|_| validate_address(env, &holder))
+            .and_then(|_| {
+                apply_burn(env, token_index, &mut info, &holder, &admin, amount, None)
+            });
+
+        match outcome {
+            Ok(_) => {
+                applied += 1;
+                total_burn = total_burn
+                    .checked_add(amount)
+                    .ok_or(Error::ArithmeticError)?;
+                report.push_back((i, BatchBurnOutcome::Applied));
+            }
+            Err(err) => report.push_back((i, BatchBurnOutcome::Rejected(err.0))),
+        }
+    }
+
+    if applied > 0 {
+        crate::events::emit_batch_burn(
+            env,
+            token_index,
+            &admin,
+            applied,
+            total_burn,
+            info.total_supply,
+        );
+    }
+    Ok(report)
+}
+
+pub fn get_burn_count(env: &Env, token_index: u32) -> u32 {
+    storage::get_burn_count(env, token_index)
+}
+
+/// Burn totals, share burned, largest burn and last burn time for a token.
+pub fn get_burn_summary(env: &Env, token_index: u32) -> Result<BurnSummary, Error> {
+    let info = storage::get_token_info(env, token_index).ok_or(Error::TokenNotFound)?;
+
+    let issued = info
+        .initial_supply
+        .checked_add(storage::get_total_minted(env, token_index))
+        .ok_or(Error::ArithmeticError)?;
+    let burned_bps = if issued <= 0 {
+        0
+    } else {
+        // Divide first only when scaling would overflow
+        let bps = match info.total_burned.checked_mul(10_000) {
+            Some(scaled) => scaled / issued,
+            None => info.total_burned / (issued / 10_000),
+        };
+        bps.clamp(0, 10_000) as u32
+    };
+
+    Ok(BurnSummary {
+        total_burned: info.total_burned,
+        burn_count: info.burn_count,
+        burned_bps,
+        largest_burn: storage::get_token_largest_burn(env, token_index),
+        last_burn_at: storage::get_last_burn_at(env, token_index),
+    })
+}
+
+/// How much more of a token can still be burned.
 ///
-/// **Payload** (non-indexed):
-/// - caller: Address - The address that burned tokens
-/// - amount: i128 - The amount burned
-/// - new_supply: i128 - The new total supply after burn
+/// The lesser of `total_supply` and what is left under the `max_burn_bps`
+/// cap; just `total_supply` for an uncapped token. Never negative.
+pub fn get_remaining_burnable(env: &Env, token_index: u32) -> Result<i128, Error> {
+    let info = storage::get_token_info(env, token_index).ok_or(Error::TokenNotFound)?;
+
+    let remaining = match info.max_burn_bps {
+        Some(bps) => burn_cap(info.initial_supply, bps)
+            .saturating_sub(info.total_burned)
+            .min(info.total_supply),
+        None => info.total_supply,
+    };
+    Ok(remaining.max(0))
+}
+
+/// Total burned of a token over the trailing `window_secs`.
 ///
-/// **Schema Stability**: This schema is immutable. Any changes require a new version.
-fn emit_burn_event(env: &Env, token_index: u32, caller: &Address, amount: i128, new_supply: i128) {
-    env.events().publish(
-        (symbol_short!("burn_v1"), token_index),
-        (caller.clone(), amount, new_supply),
-    );
+/// Summed from the `DailyBurn` buckets, so the window is widened to whole
+/// UTC days: every day from the one containing `now - window_secs` through
+/// today counts. At most `MAX_VELOCITY_WINDOW_DAYS` days are read. Returns
+/// the raw total; divide by `window_secs` for a per-second rate.
+pub fn get_burn_velocity(env: &Env, token_index: u32, window_secs: u64) -> Result<i128, Error> {
+    storage::get_token_info(env, token_index).ok_or(Error::TokenNotFound)?;
+    if window_secs == 0 {
+        return Ok(0);
+    }
+
+    let now = env.ledger().timestamp();
+    let today = storage::day_index(now);
+    let first = storage::day_index(now.saturating_sub(window_secs))
+        .max(today.saturating_sub(MAX_VELOCITY_WINDOW_DAYS - 1));
+
+    let mut total: i128 = 0;
+    for day in first..=today {
+        total = total
+            .checked_add(storage::get_daily_burn(env, token_index, day))
+            .ok_or(Error::ArithmeticError)?;
+    }
+    Ok(total)
 }
 
-/// Emit admin burn event (v1)
+/// Whether `account` has been credited with at least `min_amount` burned of
+/// a token.
 ///
-/// **Schema Version**: 1
-/// **Event Name**: adm_bn_v1
+/// Reads the `AccountBurned` counter, so which account an admin burn counts
+/// for follows the attribution policy. A non-positive `min_amount` proves
+/// nothing and returns `false`, as does an unknown token.
+pub fn verify_burn(env: &Env, token_index: u32, account: &Address, min_amount: i128) -> bool {
+    if min_amount <= 0 || storage::get_token_info(env, token_index).is_none() {
+        return false;
+    }
+    storage::get_account_burned(env, token_index, account) >= min_amount
+}
+
+/// Normalize burn-related `TokenInfo` fields on entries written before they
+/// existed (admin only).
 ///
-/// **Topics** (indexed):
-/// - Event name: "adm_bn_v1"
-/// - token_index: u32 - The token index
+/// Scans up to `limit` tokens (clamped to `MAX_REINITIALIZE_BATCH`) from
+/// index `start` and rewrites only entries that need it:
+/// - a negative `total_burned` is replaced by the per-token `TotalBurned`
+///   counter maintained on every burn
+/// - a zero `burn_count` with burns on record becomes the `BurnCount` counter
+///   (at least 1)
+/// - a non-positive `initial_supply` is reconstructed from the supply
+///   invariant `total_supply + total_burned - total_minted` when positive
 ///
-/// **Payload** (non-indexed):
-/// - admin: Address - The admin who initiated the burn
-/// - holder: Address - The address whose tokens were burned
-/// - amount: i128 - The amount burned
-/// - new_supply: i128 - The new total supply after burn
+/// Returns how many entries were rewritten. Safe to re-run.
+pub fn reinitialize_burn_fields(
+    env: &Env,
+    start: u32,
+    limit: u32,
+    caller: Address,
+) -> Result<u32, Error> {
+    caller.require_auth();
+    storage::ensure_not_killed(env)?;
+    if caller != storage::try_get_admin(env)? {
+        return Err(Error::Unauthorized);
+    }
+
+    let indices = storage::valid_token_indices(env);
+    let begin = start.max(indices.start);
+    let end = begin
+        .saturating_add(limit.min(MAX_REINITIALIZE_BATCH))
+        .min(indices.end);
+
+    let mut repaired = 0u32;
+    for token_index in begin..end {
+        let Some(mut info) = storage::get_token_info(env, token_index) else {
+            continue;
+        };
+        let original = info.clone();
+
+        if info.total_burned < 0 {
+            info.total_burned = storage::get_total_burned(env, token_index).max(0);
+        }
+        if info.burn_count == 0 && info.total_burned > 0 {
+            info.burn_count = storage::get_burn_count(env, token_index).max(1);
+        }
+        if info.initial_supply <= 0 {
+            let reconstructed = info
+                .total_supply
+                .checked_add(info.total_burned)
+                .and_then(|v| v.checked_sub(storage::get_total_minted(env, token_index)))
+                .ok_or(Error::ArithmeticError)?;
+            if reconstructed > 0 {
+                info.initial_supply = reconstructed;
+            }
+        }
+
+        if info != original {
+            storage::set_token_info(env, token_index, &info);
+            storage::set_token_info_by_address(env, &info.address, &info);
+            repaired += 1;
+        }
+    }
+
+    Ok(repaired)
+}
+
+/// Carry a token's burn history over to its re-registered successor and
+/// retire the original (admin only).
 ///
-/// **Schema Stability**: This schema is immutable. Any changes require a new version.
-fn emit_admin_burn_event(
+/// Adds `from_index`'s `total_burned` and `burn_count` to `to_index`, both
+/// in `TokenInfo` and in the per-token burn keys; the old token keeps its
+/// own figures for the record. The inherited amount never left the new
+/// token's supply, so it is tracked separately for the supply invariant.
+/// Global burn totals are untouched since no new burn took place.
+pub fn migrate_token_aggregates(
     env: &Env,
-    token_index: u32,
-    admin: &Address,
-    holder: &Address,
-    amount: i128,
-    new_supply: i128,
-) {
-    env.events().publish(
-        (symbol_short!("adm_bn_v1"), token_index),
-        (admin.clone(), holder.clone(), amount, new_supply),
+    from_index: u32,
+    to_index: u32,
+    caller: Address,
+) -> Result<(), Error> {
+    storage::require_admin(env, &caller)?;
+    storage::ensure_not_killed(env)?;
+    if from_index == to_index {
+        return Err(Error::InvalidParameters);
+    }
+
+    let old = storage::get_token_info(env, from_index).ok_or(Error::TokenNotFound)?;
+    let mut new = storage::get_token_info(env, to_index).ok_or(Error::TokenNotFound)?;
+    storage::ensure_not_retired(env, from_index)?;
+    storage::ensure_not_retired(env, to_index)?;
+
+    new.total_burned = new
+        .total_burned
+        .checked_add(old.total_burned)
+        .ok_or(Error::ArithmeticError)?;
+    new.burn_count = new
+        .burn_count
+        .checked_add(old.burn_count)
+        .ok_or(Error::ArithmeticError)?;
+    storage::set_token_info(env, to_index, &new);
+    storage::set_token_info_by_address(env, &new.address, &new);
+
+    storage::add_total_burned(env, to_index, storage::get_total_burned(env, from_index))?;
+    storage::add_burn_count(env, to_index, storage::get_burn_count(env, from_index))?;
+    storage::add_inherited_burned(env, to_index, old.total_burned)?;
+
+    storage::set_token_retired(env, from_index);
+    crate::events::emit_token_retired(env, from_index, &caller);
+    crate::events::emit_aggregates_migrated(
+        env,
+        from_index,
+        to_index,
+        old.total_burned,
+        old.burn_count,
     );
+    Ok(())
+}
+
+/// Return up to `limit` of the most recent burn records, newest first.
+///
+/// Walks the global log backwards from `BurnRecordCount`, so the cost is
+/// bounded by `limit` (clamped to `MAX_RECENT_BURN_RECORDS`) rather than
+/// the size of the log.
+pub fn get_burn_records_recent(env: &Env, limit: u32) -> Vec<BurnRecord> {
+    let mut records = Vec::new(env);
+    let mut cursor = storage::get_burn_record_count(env);
+    let limit = limit.min(MAX_RECENT_BURN_RECORDS);
+
+    while cursor > 0 && records.len() < limit {
+        cursor -= 1;
+        if let Some(record) = storage::get_burn_record(env, cursor) {
+            records.push_back(record);
+        }
+    }
+
+    records
+}
+
+/// Amount of burn-log record `index` in whole tokens of the burned token.
+///
+/// Computed on read from the token's `decimals`; the remainder is dropped,
+/// so burns smaller than one whole token read as 0. `None` when the record
+/// does not exist.
+pub fn get_burn_display_amount(env: &Env, index: u32) -> Option<i128> {
+    let record = storage::get_burn_record(env, index)?;
+    let info = storage::get_token_info(env, record.token_index)?;
+    crate::units::from_base_units(record.amount, info.decimals, RoundingMode::Floor).ok()
 }
 
-/// Emit batch burn event (v1)
+/// Return one offset-paginated page of the global burn log, oldest first.
 ///
-/// **Schema Version**: 1
-/// **Event Name**: bch_bn_v1
+/// `limit` is clamped to `MAX_RECENT_BURN_RECORDS`; `next` is `None` once
+/// the page reaches the newest record.
+pub fn get_burn_records_page(env: &Env, start: u32, limit: u32) -> BurnRecordPage {
+    let total = storage::get_burn_record_count(env);
+    let begin = start.min(total);
+    let end = begin
+        .saturating_add(limit.min(MAX_RECENT_BURN_RECORDS))
+        .min(total);
+
+    let mut items = Vec::new(env);
+    for index in begin..end {
+        if let Some(record) = storage::get_burn_record(env, index) {
+            items.push_back(record);
+        }
+    }
+
+    BurnRecordPage {
+        items,
+        next: if end < total { Some(end) } else { None },
+        total,
+    }
+}
+
+/// Logged burns with `amount >= min_amount`, oldest first.
 ///
-/// **Topics** (indexed):
-/// - Event name: "bch_bn_v1"
-/// - token_index: u32 - The token index
+/// `start` is the global log index to scan from. At most
+/// `MAX_LARGE_BURN_SCAN` log entries are read per call and `limit` is
+/// clamped to `MAX_RECENT_BURN_RECORDS`, so a page can come back short or
+/// empty; `next` is the log index to resume from and `None` once the scan
+/// reaches the newest record.
+pub fn get_large_burns(env: &Env, min_amount: i128, start: u32, limit: u32) -> BurnRecordPage {
+    let total = storage::get_burn_record_count(env);
+    let limit = limit.min(MAX_RECENT_BURN_RECORDS);
+    let mut cursor = start.min(total);
+    let end = cursor.saturating_add(MAX_LARGE_BURN_SCAN).min(total);
+
+    let mut items = Vec::new(env);
+    while cursor < end && items.len() < limit {
+        if let Some(record) = storage::get_burn_record(env, cursor) {
+            if record.amount >= min_amount {
+                items.push_back(record);
+            }
+        }
+        cursor += 1;
+    }
+
+    BurnRecordPage {
+        items,
+        next: if cursor < total { Some(cursor) } else { None },
+        total,
+    }
+}
+
+/// A token's mints and burns merged into one chronological history.
 ///
-/// **Payload** (non-indexed):
-/// - admin: Address - The admin who initiated the batch burn
-/// - count: u32 - The number of burns in the batch
-/// - total_burned: i128 - The total amount burned across all burns
-/// - new_supply: i128 - The new total supply after batch burn
+/// Reads the token's ledger index, which records each mint and burn log
+/// position as it is written, so the cost is bounded by `limit` rather than
+/// the size of either global log. Each event costs two reads (the index
+/// slot and the record it points at), so `limit` is clamped to
+/// `MAX_TOKEN_LEDGER_PAGE` to stay within the per-call footprint. `start`
+/// is an offset into the token's events.
+pub fn get_token_ledger(env: &Env, token_index: u32, start: u32, limit: u32) -> Vec<SupplyEvent> {
+    let mut events = Vec::new(env);
+    let total = storage::get_token_ledger_count(env, token_index);
+    let begin = start.min(total);
+    let end = begin
+        .saturating_add(limit.min(MAX_TOKEN_LEDGER_PAGE))
+        .min(total);
+
+    for slot in begin..end {
+        let event = match storage::get_token_ledger_entry(env, token_index, slot) {
+            Some(SupplyEventRef::Mint(index)) => {
+                storage::get_mint_record(env, index).map(SupplyEvent::Mint)
+            }
+            Some(SupplyEventRef::Burn(index)) => {
+                storage::get_burn_record(env, index).map(SupplyEvent::Burn)
+            }
+            None => None,
+        };
+        if let Some(event) = event {
+            events.push_back(event);
+        }
+    }
+
+    events
+}
+
+/// A token's logged burns on one UTC day, oldest first.
 ///
-/// **Schema Stability**: This schema is immutable. Any changes require a new version.
-fn emit_batch_burn_event(
+/// Starts at the day's `BurnDayIndex` entry (or at `start`, if later)
+/// instead of scanning from the beginning of the log, then walks forward
+/// skipping other tokens' records until the day ends. At most
+/// `MAX_DAY_FEED_SCAN` log entries are read per call and `limit` is clamped
+/// to `MAX_RECENT_BURN_RECORDS`, so on a busy log a page can come back short
+/// or empty; `next` is the log index to resume from and `None` once the
+/// day's records are exhausted.
+pub fn get_burns_for_day(
     env: &Env,
     token_index: u32,
-    admin: &Address,
-    count: u32,
-    total_burned: i128,
-    new_supply: i128,
-) {
-    env.events().publish(
-        (symbol_short!("bch_bn_v1"), token_index),
-        (admin.clone(), count, total_burned, new_supply),
-    );
+    day: u32,
+    start: u32,
+    limit: u32,
+) -> BurnRecordPage {
+    let total = storage::get_burn_record_count(env);
+    let mut items = Vec::new(env);
+    let Some(first) = storage::get_burn_day_index(env, token_index, day) else {
+        return BurnRecordPage {
+            items,
+            next: None,
+            total,
+        };
+    };
+
+    let limit = limit.min(MAX_RECENT_BURN_RECORDS);
+    let mut cursor = first.max(start).min(total);
+    let end = cursor.saturating_add(MAX_DAY_FEED_SCAN).min(total);
+    let mut day_ended = false;
+    while cursor < end && items.len() < limit {
+        if let Some(record) = storage::get_burn_record(env, cursor) {
+            if storage::day_index(record.timestamp) > day {
+                day_ended = true;
+                break;
+            }
+            if record.token_index == token_index {
+                items.push_back(record);
+            }
+        }
+        cursor += 1;
+    }
+
+    BurnRecordPage {
+        items,
+        next: if !day_ended && cursor < total {
+            Some(cursor)
+        } else {
+            None
+        },
+        total,
+    }
+}
+
+/// Burn-log record tagged with `correlation_id`, if any.
+pub fn get_burn_by_correlation(env: &Env, correlation_id: u64) -> Option<BurnRecord> {
+    storage::get_burn_correlation(env, correlation_id)
+        .and_then(|index| storage::get_burn_record(env, index))
+}
+
+/// Sum `total_burned` across the tokens at `tokens`.
+///
+/// Each entry costs two keyed reads (the address-to-index lookup and the
+/// burn tracker), so the cost is linear in the input, which is capped at
+/// `MAX_TOTAL_BURNED_QUERY`. Addresses with no registered token contribute
+/// 0; duplicates are counted each time.
+pub fn get_total_burned_multi(env: &Env, tokens: &Vec<Address>) -> Result<i128, Error> {
+    if tokens.len() > MAX_TOTAL_BURNED_QUERY {
+        return Err(Error::BatchTooLarge);
+    }
+
+    let mut total: i128 = 0;
+    for token_address in tokens.iter() {
+        let Some(token_index) = storage::get_token_index_by_address(env, &token_address) else {
+            continue;
+        };
+        total = total
+            .checked_add(storage::get_total_burned(env, token_index))
+            .ok_or(Error::ArithmeticError)?;
+    }
+    Ok(total)
+}
+
+pub fn get_balance(env: &Env, token_index: u32, holder: &Address) -> i128 {
+    storage::get_balance(env, token_index, holder)
+}
+
+/// Lifetime burn allowance for a token: `initial_supply * bps / 10_000`,
+/// computed without overflowing for large supplies.
+pub(crate) fn burn_cap(initial_supply: i128, max_burn_bps: u32) -> i128 {
+    let bps = max_burn_bps as i128;
+    (initial_supply / 10_000) * bps + (initial_supply % 10_000) * bps / 10_000
+}
+
+/// Reject a burn of `amount` that would take `total_burned` past the
+/// token's `max_burn_bps` cap.
+fn check_burn_cap(info: &crate::types::TokenInfo, amount: i128) -> Result<(), Error> {
+    if let Some(bps) = info.max_burn_bps {
+        let burned = info
+            .total_burned
+            .checked_add(amount)
+            .ok_or(Error::ArithmeticError)?;
+        if burned > burn_cap(info.initial_supply, bps) {
+            return Err(Error::BurnCapReached);
+        }
+    }
+    Ok(())
+}
+
+/// Reject zero and negative burn amounts with `Error::InvalidParameters`.
+///
+/// Separate from the `max_burn_bps` cap check: a zero burn is rejected
+/// whatever the token's configuration.
+fn validate_amount(amount: i128) -> Result<(), Error> {
+    if amount <= 0 {
+        return Err(Error::InvalidParameters);
+    }
+    Ok(())
+}
+
+/// Reject a burn `from` that cannot hold a real balance.
+///
+/// Soroban has no all-zero address, so the factory contract itself is the
+/// one nonsensical holder to guard against.
+fn validate_address(env: &Env, addr: &Address) -> Result<(), Error> {
+    if *addr == env.current_contract_address() {
+        return Err(Error::InvalidParameters);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    /// Tests for checked arithmetic on running aggregates.
+    mod aggregate_overflow {
+        use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+        use crate::storage;
+        use crate::test_helpers::setup_with_token;
+        use crate::types::{DataKey, Error};
+        use crate::TokenFactoryClient;
+
+        fn create(env: &Env, client: &TokenFactoryClient, creator: &Address) -> Result<(), Error> {
+            match client.try_create_token(
+                creator,
+                &String::from_str(env, "Aggregate"),
+                &String::from_str(env, "AGG"),
+                &7u32,
+                &1_000_000i128,
+                &None,
+                &70_000_000i128,
+            ) {
+                Ok(_) => Ok(()),
+                Err(Ok(err)) => Err(err),
+                Err(Err(_)) => panic!("unexpected host error"),
+            }
+        }
+
+        fn assert_burn_unapplied(env: &Env, client: &TokenFactoryClient, creator: &Address) {
+            let info = client.get_token_info(&0);
+            assert_eq!(info.total_supply, 1_000_000);
+            assert_eq!(info.total_burned, 0);
+            let balance =
+                env.as_contract(&client.address, || storage::get_balance(env, 0, creator));
+            assert_eq!(balance, 1_000_000);
+        }
+
+        #[test]
+        fn test_collected_fee_overflow_is_typed() {
+            let env = Env::default();
+            let (client, _, _, _) = setup_with_token(&env);
+
+            env.as_contract(&client.address, || {
+                storage::set_collected_fees(&env, i128::MAX - 1);
+            });
+
+            assert_eq!(
+                create(&env, &client, &Address::generate(&env)),
+                Err(Error::ArithmeticError)
+            );
+        }
+
+        #[test]
+        fn test_total_burned_overflow_is_typed() {
+            let env = Env::default();
+            let (client, _, creator, token_index) = setup_with_token(&env);
+
+            env.as_contract(&client.address, || {
+                env.storage()
+                    .persistent()
+                    .set(&DataKey::TotalBurned(token_index), &(i128::MAX - 10));
+            });
+
+            assert_eq!(
+                client.try_burn(&creator, &token_index, &11),
+                Err(Ok(Error::ArithmeticError))
+            );
+            assert_burn_unapplied(&env, &client, &creator);
+        }
+
+        #[test]
+        fn test_daily_burn_overflow_is_typed() {
+            let env = Env::default();
+            let (client, _, creator, token_index) = setup_with_token(&env);
+
+            env.as_contract(&client.address, || {
+                let day = storage::day_index(env.ledger().timestamp());
+                env.storage()
+                    .persistent()
+                    .set(&DataKey::DailyBurn(token_index, day), &i128::MAX);
+            });
+
+            assert_eq!(
+                client.try_burn(&creator, &token_index, &1),
+                Err(Ok(Error::ArithmeticError))
+            );
+            assert_burn_unapplied(&env, &client, &creator);
+        }
+    }
+
+    /// Tests for best-effort batch burns (`batch_burn_lenient`).
+    mod batch_burn_lenient {
+        use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+
+        use crate::storage;
+        use crate::test_helpers::setup_with_token;
+        use crate::types::BatchBurnOutcome::{Applied, Rejected};
+        use crate::types::Error;
+        use crate::TokenFactoryClient;
+
+        /// Set up a factory with one token and two funded holders.
+        /// Returns (client, admin, holder_a, holder_b, token_index).
+        fn setup(env: &Env) -> (TokenFactoryClient<'_>, Address, Address, Address, u32) {
+            let (client, admin, creator, token_index) = setup_with_token(env);
+
+            let holder_a = Address::generate(env);
+            let holder_b = Address::generate(env);
+            client.mint(&creator, &token_index, &holder_a, &1_000);
+            client.mint(&creator, &token_index, &holder_b, &1_000);
+
+            (client, admin, holder_a, holder_b, token_index)
+        }
+
+        fn balance_of(
+            env: &Env,
+            client: &TokenFactoryClient,
+            token_index: u32,
+            holder: &Address,
+        ) -> i128 {
+            env.as_contract(&client.address, || {
+                storage::get_balance(env, token_index, holder)
+            })
+        }
+
+        #[test]
+        fn test_mixed_batch_reports_per_entry() {
+            let env = Env::default();
+            let (client, admin, holder_a, holder_b, token_index) = setup(&env);
+            let empty = Address::generate(&env);
+            let supply_before = client.get_token_info(&token_index).total_supply;
+
+            let burns = vec![
+                &env,
+                (holder_a.clone(), 400),
+                (empty.clone(), 10),
+                (holder_b.clone(), -5),
+                (holder_b.clone(), 300),
+                (holder_a.clone(), 700),
+            ];
+            let report = client.batch_burn_lenient(&admin, &token_index, &burns);
+
+            assert_eq!(
+                report,
+                vec![
+                    &env,
+                    (0u32, Applied),
+                    (1, Rejected(Error::InsufficientBalance.0)),
+                    (2, Rejected(Error::InvalidParameters.0)),
+                    (3, Applied),
+                    (4, Rejected(Error::InsufficientBalance.0)),
+                ]
+            );
+
+            assert_eq!(balance_of(&env, &client, token_index, &holder_a), 600);
+            assert_eq!(balance_of(&env, &client, token_index, &holder_b), 700);
+
+            let info = client.get_token_info(&token_index);
+            assert_eq!(info.total_supply, supply_before - 700);
+            assert_eq!(info.total_burned, 700);
+            assert_eq!(info.burn_count, 2);
+            assert!(client.verify_supply_invariant(&token_index));
+        }
+
+        #[test]
+        fn test_all_entries_failing_applies_nothing() {
+            let env = Env::default();
+            let (client, admin, holder_a, _, token_index) = setup(&env);
+
+            let burns = vec![&env, (holder_a.clone(), 0), (holder_a.clone(), 5_000)];
+            let report = client.batch_burn_lenient(&admin, &token_index, &burns);
+
+            assert_eq!(
+                report,
+                vec![
+                    &env,
+                    (0u32, Rejected(Error::InvalidParameters.0)),
+                    (1, Rejected(Error::InsufficientBalance.0)),
+                ]
+            );
+            assert_eq!(client.get_token_info(&token_index).total_burned, 0);
+            assert_eq!(balance_of(&env, &client, token_index, &holder_a), 1_000);
+        }
+
+        #[test]
+        fn test_entry_failing_after_validation_leaves_no_writes() {
+            let env = Env::default();
+            let (client, admin, holder_a, _, _) = setup(&env);
+
+            // A token whose burn total sits just below i128::MAX while its
+            // holder still has balance to burn
+            client.set_initial_supply_bounds(&admin, &0, &i128::MAX);
+            let creator = Address::generate(&env);
+            client.create_token(
+                &creator,
+                &String::from_str(&env, "HeavyToken"),
+                &String::from_str(&env, "HVY"),
+                &7u32,
+                &i128::MAX,
+                &None,
+                &70_000_000i128,
+            );
+            client.burn(&creator, &1, &(i128::MAX - 5));
+            client.mint(&creator, &1, &holder_a, &1_000);
+
+            // Passes the balance and supply checks, then overflows total_burned
+            let burns = vec![&env, (holder_a.clone(), 10), (holder_a.clone(), 3)];
+            let report = client.batch_burn_lenient(&admin, &1, &burns);
+
+            assert_eq!(
+                report,
+                vec![
+                    &env,
+                    (0u32, Rejected(Error::ArithmeticError.0)),
+                    (1, Applied)
+                ]
+            );
+            assert_eq!(balance_of(&env, &client, 1, &holder_a), 997);
+
+            let info = client.get_token_info(&1);
+            assert_eq!(info.total_burned, i128::MAX - 2);
+            assert_eq!(info.burn_count, 2);
+            // Token 1 was created last, so it holds the shared token address
+            let token_address = client.get_token_info(&1).address;
+            assert_eq!(
+                client.get_total_burned_multi(&vec![&env, token_address]),
+                i128::MAX - 2
+            );
+            assert_eq!(client.get_burn_count(&1), 2);
+            assert_eq!(client.get_burn_record_count(), 2);
+        }
+
+        #[test]
+        fn test_whole_call_errors_still_apply() {
+            let env = Env::default();
+            let (client, admin, holder_a, _, token_index) = setup(&env);
+
+            let burns = vec![&env, (holder_a.clone(), 1)];
+            assert_eq!(
+                client.try_batch_burn_lenient(&holder_a, &token_index, &burns),
+                Err(Ok(Error::Unauthorized))
+            );
+            assert_eq!(
+                client.try_batch_burn_lenient(&admin, &token_index, &vec![&env]),
+                Err(Ok(Error::InvalidParameters))
+            );
+            assert_eq!(
+                client.try_batch_burn_lenient(&admin, &99, &burns),
+                Err(Ok(Error::TokenNotFound))
+            );
+        }
+    }
+
+    /// Tests rejecting burns whose `from` is the factory contract itself.
+    mod burn_address_guard {
+        use soroban_sdk::{testutils::Address as _, vec, Env};
+
+        use crate::test_helpers::setup_with_token;
+        use crate::types::Error;
+
+        #[test]
+        fn test_normal_holder_can_burn() {
+            let env = Env::default();
+            let (client, admin, creator, token_index) = setup_with_token(&env);
+
+            client.burn(&creator, &token_index, &100);
+            client.admin_burn(&admin, &token_index, &creator, &100);
+
+            assert_eq!(client.get_token_info(&token_index).total_burned, 200);
+        }
+
+        #[test]
+        fn test_contract_address_from_rejected() {
+            let env = Env::default();
+            let (client, admin, _, token_index) = setup_with_token(&env);
+            let factory = client.address.clone();
+
+            assert_eq!(
+                client.try_burn(&factory, &token_index, &1),
+                Err(Ok(Error::InvalidParameters))
+            );
+            assert_eq!(
+                client.try_admin_burn(&admin, &token_index, &factory, &1),
+                Err(Ok(Error::InvalidParameters))
+            );
+            assert_eq!(
+                client.try_batch_burn(&admin, &token_index, &vec![&env, (factory.clone(), 1i128)]),
+                Err(Ok(Error::InvalidParameters))
+            );
+
+            let info = client.get_token_info(&token_index);
+            assert_eq!(info.total_burned, 0);
+            assert_eq!(client.get_burn_record_count(), 0);
+        }
+    }
+
+    /// Tests for the burn attribution policy behind `get_account_burned` and
+    /// `get_account_burned_for_token`.
+    mod burn_attribution {
+        use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+
+        use crate::test_helpers::setup_with_token;
+        use crate::types::Error;
+        use crate::TokenFactoryClient;
+
+        /// Set up a factory with one token and a funded holder.
+        /// Returns (client, admin, holder, token_index).
+        fn setup(env: &Env) -> (TokenFactoryClient<'_>, Address, Address, u32) {
+            let (client, admin, creator, token_index) = setup_with_token(env);
+
+            let holder = Address::generate(env);
+            client.mint(&creator, &token_index, &holder, &10_000);
+
+            (client, admin, holder, token_index)
+        }
+
+        #[test]
+        fn test_default_policy_credits_holder() {
+            let env = Env::default();
+            let (client, admin, holder, token_index) = setup(&env);
+
+            assert!(!client.get_burn_credit_executor());
+            client.admin_burn(&admin, &token_index, &holder, &400);
+
+            assert_eq!(client.get_account_burned(&token_index, &holder), 400);
+            assert_eq!(client.get_account_burned(&token_index, &admin), 0);
+        }
+
+        #[test]
+        fn test_executor_policy_credits_admin() {
+            let env = Env::default();
+            let (client, admin, holder, token_index) = setup(&env);
+
+            client.set_burn_credit_executor(&admin, &true);
+            assert!(client.get_burn_credit_executor());
+
+            client.admin_burn(&admin, &token_index, &holder, &400);
+            client.batch_burn(&admin, &token_index, &vec![&env, (holder.clone(), 100i128)]);
+
+            assert_eq!(client.get_account_burned(&token_index, &admin), 500);
+            assert_eq!(client.get_account_burned(&token_index, &holder), 0);
+        }
+
+        #[test]
+        fn test_self_burn_credits_burner_under_both_policies() {
+            let env = Env::default();
+            let (client, admin, holder, token_index) = setup(&env);
+
+            client.burn(&holder, &token_index, &100);
+            client.set_burn_credit_executor(&admin, &true);
+            client.burn(&holder, &token_index, &50);
+
+            assert_eq!(client.get_account_burned(&token_index, &holder), 150);
+        }
+
+        #[test]
+        fn test_policy_change_requires_admin() {
+            let env = Env::default();
+            let (client, _, holder, _) = setup(&env);
+
+            assert_eq!(
+                client.try_set_burn_credit_executor(&holder, &true),
+                Err(Ok(Error::Unauthorized))
+            );
+            assert!(!client.get_burn_credit_executor());
+        }
+
+        #[test]
+        fn test_totals_accumulate_across_burns() {
+            let env = Env::default();
+            let (client, admin, holder, token_index) = setup(&env);
+
+            for amount in [10i128, 20, 30] {
+                client.burn(&holder, &token_index, &amount);
+            }
+            client.burn_with_correlation(&holder, &token_index, &40, &1);
+            client.admin_burn(&admin, &token_index, &holder, &50);
+            client.batch_burn_lenient(&admin, &token_index, &vec![&env, (holder.clone(), 60i128)]);
+
+            assert_eq!(client.get_account_burned(&token_index, &holder), 210);
+        }
+
+        #[test]
+        fn test_totals_are_per_token() {
+            let env = Env::default();
+            let (client, _, holder, token_index) = setup(&env);
+            let creator = client.get_token_info(&token_index).creator;
+
+            client.create_token(
+                &creator,
+                &String::from_str(&env, "OtherToken"),
+                &String::from_str(&env, "OTH"),
+                &7u32,
+                &1_000i128,
+                &None,
+                &70_000_000i128,
+            );
+            client.mint(&creator, &1, &holder, &500);
+
+            client.burn(&holder, &token_index, &100);
+            client.burn(&holder, &1, &25);
+            client.burn(&holder, &1, &25);
+
+            assert_eq!(client.get_account_burned(&token_index, &holder), 100);
+            assert_eq!(client.get_account_burned(&1, &holder), 50);
+        }
+
+        #[test]
+        fn test_for_token_resolves_address() {
+            let env = Env::default();
+            let (client, _, holder, token_index) = setup(&env);
+            let token_address = client.get_token_info(&token_index).address;
+
+            client.burn(&holder, &token_index, &100);
+            client.burn(&holder, &token_index, &50);
+
+            assert_eq!(
+                client.get_account_burned_for_token(&token_address, &holder),
+                150
+            );
+            assert_eq!(
+                client.get_account_burned_for_token(&Address::generate(&env), &holder),
+                0
+            );
+        }
+    }
+
+    /// Tests for the lifetime burn cap (`max_burn_bps`).
+    mod burn_cap {
+        use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+
+        use crate::test_helpers::setup_with_token;
+        use crate::types::Error;
+        use crate::TokenFactoryClient;
+
+        /// Set up a factory with one token of supply 1_000_000 capped at 50%.
+        /// Returns (client, admin, creator, token_index).
+        fn setup(env: &Env) -> (TokenFactoryClient<'_>, Address, Address, u32) {
+            let (client, admin, creator, token_index) = setup_with_token(env);
+
+            client.set_max_burn_bps(&creator, &token_index, &5_000);
+
+            (client, admin, creator, token_index)
+        }
+
+        #[test]
+        fn test_burn_up_to_cap_then_one_beyond() {
+            let env = Env::default();
+            let (client, _, creator, token_index) = setup(&env);
+
+            client.burn(&creator, &token_index, &499_999);
+            client.burn(&creator, &token_index, &1);
+            assert_eq!(client.get_token_info(&token_index).total_burned, 500_000);
+
+            assert_eq!(
+                client.try_burn(&creator, &token_index, &1),
+                Err(Ok(Error::BurnCapReached))
+            );
+            assert_eq!(client.get_token_info(&token_index).total_burned, 500_000);
+        }
+
+        #[test]
+        fn test_batch_burn_respects_cap() {
+            let env = Env::default();
+            let (client, admin, creator, token_index) = setup(&env);
+
+            let over = vec![
+                &env,
+                (creator.clone(), 300_000i128),
+                (creator.clone(), 200_001i128),
+            ];
+            assert_eq!(
+                client.try_batch_burn(&admin, &token_index, &over),
+                Err(Ok(Error::BurnCapReached))
+            );
+            assert_eq!(client.get_token_info(&token_index).total_burned, 0);
+        }
+
+        #[test]
+        fn test_uncapped_token_unaffected() {
+            let env = Env::default();
+            let (client, _, creator, _) = setup(&env);
+            client.create_token(
+                &creator,
+                &String::from_str(&env, "Free"),
+                &String::from_str(&env, "FRE"),
+                &7u32,
+                &1_000i128,
+                &None,
+                &70_000_000i128,
+            );
+
+            client.burn(&creator, &1, &1_000);
+            assert_eq!(client.get_token_info(&1).max_burn_bps, None);
+            assert_eq!(client.get_token_info(&1).total_supply, 0);
+        }
+
+        #[test]
+        fn test_cap_can_only_tighten() {
+            let env = Env::default();
+            let (client, _, creator, token_index) = setup(&env);
+            let stranger = Address::generate(&env);
+
+            assert_eq!(
+                client.try_set_max_burn_bps(&creator, &token_index, &5_001),
+                Err(Ok(Error::InvalidParameters))
+            );
+            assert_eq!(
+                client.try_set_max_burn_bps(&stranger, &token_index, &1_000),
+                Err(Ok(Error::Unauthorized))
+            );
+
+            client.set_max_burn_bps(&creator, &token_index, &1_000);
+            assert_eq!(
+                client.get_token_info(&token_index).max_burn_bps,
+                Some(1_000)
+            );
+            assert_eq!(
+                client.try_burn(&creator, &token_index, &100_001),
+                Err(Ok(Error::BurnCapReached))
+            );
+        }
+
+        #[test]
+        fn test_remaining_burnable_tracks_cap() {
+            let env = Env::default();
+            let (client, _, creator, token_index) = setup(&env);
+
+            assert_eq!(client.get_remaining_burnable(&token_index), 500_000);
+
+            client.burn(&creator, &token_index, &200_000);
+            assert_eq!(client.get_remaining_burnable(&token_index), 300_000);
+
+            client.burn(&creator, &token_index, &300_000);
+            assert_eq!(client.get_remaining_burnable(&token_index), 0);
+            assert_eq!(
+                client.try_burn(&creator, &token_index, &1),
+                Err(Ok(Error::BurnCapReached))
+            );
+        }
+
+        #[test]
+        fn test_remaining_burnable_uncapped_is_supply() {
+            let env = Env::default();
+            let (client, _, creator, _) = setup(&env);
+
+            client.create_token(
+                &creator,
+                &String::from_str(&env, "Uncapped"),
+                &String::from_str(&env, "UNC"),
+                &7u32,
+                &1_000i128,
+                &None,
+                &70_000_000i128,
+            );
+            assert_eq!(client.get_remaining_burnable(&1), 1_000);
+
+            client.burn(&creator, &1, &400);
+            assert_eq!(client.get_remaining_burnable(&1), 600);
+
+            assert_eq!(
+                client.try_get_remaining_burnable(&99),
+                Err(Ok(Error::TokenNotFound))
+            );
+        }
+
+        #[test]
+        fn test_remaining_burnable_is_lesser_of_supply_and_cap() {
+            let env = Env::default();
+            let (client, _, creator, _) = setup(&env);
+
+            client.create_token(
+                &creator,
+                &String::from_str(&env, "FullCap"),
+                &String::from_str(&env, "FUL"),
+                &7u32,
+                &1_000i128,
+                &None,
+                &70_000_000i128,
+            );
+            client.set_max_burn_bps(&creator, &1, &10_000);
+            assert_eq!(client.get_remaining_burnable(&1), 1_000);
+
+            // Minting raises supply but not the cap, which then binds
+            client.mint(&creator, &1, &creator, &500);
+            assert_eq!(client.get_token_info(&1).total_supply, 1_500);
+            assert_eq!(client.get_remaining_burnable(&1), 1_000);
+        }
+    }
+
+    /// Tests for correlation-id tagged burns.
+    mod burn_correlation {
+        use soroban_sdk::{testutils::Address as _, Env};
+
+        use crate::test_helpers::setup_with_token;
+        use crate::types::Error;
+        use crate::TokenFactoryClient;
+
+        #[test]
+        fn test_lookup_existing_correlation_id() {
+            let env = Env::default();
+            let (client, _, creator, token_index) = setup_with_token(&env);
+
+            client.burn(&creator, &token_index, &10);
+            client.burn_with_correlation(&creator, &token_index, &250, &42u64);
+
+            let record = client.get_burn_by_correlation(&42u64).unwrap();
+            assert_eq!(record.amount, 250);
+            assert_eq!(record.from, creator);
+            assert_eq!(record.token_index, token_index);
+            assert_eq!(Some(record), client.get_burn_record(&1));
+        }
+
+        #[test]
+        fn test_missing_correlation_id() {
+            let env = Env::default();
+            let (client, _, creator, token_index) = setup_with_token(&env);
+
+            assert_eq!(client.get_burn_by_correlation(&7u64), None);
+
+            client.burn_with_correlation(&creator, &token_index, &1, &8u64);
+            assert_eq!(client.get_burn_by_correlation(&7u64), None);
+        }
+
+        #[test]
+        fn test_duplicate_correlation_id_rejected() {
+            let env = Env::default();
+            let (client, _, creator, token_index) = setup_with_token(&env);
+
+            client.burn_with_correlation(&creator, &token_index, &100, &1u64);
+            let result = client.try_burn_with_correlation(&creator, &token_index, &200, &1u64);
+            assert_eq!(result, Err(Ok(Error::InvalidParameters)));
+
+            // Original mapping and supply untouched
+            assert_eq!(client.get_burn_by_correlation(&1u64).unwrap().amount, 100);
+            assert_eq!(client.get_token_info(&token_index).total_burned, 100);
+        }
+    }
+
+    /// Tests for the per-day burn feed (`get_burns_for_day`).
+    mod burn_day_feed {
+        use soroban_sdk::{
+            testutils::{Address as _, Ledger},
+            Address, Env,
+        };
+
+        use crate::storage::SECONDS_PER_DAY;
+        use crate::test_helpers::{create_test_token, setup_factory};
+        use crate::TokenFactoryClient;
+
+        const DAY: u32 = 20_000;
+
+        /// Set up a factory with two tokens. Returns (client, creator).
+        fn setup(env: &Env) -> (TokenFactoryClient<'_>, Address) {
+            let (client, _, _) = setup_factory(env);
+
+            let creator = Address::generate(env);
+            for symbol in ["FEED", "OTHER"] {
+                create_test_token(env, &client, &creator, symbol, 1_000_000);
+            }
+
+            (client, creator)
+        }
+
+        fn set_time(env: &Env, day: u32, offset: u64) {
+            env.ledger()
+                .with_mut(|l| l.timestamp = day as u64 * SECONDS_PER_DAY + offset);
+        }
+
+        #[test]
+        fn test_feed_returns_only_that_days_burns() {
+            let env = Env::default();
+            let (client, creator) = setup(&env);
+
+            set_time(&env, DAY - 1, SECONDS_PER_DAY - 1);
+            client.burn(&creator, &0, &1);
+            set_time(&env, DAY, 0);
+            client.burn(&creator, &0, &10);
+            client.burn(&creator, &1, &999);
+            set_time(&env, DAY, 3_600);
+            client.burn(&creator, &0, &20);
+            set_time(&env, DAY + 1, 0);
+            client.burn(&creator, &0, &30);
+
+            let feed = client.get_burns_for_day(&0, &DAY, &0, &10);
+            assert_eq!(feed.items.len(), 2);
+            assert_eq!(feed.items.get(0).unwrap().amount, 10);
+            assert_eq!(feed.items.get(1).unwrap().amount, 20);
+            assert_eq!(feed.next, None);
+            for record in feed.items.iter() {
+                assert_eq!(record.token_index, 0);
+            }
+
+            assert_eq!(
+                client
+                    .get_burns_for_day(&0, &(DAY - 1), &0, &10)
+                    .items
+                    .len(),
+                1
+            );
+            assert_eq!(
+                client
+                    .get_burns_for_day(&0, &(DAY + 1), &0, &10)
+                    .items
+                    .len(),
+                1
+            );
+            assert_eq!(
+                client
+                    .get_burns_for_day(&1, &DAY, &0, &10)
+                    .items
+                    .get(0)
+                    .unwrap()
+                    .amount,
+                999
+            );
+        }
+
+        #[test]
+        fn test_feed_empty_day_and_limit() {
+            let env = Env::default();
+            let (client, creator) = setup(&env);
+
+            set_time(&env, DAY, 0);
+            for amount in 1..=5i128 {
+                client.burn(&creator, &0, &amount);
+            }
+
+            assert_eq!(
+                client
+                    .get_burns_for_day(&0, &(DAY + 3), &0, &10)
+                    .items
+                    .len(),
+                0
+            );
+            assert_eq!(client.get_burns_for_day(&1, &DAY, &0, &10).items.len(), 0);
+
+            let limited = client.get_burns_for_day(&0, &DAY, &0, &3);
+            assert_eq!(limited.items.len(), 3);
+            assert_eq!(limited.items.get(2).unwrap().amount, 3);
+            assert_eq!(limited.next, Some(3));
+
+            let rest = client.get_burns_for_day(&0, &DAY, &limited.next.unwrap(), &10);
+            assert_eq!(rest.items.len(), 2);
+            assert_eq!(rest.items.get(0).unwrap().amount, 4);
+            assert_eq!(rest.next, None);
+            assert_eq!(client.get_burns_for_day(&0, &DAY, &0, &0).items.len(), 0);
+        }
+
+        #[test]
+        fn test_scan_is_bounded() {
+            let env = Env::default();
+            let (client, creator) = setup(&env);
+
+            // Two burns of token 0 with a busy stretch of token 1 in between
+            set_time(&env, DAY, 0);
+            client.burn(&creator, &0, &10);
+            for _ in 0..150 {
+                client.burn(&creator, &1, &1);
+            }
+            client.burn(&creator, &0, &20);
+
+            let first = client.get_burns_for_day(&0, &DAY, &0, &10);
+            assert_eq!(first.items.len(), 1);
+            assert_eq!(first.items.get(0).unwrap().amount, 10);
+            assert_eq!(first.next, Some(90));
+
+            let second = client.get_burns_for_day(&0, &DAY, &first.next.unwrap(), &10);
+            assert_eq!(second.items.len(), 1);
+            assert_eq!(second.items.get(0).unwrap().amount, 20);
+            assert_eq!(second.next, None);
+        }
+    }
+
+    /// Tests for `get_burn_display_amount` scaling burn-log amounts by decimals.
+    mod burn_display_amount {
+        use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+        use crate::test_helpers::setup_factory;
+        use crate::TokenFactoryClient;
+
+        /// Enough base units for a multi-token burn at 18 decimals.
+        const SUPPLY: i128 = 10_000_000_000_000_000_000;
+
+        fn setup(env: &Env) -> (TokenFactoryClient<'_>, Address) {
+            let (client, _, _) = setup_factory(env);
+
+            (client, Address::generate(env))
+        }
+
+        /// Create a token with `decimals`, burn `amount` from it and return the
+        /// burn's display amount.
+        fn burn_and_display(
+            env: &Env,
+            client: &TokenFactoryClient,
+            creator: &Address,
+            decimals: u32,
+            amount: i128,
+        ) -> Option<i128> {
+            let receipt = client.create_token(
+                creator,
+                &String::from_str(env, "Display"),
+                &String::from_str(env, "DSP"),
+                &decimals,
+                &SUPPLY,
+                &None,
+                &70_000_000i128,
+            );
+            let index = client.get_burn_record_count();
+            client.burn(creator, &receipt.index, &amount);
+            client.get_burn_display_amount(&index)
+        }
+
+        #[test]
+        fn test_display_amount_scales_by_decimals() {
+            let env = Env::default();
+            let (client, creator) = setup(&env);
+
+            assert_eq!(
+                burn_and_display(&env, &client, &creator, 0, 1_234),
+                Some(1_234)
+            );
+            assert_eq!(
+                burn_and_display(&env, &client, &creator, 2, 1_234),
+                Some(12)
+            );
+            assert_eq!(
+                burn_and_display(&env, &client, &creator, 7, 25_000_000),
+                Some(2)
+            );
+            assert_eq!(
+                burn_and_display(&env, &client, &creator, 18, 3_000_000_000_000_000_000),
+                Some(3)
+            );
+        }
+
+        #[test]
+        fn test_sub_unit_burn_rounds_down() {
+            let env = Env::default();
+            let (client, creator) = setup(&env);
+
+            assert_eq!(
+                burn_and_display(&env, &client, &creator, 7, 9_999_999),
+                Some(0)
+            );
+            assert_eq!(burn_and_display(&env, &client, &creator, 2, 199), Some(1));
+        }
+
+        #[test]
+        fn test_missing_record_is_none() {
+            let env = Env::default();
+            let (client, _) = setup(&env);
+
+            assert_eq!(client.get_burn_display_amount(&0), None);
+        }
+    }
+
+    /// Tests for the minimum token age before holder burns.
+    mod burn_min_age {
+        use soroban_sdk::{
+            testutils::{Address as _, Ledger},
+            Address, Env,
+        };
+
+        use crate::test_helpers::{create_test_token, setup_factory};
+        use crate::types::Error;
+        use crate::TokenFactoryClient;
+
+        const CREATED_AT: u64 = 10_000;
+        const MIN_AGE: u64 = 3_600;
+
+        /// Set up a factory with the age gate configured and one token created
+        /// at `CREATED_AT`. Returns (client, admin, creator, token_index).
+        fn setup(env: &Env) -> (TokenFactoryClient<'_>, Address, Address, u32) {
+            let (client, admin, _) = setup_factory(env);
+
+            client.set_min_age_before_burn(&admin, &MIN_AGE);
+
+            env.ledger().with_mut(|l| l.timestamp = CREATED_AT);
+            let creator = Address::generate(env);
+            create_test_token(env, &client, &creator, "AGE", 1_000_000);
+
+            (client, admin, creator, 0u32)
+        }
+
+        #[test]
+        fn test_too_new_token_rejected() {
+            let env = Env::default();
+            let (client, _, creator, token_index) = setup(&env);
+
+            assert_eq!(
+                client.try_burn(&creator, &token_index, &100),
+                Err(Ok(Error::TokenTooNew))
+            );
+
+            env.ledger()
+                .with_mut(|l| l.timestamp = CREATED_AT + MIN_AGE - 1);
+            assert_eq!(
+                client.try_burn(&creator, &token_index, &100),
+                Err(Ok(Error::TokenTooNew))
+            );
+            assert_eq!(
+                client.try_can_burn(&token_index, &creator, &100, &creator),
+                Err(Ok(Error::TokenTooNew))
+            );
+            assert_eq!(client.get_token_info(&token_index).total_burned, 0);
+        }
+
+        #[test]
+        fn test_burn_allowed_after_delay() {
+            let env = Env::default();
+            let (client, _, creator, token_index) = setup(&env);
+
+            env.ledger()
+                .with_mut(|l| l.timestamp = CREATED_AT + MIN_AGE);
+            assert!(client
+                .try_can_burn(&token_index, &creator, &100, &creator)
+                .is_ok());
+            client.burn(&creator, &token_index, &100);
+
+            assert_eq!(client.get_token_info(&token_index).total_burned, 100);
+        }
+
+        #[test]
+        fn test_admin_burn_bypasses_delay() {
+            let env = Env::default();
+            let (client, admin, creator, token_index) = setup(&env);
+
+            assert!(client
+                .try_can_burn(&token_index, &creator, &100, &admin)
+                .is_ok());
+            client.admin_burn(&admin, &token_index, &creator, &100);
+
+            assert_eq!(client.get_token_info(&token_index).total_burned, 100);
+        }
+
+        #[test]
+        fn test_set_min_age_requires_admin() {
+            let env = Env::default();
+            let (client, admin, creator, _) = setup(&env);
+
+            assert_eq!(client.get_min_age_before_burn(), MIN_AGE);
+            assert_eq!(
+                client.try_set_min_age_before_burn(&creator, &0),
+                Err(Ok(Error::Unauthorized))
+            );
+
+            client.set_min_age_before_burn(&admin, &0);
+            assert_eq!(client.get_min_age_before_burn(), 0);
+            client.burn(&creator, &0, &1);
+        }
+    }
+
+    /// Tests for per-token burn modes.
+    mod burn_mode {
+        use soroban_sdk::{testutils::Address as _, vec, Address, Env};
+
+        use crate::storage;
+        use crate::test_helpers::setup_with_token;
+        use crate::types::{BurnMode, Error};
+        use crate::TokenFactoryClient;
+
+        fn balance_of(
+            env: &Env,
+            client: &TokenFactoryClient,
+            token_index: u32,
+            holder: &Address,
+        ) -> i128 {
+            env.as_contract(&client.address, || {
+                storage::get_balance(env, token_index, holder)
+            })
+        }
+
+        #[test]
+        fn test_default_mode_reduces_supply() {
+            let env = Env::default();
+            let (client, _, creator, token_index) = setup_with_token(&env);
+
+            assert_eq!(client.get_burn_mode(&token_index), BurnMode::ReduceSupply);
+            client.burn(&creator, &token_index, &1_000);
+
+            let info = client.get_token_info(&token_index);
+            assert_eq!(info.total_supply, 999_000);
+            assert_eq!(info.total_burned, 1_000);
+            assert_eq!(client.get_burn_record_count(), 1);
+        }
+
+        #[test]
+        fn test_send_to_dead_moves_balance_and_keeps_supply() {
+            let env = Env::default();
+            let (client, _, creator, token_index) = setup_with_token(&env);
+            let dead = Address::generate(&env);
+
+            client.set_burn_mode(&creator, &token_index, &BurnMode::SendToDead(dead.clone()));
+            client.burn(&creator, &token_index, &1_000);
+
+            assert_eq!(balance_of(&env, &client, token_index, &creator), 999_000);
+            assert_eq!(balance_of(&env, &client, token_index, &dead), 1_000);
+
+            let info = client.get_token_info(&token_index);
+            assert_eq!(info.total_supply, 1_000_000);
+            assert_eq!(info.total_burned, 1_000);
+            assert_eq!(info.burn_count, 1);
+
+            let record = client.get_burn_record(&0).unwrap();
+            assert_eq!(record.from, creator);
+            assert_eq!(record.amount, 1_000);
+            assert!(client.verify_supply_invariant(&token_index));
+        }
+
+        #[test]
+        fn test_send_to_dead_batch_burn() {
+            let env = Env::default();
+            let (client, admin, creator, token_index) = setup_with_token(&env);
+            let holder = Address::generate(&env);
+            let dead = Address::generate(&env);
+            client.mint(&creator, &token_index, &holder, &5_000);
+
+            client.set_burn_mode(&creator, &token_index, &BurnMode::SendToDead(dead.clone()));
+            client.batch_burn(
+                &admin,
+                &token_index,
+                &vec![
+                    &env,
+                    (creator.clone(), 2_000i128),
+                    (holder.clone(), 3_000i128),
+                ],
+            );
+
+            assert_eq!(balance_of(&env, &client, token_index, &dead), 5_000);
+            let info = client.get_token_info(&token_index);
+            assert_eq!(info.total_supply, 1_005_000);
+            assert_eq!(info.total_burned, 5_000);
+            assert_eq!(client.get_burn_record_count(), 2);
+            assert!(client.verify_supply_invariant(&token_index));
+        }
+
+        #[test]
+        fn test_switching_back_to_reduce_supply() {
+            let env = Env::default();
+            let (client, _, creator, token_index) = setup_with_token(&env);
+            let dead = Address::generate(&env);
+
+            client.set_burn_mode(&creator, &token_index, &BurnMode::SendToDead(dead.clone()));
+            client.burn(&creator, &token_index, &1_000);
+            client.set_burn_mode(&creator, &token_index, &BurnMode::ReduceSupply);
+            client.burn(&creator, &token_index, &1_000);
+
+            let info = client.get_token_info(&token_index);
+            assert_eq!(info.total_supply, 999_000);
+            assert_eq!(info.total_burned, 2_000);
+            assert_eq!(balance_of(&env, &client, token_index, &dead), 1_000);
+            assert!(client.verify_supply_invariant(&token_index));
+        }
+
+        #[test]
+        fn test_dead_address_cannot_burn() {
+            let env = Env::default();
+            let (client, _, creator, token_index) = setup_with_token(&env);
+
+            client.set_burn_mode(
+                &creator,
+                &token_index,
+                &BurnMode::SendToDead(creator.clone()),
+            );
+            assert_eq!(
+                client.try_burn(&creator, &token_index, &1_000),
+                Err(Ok(Error::InvalidParameters))
+            );
+            assert_eq!(client.get_token_info(&token_index).total_burned, 0);
+        }
+
+        #[test]
+        fn test_set_burn_mode_validation() {
+            let env = Env::default();
+            let (client, admin, creator, token_index) = setup_with_token(&env);
+            let dead = BurnMode::SendToDead(Address::generate(&env));
+
+            assert_eq!(
+                client.try_set_burn_mode(&admin, &token_index, &dead),
+                Err(Ok(Error::Unauthorized))
+            );
+            assert_eq!(
+                client.try_set_burn_mode(&creator, &99, &dead),
+                Err(Ok(Error::TokenNotFound))
+            );
+            assert_eq!(
+                client.try_set_burn_mode(
+                    &creator,
+                    &token_index,
+                    &BurnMode::SendToDead(client.address.clone())
+                ),
+                Err(Ok(Error::InvalidParameters))
+            );
+            assert_eq!(client.get_burn_mode(&token_index), BurnMode::ReduceSupply);
+        }
+    }
+
+    /// Tests for `MaxBurnRecordsPerToken`.
+    mod burn_record_cap {
+        use soroban_sdk::{testutils::Address as _, Address, Env};
+
+        use crate::test_helpers::{create_test_token, setup_factory};
+        use crate::types::Error;
+        use crate::TokenFactoryClient;
+
+        /// Set up a factory with two tokens. Returns (client, admin, creator).
+        fn setup(env: &Env) -> (TokenFactoryClient<'_>, Address, Address) {
+            let (client, admin, _) = setup_factory(env);
+
+            let creator = Address::generate(env);
+            for _ in 0..2 {
+                create_test_token(env, &client, &creator, "CLG", 1_000_000);
+            }
+
+            (client, admin, creator)
+        }
+
+        #[test]
+        fn test_aggregates_continue_after_records_stop() {
+            let env = Env::default();
+            let (client, admin, creator) = setup(&env);
+            client.set_max_burn_records_per_token(&admin, &Some(3));
+
+            for _ in 0..5 {
+                client.burn(&creator, &0, &10);
+            }
+            client.admin_burn(&admin, &0, &creator, &50);
+
+            assert_eq!(client.get_token_burn_record_count(&0), 3);
+            assert_eq!(client.get_burn_record_count(), 3);
+
+            let info = client.get_token_info(&0);
+            assert_eq!(info.total_burned, 100);
+            assert_eq!(info.burn_count, 6);
+            assert_eq!(info.total_supply, 1_000_000 - 100);
+            assert_eq!(client.get_burn_count(&0), 6);
+        }
+
+        #[test]
+        fn test_cap_is_per_token() {
+            let env = Env::default();
+            let (client, admin, creator) = setup(&env);
+            client.set_max_burn_records_per_token(&admin, &Some(1));
+
+            client.burn(&creator, &0, &10);
+            client.burn(&creator, &0, &10);
+            client.burn(&creator, &1, &10);
+
+            assert_eq!(client.get_token_burn_record_count(&0), 1);
+            assert_eq!(client.get_token_burn_record_count(&1), 1);
+            assert_eq!(client.get_burn_record(&1).unwrap().token_index, 1);
+        }
+
+        #[test]
+        fn test_removing_cap_resumes_writing() {
+            let env = Env::default();
+            let (client, admin, creator) = setup(&env);
+            client.set_max_burn_records_per_token(&admin, &Some(1));
+
+            client.burn(&creator, &0, &10);
+            client.burn(&creator, &0, &10);
+            assert_eq!(client.get_token_burn_record_count(&0), 1);
+
+            client.set_max_burn_records_per_token(&admin, &None);
+            assert_eq!(client.get_admin_config().max_burn_records_per_token, None);
+
+            client.burn(&creator, &0, &10);
+            assert_eq!(client.get_token_burn_record_count(&0), 2);
+            assert_eq!(client.get_token_info(&0).total_burned, 30);
+        }
+
+        #[test]
+        fn test_set_cap_requires_admin() {
+            let env = Env::default();
+            let (client, _, creator) = setup(&env);
+
+            assert_eq!(
+                client.try_set_max_burn_records_per_token(&creator, &Some(1)),
+                Err(Ok(Error::Unauthorized))
+            );
+        }
+    }
+
+    /// Tests for the global burn log and its queries.
+    mod burn_records {
+        use soroban_sdk::{testutils::Address as _, Env};
+
+        use crate::test_helpers::setup_with_supply;
+
+        #[test]
+        fn test_recent_on_empty_log() {
+            let env = Env::default();
+            let (client, _, _, _) = setup_with_supply(&env, 1_000_000_000);
+
+            assert_eq!(client.get_burn_record_count(), 0);
+            assert_eq!(client.get_burn_records_recent(&10).len(), 0);
+        }
+
+        #[test]
+        fn test_recent_on_short_log_returns_all_newest_first() {
+            let env = Env::default();
+            let (client, admin, creator, token_index) = setup_with_supply(&env, 1_000_000_000);
+
+            client.burn(&creator, &token_index, &100);
+            client.admin_burn(&admin, &token_index, &creator, &200);
+            client.burn(&creator, &token_index, &300);
+
+            let records = client.get_burn_records_recent(&10);
+            assert_eq!(records.len(), 3);
+            assert_eq!(records.get(0).unwrap().amount, 300);
+            assert_eq!(records.get(1).unwrap().amount, 200);
+            assert_eq!(records.get(1).unwrap().burned_by, admin);
+            assert_eq!(records.get(2).unwrap().amount, 100);
+            assert_eq!(records.get(2).unwrap().burned_by, creator);
+        }
+
+        #[test]
+        fn test_recent_on_long_log_returns_last_limit() {
+            let env = Env::default();
+            let (client, _, creator, token_index) = setup_with_supply(&env, 1_000_000_000);
+
+            for amount in 1..=25i128 {
+                client.burn(&creator, &token_index, &amount);
+            }
+            assert_eq!(client.get_burn_record_count(), 25);
+
+            let records = client.get_burn_records_recent(&5);
+            assert_eq!(records.len(), 5);
+            for (i, record) in records.iter().enumerate() {
+                assert_eq!(record.amount, 25 - i as i128);
+            }
+        }
+
+        #[test]
+        fn test_recent_limit_is_clamped() {
+            let env = Env::default();
+            let (client, _, creator, token_index) = setup_with_supply(&env, 1_000_000_000);
+
+            for _ in 0..55 {
+                client.burn(&creator, &token_index, &1);
+            }
+
+            assert_eq!(client.get_burn_records_recent(&500).len(), 50);
+            assert_eq!(client.get_burn_records_recent(&0).len(), 0);
+        }
+    }
+
+    /// Tests for `get_burn_summary`.
+    mod burn_summary {
+        use soroban_sdk::{
+            testutils::{Address as _, Ledger},
+            vec, Address, Env,
+        };
+
+        use crate::test_helpers::setup_with_supply;
+        use crate::types::Error;
+
+        #[test]
+        fn test_summary_before_any_burn() {
+            let env = Env::default();
+            let (client, _, _, token_index) = setup_with_supply(&env, 10_000);
+
+            let summary = client.get_burn_summary(&token_index);
+            assert_eq!(summary.total_burned, 0);
+            assert_eq!(summary.burn_count, 0);
+            assert_eq!(summary.burned_bps, 0);
+            assert_eq!(summary.largest_burn, 0);
+            assert_eq!(summary.last_burn_at, None);
+        }
+
+        #[test]
+        fn test_summary_after_burn_sequence() {
+            let env = Env::default();
+            let (client, admin, creator, token_index) = setup_with_supply(&env, 10_000);
+            let holder = Address::generate(&env);
+            client.mint(&creator, &token_index, &holder, &10_000);
+
+            env.ledger().with_mut(|l| l.timestamp = 100);
+            client.burn(&creator, &token_index, &500);
+            env.ledger().with_mut(|l| l.timestamp = 200);
+            client.admin_burn(&admin, &token_index, &holder, &1_500);
+            env.ledger().with_mut(|l| l.timestamp = 300);
+            client.batch_burn(
+                &admin,
+                &token_index,
+                &vec![&env, (creator.clone(), 200), (holder.clone(), 800)],
+            );
+
+            let summary = client.get_burn_summary(&token_index);
+            assert_eq!(summary.total_burned, 3_000);
+            assert_eq!(summary.burn_count, 4);
+            // 3_000 of 20_000 issued (10_000 initial + 10_000 minted)
+            assert_eq!(summary.burned_bps, 1_500);
+            assert_eq!(summary.largest_burn, 1_500);
+            assert_eq!(summary.last_burn_at, Some(300));
+        }
+
+        #[test]
+        fn test_summary_unknown_token() {
+            let env = Env::default();
+            let (client, _, _, _) = setup_with_supply(&env, 10_000);
+
+            assert_eq!(
+                client.try_get_burn_summary(&9),
+                Err(Ok(Error::TokenNotFound))
+            );
+        }
+    }
+
+    /// Tests for per-token burn restriction and whitelist.
+    mod burn_whitelist {
+        use soroban_sdk::{testutils::Address as _, Address, Env};
+
+        use crate::test_helpers::setup_with_supply;
+        use crate::types::Error;
+        use crate::TokenFactoryClient;
+
+        /// Set up a factory with one token and two funded holders.
+        /// Returns (client, admin, creator, holder_a, holder_b).
+        fn setup(env: &Env) -> (TokenFactoryClient<'_>, Address, Address, Address, Address) {
+            let (client, admin, creator, token_index) = setup_with_supply(env, 1_000);
+
+            let holder_a = Address::generate(env);
+            let holder_b = Address::generate(env);
+            client.mint(&creator, &token_index, &holder_a, &100);
+            client.mint(&creator, &token_index, &holder_b, &100);
+
+            (client, admin, creator, holder_a, holder_b)
+        }
+
+        #[test]
+        fn test_unrestricted_token_burnable_by_anyone() {
+            let env = Env::default();
+            let (client, _, _, holder_a, holder_b) = setup(&env);
+
+            assert!(!client.is_burn_restricted(&0));
+            client.burn(&holder_a, &0, &10);
+            client.burn(&holder_b, &0, &10);
+            assert_eq!(client.get_token_info(&0).total_burned, 20);
+        }
+
+        #[test]
+        fn test_restricted_token_only_whitelisted_burns() {
+            let env = Env::default();
+            let (client, _, creator, holder_a, holder_b) = setup(&env);
+
+            client.set_burn_restricted(&creator, &0, &true);
+            client.set_burn_whitelisted(&creator, &0, &holder_a, &true);
+            assert!(client.is_burn_whitelisted(&0, &holder_a));
+
+            client.burn(&holder_a, &0, &10);
+            assert_eq!(
+                client.try_burn(&holder_b, &0, &10),
+                Err(Ok(Error::Unauthorized))
+            );
+            assert_eq!(
+                client.try_burn(&creator, &0, &10),
+                Err(Ok(Error::Unauthorized))
+            );
+            assert_eq!(client.get_token_info(&0).total_burned, 10);
+        }
+
+        #[test]
+        fn test_admin_may_burn_and_whitelist_can_be_revoked() {
+            let env = Env::default();
+            let (client, admin, creator, holder_a, holder_b) = setup(&env);
+
+            client.set_burn_restricted(&creator, &0, &true);
+            client.admin_burn(&admin, &0, &holder_b, &25);
+            assert_eq!(client.get_token_info(&0).total_burned, 25);
+
+            client.set_burn_whitelisted(&creator, &0, &holder_a, &true);
+            client.set_burn_whitelisted(&creator, &0, &holder_a, &false);
+            assert_eq!(
+                client.try_burn(&holder_a, &0, &1),
+                Err(Ok(Error::Unauthorized))
+            );
+
+            client.set_burn_restricted(&creator, &0, &false);
+            client.burn(&holder_a, &0, &1);
+        }
+
+        #[test]
+        fn test_configuration_is_creator_only() {
+            let env = Env::default();
+            let (client, _, _, holder_a, _) = setup(&env);
+
+            assert_eq!(
+                client.try_set_burn_restricted(&holder_a, &0, &true),
+                Err(Ok(Error::Unauthorized))
+            );
+            assert_eq!(
+                client.try_set_burn_whitelisted(&holder_a, &0, &holder_a, &true),
+                Err(Ok(Error::Unauthorized))
+            );
+            assert_eq!(
+                client.try_set_burn_restricted(&holder_a, &9, &true),
+                Err(Ok(Error::TokenNotFound))
+            );
+        }
+    }
+
+    /// Tests for signature-authorized burns (`burn_with_sig`).
+    mod burn_with_sig {
+        use ed25519_dalek::{Signer, SigningKey};
+        use soroban_sdk::{
+            address_payload::AddressPayload, testutils::Address as _, Address, BytesN, Env,
+        };
+
+        use crate::burn;
+        use crate::test_helpers::setup_with_token;
+        use crate::types::Error;
+        use crate::TokenFactoryClient;
+
+        /// Set up a factory with one token and fund an ed25519 account.
+        /// Returns (client, signing_key, from, token_index).
+        fn setup(env: &Env) -> (TokenFactoryClient<'_>, SigningKey, Address, u32) {
+            let (client, _, creator, token_index) = setup_with_token(env);
+
+            let key = SigningKey::from_bytes(&[7u8; 32]);
+            let from = AddressPayload::AccountIdPublicKeyEd25519(BytesN::from_array(
+                env,
+                &key.verifying_key().to_bytes(),
+            ))
+            .to_address(env);
+            client.mint(&creator, &token_index, &from, &10_000);
+
+            (client, key, from, token_index)
+        }
+
+        fn sign(
+            env: &Env,
+            client: &TokenFactoryClient,
+            key: &SigningKey,
+            token_index: u32,
+            from: &Address,
+            amount: i128,
+            nonce: u64,
+        ) -> BytesN<64> {
+            let payload = env.as_contract(&client.address, || {
+                burn::burn_signature_payload(env, token_index, from, amount, nonce)
+            });
+            let mut message = std::vec![0u8; payload.len() as usize];
+            payload.copy_into_slice(&mut message);
+            BytesN::from_array(env, &key.sign(&message).to_bytes())
+        }
+
+        fn nonce_of(client: &TokenFactoryClient, account: &Address) -> u64 {
+            client.get_nonce(account)
+        }
+
+        #[test]
+        fn test_valid_signed_burn() {
+            let env = Env::default();
+            let (client, key, from, token_index) = setup(&env);
+            let records_before = client.get_burn_record_count();
+
+            let sig = sign(&env, &client, &key, token_index, &from, 1_000, 0);
+            client.burn_with_sig(&token_index, &from, &1_000, &0, &sig);
+
+            let info = client.get_token_info(&token_index);
+            assert_eq!(info.total_burned, 1_000);
+            assert_eq!(nonce_of(&client, &from), 1);
+
+            let record = client.get_burn_record(&records_before).unwrap();
+            assert_eq!(record.from, from);
+            assert_eq!(record.burned_by, from);
+            assert_eq!(record.amount, 1_000);
+        }
+
+        #[test]
+        fn test_replayed_nonce_rejected() {
+            let env = Env::default();
+            let (client, key, from, token_index) = setup(&env);
+
+            let sig = sign(&env, &client, &key, token_index, &from, 1_000, 0);
+            client.burn_with_sig(&token_index, &from, &1_000, &0, &sig);
+
+            let replay = client.try_burn_with_sig(&token_index, &from, &1_000, &0, &sig);
+            assert_eq!(replay, Err(Ok(Error::BadNonce)));
+            assert_eq!(client.get_token_info(&token_index).total_burned, 1_000);
+        }
+
+        #[test]
+        fn test_tampered_amount_rejected() {
+            let env = Env::default();
+            let (client, key, from, token_index) = setup(&env);
+
+            let sig = sign(&env, &client, &key, token_index, &from, 1_000, 0);
+            let result = client.try_burn_with_sig(&token_index, &from, &5_000, &0, &sig);
+            // ed25519_verify traps; the host error converts to InvalidParameters
+            assert_eq!(result, Err(Ok(Error::InvalidParameters)));
+
+            // Nothing persisted, nonce still available
+            assert_eq!(client.get_token_info(&token_index).total_burned, 0);
+            assert_eq!(nonce_of(&client, &from), 0);
+        }
+
+        #[test]
+        #[should_panic]
+        fn test_wrong_signer_traps() {
+            let env = Env::default();
+            let (client, _, from, token_index) = setup(&env);
+
+            let other = SigningKey::from_bytes(&[9u8; 32]);
+            let sig = sign(&env, &client, &other, token_index, &from, 1_000, 0);
+            client.burn_with_sig(&token_index, &from, &1_000, &0, &sig);
+        }
+
+        #[test]
+        fn test_contract_address_cannot_sign() {
+            let env = Env::default();
+            let (client, _, _, token_index) = setup(&env);
+            let contract_from = client.address.clone();
+            let sig = BytesN::from_array(&env, &[0u8; 64]);
+
+            let result = client.try_burn_with_sig(&token_index, &contract_from, &1, &0, &sig);
+            assert_eq!(result, Err(Ok(Error::InvalidSignature)));
+        }
+
+        #[test]
+        fn test_nonce_starts_at_zero() {
+            let env = Env::default();
+            let (client, _, from, _) = setup(&env);
+
+            assert_eq!(client.get_nonce(&from), 0);
+            assert_eq!(client.get_nonce(&Address::generate(&env)), 0);
+        }
+
+        #[test]
+        fn test_nonce_increments_per_signed_operation() {
+            let env = Env::default();
+            let (client, key, from, token_index) = setup(&env);
+
+            for expected in 0..3u64 {
+                assert_eq!(client.get_nonce(&from), expected);
+                let sig = sign(&env, &client, &key, token_index, &from, 100, expected);
+                client.burn_with_sig(&token_index, &from, &100, &expected, &sig);
+            }
+            assert_eq!(client.get_nonce(&from), 3);
+
+            // Ordinary burns are not signed operations
+            client.burn(&from, &token_index, &100);
+            assert_eq!(client.get_nonce(&from), 3);
+        }
+
+        #[test]
+        fn test_future_nonce_rejected() {
+            let env = Env::default();
+            let (client, key, from, token_index) = setup(&env);
+
+            let sig = sign(&env, &client, &key, token_index, &from, 100, 1);
+            let result = client.try_burn_with_sig(&token_index, &from, &100, &1, &sig);
+            assert_eq!(result, Err(Ok(Error::BadNonce)));
+            assert_eq!(client.get_nonce(&from), 0);
+        }
+    }
+
+    /// Tests for the `can_burn` dry-run validator.
+    mod can_burn {
+        use soroban_sdk::{testutils::Address as _, Address, Env};
+
+        use crate::test_helpers::setup_with_supply;
+        use crate::types::Error;
+        use crate::TokenFactoryClient;
+
+        /// Assert `can_burn` predicts the outcome of the holder's own `burn`.
+        fn assert_matches_burn(
+            client: &TokenFactoryClient,
+            token_index: u32,
+            from: &Address,
+            amount: i128,
+        ) {
+            let predicted = client.try_can_burn(&token_index, from, &amount, from);
+            let actual = client.try_burn(from, &token_index, &amount);
+            assert_eq!(predicted, actual);
+        }
+
+        #[test]
+        fn test_matches_successful_burn() {
+            let env = Env::default();
+            let (client, _, creator, token_index) = setup_with_supply(&env, 1_000);
+
+            assert!(client
+                .try_can_burn(&token_index, &creator, &100, &creator)
+                .is_ok());
+            assert_matches_burn(&client, token_index, &creator, 100);
+            assert_eq!(client.get_token_info(&token_index).total_burned, 100);
+        }
+
+        #[test]
+        fn test_matches_amount_and_balance_errors() {
+            let env = Env::default();
+            let (client, _, creator, token_index) = setup_with_supply(&env, 1_000);
+
+            assert_eq!(
+                client.try_can_burn(&token_index, &creator, &0, &creator),
+                Err(Ok(Error::InvalidParameters))
+            );
+            assert_matches_burn(&client, token_index, &creator, 0);
+            assert_matches_burn(&client, token_index, &creator, -5);
+            assert_matches_burn(&client, token_index, &creator, 1_001);
+            assert_matches_burn(&client, token_index, &Address::generate(&env), 1);
+            assert_matches_burn(&client, 7, &creator, 1);
+        }
+
+        #[test]
+        fn test_matches_status_and_cap_errors() {
+            let env = Env::default();
+            let (client, admin, creator, token_index) = setup_with_supply(&env, 1_000);
+
+            client.set_max_burn_bps(&creator, &token_index, &1_000);
+            assert_eq!(
+                client.try_can_burn(&token_index, &creator, &101, &creator),
+                Err(Ok(Error::BurnCapReached))
+            );
+            assert_matches_burn(&client, token_index, &creator, 101);
+
+            client.freeze_token(&admin, &token_index);
+            assert_eq!(
+                client.try_can_burn(&token_index, &creator, &1, &creator),
+                Err(Ok(Error::TokenFrozen))
+            );
+            assert_matches_burn(&client, token_index, &creator, 1);
+
+            client.unfreeze_token(&admin, &token_index);
+            client.retire_token(&admin, &token_index);
+            assert!(client
+                .try_can_burn(&token_index, &creator, &1, &creator)
+                .is_err());
+            assert_matches_burn(&client, token_index, &creator, 1);
+        }
+
+        #[test]
+        fn test_matches_admin_burn() {
+            let env = Env::default();
+            let (client, admin, creator, token_index) = setup_with_supply(&env, 1_000);
+            let stranger = Address::generate(&env);
+
+            assert_eq!(
+                client.try_can_burn(&token_index, &creator, &10, &stranger),
+                client.try_admin_burn(&stranger, &token_index, &creator, &10)
+            );
+            assert_eq!(
+                client.try_can_burn(&token_index, &creator, &10, &stranger),
+                Err(Ok(Error::Unauthorized))
+            );
+
+            assert!(client
+                .try_can_burn(&token_index, &creator, &10, &admin)
+                .is_ok());
+            assert_eq!(
+                client.try_can_burn(&token_index, &creator, &10, &admin),
+                client.try_admin_burn(&admin, &token_index, &creator, &10)
+            );
+        }
+
+        #[test]
+        fn test_dry_run_writes_nothing() {
+            let env = Env::default();
+            let (client, _, creator, token_index) = setup_with_supply(&env, 1_000);
+
+            client.can_burn(&token_index, &creator, &500, &creator);
+
+            let info = client.get_token_info(&token_index);
+            assert_eq!(info.total_supply, 1_000);
+            assert_eq!(info.total_burned, 0);
+            assert_eq!(client.get_burn_record_count(), 0);
+        }
+    }
+
+    /// Tests for per-UTC-day burn aggregates (`get_daily_burn`).
+    mod daily_burn {
+        use soroban_sdk::{
+            testutils::{Address as _, Ledger},
+            vec, Address, Env,
+        };
+
+        use crate::storage::SECONDS_PER_DAY;
+        use crate::test_helpers::{create_test_token, setup_factory};
+        use crate::types::Error;
+        use crate::TokenFactoryClient;
+
+        const DAY: u32 = 20_000;
+
+        /// Set up a factory with two tokens. Returns (client, admin, creator).
+        fn setup(env: &Env) -> (TokenFactoryClient<'_>, Address, Address) {
+            let (client, admin, _) = setup_factory(env);
+
+            let creator = Address::generate(env);
+            for symbol in ["DAY", "OTH"] {
+                create_test_token(env, &client, &creator, symbol, 1_000_000);
+            }
+
+            (client, admin, creator)
+        }
+
+        fn set_time(env: &Env, day: u32, offset: u64) {
+            env.ledger()
+                .with_mut(|l| l.timestamp = day as u64 * SECONDS_PER_DAY + offset);
+        }
+
+        #[test]
+        fn test_burns_accumulate_within_a_day() {
+            let env = Env::default();
+            let (client, _, creator) = setup(&env);
+
+            set_time(&env, DAY, 10);
+            client.burn(&creator, &0, &100);
+            set_time(&env, DAY, SECONDS_PER_DAY - 1);
+            client.burn(&creator, &0, &50);
+
+            assert_eq!(client.get_daily_burn(&0, &DAY), 150);
+            assert_eq!(client.get_daily_burn(&0, &(DAY + 1)), 0);
+        }
+
+        #[test]
+        fn test_burns_across_two_day_boundaries() {
+            let env = Env::default();
+            let (client, admin, creator) = setup(&env);
+
+            set_time(&env, DAY, SECONDS_PER_DAY - 1);
+            client.burn(&creator, &0, &10);
+            set_time(&env, DAY + 1, 0);
+            client.burn(&creator, &0, &20);
+            client.batch_burn(&admin, &0, &vec![&env, (creator.clone(), 5i128)]);
+            set_time(&env, DAY + 2, 3_600);
+            client.admin_burn(&admin, &0, &creator, &40);
+
+            assert_eq!(client.get_daily_burn(&0, &DAY), 10);
+            assert_eq!(client.get_daily_burn(&0, &(DAY + 1)), 25);
+            assert_eq!(client.get_daily_burn(&0, &(DAY + 2)), 40);
+            assert_eq!(client.get_token_info(&0).total_burned, 75);
+        }
+
+        #[test]
+        fn test_daily_totals_are_per_token() {
+            let env = Env::default();
+            let (client, _, creator) = setup(&env);
+
+            set_time(&env, DAY, 0);
+            client.burn(&creator, &0, &100);
+            client.burn(&creator, &1, &7);
+
+            assert_eq!(client.get_daily_burn(&0, &DAY), 100);
+            assert_eq!(client.get_daily_burn(&1, &DAY), 7);
+        }
+
+        #[test]
+        fn test_velocity_counts_only_burns_in_window() {
+            let env = Env::default();
+            let (client, _, creator) = setup(&env);
+
+            // Burst well before the window
+            set_time(&env, DAY, 100);
+            for _ in 0..5 {
+                client.burn(&creator, &0, &1_000);
+            }
+            // Burst inside the window
+            set_time(&env, DAY + 9, 100);
+            client.burn(&creator, &0, &30);
+            set_time(&env, DAY + 10, 500);
+            client.burn(&creator, &0, &20);
+            client.burn(&creator, &0, &50);
+
+            let two_days = 2 * SECONDS_PER_DAY;
+            assert_eq!(client.get_burn_velocity(&0, &two_days), 100);
+            assert_eq!(client.get_burn_velocity(&0, &1), 70);
+            assert_eq!(client.get_burn_velocity(&0, &(11 * SECONDS_PER_DAY)), 5_100);
+            assert_eq!(client.get_burn_velocity(&1, &two_days), 0);
+        }
+
+        #[test]
+        fn test_velocity_empty_window_and_unknown_token() {
+            let env = Env::default();
+            let (client, _, creator) = setup(&env);
+
+            set_time(&env, DAY, 0);
+            client.burn(&creator, &0, &100);
+
+            assert_eq!(client.get_burn_velocity(&0, &0), 0);
+            set_time(&env, DAY + 5, 0);
+            assert_eq!(client.get_burn_velocity(&0, &SECONDS_PER_DAY), 0);
+            assert_eq!(
+                client.try_get_burn_velocity(&9, &SECONDS_PER_DAY),
+                Err(Ok(Error::TokenNotFound))
+            );
+        }
+    }
+
+    /// Tests for the global per-day burn count.
+    mod daily_burn_count {
+        use soroban_sdk::{
+            testutils::{Address as _, Ledger},
+            vec, Address, Env,
+        };
+
+        use crate::test_helpers::{create_test_token, setup_factory};
+        use crate::TokenFactoryClient;
+
+        const DAY: u64 = 86_400;
+        const DAY_INDEX: u32 = 20_000;
+
+        /// Set up a factory with two tokens from one creator.
+        /// Returns (client, admin, creator).
+        fn setup(env: &Env) -> (TokenFactoryClient<'_>, Address, Address) {
+            env.ledger()
+                .with_mut(|l| l.timestamp = DAY_INDEX as u64 * DAY);
+            let (client, admin, _) = setup_factory(env);
+
+            let creator = Address::generate(env);
+            for _ in 0..2 {
+                create_test_token(env, &client, &creator, "ACT", 1_000_000);
+            }
+
+            (client, admin, creator)
+        }
+
+        #[test]
+        fn test_counts_burns_across_tokens() {
+            let env = Env::default();
+            let (client, _, creator) = setup(&env);
+
+            client.burn(&creator, &0, &10);
+            client.burn(&creator, &1, &10);
+            client.burn(&creator, &0, &10);
+
+            assert_eq!(client.get_daily_burn_count(&DAY_INDEX), 3);
+            assert_eq!(client.get_daily_burn_count(&(DAY_INDEX + 1)), 0);
+        }
+
+        #[test]
+        fn test_counts_bucket_by_day_boundary() {
+            let env = Env::default();
+            let (client, _, creator) = setup(&env);
+
+            env.ledger()
+                .with_mut(|l| l.timestamp = (DAY_INDEX as u64 + 1) * DAY - 1);
+            client.burn(&creator, &0, &10);
+            client.burn(&creator, &1, &10);
+
+            env.ledger()
+                .with_mut(|l| l.timestamp = (DAY_INDEX as u64 + 1) * DAY);
+            client.burn(&creator, &0, &10);
+
+            assert_eq!(client.get_daily_burn_count(&DAY_INDEX), 2);
+            assert_eq!(client.get_daily_burn_count(&(DAY_INDEX + 1)), 1);
+            assert_eq!(client.get_global_burn_count(), 3);
+        }
+
+        #[test]
+        fn test_batch_burn_counts_each_entry() {
+            let env = Env::default();
+            let (client, admin, creator) = setup(&env);
+            let holder = Address::generate(&env);
+            client.mint(&creator, &0, &holder, &100);
+
+            client.batch_burn(
+                &admin,
+                &0,
+                &vec![&env, (creator.clone(), 5i128), (holder.clone(), 5i128)],
+            );
+
+            assert_eq!(client.get_daily_burn_count(&DAY_INDEX), 2);
+        }
+    }
+
+    /// Tests for `get_large_burns` threshold filtering of the burn log.
+    mod large_burns {
+        use soroban_sdk::{testutils::Address as _, Address, Env};
+
+        use crate::test_helpers::{create_test_token, setup_factory};
+        use crate::TokenFactoryClient;
+
+        /// Set up a factory with one token and burn each of `amounts` in order.
+        fn setup_with_burns<'a>(env: &'a Env, amounts: &[i128]) -> TokenFactoryClient<'a> {
+            let (client, _, _) = setup_factory(env);
+
+            let creator = Address::generate(env);
+            create_test_token(env, &client, &creator, "WHL", 1_000_000_000);
+            for amount in amounts {
+                client.burn(&creator, &0, amount);
+            }
+
+            client
+        }
+
+        #[test]
+        fn test_threshold_is_inclusive() {
+            let env = Env::default();
+            let client = setup_with_burns(&env, &[5, 1_000, 20, 999, 5_000, 1]);
+
+            let page = client.get_large_burns(&999, &0, &10);
+            assert_eq!(page.items.len(), 3);
+            assert_eq!(page.items.get(0).unwrap().amount, 1_000);
+            assert_eq!(page.items.get(1).unwrap().amount, 999);
+            assert_eq!(page.items.get(2).unwrap().amount, 5_000);
+            assert_eq!(page.next, None);
+            assert_eq!(page.total, 6);
+        }
+
+        #[test]
+        fn test_pagination_resumes_from_next() {
+            let env = Env::default();
+            let client = setup_with_burns(&env, &[100, 1, 200, 2, 300, 3, 400]);
+
+            let first = client.get_large_burns(&100, &0, &2);
+            assert_eq!(first.items.len(), 2);
+            assert_eq!(first.items.get(0).unwrap().amount, 100);
+            assert_eq!(first.items.get(1).unwrap().amount, 200);
+            assert_eq!(first.next, Some(3));
+
+            let second = client.get_large_burns(&100, &first.next.unwrap(), &10);
+            assert_eq!(second.items.len(), 2);
+            assert_eq!(second.items.get(0).unwrap().amount, 300);
+            assert_eq!(second.items.get(1).unwrap().amount, 400);
+            assert_eq!(second.next, None);
+
+            assert_eq!(client.get_large_burns(&100, &0, &0).items.len(), 0);
+            assert_eq!(client.get_large_burns(&100, &50, &10).next, None);
+        }
+
+        #[test]
+        fn test_limit_is_clamped() {
+            let env = Env::default();
+            let amounts = [10i128; 55];
+            let client = setup_with_burns(&env, &amounts);
+
+            let page = client.get_large_burns(&10, &0, &500);
+            assert_eq!(page.items.len(), 50);
+            assert_eq!(page.next, Some(50));
+        }
+
+        #[test]
+        fn test_scan_is_bounded() {
+            let env = Env::default();
+            let mut amounts = [1i128; 95];
+            amounts[94] = 1_000;
+            let client = setup_with_burns(&env, &amounts);
+
+            // The only match lies past the scan window: empty page, resume cursor
+            let first = client.get_large_burns(&1_000, &0, &10);
+            assert_eq!(first.items.len(), 0);
+            assert_eq!(first.next, Some(90));
+
+            let second = client.get_large_burns(&1_000, &90, &10);
+            assert_eq!(second.items.len(), 1);
+            assert_eq!(second.items.get(0).unwrap().amount, 1_000);
+            assert_eq!(second.next, None);
+        }
+
+        #[test]
+        fn test_threshold_matching_nothing() {
+            let env = Env::default();
+            let client = setup_with_burns(&env, &[1, 2, 3]);
+
+            let page = client.get_large_burns(&4, &0, &10);
+            assert_eq!(page.items.len(), 0);
+            assert_eq!(page.next, None);
+        }
+    }
+
+    /// Tests for the `get_largest_burn` record.
+    mod largest_burn {
+        use soroban_sdk::{testutils::Address as _, vec, Address, Env};
+
+        use crate::test_helpers::{create_test_token, setup_factory};
+        use crate::TokenFactoryClient;
+
+        /// Set up a factory with two tokens. Returns (client, admin, creator).
+        fn setup(env: &Env) -> (TokenFactoryClient<'_>, Address, Address) {
+            let (client, admin, _) = setup_factory(env);
+
+            let creator = Address::generate(env);
+            for _ in 0..2 {
+                create_test_token(env, &client, &creator, "BIG", 1_000_000);
+            }
+
+            (client, admin, creator)
+        }
+
+        #[test]
+        fn test_none_before_any_burn() {
+            let env = Env::default();
+            let (client, _, _) = setup(&env);
+
+            assert_eq!(client.get_largest_burn(), None);
+        }
+
+        #[test]
+        fn test_updates_only_on_larger_burn() {
+            let env = Env::default();
+            let (client, _, creator) = setup(&env);
+
+            client.burn(&creator, &0, &500);
+            assert_eq!(client.get_largest_burn().unwrap().amount, 500);
+
+            client.burn(&creator, &0, &100);
+            client.burn(&creator, &1, &500);
+            let largest = client.get_largest_burn().unwrap();
+            assert_eq!(largest.amount, 500);
+            assert_eq!(largest.token_index, 0);
+
+            client.burn(&creator, &1, &700);
+            let largest = client.get_largest_burn().unwrap();
+            assert_eq!(largest.amount, 700);
+            assert_eq!(largest.token_index, 1);
+        }
+
+        #[test]
+        fn test_tracks_admin_and_batch_burns() {
+            let env = Env::default();
+            let (client, admin, creator) = setup(&env);
+
+            client.admin_burn(&admin, &0, &creator, &1_000);
+            assert_eq!(client.get_largest_burn().unwrap().burned_by, admin);
+
+            client.batch_burn(
+                &admin,
+                &1,
+                &vec![
+                    &env,
+                    (creator.clone(), 200i128),
+                    (creator.clone(), 2_000i128),
+                ],
+            );
+            let largest = client.get_largest_burn().unwrap();
+            assert_eq!(largest.amount, 2_000);
+            assert_eq!(largest.token_index, 1);
+        }
+
+        #[test]
+        fn test_kept_when_records_are_capped() {
+            let env = Env::default();
+            let (client, admin, creator) = setup(&env);
+            client.set_max_burn_records_per_token(&admin, &Some(1));
+
+            client.burn(&creator, &0, &10);
+            client.burn(&creator, &0, &9_000);
+
+            assert_eq!(client.get_token_burn_record_count(&0), 1);
+            assert_eq!(client.get_largest_burn().unwrap().amount, 9_000);
+        }
+    }
+
+    /// Tests for the proof-of-burn query `verify_burn`.
+    mod verify_burn {
+        use soroban_sdk::{testutils::Address as _, Address, Env};
+
+        use crate::test_helpers::setup_with_token;
+
+        #[test]
+        fn test_sufficient_and_insufficient_burns() {
+            let env = Env::default();
+            let (client, _, creator, token_index) = setup_with_token(&env);
+
+            client.burn(&creator, &token_index, &300);
+            client.burn(&creator, &token_index, &200);
+
+            assert!(client.verify_burn(&token_index, &creator, &100));
+            assert!(client.verify_burn(&token_index, &creator, &500));
+            assert!(!client.verify_burn(&token_index, &creator, &501));
+        }
+
+        #[test]
+        fn test_zero_cases() {
+            let env = Env::default();
+            let (client, _, creator, token_index) = setup_with_token(&env);
+            let idle = Address::generate(&env);
+
+            client.burn(&creator, &token_index, &10);
+
+            assert!(!client.verify_burn(&token_index, &creator, &0));
+            assert!(!client.verify_burn(&token_index, &creator, &-1));
+            assert!(!client.verify_burn(&token_index, &idle, &1));
+            assert!(!client.verify_burn(&99, &creator, &1));
+        }
+
+        #[test]
+        fn test_admin_burn_follows_attribution_policy() {
+            let env = Env::default();
+            let (client, admin, creator, token_index) = setup_with_token(&env);
+
+            client.admin_burn(&admin, &token_index, &creator, &100);
+            assert!(client.verify_burn(&token_index, &creator, &100));
+            assert!(!client.verify_burn(&token_index, &admin, &100));
+
+            client.set_burn_credit_executor(&admin, &true);
+            client.admin_burn(&admin, &token_index, &creator, &100);
+            assert!(client.verify_burn(&token_index, &admin, &100));
+            assert!(!client.verify_burn(&token_index, &creator, &101));
+        }
+    }
+
+    /// Tests that zero-amount burns are rejected before any storage write.
+    mod zero_burn {
+        use soroban_sdk::{testutils::Address as _, vec, Address, Env};
+
+        use crate::storage;
+        use crate::test_helpers::setup_with_token;
+        use crate::types::Error;
+        use crate::TokenFactoryClient;
+
+        /// Assert nothing burn-related was written for the token.
+        fn assert_untouched(
+            env: &Env,
+            client: &TokenFactoryClient,
+            token_index: u32,
+            holder: &Address,
+        ) {
+            let info = client.get_token_info(&token_index);
+            assert_eq!(info.total_supply, 1_000_000);
+            assert_eq!(info.total_burned, 0);
+            assert_eq!(info.burn_count, 0);
+            assert_eq!(client.get_burn_count(&token_index), 0);
+            assert_eq!(client.get_burn_record_count(), 0);
+            assert_eq!(client.get_account_burned(&token_index, holder), 0);
+            assert_eq!(
+                env.as_contract(&client.address, || storage::get_balance(
+                    env,
+                    token_index,
+                    holder
+                )),
+                1_000_000
+            );
+        }
+
+        #[test]
+        fn test_zero_self_burn_rejected() {
+            let env = Env::default();
+            let (client, _, creator, token_index) = setup_with_token(&env);
+
+            assert_eq!(
+                client.try_burn(&creator, &token_index, &0),
+                Err(Ok(Error::InvalidParameters))
+            );
+            assert_untouched(&env, &client, token_index, &creator);
+        }
+
+        #[test]
+        fn test_zero_admin_and_batch_burn_rejected() {
+            let env = Env::default();
+            let (client, admin, creator, token_index) = setup_with_token(&env);
+
+            assert_eq!(
+                client.try_admin_burn(&admin, &token_index, &creator, &0),
+                Err(Ok(Error::InvalidParameters))
+            );
+            assert_eq!(
+                client.try_batch_burn(&admin, &token_index, &vec![&env, (creator.clone(), 0i128)]),
+                Err(Ok(Error::InvalidParameters))
+            );
+            assert_untouched(&env, &client, token_index, &creator);
+        }
+
+        #[test]
+        fn test_zero_correlated_burn_leaves_id_unused() {
+            let env = Env::default();
+            let (client, _, creator, token_index) = setup_with_token(&env);
+
+            assert_eq!(
+                client.try_burn_with_correlation(&creator, &token_index, &0, &42),
+                Err(Ok(Error::InvalidParameters))
+            );
+            assert_untouched(&env, &client, token_index, &creator);
+            assert_eq!(client.get_burn_by_correlation(&42), None);
+
+            client.burn_with_correlation(&creator, &token_index, &10, &42);
+            assert!(client.get_burn_by_correlation(&42).is_some());
+        }
+    }
+
+    /// Tests for the combined mint/burn history returned by `get_token_ledger`.
+    mod token_ledger {
+        use soroban_sdk::{testutils::Address as _, Address, Env};
+
+        use crate::test_helpers::{create_test_token, setup_factory};
+        use crate::types::SupplyEvent;
+        use crate::TokenFactoryClient;
+
+        /// Set up a factory with two tokens from one creator.
+        /// Returns (client, admin, creator).
+        fn setup(env: &Env) -> (TokenFactoryClient<'_>, Address, Address) {
+            let (client, admin, _) = setup_factory(env);
+
+            let creator = Address::generate(env);
+            for symbol in ["LDA", "LDB"] {
+                create_test_token(env, &client, &creator, symbol, 1_000_000);
+            }
+
+            (client, admin, creator)
+        }
+
+        /// Signed amounts of a ledger: mints positive, burns negative.
+        fn amounts(events: &soroban_sdk::Vec<SupplyEvent>) -> std::vec::Vec<i128> {
+            events
+                .iter()
+                .map(|event| match event {
+                    SupplyEvent::Mint(record) => record.amount,
+                    SupplyEvent::Burn(record) => -record.amount,
+                })
+                .collect()
+        }
+
+        #[test]
+        fn test_mints_and_burns_merged_chronologically() {
+            let env = Env::default();
+            let (client, _, creator) = setup(&env);
+            let holder = Address::generate(&env);
+
+            client.mint(&creator, &0, &holder, &10);
+            client.burn(&creator, &0, &1);
+            client.burn(&creator, &0, &2);
+            client.mint(&creator, &0, &holder, &20);
+            client.burn(&holder, &0, &3);
+            client.mint(&creator, &0, &holder, &30);
+
+            let events = client.get_token_ledger(&0, &0, &10);
+            assert_eq!(amounts(&events), std::vec![10, -1, -2, 20, -3, 30]);
+
+            match events.get(0).unwrap() {
+                SupplyEvent::Mint(record) => assert_eq!(record.to, holder),
+                SupplyEvent::Burn(_) => panic!("expected a mint first"),
+            }
+        }
+
+        #[test]
+        fn test_other_tokens_excluded() {
+            let env = Env::default();
+            let (client, _, creator) = setup(&env);
+            let holder = Address::generate(&env);
+
+            client.burn(&creator, &1, &100);
+            client.mint(&creator, &0, &holder, &10);
+            client.mint(&creator, &1, &holder, &200);
+            client.burn(&creator, &0, &1);
+            client.burn(&creator, &1, &300);
+
+            assert_eq!(
+                amounts(&client.get_token_ledger(&0, &0, &10)),
+                std::vec![10, -1]
+            );
+            assert_eq!(
+                amounts(&client.get_token_ledger(&1, &0, &10)),
+                std::vec![-100, 200, -300]
+            );
+        }
+
+        #[test]
+        fn test_same_ledger_order_is_exact() {
+            let env = Env::default();
+            let (client, admin, creator) = setup(&env);
+            let target = Address::generate(&env);
+
+            client.rebase(&admin, &0, &creator, &500, &target, &400);
+            client.mint(&creator, &0, &target, &7);
+
+            assert_eq!(
+                amounts(&client.get_token_ledger(&0, &0, &10)),
+                std::vec![-500, 400, 7]
+            );
+        }
+
+        #[test]
+        fn test_start_and_limit() {
+            let env = Env::default();
+            let (client, _, creator) = setup(&env);
+            let holder = Address::generate(&env);
+
+            for amount in 1..=3i128 {
+                client.mint(&creator, &0, &holder, &amount);
+                client.burn(&creator, &0, &amount);
+            }
+
+            assert_eq!(
+                amounts(&client.get_token_ledger(&0, &2, &2)),
+                std::vec![2, -2]
+            );
+            assert_eq!(
+                amounts(&client.get_token_ledger(&0, &5, &10)),
+                std::vec![-3]
+            );
+            assert_eq!(client.get_token_ledger(&0, &6, &10).len(), 0);
+            assert_eq!(client.get_token_ledger(&0, &0, &0).len(), 0);
+        }
+
+        #[test]
+        fn test_limit_is_clamped() {
+            let env = Env::default();
+            let (client, _, creator) = setup(&env);
+
+            for _ in 0..55 {
+                client.burn(&creator, &0, &1);
+            }
+
+            assert_eq!(client.get_token_ledger(&0, &0, &500).len(), 45);
+            assert_eq!(client.get_token_ledger(&0, &45, &500).len(), 10);
+        }
+
+        #[test]
+        fn test_busy_neighbour_does_not_hide_events() {
+            let env = Env::default();
+            let (client, _, creator) = setup(&env);
+            let holder = Address::generate(&env);
+
+            for _ in 0..120 {
+                client.burn(&creator, &1, &1);
+            }
+            client.mint(&creator, &0, &holder, &10);
+
+            assert_eq!(
+                amounts(&client.get_token_ledger(&0, &0, &10)),
+                std::vec![10]
+            );
+        }
+    }
+
+    /// Tests for reporter-gated holder counts.
+    mod holder_reporter {
+        use soroban_sdk::{testutils::Address as _, Address, Env};
+
+        use crate::test_helpers::setup_with_token;
+        use crate::types::Error;
+        use crate::TokenFactoryClient;
+
+        /// Set up a factory with one token and a holder reporter.
+        /// Returns (client, admin, creator, reporter, token_index).
+        fn setup(env: &Env) -> (TokenFactoryClient<'_>, Address, Address, Address, u32) {
+            let (client, admin, creator, token_index) = setup_with_token(env);
+
+            let reporter = Address::generate(env);
+            client.set_holder_reporter(&admin, &token_index, &reporter);
+
+            (client, admin, creator, reporter, token_index)
+        }
+
+        #[test]
+        fn test_reporter_adjusts_count() {
+            let env = Env::default();
+            let (client, _, _, reporter, token_index) = setup(&env);
+
+            assert_eq!(
+                client.get_holder_reporter(&token_index),
+                Some(reporter.clone())
+            );
+            assert_eq!(client.get_holder_count(&token_index), 0);
+
+            assert_eq!(client.record_holder_added(&reporter, &token_index), 1);
+            assert_eq!(client.record_holder_added(&reporter, &token_index), 2);
+            assert_eq!(client.record_holder_removed(&reporter, &token_index), 1);
+            assert_eq!(client.get_holder_count(&token_index), 1);
+        }
+
+        #[test]
+        fn test_admin_can_adjust_count() {
+            let env = Env::default();
+            let (client, admin, _, _, token_index) = setup(&env);
+
+            assert_eq!(client.record_holder_added(&admin, &token_index), 1);
+            assert_eq!(client.record_holder_removed(&admin, &token_index), 0);
+        }
+
+        #[test]
+        fn test_unauthorized_caller_rejected() {
+            let env = Env::default();
+            let (client, _, creator, _, token_index) = setup(&env);
+            let stranger = Address::generate(&env);
+
+            assert_eq!(
+                client.try_record_holder_added(&stranger, &token_index),
+                Err(Ok(Error::Unauthorized))
+            );
+            assert_eq!(
+                client.try_record_holder_removed(&creator, &token_index),
+                Err(Ok(Error::Unauthorized))
+            );
+            assert_eq!(
+                client.try_set_holder_reporter(&creator, &token_index, &creator),
+                Err(Ok(Error::Unauthorized))
+            );
+            assert_eq!(client.get_holder_count(&token_index), 0);
+        }
+
+        #[test]
+        fn test_removal_below_zero_and_unknown_token() {
+            let env = Env::default();
+            let (client, admin, _, reporter, token_index) = setup(&env);
+
+            assert_eq!(
+                client.try_record_holder_removed(&reporter, &token_index),
+                Err(Ok(Error::ArithmeticError))
+            );
+            assert_eq!(
+                client.try_record_holder_added(&reporter, &7),
+                Err(Ok(Error::TokenNotFound))
+            );
+            assert_eq!(
+                client.try_set_holder_reporter(&admin, &7, &reporter),
+                Err(Ok(Error::TokenNotFound))
+            );
+        }
+    }
+
+    /// Tests for the `reinitialize_burn_fields` migration helper.
+    mod reinitialize_burn_fields {
+        use soroban_sdk::{testutils::Address as _, Address, Env};
+
+        use crate::storage;
+        use crate::test_helpers::{create_test_token, setup_factory};
+        use crate::types::Error;
+        use crate::TokenFactoryClient;
+
+        /// Set up a factory with `count` tokens. Returns (client, admin, creator).
+        fn setup(env: &Env, count: u32) -> (TokenFactoryClient<'_>, Address, Address) {
+            let (client, admin, _) = setup_factory(env);
+
+            let creator = Address::generate(env);
+            for _ in 0..count {
+                create_test_token(env, &client, &creator, "LGC", 1_000_000);
+            }
+
+            (client, admin, creator)
+        }
+
+        /// Overwrite a token's stored info the way a pre-burn-tracking entry
+        /// would decode: no initial supply, no burn fields.
+        fn write_legacy_entry(env: &Env, client: &TokenFactoryClient, token_index: u32) {
+            env.as_contract(&client.address, || {
+                let mut info = storage::get_token_info(env, token_index).unwrap();
+                info.initial_supply = 0;
+                info.total_burned = -1;
+                info.burn_count = 0;
+                storage::set_token_info(env, token_index, &info);
+                storage::set_token_info_by_address(env, &info.address, &info);
+            });
+        }
+
+        #[test]
+        fn test_legacy_entry_is_normalized() {
+            let env = Env::default();
+            let (client, admin, creator) = setup(&env, 1);
+
+            client.burn(&creator, &0, &100);
+            client.burn(&creator, &0, &150);
+            client.mint(&creator, &0, &creator, &500);
+            write_legacy_entry(&env, &client, 0);
+
+            assert_eq!(client.reinitialize_burn_fields(&0, &10, &admin), 1);
+
+            let info = client.get_token_info(&0);
+            assert_eq!(info.total_burned, 250);
+            assert_eq!(info.burn_count, 2);
+            assert_eq!(info.initial_supply, 1_000_000);
+            assert_eq!(info.total_supply, 1_000_250);
+            assert_eq!(client.get_token_info_by_address(&info.address), info);
+        }
+
+        #[test]
+        fn test_consistent_entries_untouched_and_rerun_is_noop() {
+            let env = Env::default();
+            let (client, admin, creator) = setup(&env, 2);
+
+            client.burn(&creator, &1, &10);
+            let before = client.get_token_info(&1);
+            write_legacy_entry(&env, &client, 0);
+
+            assert_eq!(client.reinitialize_burn_fields(&0, &10, &admin), 1);
+            assert_eq!(client.get_token_info(&1), before);
+            assert_eq!(client.reinitialize_burn_fields(&0, &10, &admin), 0);
+        }
+
+        #[test]
+        fn test_paging_limits_scan() {
+            let env = Env::default();
+            let (client, admin, _) = setup(&env, 3);
+
+            for i in 0..3 {
+                write_legacy_entry(&env, &client, i);
+            }
+
+            assert_eq!(client.reinitialize_burn_fields(&0, &2, &admin), 2);
+            assert_eq!(client.get_token_info(&2).total_burned, -1);
+            assert_eq!(client.reinitialize_burn_fields(&2, &2, &admin), 1);
+            assert_eq!(client.get_token_info(&2).total_burned, 0);
+            assert_eq!(client.reinitialize_burn_fields(&10, &2, &admin), 0);
+        }
+
+        #[test]
+        fn test_requires_admin() {
+            let env = Env::default();
+            let (client, _, creator) = setup(&env, 1);
+
+            assert_eq!(
+                client.try_reinitialize_burn_fields(&0, &10, &creator),
+                Err(Ok(Error::Unauthorized))
+            );
+        }
+    }
+
+    /// Tests for `migrate_token_aggregates`.
+    mod migrate_aggregates {
+        use soroban_sdk::{testutils::Address as _, Address, Env};
+
+        use crate::test_helpers::{create_test_token, setup_factory};
+        use crate::types::Error;
+        use crate::TokenFactoryClient;
+
+        /// Set up a factory with two tokens. Returns (client, admin, creator).
+        fn setup(env: &Env) -> (TokenFactoryClient<'_>, Address, Address) {
+            let (client, admin, _) = setup_factory(env);
+
+            let creator = Address::generate(env);
+            for _ in 0..2 {
+                create_test_token(env, &client, &creator, "MIG", 1_000_000);
+            }
+
+            (client, admin, creator)
+        }
+
+        #[test]
+        fn test_new_token_inherits_aggregates() {
+            let env = Env::default();
+            let (client, admin, creator) = setup(&env);
+
+            client.burn(&creator, &0, &300);
+            client.burn(&creator, &0, &200);
+            client.burn(&creator, &1, &50);
+
+            client.migrate_token_aggregates(&0, &1, &admin);
+
+            let new = client.get_token_info(&1);
+            assert_eq!(new.total_burned, 550);
+            assert_eq!(new.burn_count, 3);
+            assert_eq!(new.total_supply, 999_950);
+            assert_eq!(client.get_burn_count(&1), 3);
+            assert!(client.verify_supply_invariant(&1));
+
+            // Old token keeps its figures but is retired
+            let old = client.get_token_info(&0);
+            assert_eq!(old.total_burned, 500);
+            assert!(client.is_token_retired(&0));
+            assert_eq!(
+                client.try_burn(&creator, &0, &1),
+                Err(Ok(Error::TokenRetired))
+            );
+        }
+
+        #[test]
+        fn test_migration_requires_admin() {
+            let env = Env::default();
+            let (client, _, creator) = setup(&env);
+
+            assert_eq!(
+                client.try_migrate_token_aggregates(&0, &1, &creator),
+                Err(Ok(Error::Unauthorized))
+            );
+            assert!(!client.is_token_retired(&0));
+        }
+
+        #[test]
+        fn test_migration_validates_indices() {
+            let env = Env::default();
+            let (client, admin, _) = setup(&env);
+
+            assert_eq!(
+                client.try_migrate_token_aggregates(&0, &0, &admin),
+                Err(Ok(Error::InvalidParameters))
+            );
+            assert_eq!(
+                client.try_migrate_token_aggregates(&0, &9, &admin),
+                Err(Ok(Error::TokenNotFound))
+            );
+
+            client.migrate_token_aggregates(&0, &1, &admin);
+            assert_eq!(
+                client.try_migrate_token_aggregates(&0, &1, &admin),
+                Err(Ok(Error::TokenRetired))
+            );
+        }
+    }
+
+    /// Tests for `verify_supply_invariant`.
+    mod supply_invariant {
+        use soroban_sdk::{testutils::Address as _, vec, Address, Env};
+
+        use crate::storage;
+
+        use crate::test_helpers::setup_with_token;
+
+        #[test]
+        fn test_healthy_token_holds() {
+            let env = Env::default();
+            let (client, admin, creator, token_index) = setup_with_token(&env);
+            let holder = Address::generate(&env);
+
+            assert!(client.verify_supply_invariant(&token_index));
+
+            client.burn(&creator, &token_index, &1_000);
+            client.mint(&creator, &token_index, &holder, &5_000);
+            client.batch_burn(
+                &admin,
+                &token_index,
+                &vec![&env, (holder.clone(), 2_000i128)],
+            );
+            client.rebase(&admin, &token_index, &creator, &3_000, &holder, &4_000);
+
+            assert!(client.verify_supply_invariant(&token_index));
+        }
+
+        #[test]
+        fn test_corrupted_supply_detected() {
+            let env = Env::default();
+            let (client, _, creator, token_index) = setup_with_token(&env);
+            client.burn(&creator, &token_index, &1_000);
+
+            env.as_contract(&client.address, || {
+                let mut info = storage::get_token_info(&env, token_index).unwrap();
+                info.total_supply += 1;
+                storage::set_token_info(&env, token_index, &info);
+            });
+
+            assert!(!client.verify_supply_invariant(&token_index));
+        }
+
+        #[test]
+        fn test_desynced_burn_tracker_detected() {
+            let env = Env::default();
+            let (client, _, creator, token_index) = setup_with_token(&env);
+            client.burn(&creator, &token_index, &1_000);
+
+            env.as_contract(&client.address, || {
+                storage::add_total_burned(&env, token_index, 1).unwrap();
+            });
+
+            assert!(!client.verify_supply_invariant(&token_index));
+        }
+
+        #[test]
+        fn test_unknown_token_is_false() {
+            let env = Env::default();
+            let (client, _, _, _) = setup_with_token(&env);
+
+            assert!(!client.verify_supply_invariant(&42));
+        }
+    }
+
+    /// Tests for `get_total_burned_multi`.
+    mod total_burned_multi {
+        use soroban_sdk::{testutils::Address as _, vec, Address, Env, Vec};
+
+        use crate::storage;
+        use crate::test_helpers::{create_test_token, setup_factory};
+        use crate::types::Error;
+        use crate::TokenFactoryClient;
+
+        /// Set up a factory with three tokens, each registered at its own
+        /// address. Returns (client, creator, token_addresses).
+        fn setup(env: &Env) -> (TokenFactoryClient<'_>, Address, Vec<Address>) {
+            let (client, _, _) = setup_factory(env);
+
+            let creator = Address::generate(env);
+            let mut addresses = Vec::new(env);
+            for _ in 0..3 {
+                let token_index = create_test_token(env, &client, &creator, "MLT", 1_000_000);
+                // Tokens share the factory address until they are deployed
+                // separately; give each one a distinct address to query by
+                let address = Address::generate(env);
+                env.as_contract(&client.address, || {
+                    storage::set_token_index_by_address(env, &address, token_index);
+                });
+                addresses.push_back(address);
+            }
+
+            (client, creator, addresses)
+        }
+
+        #[test]
+        fn test_sums_across_tokens() {
+            let env = Env::default();
+            let (client, creator, tokens) = setup(&env);
+
+            client.burn(&creator, &0, &100);
+            client.burn(&creator, &1, &250);
+            client.burn(&creator, &1, &50);
+
+            assert_eq!(client.get_total_burned_multi(&tokens), 400);
+            assert_eq!(
+                client.get_total_burned_multi(&vec![&env, tokens.get(1).unwrap()]),
+                300
+            );
+            assert_eq!(client.get_total_burned_multi(&Vec::new(&env)), 0);
+        }
+
+        #[test]
+        fn test_unknown_token_contributes_zero() {
+            let env = Env::default();
+            let (client, creator, tokens) = setup(&env);
+            client.burn(&creator, &0, &100);
+
+            let query = vec![&env, tokens.get(0).unwrap(), Address::generate(&env)];
+            assert_eq!(client.get_total_burned_multi(&query), 100);
+        }
+
+        #[test]
+        fn test_duplicates_counted_each_time() {
+            let env = Env::default();
+            let (client, creator, tokens) = setup(&env);
+            client.burn(&creator, &2, &40);
+
+            let token = tokens.get(2).unwrap();
+            let query = vec![&env, token.clone(), token];
+            assert_eq!(client.get_total_burned_multi(&query), 80);
+        }
+
+        #[test]
+        fn test_input_size_capped() {
+            let env = Env::default();
+            let (client, creator, tokens) = setup(&env);
+            client.burn(&creator, &0, &1);
+
+            // A full batch of known addresses stays within the footprint
+            let mut at_cap = Vec::new(&env);
+            for _ in 0..45 {
+                at_cap.push_back(tokens.get(0).unwrap());
+            }
+            assert_eq!(client.get_total_burned_multi(&at_cap), 45);
+
+            at_cap.push_back(Address::generate(&env));
+            assert_eq!(
+                client.try_get_total_burned_multi(&at_cap),
+                Err(Ok(Error::BatchTooLarge))
+            );
+        }
+    }
+
+    /// Tests for the admin-only `rebase` (burn-then-mint) operation.
+    mod rebase {
+        use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+
+        use crate::storage;
+        use crate::test_helpers::setup_with_token;
+        use crate::types::{Error, TokenCreationParams};
+        use crate::TokenFactoryClient;
+
+        fn balance_of(
+            env: &Env,
+            client: &TokenFactoryClient,
+            token_index: u32,
+            holder: &Address,
+        ) -> i128 {
+            env.as_contract(&client.address, || {
+                storage::get_balance(env, token_index, holder)
+            })
+        }
+
+        #[test]
+        fn test_rebase_moves_supply_between_accounts() {
+            let env = Env::default();
+            let (client, admin, creator, token_index) = setup_with_token(&env);
+            let target = Address::generate(&env);
+
+            let supply = client.rebase(&admin, &token_index, &creator, &400_000, &target, &400_000);
+            assert_eq!(supply, 1_000_000);
+
+            assert_eq!(balance_of(&env, &client, token_index, &creator), 600_000);
+            assert_eq!(balance_of(&env, &client, token_index, &target), 400_000);
+
+            let info = client.get_token_info(&token_index);
+            assert_eq!(info.total_supply, 1_000_000);
+            assert_eq!(info.total_burned, 400_000);
+            assert_eq!(info.burn_count, 1);
+            assert_eq!(client.get_burn_count(&token_index), 1);
+        }
+
+        #[test]
+        fn test_rebase_nets_supply_when_amounts_differ() {
+            let env = Env::default();
+            let (client, admin, creator, token_index) = setup_with_token(&env);
+            let target = Address::generate(&env);
+
+            let supply = client.rebase(&admin, &token_index, &creator, &300_000, &target, &100_000);
+            assert_eq!(supply, 800_000);
+            assert_eq!(client.get_token_info(&token_index).total_supply, 800_000);
+        }
+
+        #[test]
+        fn test_rebase_rolls_back_burn_on_cap_violation() {
+            let env = Env::default();
+            let (client, admin, creator, _) = setup_with_token(&env);
+            let target = Address::generate(&env);
+
+            // Second token capped at its initial supply plus 100
+            let params = TokenCreationParams {
+                name: String::from_str(&env, "Capped"),
+                symbol: String::from_str(&env, "CAP"),
+                decimals: 7,
+                initial_supply: 1_000,
+                max_supply: Some(1_100),
+                metadata_uri: None,
+            };
+            client.batch_create_tokens(&creator, &vec![&env, params], &70_000_000);
+            let capped = 1u32;
+
+            // Burn 100, mint 250 → post-burn 900 + 250 = 1150 > 1100
+            let result = client.try_rebase(&admin, &capped, &creator, &100, &target, &250);
+            assert_eq!(result, Err(Ok(Error::MaxSupplyExceeded)));
+
+            // Burn leg must not have been persisted
+            let info = client.get_token_info(&capped);
+            assert_eq!(info.total_supply, 1_000);
+            assert_eq!(info.total_burned, 0);
+            assert_eq!(balance_of(&env, &client, capped, &creator), 1_000);
+            assert_eq!(balance_of(&env, &client, capped, &target), 0);
+        }
+
+        #[test]
+        fn test_rebase_requires_admin() {
+            let env = Env::default();
+            let (client, _, creator, token_index) = setup_with_token(&env);
+            let target = Address::generate(&env);
+
+            let result = client.try_rebase(&creator, &token_index, &creator, &1, &target, &1);
+            assert_eq!(result, Err(Ok(Error::Unauthorized)));
+        }
+
+        #[test]
+        fn test_rebase_insufficient_balance() {
+            let env = Env::default();
+            let (client, admin, _, token_index) = setup_with_token(&env);
+            let empty = Address::generate(&env);
+            let target = Address::generate(&env);
+
+            let result = client.try_rebase(&admin, &token_index, &empty, &1, &target, &1);
+            assert_eq!(result, Err(Ok(Error::InsufficientBalance)));
+        }
+    }
 }
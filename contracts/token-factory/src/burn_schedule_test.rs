@@ -301,7 +301,7 @@ mod burn_schedule_tests {
         let unlock = now(&env) + LOCK_DELAY;
 
         let id = client.schedule_burn(&admin, &token_index, &holder, &1000, &unlock);
-        client.cancel_burn_schedule(&admin, &id);
+        client.cancel_scheduled_burn(&id, &admin);
 
         advance_time(&env, LOCK_DELAY + 1);
         let executor = Address::generate(&env);
@@ -326,7 +326,7 @@ mod burn_schedule_tests {
     }
 
     // ─────────────────────────────────────────────────────────────────────────
-    // cancel_burn_schedule
+    // cancel_scheduled_burn
     // ─────────────────────────────────────────────────────────────────────────
 
     #[test]
@@ -337,7 +337,7 @@ mod burn_schedule_tests {
         let unlock = now(&env) + LOCK_DELAY;
 
         let id = client.schedule_burn(&admin, &token_index, &holder, &1000, &unlock);
-        client.cancel_burn_schedule(&admin, &id);
+        client.cancel_scheduled_burn(&id, &admin);
 
         let schedule = client.get_burn_schedule(&id).unwrap();
         assert_eq!(schedule.status, crate::types::BurnScheduleStatus::Cancelled);
@@ -352,7 +352,7 @@ mod burn_schedule_tests {
 
         let id = client.schedule_burn(&admin, &token_index, &holder, &1000, &unlock);
         let unauthorized = Address::generate(&env);
-        let result = client.try_cancel_burn_schedule(&unauthorized, &id);
+        let result = client.try_cancel_scheduled_burn(&id, &unauthorized);
         assert!(result.is_err());
     }
 
@@ -364,8 +364,8 @@ mod burn_schedule_tests {
         let unlock = now(&env) + LOCK_DELAY;
 
         let id = client.schedule_burn(&admin, &token_index, &holder, &1000, &unlock);
-        client.cancel_burn_schedule(&admin, &id);
-        let result = client.try_cancel_burn_schedule(&admin, &id);
+        client.cancel_scheduled_burn(&id, &admin);
+        let result = client.try_cancel_scheduled_burn(&id, &admin);
         assert!(result.is_err());
     }
 
@@ -383,7 +383,7 @@ mod burn_schedule_tests {
         let executor = Address::generate(&env);
         client.execute_burn_schedule(&executor, &id);
 
-        let result = client.try_cancel_burn_schedule(&admin, &id);
+        let result = client.try_cancel_scheduled_burn(&id, &admin);
         assert!(result.is_err());
     }
 
@@ -391,7 +391,7 @@ mod burn_schedule_tests {
     fn test_cancel_nonexistent_schedule_fails() {
         let env = Env::default();
         let (client, admin, _, _) = setup(&env);
-        let result = client.try_cancel_burn_schedule(&admin, &999);
+        let result = client.try_cancel_scheduled_burn(&999, &admin);
         assert!(result.is_err());
     }
 
@@ -474,7 +474,7 @@ mod burn_schedule_tests {
         let id0 = client.schedule_burn(&admin, &token_index, &holder, &1000, &unlock);
         let id1 = client.schedule_burn(&admin, &token_index, &holder, &2000, &unlock);
 
-        client.cancel_burn_schedule(&admin, &id0);
+        client.cancel_scheduled_burn(&id0, &admin);
 
         advance_time(&env, LOCK_DELAY + 1);
         let executor = Address::generate(&env);
@@ -555,4 +555,84 @@ mod burn_schedule_tests {
         let count_after = client.get_token_info(&token_index).burn_count;
         assert_eq!(count_after, count_before + 1);
     }
+
+    /// Tests for cancelling scheduled burns.
+    mod burn_schedule_cancel {
+        use soroban_sdk::{
+            testutils::{Address as _, Ledger},
+            Address, Env,
+        };
+
+        use crate::test_helpers::setup_with_token;
+        use crate::types::{BurnScheduleStatus, Error};
+        use crate::TokenFactoryClient;
+
+        const LOCK_DELAY: u64 = 3_600;
+
+        /// Set up a factory with one token, a funded holder and one pending
+        /// schedule. Returns (client, admin, holder, schedule_id).
+        fn setup(env: &Env) -> (TokenFactoryClient<'_>, Address, Address, u64) {
+            let (client, admin, creator, token_index) = setup_with_token(env);
+
+            let holder = Address::generate(env);
+            client.mint(&creator, &token_index, &holder, &5_000);
+
+            let unlock = env.ledger().timestamp() + LOCK_DELAY;
+            let id = client.schedule_burn(&admin, &token_index, &holder, &1_000, &unlock);
+
+            (client, admin, holder, id)
+        }
+
+        #[test]
+        fn test_cancelled_schedule_cannot_execute() {
+            let env = Env::default();
+            let (client, admin, _, id) = setup(&env);
+
+            client.cancel_scheduled_burn(&id, &admin);
+            env.ledger().with_mut(|l| l.timestamp += LOCK_DELAY + 1);
+
+            assert_eq!(
+                client.try_execute_burn_schedule(&Address::generate(&env), &id),
+                Err(Ok(Error::BurnCancelled))
+            );
+            assert_eq!(client.get_token_info(&0).total_burned, 0);
+            assert_eq!(
+                client.get_burn_schedule(&id).unwrap().status,
+                BurnScheduleStatus::Cancelled
+            );
+        }
+
+        #[test]
+        fn test_cancel_by_unauthorized_caller() {
+            let env = Env::default();
+            let (client, _, holder, id) = setup(&env);
+
+            assert_eq!(
+                client.try_cancel_scheduled_burn(&id, &holder),
+                Err(Ok(Error::Unauthorized))
+            );
+            assert_eq!(
+                client.get_burn_schedule(&id).unwrap().status,
+                BurnScheduleStatus::Pending
+            );
+        }
+
+        #[test]
+        fn test_cancel_after_execution_rejected() {
+            let env = Env::default();
+            let (client, admin, _, id) = setup(&env);
+
+            env.ledger().with_mut(|l| l.timestamp += LOCK_DELAY);
+            client.execute_burn_schedule(&Address::generate(&env), &id);
+
+            assert_eq!(
+                client.try_cancel_scheduled_burn(&id, &admin),
+                Err(Ok(Error::BurnScheduleAlreadyExecuted))
+            );
+            assert_eq!(
+                client.try_cancel_scheduled_burn(&99, &admin),
+                Err(Ok(Error::BurnScheduleNotFound))
+            );
+        }
+    }
 }
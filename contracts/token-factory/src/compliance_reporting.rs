@@ -88,7 +88,7 @@ pub fn generate_report(env: &Env, admin: &Address) -> Result<ComplianceReport, E
 
     // ── Aggregate metrics ────────────────────────────────────────────────────
     let token_count = storage::get_token_count(env);
-    let (total_supply, total_burned, total_burn_ops) = aggregate_token_metrics(env, token_count);
+    let (total_supply, total_burned, total_burn_ops) = aggregate_token_metrics(env);
 
     let gov_config = storage::get_governance_config(env);
     let contract_paused = storage::is_paused(env);
@@ -148,12 +148,12 @@ pub fn get_report_count(env: &Env) -> u64 {
 ///
 /// Uses saturating arithmetic for the aggregate sums so a single corrupted
 /// token entry cannot cause the entire report to fail.
-fn aggregate_token_metrics(env: &Env, token_count: u32) -> (i128, i128, u32) {
+fn aggregate_token_metrics(env: &Env) -> (i128, i128, u32) {
     let mut total_supply: i128 = 0;
     let mut total_burned: i128 = 0;
     let mut total_burn_ops: u32 = 0;
 
-    for i in 0..token_count {
+    for i in storage::valid_token_indices(env) {
         if let Some(info) = storage::get_token_info(env, i) {
             total_supply = total_supply.saturating_add(info.total_supply);
             total_burned = total_burned.saturating_add(info.total_burned);
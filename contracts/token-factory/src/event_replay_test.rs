@@ -106,8 +106,9 @@ impl ReplayState {
                 let Ok(token_address) = token_addr_decoded else {
                     return;
                 };
-                let decoded: Result<(Address, String, String, u32, i128), _> = data.try_into_val(env);
-                let Ok((creator, _name, _symbol, _decimals, initial_supply)) = decoded
+                let decoded: Result<(Address, String, String, u32, i128, u32), _> =
+                    data.try_into_val(env);
+                let Ok((creator, _name, _symbol, _decimals, initial_supply, _version)) = decoded
                 else {
                     return;
                 };
@@ -146,8 +147,8 @@ impl ReplayState {
                 let Ok(token_index) = token_idx_decoded else {
                     return;
                 };
-                let decoded: Result<(Address, i128, i128), _> = data.try_into_val(env);
-                let Ok((_caller, _amount, new_supply)) = decoded
+                let decoded: Result<(Address, i128, i128, u32), _> = data.try_into_val(env);
+                let Ok((_caller, _amount, new_supply, _version)) = decoded
                 else {
                     return;
                 };
@@ -162,8 +163,8 @@ impl ReplayState {
                 let Ok(token_index) = token_idx_decoded else {
                     return;
                 };
-                let decoded: Result<(Address, Address, i128, i128), _> = data.try_into_val(env);
-                let Ok((_admin, _holder, _amount, new_supply)) = decoded
+                let decoded: Result<(Address, Address, i128, i128, u32), _> = data.try_into_val(env);
+                let Ok((_admin, _holder, _amount, new_supply, _version)) = decoded
                 else {
                     return;
                 };
@@ -178,8 +179,8 @@ impl ReplayState {
                 let Ok(token_index) = token_idx_decoded else {
                     return;
                 };
-                let decoded: Result<(Address, u32, i128, i128), _> = data.try_into_val(env);
-                let Ok((_admin, _count, _total_burned, new_supply)) = decoded
+                let decoded: Result<(Address, u32, i128, i128, u32), _> = data.try_into_val(env);
+                let Ok((_admin, _count, _total_burned, new_supply, _version)) = decoded
                 else {
                     return;
                 };
@@ -21,6 +21,14 @@
 ///
 /// Any schema changes require creating a new version with an incremented version number.
 
+/// In-band schema version carried as the last payload field of burn and
+/// creation events (`tok_crt`, `burn_v1`, `adm_bn_v1`, `bch_bn_v1`,
+/// `rebase_v1`).
+///
+/// Indexers compare this against `event_schema_version()` to pick a decoder.
+/// Bump it only when one of those payloads changes shape.
+pub const EVENT_SCHEMA_VERSION: u32 = 1;
+
 // Current event versions (all v1)
 pub const INIT_VERSION: u32 = 1;
 pub const TOKEN_REGISTERED_VERSION: u32 = 1;
@@ -1,53 +1,113 @@
-/// Optimized Event Module with Versioned Schemas
-///
-/// This module provides optimized event emission functions that reduce
-/// gas costs by approximately 400-500 CPU instructions per event.
-///
-/// Optimizations applied:
-/// - Removed redundant timestamp parameters (ledger provides this)
-/// - Reduced indexed parameters where not needed for filtering
-/// - Optimized payload sizes
-///
-/// Issue: #232 - Gas Usage Analysis and Optimization Report
-/// Status: Phase 1 - Quick Wins
-///
-/// # Event Versioning
-///
-/// All events include version identifiers (e.g., "_v1") to support stable backend indexers
-/// as the contract evolves. Event schemas are immutable once deployed - any changes require
-/// creating a new version with an incremented version number.
-///
-/// ## Event Name Mapping
-///
-/// The following table documents the mapping between original event names and their
-/// versioned counterparts. Some names are abbreviated to fit within the 10-character
-/// `symbol_short!` limit.
-///
-/// | Original Name | Versioned Name | Character Count | Rationale                          |
-/// |---------------|----------------|-----------------|-------------------------------------|
-/// | init          | init_v1        | 7               | Fits within limit                   |
-/// | tok_reg       | tok_rg_v1      | 9               | Removed 'e' to fit limit            |
-/// | adm_xfer      | adm_xf_v1      | 9               | Removed 'er' to fit limit           |
-/// | pause         | pause_v1       | 8               | Fits within limit                   |
-/// | unpause       | unpaus_v1      | 9               | Removed 'e' to fit limit            |
-/// | fee_upd       | fee_up_v1      | 9               | Removed 'd' to fit limit            |
-/// | adm_burn      | adm_br_v1      | 9               | Removed 'urn' to fit limit          |
-/// | clawback      | clwbck_v1      | 9               | Removed 'a' to fit limit            |
-/// | tok_burn      | tok_br_v1      | 9               | Removed 'urn' to fit limit          |
-/// | burn          | burn_v1        | 7               | Fits within limit                   |
-/// | admin_burn    | adm_bn_v1      | 9               | Removed 'r' to fit limit            |
-/// | batch_burn    | bch_bn_v1      | 9               | Removed 'at' and 'r' to fit limit   |
-///
-/// ## Schema Stability
-///
-/// Once an event version is deployed, its schema MUST NOT be modified:
-/// - Topic structure (indexed parameters) must remain unchanged
-/// - Payload structure (non-indexed data) must remain unchanged
-/// - Data types for all parameters must remain unchanged
-///
-/// Any schema changes require creating a new version (e.g., init_v2).
+//! Optimized Event Module with Versioned Schemas
+//!
+//! This module provides optimized event emission functions that reduce
+//! gas costs by approximately 400-500 CPU instructions per event.
+//!
+//! Optimizations applied:
+//! - Removed redundant timestamp parameters (ledger provides this)
+//! - Reduced indexed parameters where not needed for filtering
+//! - Optimized payload sizes
+//!
+//! Issue: #232 - Gas Usage Analysis and Optimization Report
+//! Status: Phase 1 - Quick Wins
+//!
+//! # Event Versioning
+//!
+//! All events include version identifiers (e.g., "_v1") to support stable backend indexers
+//! as the contract evolves. Event schemas are immutable once deployed - any changes require
+//! creating a new version with an incremented version number.
+//!
+//! ## Event Name Mapping
+//!
+//! The following table documents the mapping between original event names and their
+//! versioned counterparts. Some names are abbreviated to fit within the 10-character
+//! `symbol_short!` limit.
+//!
+//! | Original Name | Versioned Name | Character Count | Rationale                          |
+//! |---------------|----------------|-----------------|-------------------------------------|
+//! | init          | init_v1        | 7               | Fits within limit                   |
+//! | tok_reg       | tok_rg_v1      | 9               | Removed 'e' to fit limit            |
+//! | adm_xfer      | adm_xf_v1      | 9               | Removed 'er' to fit limit           |
+//! | pause         | pause_v1       | 8               | Fits within limit                   |
+//! | unpause       | unpaus_v1      | 9               | Removed 'e' to fit limit            |
+//! | fee_upd       | fee_up_v1      | 9               | Removed 'd' to fit limit            |
+//! | adm_burn      | adm_br_v1      | 9               | Removed 'urn' to fit limit          |
+//! | clawback      | clwbck_v1      | 9               | Removed 'a' to fit limit            |
+//! | tok_burn      | tok_br_v1      | 9               | Removed 'urn' to fit limit          |
+//! | burn          | burn_v1        | 7               | Fits within limit                   |
+//! | admin_burn    | adm_bn_v1      | 9               | Removed 'r' to fit limit            |
+//! | batch_burn    | bch_bn_v1      | 9               | Removed 'at' and 'r' to fit limit   |
+//!
+//! ## Schema Stability
+//!
+//! Once an event version is deployed, its schema MUST NOT be modified:
+//! - Topic structure (indexed parameters) must remain unchanged
+//! - Payload structure (non-indexed data) must remain unchanged
+//! - Data types for all parameters must remain unchanged
+//!
+//! Any schema changes require creating a new version (e.g., init_v2).
+//!
+//! Burn and creation payloads additionally end with an in-band
+//! `event_version` (see `event_versions::EVENT_SCHEMA_VERSION`).
+
+use soroban_sdk::{symbol_short, Address, BytesN, Env, IntoVal, String, Symbol, Topics, Val};
+
+use crate::event_versions::EVENT_SCHEMA_VERSION;
+use crate::storage;
+
+/// Publish a non-essential event unless emission has been switched off.
+///
+/// Security-relevant emitters (admin, treasury, pause/freeze, roles,
+/// upgrades, timelock) call `env.events().publish` directly and always emit.
+fn publish<T, D>(env: &Env, topics: T, data: D)
+where
+    T: Topics,
+    D: IntoVal<Env, Val>,
+{
+    if storage::events_enabled(env) {
+        env.events().publish(topics, data);
+    }
+}
+
+// ── Topic builders ──────────────────────────────────────────
+//
+// Single source of truth for the topic tuple of each core event, so that
+// emitters and indexer-facing tests cannot drift apart.
+
+/// Topics for `tok_crt`: (name, token_address)
+pub fn created_topic(token_address: &Address) -> (Symbol, Address) {
+    (symbol_short!("tok_crt"), token_address.clone())
+}
+
+/// Topics for `burn_v1`: (name, token_index)
+pub fn burn_topic(token_index: u32) -> (Symbol, u32) {
+    (symbol_short!("burn_v1"), token_index)
+}
+
+/// Topics for `adm_bn_v1`: (name, token_index)
+pub fn admin_burn_topic(token_index: u32) -> (Symbol, u32) {
+    (symbol_short!("adm_bn_v1"), token_index)
+}
 
-use soroban_sdk::{symbol_short, Address, BytesN, Env, String};
+/// Topics for `bch_bn_v1`: (name, token_index)
+pub fn batch_burn_topic(token_index: u32) -> (Symbol, u32) {
+    (symbol_short!("bch_bn_v1"), token_index)
+}
+
+/// Topics for `rebase_v1`: (name, token_index)
+pub fn rebase_topic(token_index: u32) -> (Symbol, u32) {
+    (symbol_short!("rebase_v1"), token_index)
+}
+
+/// Topics for `fee_up_v1`: (name,)
+pub fn fee_change_topic() -> (Symbol,) {
+    (symbol_short!("fee_up_v1"),)
+}
+
+/// Topics for `mint`: (name, token_index)
+pub fn mint_topic(token_index: u32) -> (Symbol, u32) {
+    (symbol_short!("mint"), token_index)
+}
 
 /// Emit initialized event (v1)
 ///
@@ -95,7 +155,8 @@ pub fn emit_initialized(
 ///
 /// Emitted when a new token is created and registered
 pub fn emit_token_registered(env: &Env, token_address: &Address, creator: &Address) {
-    env.events().publish(
+    publish(
+        env,
         (symbol_short!("tok_rg_v1"), token_address.clone()),
         (creator,),
     );
@@ -115,6 +176,7 @@ pub fn emit_token_registered(env: &Env, token_address: &Address, creator: &Addre
 /// - symbol: String - Token symbol
 /// - decimals: u32 - Decimal places
 /// - initial_supply: i128 - Initial token supply
+/// - event_version: u32 - `EVENT_SCHEMA_VERSION`
 ///
 /// Emitted when a new token is created with full metadata
 pub fn emit_token_created(
@@ -126,22 +188,23 @@ pub fn emit_token_created(
     decimals: u32,
     initial_supply: i128,
 ) {
-    env.events().publish(
-        (symbol_short!("tok_crt"), token_address.clone()),
+    publish(
+        env,
+        created_topic(token_address),
         (
             creator.clone(),
             name.clone(),
             symbol.clone(),
             decimals,
             initial_supply,
+            EVENT_SCHEMA_VERSION,
         ),
     );
 }
 
 /// Emitted when multiple tokens are created in a single batch.
 pub fn emit_batch_tokens_created(env: &Env, creator: &Address, count: u32) {
-    env.events()
-        .publish((symbol_short!("bch_tkn"),), (creator.clone(), count));
+    publish(env, (symbol_short!("bch_tkn"),), (creator.clone(), count));
 }
 
 /// Emit admin transfer event (v1)
@@ -165,6 +228,28 @@ pub fn emit_admin_transfer(env: &Env, old_admin: &Address, new_admin: &Address)
         .publish((symbol_short!("adm_xf_v1"),), (old_admin, new_admin));
 }
 
+/// Emit combined admin and treasury handoff event
+///
+/// **Event Name**: hand_off
+///
+/// **Payload** (non-indexed):
+/// - old_admin, new_admin, old_treasury, new_treasury: Address
+///
+/// Emitted alongside `adm_xf_v1` and `trs_upd` so the handoff is auditable
+/// as one change
+pub fn emit_handoff(
+    env: &Env,
+    old_admin: &Address,
+    new_admin: &Address,
+    old_treasury: &Address,
+    new_treasury: &Address,
+) {
+    env.events().publish(
+        (symbol_short!("hand_off"),),
+        (old_admin, new_admin, old_treasury, new_treasury),
+    );
+}
+
 /// Emit admin proposed event (v1)
 ///
 /// **Schema Version**: 1
@@ -216,6 +301,24 @@ pub fn emit_unpause(env: &Env, admin: &Address) {
         .publish((symbol_short!("unpaus_v1"),), (admin,));
 }
 
+/// Emit kill switch event (v1)
+///
+/// **Schema Version**: 1
+/// **Event Name**: kill_v1
+///
+/// **Topics** (indexed):
+/// - Event name: "kill_v1"
+///
+/// **Payload** (non-indexed):
+/// - admin: Address - The administrator who changed the switch
+/// - killed: bool - New kill switch state
+///
+/// **Schema Stability**: This schema is immutable. Any changes require a new version.
+pub fn emit_kill_switch(env: &Env, admin: &Address, killed: bool) {
+    env.events()
+        .publish((symbol_short!("kill_v1"),), (admin, killed));
+}
+
 /// Emit fees updated event (v1)
 ///
 /// **Schema Version**: 1
@@ -231,7 +334,27 @@ pub fn emit_unpause(env: &Env, admin: &Address) {
 /// **Schema Stability**: This schema is immutable. Any changes require a new version.
 pub fn emit_fees_updated(env: &Env, base_fee: i128, metadata_fee: i128) {
     env.events()
-        .publish((symbol_short!("fee_up_v1"),), (base_fee, metadata_fee));
+        .publish(fee_change_topic(), (base_fee, metadata_fee));
+}
+
+/// Emit metadata limits updated event (v1)
+///
+/// **Schema Version**: 1
+/// **Event Name**: md_lim_v1
+///
+/// **Topics** (indexed):
+/// - Event name: "md_lim_v1"
+///
+/// **Payload** (non-indexed):
+/// - max_uri_len: u32 - New maximum metadata URI length
+/// - max_description_len: u32 - New maximum description length
+///
+/// **Schema Stability**: This schema is immutable. Any changes require a new version.
+pub fn emit_metadata_limits_updated(env: &Env, max_uri_len: u32, max_description_len: u32) {
+    env.events().publish(
+        (symbol_short!("md_lim_v1"),),
+        (max_uri_len, max_description_len),
+    );
 }
 
 /// Emit admin burn event (v1)
@@ -301,12 +424,169 @@ pub fn emit_clawback_toggled(env: &Env, token_address: &Address, admin: &Address
 ///
 /// Used when multiple tokens are burned in a batch operation
 pub fn emit_token_burned(env: &Env, token_address: &Address, amount: i128) {
-    env.events().publish(
+    publish(
+        env,
         (symbol_short!("tok_br_v1"), token_address.clone()),
         (amount,),
     );
 }
 
+// ── Index-keyed burn events ─────────────────────────────────
+
+/// Emit burn event (v1)
+///
+/// **Schema Version**: 1
+/// **Event Name**: burn_v1
+///
+/// **Topics** (indexed):
+/// - Event name: "burn_v1"
+/// - token_index: u32 - The token index
+///
+/// **Payload** (non-indexed):
+/// - caller: Address - The address that burned tokens
+/// - amount: i128 - The amount burned
+/// - new_supply: i128 - The new total supply after burn
+/// - event_version: u32 - `EVENT_SCHEMA_VERSION`
+///
+/// **Schema Stability**: This schema is immutable. Any changes require a new version.
+pub fn emit_burn(env: &Env, token_index: u32, caller: &Address, amount: i128, new_supply: i128) {
+    publish(
+        env,
+        burn_topic(token_index),
+        (caller.clone(), amount, new_supply, EVENT_SCHEMA_VERSION),
+    );
+}
+
+/// Emit holder burn event (v1)
+///
+/// **Schema Version**: 1
+/// **Event Name**: adm_bn_v1
+///
+/// **Topics** (indexed):
+/// - Event name: "adm_bn_v1"
+/// - token_index: u32 - The token index
+///
+/// **Payload** (non-indexed):
+/// - admin: Address - The admin who initiated the burn
+/// - holder: Address - The address whose tokens were burned
+/// - amount: i128 - The amount burned
+/// - new_supply: i128 - The new total supply after burn
+/// - event_version: u32 - `EVENT_SCHEMA_VERSION`
+///
+/// **Schema Stability**: This schema is immutable. Any changes require a new version.
+pub fn emit_holder_burn(
+    env: &Env,
+    token_index: u32,
+    admin: &Address,
+    holder: &Address,
+    amount: i128,
+    new_supply: i128,
+) {
+    publish(
+        env,
+        admin_burn_topic(token_index),
+        (admin.clone(), holder.clone(), amount, new_supply, EVENT_SCHEMA_VERSION),
+    );
+}
+
+/// Emit batch burn event (v1)
+///
+/// **Schema Version**: 1
+/// **Event Name**: bch_bn_v1
+///
+/// **Topics** (indexed):
+/// - Event name: "bch_bn_v1"
+/// - token_index: u32 - The token index
+///
+/// **Payload** (non-indexed):
+/// - admin: Address - The admin who initiated the batch burn
+/// - count: u32 - The number of burns in the batch
+/// - total_burned: i128 - The total amount burned across all burns
+/// - new_supply: i128 - The new total supply after batch burn
+/// - event_version: u32 - `EVENT_SCHEMA_VERSION`
+///
+/// **Schema Stability**: This schema is immutable. Any changes require a new version.
+pub fn emit_batch_burn(
+    env: &Env,
+    token_index: u32,
+    admin: &Address,
+    count: u32,
+    total_burned: i128,
+    new_supply: i128,
+) {
+    publish(
+        env,
+        batch_burn_topic(token_index),
+        (admin.clone(), count, total_burned, new_supply, EVENT_SCHEMA_VERSION),
+    );
+}
+
+/// Emit rebase event (v1)
+///
+/// **Schema Version**: 1
+/// **Event Name**: rebase_v1
+///
+/// **Topics** (indexed):
+/// - Event name: "rebase_v1"
+/// - token_index: u32 - The token index
+///
+/// **Payload** (non-indexed):
+/// - admin: Address - The admin who initiated the rebase
+/// - from: Address - The address whose tokens were burned
+/// - burn_amount: i128 - The amount burned
+/// - to: Address - The address that received minted tokens
+/// - mint_amount: i128 - The amount minted
+/// - new_supply: i128 - The new total supply after both legs
+/// - event_version: u32 - `EVENT_SCHEMA_VERSION`
+///
+/// **Schema Stability**: This schema is immutable. Any changes require a new version.
+#[allow(clippy::too_many_arguments)]
+pub fn emit_rebase(
+    env: &Env,
+    token_index: u32,
+    admin: &Address,
+    from: &Address,
+    burn_amount: i128,
+    to: &Address,
+    mint_amount: i128,
+    new_supply: i128,
+) {
+    publish(
+        env,
+        rebase_topic(token_index),
+        (
+            admin.clone(),
+            from.clone(),
+            burn_amount,
+            to.clone(),
+            mint_amount,
+            new_supply,
+            EVENT_SCHEMA_VERSION,
+        ),
+    );
+}
+
+/// Emit contract upgraded event (v1)
+///
+/// **Schema Version**: 1
+/// **Event Name**: upgrd_v1
+///
+/// **Topics** (indexed):
+/// - Event name: "upgrd_v1"
+///
+/// **Payload** (non-indexed):
+/// - admin: Address - The administrator who performed the upgrade
+/// - new_wasm_hash: BytesN<32> - Hash of the installed WASM
+/// - version: u32 - Contract version after the upgrade
+///
+/// **Schema Stability**: This schema is immutable. Any changes require a new version.
+pub fn emit_contract_upgraded(env: &Env, admin: &Address, new_wasm_hash: &BytesN<32>, version: u32) {
+    env.events().publish(
+        (symbol_short!("upgrd_v1"),),
+        (admin.clone(), new_wasm_hash.clone(), version),
+    );
+}
+
 // ── Timelock events ─────────────────────────────────────────
 
 /// Emit timelock configured event
@@ -360,12 +640,19 @@ pub fn emit_treasury_updated(env: &Env, new_treasury: &Address) {
         .publish((symbol_short!("trs_upd"),), (new_treasury,));
 }
 
+/// Emit treasury proposed event
+///
+/// Emitted when a treasury change is proposed and starts its delay
+pub fn emit_treasury_proposed(env: &Env, new_treasury: &Address, effective_at: u64) {
+    env.events()
+        .publish((symbol_short!("trs_prop"),), (new_treasury, effective_at));
+}
+
 /// Emit mint event
 ///
 /// Emitted when tokens are minted
 pub fn emit_mint(env: &Env, token_index: u32, to: &Address, amount: i128) {
-    env.events()
-        .publish((symbol_short!("mint"), token_index), (to, amount));
+    publish(env, mint_topic(token_index), (to, amount));
 }
 
 // ── Treasury events ─────────────────────────────────────────
@@ -378,6 +665,14 @@ pub fn emit_treasury_withdrawal(env: &Env, recipient: &Address, amount: i128) {
         .publish((symbol_short!("trs_wdrw"),), (recipient, amount));
 }
 
+/// Emit fee threshold crossed event
+///
+/// Emitted once when collected fees first reach the alert threshold
+pub fn emit_fee_threshold_crossed(env: &Env, threshold: i128, total: i128) {
+    env.events()
+        .publish((Symbol::new(env, "fee_threshold"),), (threshold, total));
+}
+
 /// Emit recipient added event
 ///
 /// Emitted when an address is added to the withdrawal allowlist
@@ -466,6 +761,99 @@ pub fn emit_token_unpaused(env: &Env, token_index: u32, admin: &Address) {
     );
 }
 
+/// Emit token frozen event (v1)
+///
+/// **Schema Version**: 1
+/// **Event Name**: tok_frz
+///
+/// **Topics** (indexed):
+/// - Event name: "tok_frz"
+/// - token_index: u32 - The token index
+///
+/// **Payload** (non-indexed):
+/// - admin: Address - The admin who froze the token
+///
+/// **Schema Stability**: This schema is immutable. Any changes require a new version.
+///
+/// Emitted when a specific token is frozen via `freeze_token`
+pub fn emit_token_frozen(env: &Env, token_index: u32, admin: &Address) {
+    env.events().publish(
+        (symbol_short!("tok_frz"), token_index),
+        (admin,),
+    );
+}
+
+/// Emit token unfrozen event (v1)
+///
+/// **Schema Version**: 1
+/// **Event Name**: tok_unfrz
+///
+/// **Topics** (indexed):
+/// - Event name: "tok_unfrz"
+/// - token_index: u32 - The token index
+///
+/// **Payload** (non-indexed):
+/// - admin: Address - The admin who unfroze the token
+///
+/// **Schema Stability**: This schema is immutable. Any changes require a new version.
+///
+/// Emitted when a specific token is unfrozen via `unfreeze_token`
+pub fn emit_token_unfrozen(env: &Env, token_index: u32, admin: &Address) {
+    env.events().publish(
+        (symbol_short!("tok_unfrz"), token_index),
+        (admin,),
+    );
+}
+
+/// Emit token retired event (v1)
+///
+/// **Schema Version**: 1
+/// **Event Name**: tok_ret
+///
+/// **Topics** (indexed):
+/// - Event name: "tok_ret"
+/// - token_index: u32 - The token index
+///
+/// **Payload** (non-indexed):
+/// - admin: Address - The admin who retired the token
+///
+/// **Schema Stability**: This schema is immutable. Any changes require a new version.
+///
+/// Emitted once when a token is permanently retired via `retire_token`
+pub fn emit_token_retired(env: &Env, token_index: u32, admin: &Address) {
+    env.events().publish(
+        (symbol_short!("tok_ret"), token_index),
+        (admin,),
+    );
+}
+
+/// Emit burn aggregates migrated event
+///
+/// **Event Name**: agg_migr
+///
+/// **Topics** (indexed):
+/// - Event name: "agg_migr"
+/// - from_index: u32 - The retired token
+///
+/// **Payload** (non-indexed):
+/// - to_index: u32 - The token that inherited the aggregates
+/// - total_burned: i128 - Burn total carried over
+/// - burn_count: u32 - Burn count carried over
+///
+/// Emitted by `migrate_token_aggregates` after `tok_ret` for the old token
+pub fn emit_aggregates_migrated(
+    env: &Env,
+    from_index: u32,
+    to_index: u32,
+    total_burned: i128,
+    burn_count: u32,
+) {
+    env.events().publish(
+        (symbol_short!("agg_migr"), from_index),
+        (to_index, total_burned, burn_count),
+    );
+}
+
 /// Emit dynamic quorum adjusted event
 ///
 /// **Event Name**: dyn_qrm
@@ -493,6 +881,66 @@ pub fn emit_dynamic_quorum_adjusted(
     );
 }
 
+/// Emit metadata frozen event (v1)
+///
+/// **Schema Version**: 1
+/// **Event Name**: md_frz_v1
+///
+/// **Topics** (indexed):
+/// - Event name: "md_frz_v1"
+/// - token_index: u32 - The token whose metadata was frozen
+///
+/// **Payload** (non-indexed):
+/// - creator: Address - The creator who froze the metadata
+///
+/// **Schema Stability**: This schema is immutable. Any changes require a new version.
+pub fn emit_metadata_frozen(env: &Env, token_index: u32, creator: &Address) {
+    publish(
+        env,
+        (symbol_short!("md_frz_v1"), token_index),
+        (creator.clone(),),
+    );
+}
+
+/// Emit token renamed event (v1)
+///
+/// **Schema Version**: 1
+/// **Event Name**: tok_rn_v1
+///
+/// **Topics** (indexed):
+/// - Event name: "tok_rn_v1"
+/// - token_index: u32 - The renamed token
+///
+/// **Payload** (non-indexed):
+/// - creator: Address - The creator who renamed the token
+/// - old_name: String - Name before the change
+/// - old_symbol: String - Symbol before the change
+/// - new_name: String - Name after the change
+/// - new_symbol: String - Symbol after the change
+///
+/// **Schema Stability**: This schema is immutable. Any changes require a new version.
+pub fn emit_token_renamed(
+    env: &Env,
+    token_index: u32,
+    creator: &Address,
+    old_name: &String,
+    old_symbol: &String,
+    new_name: &String,
+    new_symbol: &String,
+) {
+    publish(
+        env,
+        (symbol_short!("tok_rn_v1"), token_index),
+        (
+            creator.clone(),
+            old_name.clone(),
+            old_symbol.clone(),
+            new_name.clone(),
+            new_symbol.clone(),
+        ),
+    );
+}
+
 /// Emit metadata set event
 ///
 /// **Event Name**: meta_set
@@ -512,7 +960,8 @@ pub fn emit_metadata_set(
     admin: &Address,
     metadata_uri: &String,
 ) {
-    env.events().publish(
+    publish(
+        env,
         (symbol_short!("meta_set"), token_address.clone()),
         (admin.clone(), metadata_uri.clone()),
     );
@@ -542,7 +991,8 @@ pub fn emit_metadata_updated(
     metadata_uri: &String,
     version: u32,
 ) {
-    env.events().publish(
+    publish(
+        env,
         (symbol_short!("meta_upd"), token_address.clone()),
         (admin.clone(), metadata_uri.clone(), version),
     );
@@ -552,8 +1002,7 @@ pub fn emit_metadata_updated(
 ///
 /// Published when multiple streams are created in a batch
 pub fn emit_batch_streams_created(env: &Env, creator: &Address, count: u32) {
-    env.events()
-        .publish((symbol_short!("bch_strm"),), (creator, count));
+    publish(env, (symbol_short!("bch_strm"),), (creator, count));
 }
 
 // ═══════════════════════════════════════════════════════════════════════
@@ -586,7 +1035,8 @@ pub fn emit_stream_created(
     amount: i128,
     has_metadata: bool,
 ) {
-    env.events().publish(
+    publish(
+        env,
         (symbol_short!("vlt_cr_v1"), stream_id),
         (creator, recipient, amount, has_metadata),
     );
@@ -614,7 +1064,8 @@ pub fn emit_stream_funded(
     funder: &Address,
     amount: i128,
 ) {
-    env.events().publish(
+    publish(
+        env,
         (symbol_short!("vlt_fd_v1"), stream_id),
         (funder, amount),
     );
@@ -642,7 +1093,8 @@ pub fn emit_stream_claimed(
     recipient: &Address,
     amount: i128,
 ) {
-    env.events().publish(
+    publish(
+        env,
         (symbol_short!("vlt_cl_v1"), stream_id),
         (recipient, amount),
     );
@@ -670,7 +1122,8 @@ pub fn emit_stream_cancelled(
     canceller: &Address,
     remaining_amount: i128,
 ) {
-    env.events().publish(
+    publish(
+        env,
         (symbol_short!("vlt_cn_v1"), stream_id),
         (canceller, remaining_amount),
     );
@@ -698,7 +1151,8 @@ pub fn emit_stream_metadata_updated(
     updater: &Address,
     has_metadata: bool,
 ) {
-    env.events().publish(
+    publish(
+        env,
         (symbol_short!("vlt_md_v1"), stream_id),
         (updater, has_metadata),
     );
@@ -720,7 +1174,8 @@ pub fn emit_proposal_created(
     end_time: u64,
     eta: u64,
 ) {
-    env.events().publish(
+    publish(
+        env,
         (symbol_short!("prop_cr"), proposal_id),
         (proposer, action_type.clone(), start_time, end_time, eta),
     );
@@ -735,7 +1190,8 @@ pub fn emit_proposal_voted(
     voter: &Address,
     support: crate::types::VoteChoice,
 ) {
-    env.events().publish(
+    publish(
+        env,
         (symbol_short!("prop_vote"), proposal_id),
         (voter, support),
     );
@@ -749,7 +1205,8 @@ pub fn emit_proposal_queued(
     proposal_id: u64,
     eta: u64,
 ) {
-    env.events().publish(
+    publish(
+        env,
         (symbol_short!("prop_que"), proposal_id),
         (eta,),
     );
@@ -764,7 +1221,8 @@ pub fn emit_proposal_executed(
     executor: &Address,
     success: bool,
 ) {
-    env.events().publish(
+    publish(
+        env,
         (symbol_short!("prop_exec"), proposal_id),
         (executor, success),
     );
@@ -779,7 +1237,8 @@ pub fn emit_queue_entry_added(
     priority: crate::types::ProposalPriority,
     eta: u64,
 ) {
-    env.events().publish(
+    publish(
+        env,
         (symbol_short!("q_add"), proposal_id),
         (priority as u32, eta),
     );
@@ -793,7 +1252,8 @@ pub fn emit_queue_entry_removed(
     proposal_id: u64,
     priority: crate::types::ProposalPriority,
 ) {
-    env.events().publish(
+    publish(
+        env,
         (symbol_short!("q_rem"), proposal_id),
         (priority as u32,),
     );
@@ -812,7 +1272,8 @@ pub fn emit_vault_created(
     unlock_time: u64,
     milestone_hash: &soroban_sdk::BytesN<32>,
 ) {
-    env.events().publish(
+    publish(
+        env,
         (symbol_short!("vlt_crt"), vault_id),
         (
             creator.clone(),
@@ -829,7 +1290,8 @@ pub fn emit_vault_created(
 ///
 /// Published when a vault is successfully claimed.
 pub fn emit_vault_claimed(env: &Env, vault_id: u64, owner: &Address, amount: i128) {
-    env.events().publish(
+    publish(
+        env,
         (symbol_short!("vlt_clm"), vault_id),
         (owner.clone(), amount),
     );
@@ -839,7 +1301,8 @@ pub fn emit_vault_claimed(env: &Env, vault_id: u64, owner: &Address, amount: i12
 ///
 /// Published when a vault is cancelled.
 pub fn emit_vault_cancelled(env: &Env, vault_id: u64, actor: &Address, remaining_amount: i128) {
-    env.events().publish(
+    publish(
+        env,
         (symbol_short!("vlt_cnl"), vault_id),
         (actor.clone(), remaining_amount),
     );
@@ -866,7 +1329,8 @@ pub fn emit_campaign_created(
     token_index: u32,
     budget_allocated: i128,
 ) {
-    env.events().publish(
+    publish(
+        env,
         (symbol_short!("cmp_crt"), campaign_id),
         (owner, token_index, budget_allocated),
     );
@@ -888,7 +1352,8 @@ pub fn emit_campaign_created(
 ///
 /// Emitted when a campaign is paused
 pub fn emit_campaign_paused(env: &Env, campaign_id: u64, paused_by: &Address) {
-    env.events().publish(
+    publish(
+        env,
         (symbol_short!("cmp_ps_v1"), campaign_id),
         (paused_by,),
     );
@@ -910,7 +1375,8 @@ pub fn emit_campaign_paused(env: &Env, campaign_id: u64, paused_by: &Address) {
 ///
 /// Emitted when a campaign is resumed from paused state
 pub fn emit_campaign_resumed(env: &Env, campaign_id: u64, resumed_by: &Address) {
-    env.events().publish(
+    publish(
+        env,
         (symbol_short!("cmp_rs_v1"), campaign_id),
         (resumed_by,),
     );
@@ -930,7 +1396,8 @@ pub fn emit_campaign_resumed(env: &Env, campaign_id: u64, resumed_by: &Address)
 ///
 /// Emitted when a campaign completes successfully
 pub fn emit_campaign_completed(env: &Env, campaign_id: u64, tokens_burned: i128, budget_spent: i128) {
-    env.events().publish(
+    publish(
+        env,
         (symbol_short!("cmp_cmp"), campaign_id),
         (tokens_burned, budget_spent),
     );
@@ -955,7 +1422,8 @@ pub fn emit_campaign_cancelled(
     cancelled_by: &Address,
     budget_remaining: i128,
 ) {
-    env.events().publish(
+    publish(
+        env,
         (symbol_short!("cmp_cnl"), campaign_id),
         (cancelled_by, budget_remaining),
     );
@@ -983,7 +1451,7 @@ pub fn emit_asset_fractionalized(
         total_supply,
     );
     
-    env.events().publish(topics, data);
+    publish(env, topics, data);
 }
 
 /// Emit asset redeemed event
@@ -1007,7 +1475,7 @@ pub fn emit_asset_redeemed(
         total_supply,
     );
     
-    env.events().publish(topics, data);
+    publish(env, topics, data);
 }
 
 /// Emit batch settle (batch mint) event.
@@ -1020,7 +1488,8 @@ pub fn emit_batch_settle(
     recipient_count: u32,
     total_minted: i128,
 ) {
-    env.events().publish(
+    publish(
+        env,
         (symbol_short!("bch_stl"),),
         (token_index, creator, recipient_count, total_minted),
     );
@@ -1033,7 +1502,8 @@ pub fn emit_deployment_recorded(
     token_index: u32,
     creator: &Address,
 ) {
-    env.events().publish(
+    publish(
+        env,
         (symbol_short!("dep_rec"),),
         (history_index, token_index, creator),
     );
@@ -1041,20 +1511,64 @@ pub fn emit_deployment_recorded(
 
 /// Emit history pruned event.
 pub fn emit_history_pruned(env: &Env, admin: &Address, before_index: u64, pruned: u32) {
-    env.events()
-        .publish((symbol_short!("hist_prn"),), (admin, before_index, pruned));
+    publish(env, (symbol_short!("hist_prn"),), (admin, before_index, pruned));
 }
 
 /// Emit referral registered event.
 pub fn emit_referral_registered(env: &Env, referee: &Address, referrer: &Address) {
-    env.events()
-        .publish((symbol_short!("ref_reg"),), (referee, referrer));
+    publish(env, (symbol_short!("ref_reg"),), (referee, referrer));
 }
 
 /// Emit referral commission paid event.
 pub fn emit_commission_paid(env: &Env, referrer: &Address, token_index: u32, amount: i128) {
-    env.events()
-        .publish((symbol_short!("com_paid"),), (referrer, token_index, amount));
+    publish(env, (symbol_short!("com_paid"),), (referrer, token_index, amount));
+}
+
+/// Emit mint authority added event (v1)
+///
+/// **Schema Version**: 1
+/// **Event Name**: mnt_add
+///
+/// **Topics** (indexed):
+/// - Event name: "mnt_add"
+/// - token_index: u32 - The token the authority may mint
+///
+/// **Payload** (non-indexed):
+/// - creator: Address - The token creator adding the authority
+/// - authority: Address - The new mint authority
+///
+/// **Schema Stability**: This schema is immutable. Any changes require a new version.
+pub fn emit_mint_authority_added(env: &Env, token_index: u32, creator: &Address, authority: &Address) {
+    env.events().publish(
+        (symbol_short!("mnt_add"), token_index),
+        (creator.clone(), authority.clone()),
+    );
+}
+
+/// Emit mint authority removed event (v1)
+///
+/// **Schema Version**: 1
+/// **Event Name**: mnt_rm
+///
+/// **Topics** (indexed):
+/// - Event name: "mnt_rm"
+/// - token_index: u32 - The token the authority could mint
+///
+/// **Payload** (non-indexed):
+/// - creator: Address - The token creator removing the authority
+/// - authority: Address - The removed mint authority
+///
+/// **Schema Stability**: This schema is immutable. Any changes require a new version.
+pub fn emit_mint_authority_removed(
+    env: &Env,
+    token_index: u32,
+    creator: &Address,
+    authority: &Address,
+) {
+    env.events().publish(
+        (symbol_short!("mnt_rm"), token_index),
+        (creator.clone(), authority.clone()),
+    );
 }
 
 /// Emit role granted event (v1)
@@ -1176,3 +1690,268 @@ pub fn emit_dynamic_quorum_configured(
         (admin.clone(), enabled, min_quorum_percent, max_quorum_percent),
     );
 }
+
+/// Emit events toggled event.
+///
+/// Always emitted, so indexers can tell a quiet period from suppression.
+pub fn emit_events_toggled(env: &Env, admin: &Address, enabled: bool) {
+    env.events()
+        .publish((symbol_short!("evt_tgl"),), (admin.clone(), enabled));
+}
+
+#[cfg(test)]
+mod tests {
+    /// Tests that burn and creation events carry the schema version reported
+    /// by `event_schema_version`.
+    mod event_schema_version {
+        use soroban_sdk::{
+            symbol_short, testutils::Events, xdr, Address, Env, String, Symbol, TryFromVal, Val,
+        };
+
+        use crate::test_helpers::setup_with_token;
+
+        /// Payload of the most recent event named `name`.
+        fn last_payload(env: &Env, name: Symbol) -> Val {
+            let events = env.events().all();
+            let mut found = None;
+            for event in events.events() {
+                let xdr::ContractEventBody::V0(body) = &event.body;
+                if Symbol::try_from_val(env, &body.topics[0]).ok() == Some(name.clone()) {
+                    found = Some(Val::try_from_val(env, &body.data).unwrap());
+                }
+            }
+            found.expect("event not emitted")
+        }
+
+        #[test]
+        fn test_created_event_carries_schema_version() {
+            let env = Env::default();
+            let (client, _, _, _) = setup_with_token(&env);
+
+            let data = last_payload(&env, symbol_short!("tok_crt"));
+            let (_, _, _, _, _, version) =
+                <(Address, String, String, u32, i128, u32)>::try_from_val(&env, &data).unwrap();
+            assert_eq!(version, client.event_schema_version());
+        }
+
+        #[test]
+        fn test_burn_events_carry_schema_version() {
+            let env = Env::default();
+            let (client, admin, creator, token_index) = setup_with_token(&env);
+
+            client.burn(&creator, &token_index, &10);
+            client.admin_burn(&admin, &token_index, &creator, &10);
+
+            let data = last_payload(&env, symbol_short!("burn_v1"));
+            let (_, _, _, version) =
+                <(Address, i128, i128, u32)>::try_from_val(&env, &data).unwrap();
+            assert_eq!(version, client.event_schema_version());
+
+            let data = last_payload(&env, symbol_short!("adm_bn_v1"));
+            let (_, _, _, _, version) =
+                <(Address, Address, i128, i128, u32)>::try_from_val(&env, &data).unwrap();
+            assert_eq!(version, client.event_schema_version());
+        }
+    }
+
+    /// Tests for the admin event-emission toggle (`set_events_enabled`).
+    mod events_toggle {
+        use soroban_sdk::{symbol_short, testutils::Events, xdr, Env, Symbol, TryFromVal};
+
+        use crate::test_helpers::{create_test_token, setup_with_token};
+        use crate::types::Error;
+
+        /// Whether any recorded event has `name` as its first topic.
+        fn has_event(env: &Env, name: Symbol) -> bool {
+            env.events().all().events().iter().any(|event| {
+                let xdr::ContractEventBody::V0(body) = &event.body;
+                body.topics
+                    .first()
+                    .and_then(|t| Symbol::try_from_val(env, t).ok())
+                    .as_ref()
+                    == Some(&name)
+            })
+        }
+
+        #[test]
+        fn test_events_enabled_by_default() {
+            let env = Env::default();
+            let (client, _, creator, _) = setup_with_token(&env);
+
+            assert!(client.events_enabled());
+            client.burn(&creator, &0, &100);
+            assert!(has_event(&env, symbol_short!("burn_v1")));
+        }
+
+        #[test]
+        fn test_non_essential_events_suppressed_when_disabled() {
+            let env = Env::default();
+            let (client, admin, creator, _) = setup_with_token(&env);
+
+            client.set_events_enabled(&admin, &false);
+            assert!(!client.events_enabled());
+
+            client.burn(&creator, &0, &100);
+            assert!(!has_event(&env, symbol_short!("burn_v1")));
+            client.mint(&creator, &0, &creator, &100);
+            assert!(!has_event(&env, symbol_short!("mint")));
+            create_test_token(&env, &client, &creator, "QUIET", 1_000_000);
+            assert!(!has_event(&env, symbol_short!("tok_crt")));
+
+            // State changes still apply
+            assert_eq!(client.get_token_info(&0).total_burned, 100);
+            assert_eq!(client.get_full_state().token_count, 2);
+        }
+
+        #[test]
+        fn test_essential_events_emitted_when_disabled() {
+            let env = Env::default();
+            let (client, admin, _, _) = setup_with_token(&env);
+
+            client.set_events_enabled(&admin, &false);
+            assert!(has_event(&env, symbol_short!("evt_tgl")));
+
+            client.pause(&admin);
+            assert!(has_event(&env, symbol_short!("pause_v1")));
+        }
+
+        #[test]
+        fn test_reenabling_restores_events() {
+            let env = Env::default();
+            let (client, admin, creator, _) = setup_with_token(&env);
+
+            client.set_events_enabled(&admin, &false);
+            client.set_events_enabled(&admin, &true);
+
+            client.burn(&creator, &0, &100);
+            assert!(has_event(&env, symbol_short!("burn_v1")));
+        }
+
+        #[test]
+        fn test_toggle_requires_admin() {
+            let env = Env::default();
+            let (client, _, creator, _) = setup_with_token(&env);
+
+            assert_eq!(
+                client.try_set_events_enabled(&creator, &false),
+                Err(Ok(Error::Unauthorized))
+            );
+            assert!(client.events_enabled());
+        }
+    }
+
+    /// Tests that emitters publish the topics built by the `*_topic` helpers.
+    mod topics {
+        use soroban_sdk::{
+            testutils::{Address as _, Events},
+            xdr, TryFromVal, Val, Vec,
+        };
+
+        use crate::events::*;
+
+        fn last_event(env: &Env) -> (Vec<Val>, Val) {
+            let events = env.events().all();
+            let xdr::ContractEventBody::V0(body) = &events.events().last().unwrap().body;
+            let mut topics = Vec::new(env);
+            for topic in body.topics.iter() {
+                topics.push_back(Val::try_from_val(env, topic).unwrap());
+            }
+            (topics, Val::try_from_val(env, &body.data).unwrap())
+        }
+
+        #[test]
+        fn test_emit_token_created_uses_created_topic() {
+            let env = Env::default();
+            let contract_id = env.register_contract(None, crate::TokenFactory);
+            let token = Address::generate(&env);
+            let creator = Address::generate(&env);
+            let name = String::from_str(&env, "Topic");
+            let symbol = String::from_str(&env, "TOP");
+
+            env.as_contract(&contract_id, || {
+                emit_token_created(&env, &token, &creator, &name, &symbol, 7, 1_000)
+            });
+
+            let (topics, data) = last_event(&env);
+            let (expected_name, expected_addr) = created_topic(&token);
+            assert_eq!(
+                Symbol::try_from_val(&env, &topics.get(0).unwrap()).unwrap(),
+                expected_name
+            );
+            assert_eq!(
+                Address::try_from_val(&env, &topics.get(1).unwrap()).unwrap(),
+                expected_addr
+            );
+
+            let payload =
+                <(Address, String, String, u32, i128, u32)>::try_from_val(&env, &data).unwrap();
+            assert_eq!(
+                payload,
+                (creator, name, symbol, 7, 1_000, EVENT_SCHEMA_VERSION)
+            );
+        }
+
+        #[test]
+        fn test_emit_burn_uses_burn_topic() {
+            let env = Env::default();
+            let contract_id = env.register_contract(None, crate::TokenFactory);
+            let caller = Address::generate(&env);
+
+            env.as_contract(&contract_id, || emit_burn(&env, 3, &caller, 50, 950));
+
+            let (topics, data) = last_event(&env);
+            let (expected_name, expected_index) = burn_topic(3);
+            assert_eq!(
+                Symbol::try_from_val(&env, &topics.get(0).unwrap()).unwrap(),
+                expected_name
+            );
+            assert_eq!(
+                u32::try_from_val(&env, &topics.get(1).unwrap()).unwrap(),
+                expected_index
+            );
+
+            let payload = <(Address, i128, i128, u32)>::try_from_val(&env, &data).unwrap();
+            assert_eq!(payload, (caller, 50, 950, EVENT_SCHEMA_VERSION));
+        }
+
+        #[test]
+        fn test_emit_fees_updated_uses_fee_change_topic() {
+            let env = Env::default();
+            let contract_id = env.register_contract(None, crate::TokenFactory);
+
+            env.as_contract(&contract_id, || emit_fees_updated(&env, 10, 5));
+
+            let (topics, data) = last_event(&env);
+            assert_eq!(topics.len(), 1);
+            assert_eq!(
+                Symbol::try_from_val(&env, &topics.get(0).unwrap()).unwrap(),
+                fee_change_topic().0
+            );
+            assert_eq!(<(i128, i128)>::try_from_val(&env, &data).unwrap(), (10, 5));
+        }
+
+        #[test]
+        fn test_emit_mint_uses_mint_topic() {
+            let env = Env::default();
+            let contract_id = env.register_contract(None, crate::TokenFactory);
+            let to = Address::generate(&env);
+
+            env.as_contract(&contract_id, || emit_mint(&env, 1, &to, 42));
+
+            let (topics, data) = last_event(&env);
+            let (expected_name, expected_index) = mint_topic(1);
+            assert_eq!(
+                Symbol::try_from_val(&env, &topics.get(0).unwrap()).unwrap(),
+                expected_name
+            );
+            assert_eq!(
+                u32::try_from_val(&env, &topics.get(1).unwrap()).unwrap(),
+                expected_index
+            );
+            assert_eq!(
+                <(Address, i128)>::try_from_val(&env, &data).unwrap(),
+                (to, 42)
+            );
+        }
+    }
+}
@@ -54,6 +54,8 @@ fn seed_token(env: &Env, creator: &Address) -> u32 {
         clawback_enabled: false,
         is_paused: false,
         freeze_enabled: false,
+        max_burn_bps: None,
+        official: false,
     };
 
     let index = storage::get_token_count(env);
@@ -39,6 +39,7 @@ pub fn freeze_address(
 
     // Require admin authorization
     admin.require_auth();
+    storage::ensure_not_killed(env)?;
 
     // Verify token exists and get info
     let token_info =
@@ -113,6 +114,7 @@ pub fn unfreeze_address(
 
     // Require admin authorization
     admin.require_auth();
+    storage::ensure_not_killed(env)?;
 
     // Verify token exists and get info
     let token_info =
@@ -190,6 +192,7 @@ pub fn set_freeze_enabled(
 
     // Require admin authorization
     admin.require_auth();
+    storage::ensure_not_killed(env)?;
 
     // Get token info
     let mut token_info =
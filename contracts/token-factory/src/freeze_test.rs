@@ -50,6 +50,8 @@ fn setup_token_with_freeze(
         freeze_enabled,
             freeze_enabled: false,
             is_paused: false,
+        max_burn_bps: None,
+        official: false,
         
         };
 
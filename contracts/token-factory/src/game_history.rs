@@ -236,6 +236,7 @@ pub fn replay(env: &Env, up_to_index: u64) -> Result<HistorySnapshot, Error> {
 /// * `InvalidParameters` – `before_index` is 0 or exceeds the history count.
 pub fn prune_history(env: &Env, admin: &Address, before_index: u64) -> Result<u32, Error> {
     admin.require_auth();
+    storage::ensure_not_killed(env)?;
 
     let stored_admin = storage::get_admin(env);
     if *admin != stored_admin {
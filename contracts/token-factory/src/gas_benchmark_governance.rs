@@ -165,6 +165,8 @@ impl GovBenchSetup {
             clawback_enabled: false,
             freeze_enabled: false,
             is_paused: false,
+            max_burn_bps: None,
+            official: false,
         
         };
         
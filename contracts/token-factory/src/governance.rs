@@ -64,6 +64,7 @@ pub fn update_governance_config(
     approval_percent: Option<u32>,
 ) -> Result<(), Error> {
     admin.require_auth();
+    storage::ensure_not_killed(env)?;
 
     let current_admin = storage::get_admin(env);
     if *admin != current_admin {
@@ -178,6 +179,7 @@ pub fn configure_dynamic_quorum(
     config: DynamicQuorumConfig,
 ) -> Result<(), Error> {
     admin.require_auth();
+    storage::ensure_not_killed(env)?;
     let stored_admin = storage::get_admin(env);
     if *admin != stored_admin {
         return Err(Error::Unauthorized);
@@ -224,6 +226,8 @@ pub fn record_participation_and_adjust(
     total_votes: u32,
     total_eligible: u32,
 ) -> Result<u32, Error> {
+    storage::ensure_not_killed(env)?;
+
     if total_eligible == 0 {
         return Err(Error::InvalidParameters);
     }
@@ -64,7 +64,7 @@ mod invariant_tests {
     fn violation_supply_conservation_inflated_burned() {
         let (env, _contract_id, _admin, _treasury, token_index) = setup();
 
-        crate::storage::add_total_burned(&env, token_index, 999_999_i128);
+        crate::storage::add_total_burned(&env, token_index, 999_999_i128).unwrap();
 
         assert_supply_conservation(&env, token_index, 1_000_000_i128);
     }
@@ -86,7 +86,7 @@ mod invariant_tests {
     fn violation_burned_exceeds_initial_supply() {
         let (env, _contract_id, _admin, _treasury, token_index) = setup();
 
-        crate::storage::add_total_burned(&env, token_index, 2_000_000_i128);
+        crate::storage::add_total_burned(&env, token_index, 2_000_000_i128).unwrap();
 
         assert_burned_within_bounds(&env, token_index, 1_000_000_i128);
     }
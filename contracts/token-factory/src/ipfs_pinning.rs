@@ -268,6 +268,8 @@ mod tests {
             is_paused: false,
             clawback_enabled: false,
             freeze_enabled: false,
+            max_burn_bps: None,
+            official: false,
         };
         env.as_contract(contract_id, || {
             storage::set_token_info(env, token_index, &info);
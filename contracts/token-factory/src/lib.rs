@@ -48,6 +48,7 @@ mod timelock;
 mod token_creation;
 mod treasury;
 mod types;
+mod units;
 mod vesting;
 mod validation;
 
@@ -120,7 +121,7 @@ mod burn_integration_test;
 
 use soroban_sdk::{contract, contractimpl, symbol_short, Address, Bytes, BytesN, Env, String, Symbol, Vec};
 use types::{
-    AuctionStatus, BurnAuction, BuybackCampaign, CampaignStatus, ContractMetadata,
+    AuctionStatus, BurnAuction, BurnMode, BuybackCampaign, CampaignStatus, ContractMetadata,
     DynamicQuorumConfig, Error, FactoryState, PaginationCursor, StreamInfo, StreamPage,
     StreamParams, TokenCreationParams, TokenInfo, TokenStats, Vault, VaultStatus,
 };
@@ -167,9 +168,120 @@ impl TokenFactory {
         treasury: Address,
         base_fee: i128,
         metadata_fee: i128,
+    ) -> Result<(), Error> {
+        Self::init_core(&env, &admin, &treasury, base_fee, metadata_fee)
+    }
+
+    /// Initialize the factory with its complete configuration in one call
+    ///
+    /// Same checks as `initialize`, additionally recording the asset
+    /// contract fees are denominated in. Either entrypoint leaves the
+    /// contract fully configured and stamped with the storage schema
+    /// version, so no follow-up setters are needed before creation opens.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `admin` - Address with administrative privileges
+    /// * `treasury` - Address that will receive deployment fees
+    /// * `base_fee` - Base fee for token deployment (must be >= 0)
+    /// * `metadata_fee` - Additional fee for metadata (must be >= 0)
+    /// * `fee_token` - Asset contract in which fees are denominated
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success
+    ///
+    /// # Errors
+    /// * `Error::AlreadyInitialized` - Contract has already been initialized
+    /// * `Error::InvalidParameters` - Either fee is negative
+    pub fn initialize_with_config(
+        env: Env,
+        admin: Address,
+        treasury: Address,
+        base_fee: i128,
+        metadata_fee: i128,
+        fee_token: Address,
+    ) -> Result<(), Error> {
+        Self::init_core(&env, &admin, &treasury, base_fee, metadata_fee)?;
+        storage::set_fee_token(&env, &fee_token);
+        Ok(())
+    }
+
+    /// Get the storage schema version recorded at initialization
+    ///
+    /// # Returns
+    /// Returns the schema version, or 0 if the contract is not initialized
+    pub fn schema_version(env: Env) -> u32 {
+        storage::get_schema_version(&env)
+    }
+
+    /// Get the asset contract fees are denominated in, if configured
+    pub fn get_fee_token(env: Env) -> Option<Address> {
+        storage::get_fee_token(&env)
+    }
+
+    /// Set the asset contract fees are denominated in (admin only)
+    ///
+    /// For factories initialized through `initialize`, which does not take
+    /// a fee token.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `admin` - Admin address (must authorize)
+    /// * `fee_token` - Asset contract address
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the admin
+    pub fn set_fee_token(env: Env, admin: Address, fee_token: Address) -> Result<(), Error> {
+        storage::require_admin(&env, &admin)?;
+        storage::ensure_not_killed(&env)?;
+
+        storage::set_fee_token(&env, &fee_token);
+        Ok(())
+    }
+
+    /// Get the treasury's balance of the fee token
+    ///
+    /// Reads `balance(treasury)` from the configured fee token, so
+    /// accumulated fees can be checked without off-chain tooling.
+    ///
+    /// # Returns
+    /// Returns the treasury balance in fee-token units
+    ///
+    /// # Errors
+    /// * `Error::NotInitialized` - Contract has not been initialized
+    /// * `Error::FeeTokenNotConfigured` - No fee token has been set
+    pub fn get_treasury_balance(env: Env) -> Result<i128, Error> {
+        let treasury = storage::try_get_treasury(&env)?;
+        let fee_token = storage::get_fee_token(&env).ok_or(Error::FeeTokenNotConfigured)?;
+        Ok(soroban_sdk::token::Client::new(&env, &fee_token).balance(&treasury))
+    }
+
+    /// Caller must authorize and be the token's holder reporter or the admin.
+    fn require_holder_reporter(env: &Env, caller: &Address, token_index: u32) -> Result<(), Error> {
+        caller.require_auth();
+        storage::ensure_not_killed(env)?;
+        storage::get_token_info(env, token_index).ok_or(Error::TokenNotFound)?;
+
+        let is_reporter = storage::get_holder_reporter(env, token_index).as_ref() == Some(caller);
+        if !is_reporter && *caller != storage::try_get_admin(env)? {
+            return Err(Error::Unauthorized);
+        }
+        Ok(())
+    }
+
+    /// Shared body of `initialize` and `initialize_with_config`.
+    fn init_core(
+        env: &Env,
+        admin: &Address,
+        treasury: &Address,
+        base_fee: i128,
+        metadata_fee: i128,
     ) -> Result<(), Error> {
         // Early return if already initialized
-        if storage::has_admin(&env) {
+        if storage::has_admin(env) {
             return Err(Error::AlreadyInitialized);
         }
 
@@ -180,13 +292,14 @@ impl TokenFactory {
         }
 
         // Set initial state
-        storage::set_admin(&env, &admin);
-        storage::set_treasury(&env, &treasury);
-        storage::set_base_fee(&env, base_fee);
-        storage::set_metadata_fee(&env, metadata_fee);
+        storage::set_admin(env, admin);
+        storage::set_treasury(env, treasury);
+        storage::set_base_fee(env, base_fee);
+        storage::set_metadata_fee(env, metadata_fee);
+        storage::set_schema_version(env, storage::STORAGE_SCHEMA_VERSION);
 
         // Emit initialized event
-        events::emit_initialized(&env, &admin, &treasury, base_fee, metadata_fee);
+        events::emit_initialized(env, admin, treasury, base_fee, metadata_fee);
 
         Ok(())
     }
@@ -204,14 +317,77 @@ impl TokenFactory {
     /// # Returns
     /// Returns a `FactoryState` struct with current configuration
     ///
+    /// # Errors
+    /// * `Error::NotInitialized` - Contract has not been initialized
+    ///
     /// # Examples
     /// ```
     /// let state = factory.get_state(&env);
     /// assert_eq!(state.admin, expected_admin);
     /// assert_eq!(state.base_fee, 1_000_000);
     /// ```
-    pub fn get_state(env: Env) -> FactoryState {
-        storage::get_factory_state(&env)
+    pub fn get_state(env: Env) -> Result<FactoryState, Error> {
+        storage::try_get_factory_state(&env)
+    }
+
+    /// Get the factory state together with runtime counters and versions
+    ///
+    /// Superset of `get_state` adding the schema and contract versions,
+    /// the token count and the number of burns across all tokens.
+    ///
+    /// # Returns
+    /// Returns a `FullState` struct
+    ///
+    /// # Errors
+    /// * `Error::NotInitialized` - Contract has not been initialized
+    pub fn get_full_state(env: Env) -> Result<types::FullState, Error> {
+        storage::get_full_state(&env)
+    }
+
+    /// Get the fee token, treasury and fees in a single read
+    ///
+    /// Lets a wallet build a creation transaction, including the fee
+    /// transfer authorization, without separate calls per value.
+    /// `fee_token` is `None` on factories initialized without one.
+    ///
+    /// # Returns
+    /// Returns a `PaymentInfo` struct
+    ///
+    /// # Errors
+    /// * `Error::NotInitialized` - Contract has not been initialized
+    pub fn get_payment_info(env: Env) -> Result<types::PaymentInfo, Error> {
+        storage::get_payment_info(&env)
+    }
+
+    /// Get every admin-gated setting in a single read
+    ///
+    /// Bundles admin, pending admin, treasury, fees, pause flag, treasury
+    /// withdrawal caps and the timelock delay so admin dashboards do not
+    /// need one call per value.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    ///
+    /// # Returns
+    /// Returns an `AdminConfig` struct with current configuration
+    ///
+    /// # Errors
+    /// * `Error::NotInitialized` - Contract has not been initialized
+    pub fn get_admin_config(env: Env) -> Result<types::AdminConfig, Error> {
+        storage::get_admin_config(&env)
+    }
+
+    /// Get the in-band schema version of burn and creation events
+    ///
+    /// Burn (`burn_v1`, `adm_bn_v1`, `bch_bn_v1`, `rebase_v1`) and creation
+    /// (`tok_crt`) payloads end with an `event_version` field equal to this
+    /// value. It only changes when one of those payloads changes shape.
+    ///
+    /// # Returns
+    /// Returns the current event schema version
+    pub fn event_schema_version(env: Env) -> u32 {
+        let _ = env;
+        event_versions::EVENT_SCHEMA_VERSION
     }
 
     /// Get the current base fee for token deployment
@@ -225,14 +401,17 @@ impl TokenFactory {
     /// # Returns
     /// Returns the base fee as an i128 in stroops
     ///
+    /// # Errors
+    /// * `Error::NotInitialized` - Contract has not been initialized
+    ///
     /// # Examples
     /// ```
     /// let base_fee = factory.get_base_fee(&env);
     /// // Ensure user has sufficient balance
     /// assert!(user_balance >= base_fee);
     /// ```
-    pub fn get_base_fee(env: Env) -> i128 {
-        storage::get_base_fee(&env)
+    pub fn get_base_fee(env: Env) -> Result<i128, Error> {
+        storage::try_get_base_fee(&env)
     }
 
     /// Get the current metadata fee for token deployment
@@ -246,13 +425,59 @@ impl TokenFactory {
     /// # Returns
     /// Returns the metadata fee as an i128 in stroops
     ///
+    /// # Errors
+    /// * `Error::NotInitialized` - Contract has not been initialized
+    ///
     /// # Examples
     /// ```
     /// let total_fee = factory.get_base_fee(&env) + factory.get_metadata_fee(&env);
     /// // Total fee when including metadata
     /// ```
-    pub fn get_metadata_fee(env: Env) -> i128 {
-        storage::get_metadata_fee(&env)
+    pub fn get_metadata_fee(env: Env) -> Result<i128, Error> {
+        storage::try_get_metadata_fee(&env)
+    }
+
+    /// Override the metadata fee for a single token (admin only)
+    ///
+    /// For premium listing tiers. `None` removes the override so the token
+    /// falls back to the global metadata fee.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `admin` - Admin address (must authorize)
+    /// * `token_index` - Index of the token
+    /// * `fee` - Override in stroops (None to clear)
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the admin
+    /// * `Error::TokenNotFound` - Token index does not exist
+    /// * `Error::InvalidParameters` - Fee is negative
+    pub fn set_token_metadata_fee(
+        env: Env,
+        admin: Address,
+        token_index: u32,
+        fee: Option<i128>,
+    ) -> Result<(), Error> {
+        storage::require_admin(&env, &admin)?;
+        storage::ensure_not_killed(&env)?;
+        storage::get_token_info(&env, token_index).ok_or(Error::TokenNotFound)?;
+        if matches!(fee, Some(fee) if fee < 0) {
+            return Err(Error::InvalidParameters);
+        }
+
+        storage::set_token_metadata_fee_override(&env, token_index, fee);
+        Ok(())
+    }
+
+    /// Get the metadata fee that applies to a token
+    ///
+    /// Returns the token's override when one is set, otherwise the global
+    /// metadata fee.
+    ///
+    /// # Errors
+    /// * `Error::NotInitialized` - Contract has not been initialized
+    pub fn get_token_metadata_fee(env: Env, token_index: u32) -> Result<i128, Error> {
+        storage::get_effective_metadata_fee(&env, token_index)
     }
 
     /// Transfer admin rights to a new address
@@ -268,26 +493,23 @@ impl TokenFactory {
     ///
     /// # Errors
     /// * `Unauthorized` - If caller is not the current admin
-    /// * `InvalidParameters` - If new admin is same as current or invalid
+    /// * `InvalidParameters` - If new admin is same as current or invalid, or
+    ///   is the treasury while a separate treasury is required
     pub fn transfer_admin(
         env: Env,
         current_admin: Address,
         new_admin: Address,
     ) -> Result<(), Error> {
-        // Require current admin authorization
-        current_admin.require_auth();
-
-        // Combined verification (Phase 1 optimization)
         // Early return if not authorized
-        let stored_admin = storage::get_admin(&env);
-        if current_admin != stored_admin {
-            return Err(Error::Unauthorized);
-        }
+        storage::require_admin(&env, &current_admin)?;
+        storage::ensure_not_killed(&env)?;
 
         // Validate new admin is different
         if new_admin == current_admin {
             return Err(Error::InvalidParameters);
         }
+        let treasury = storage::try_get_treasury(&env)?;
+        storage::check_treasury_separation(&env, &new_admin, &treasury)?;
 
         // Update admin in storage
         storage::set_admin(&env, &new_admin);
@@ -304,6 +526,56 @@ impl TokenFactory {
         Ok(())
     }
 
+    /// Hand off both admin rights and the treasury in one call
+    ///
+    /// Validates both changes before writing either, so a failure leaves
+    /// admin and treasury untouched. Clears any pending admin or treasury
+    /// proposal, which the direct change supersedes.
+    ///
+    /// # Arguments
+    /// * `current_admin` - The current admin address (must authorize)
+    /// * `new_admin` - Address receiving admin rights
+    /// * `new_treasury` - Address receiving fees
+    ///
+    /// # Errors
+    /// * `Unauthorized` - If caller is not the current admin
+    /// * `InvalidParameters` - If either address is unchanged or is the
+    ///   factory itself, or they coincide while a separate treasury is required
+    ///
+    /// # Events
+    /// Emits `adm_xf_v1`, `trs_upd` and a combined `hand_off`
+    pub fn transfer_admin_and_treasury(
+        env: Env,
+        current_admin: Address,
+        new_admin: Address,
+        new_treasury: Address,
+    ) -> Result<(), Error> {
+        storage::require_admin(&env, &current_admin)?;
+        storage::ensure_not_killed(&env)?;
+
+        let old_treasury = storage::try_get_treasury(&env)?;
+        let factory = env.current_contract_address();
+        if new_admin == current_admin
+            || new_treasury == old_treasury
+            || new_admin == factory
+            || new_treasury == factory
+        {
+            return Err(Error::InvalidParameters);
+        }
+        storage::check_treasury_separation(&env, &new_admin, &new_treasury)?;
+
+        storage::set_admin(&env, &new_admin);
+        storage::set_treasury(&env, &new_treasury);
+        storage::clear_pending_admin(&env);
+        storage::clear_pending_treasury(&env);
+
+        events::emit_admin_transfer(&env, &current_admin, &new_admin);
+        events::emit_treasury_updated(&env, &new_treasury);
+        events::emit_handoff(&env, &current_admin, &new_admin, &old_treasury, &new_treasury);
+
+        Ok(())
+    }
+
     /// Propose a new admin (two-step transfer - step 1)
     ///
     /// Initiates a two-step admin transfer by proposing a new admin.
@@ -320,25 +592,26 @@ impl TokenFactory {
     ///
     /// # Errors
     /// * `Unauthorized` - If caller is not the current admin
-    /// * `InvalidParameters` - If new admin is same as current
+    /// * `InvalidParameters` - If new admin is same as current, or is the
+    ///   treasury while a separate treasury is required
     pub fn propose_admin(
         env: Env,
         current_admin: Address,
         new_admin: Address,
     ) -> Result<(), Error> {
-        current_admin.require_auth();
-
-        let stored_admin = storage::get_admin(&env);
-        if current_admin != stored_admin {
-            return Err(Error::Unauthorized);
-        }
+        storage::require_admin(&env, &current_admin)?;
+        storage::ensure_not_killed(&env)?;
 
         if new_admin == current_admin {
             return Err(Error::InvalidParameters);
         }
+        let treasury = storage::try_get_treasury(&env)?;
+        storage::check_treasury_separation(&env, &new_admin, &treasury)?;
 
         // Overwrite any existing pending admin (prevents stale proposals)
+        let expires_at = storage::proposal_expiry(&env, env.ledger().timestamp())?;
         storage::set_pending_admin(&env, &new_admin);
+        storage::set_pending_admin_expires_at(&env, expires_at);
 
         events::emit_admin_proposed(&env, &current_admin, &new_admin);
 
@@ -358,17 +631,28 @@ impl TokenFactory {
     /// Returns `Ok(())` on success
     ///
     /// # Errors
-    /// * `Unauthorized` - If caller is not the pending admin or no pending admin exists
+    /// * `Unauthorized` - If caller is not the pending admin, no pending admin
+    ///   exists, or the proposal has expired
+    /// * `InvalidParameters` - If the pending admin is the treasury while a
+    ///   separate treasury is required
     pub fn accept_admin(env: Env, new_admin: Address) -> Result<(), Error> {
         new_admin.require_auth();
+        storage::ensure_not_killed(&env)?;
 
         let pending = storage::get_pending_admin(&env).ok_or(Error::Unauthorized)?;
 
         if new_admin != pending {
             return Err(Error::Unauthorized);
         }
+        // An expired proposal is void, as if none were pending
+        if storage::is_proposal_expired(&env, storage::get_pending_admin_expires_at(&env)) {
+            return Err(Error::Unauthorized);
+        }
 
-        let old_admin = storage::get_admin(&env);
+        let old_admin = storage::try_get_admin(&env)?;
+        // The treasury may have changed, or the flag been enabled, since the proposal
+        let treasury = storage::try_get_treasury(&env)?;
+        storage::check_treasury_separation(&env, &new_admin, &treasury)?;
 
         // Update admin and clear pending in single operation
         storage::set_admin(&env, &new_admin);
@@ -379,96 +663,339 @@ impl TokenFactory {
         Ok(())
     }
 
-    /// Pause the contract (admin only)
+    /// Require the treasury and admin to be different addresses (admin only)
     ///
-    /// Halts critical operations like token creation and metadata updates.
-    /// Admin functions like fee updates remain operational during pause.
-    /// This is a safety mechanism for emergency situations.
+    /// While enabled, treasury changes to the admin address and admin
+    /// changes to the treasury address are rejected. Off by default.
     ///
     /// # Arguments
-    /// * `env` - The contract environment
-    /// * `admin` - Admin address (must authorize and match stored admin)
-    ///
-    /// # Returns
-    /// Returns `Ok(())` on success
+    /// * `admin` - Admin address (must authorize)
+    /// * `required` - Whether separation is enforced
     ///
     /// # Errors
     /// * `Error::Unauthorized` - Caller is not the admin
-    ///
-    /// # Examples
-    /// ```
-    /// // Emergency pause
-    /// factory.pause(&env, admin_address)?;
-    /// assert!(factory.is_paused(&env));
-    /// ```
-    pub fn pause(env: Env, admin: Address) -> Result<(), Error> {
+    /// * `Error::InvalidParameters` - Enabling while the treasury is the admin
+    pub fn set_require_separate_treasury(
+        env: Env,
+        admin: Address,
+        required: bool,
+    ) -> Result<(), Error> {
         admin.require_auth();
+        storage::ensure_not_killed(&env)?;
 
-        // Combined verification (Phase 1 optimization)
-        let current_admin = storage::get_admin(&env);
+        let current_admin = storage::try_get_admin(&env)?;
         if admin != current_admin {
             return Err(Error::Unauthorized);
         }
 
-        storage::set_paused(&env, true);
+        if required && storage::try_get_treasury(&env)? == current_admin {
+            return Err(Error::InvalidParameters);
+        }
 
-        // Use optimized event
-        events::emit_pause(&env, &admin);
+        storage::set_require_separate_treasury(&env, required);
+        Ok(())
+    }
+
+    /// Whether the treasury must differ from the admin
+    pub fn get_require_separate_treasury(env: Env) -> bool {
+        storage::get_require_separate_treasury(&env)
+    }
+
+    /// Set how long a proposed treasury must wait before it applies (admin only)
+    ///
+    /// Only affects proposals made after the call; a pending proposal
+    /// keeps the effective timestamp it was given.
+    ///
+    /// # Arguments
+    /// * `admin` - Admin address (must authorize)
+    /// * `delay_seconds` - Delay between `propose_treasury` and `apply_treasury`
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the admin
+    pub fn set_treasury_change_delay(
+        env: Env,
+        admin: Address,
+        delay_seconds: u64,
+    ) -> Result<(), Error> {
+        storage::require_admin(&env, &admin)?;
+        storage::ensure_not_killed(&env)?;
 
+        storage::set_treasury_change_delay(&env, delay_seconds);
         Ok(())
     }
 
-    /// Unpause the contract (admin only)
+    /// Propose a new treasury (delayed change - step 1)
     ///
-    /// Resumes normal operations after a pause. All previously
-    /// restricted operations become available again.
+    /// The proposal becomes applicable once the treasury change delay has
+    /// elapsed. A new proposal replaces any pending one and restarts the delay.
     ///
     /// # Arguments
-    /// * `env` - The contract environment
-    /// * `admin` - Admin address (must authorize and match stored admin)
+    /// * `admin` - Admin address (must authorize)
+    /// * `new_treasury` - Proposed treasury address
     ///
     /// # Returns
-    /// Returns `Ok(())` on success
+    /// Returns the timestamp from which `apply_treasury` will succeed
     ///
     /// # Errors
     /// * `Error::Unauthorized` - Caller is not the admin
+    /// * `Error::InvalidParameters` - `new_treasury` is already the treasury,
+    ///   or is the admin while a separate treasury is required
+    /// * `Error::ArithmeticError` - Effective timestamp overflows
+    pub fn propose_treasury(
+        env: Env,
+        admin: Address,
+        new_treasury: Address,
+    ) -> Result<u64, Error> {
+        storage::require_admin(&env, &admin)?;
+        storage::ensure_not_killed(&env)?;
+
+        if new_treasury == storage::try_get_treasury(&env)? {
+            return Err(Error::InvalidParameters);
+        }
+        storage::check_treasury_separation(&env, &admin, &new_treasury)?;
+
+        let effective_at = env
+            .ledger()
+            .timestamp()
+            .checked_add(storage::get_treasury_change_delay(&env))
+            .ok_or(Error::ArithmeticError)?;
+
+        storage::set_pending_treasury(
+            &env,
+            &types::PendingTreasury {
+                treasury: new_treasury.clone(),
+                effective_at,
+                expires_at: storage::proposal_expiry(&env, effective_at)?,
+            },
+        );
+
+        events::emit_treasury_proposed(&env, &new_treasury, effective_at);
+
+        Ok(effective_at)
+    }
+
+    /// Apply the pending treasury (delayed change - step 2)
     ///
-    /// # Examples
-    /// ```
-    /// // Resume operations
-    /// factory.unpause(&env, admin_address)?;
-    /// assert!(!factory.is_paused(&env));
-    /// ```
-    pub fn unpause(env: Env, admin: Address) -> Result<(), Error> {
-        admin.require_auth();
+    /// Callable by anyone once the delay has elapsed, since the outcome
+    /// was fixed by the admin at proposal time.
+    ///
+    /// # Errors
+    /// * `Error::ChangeNotFound` - No treasury change is pending, or it has expired
+    /// * `Error::TimelockNotExpired` - The effective timestamp has not been reached
+    /// * `Error::InvalidParameters` - The pending treasury is now the admin
+    ///   while a separate treasury is required
+    pub fn apply_treasury(env: Env) -> Result<(), Error> {
+        storage::ensure_not_killed(&env)?;
 
-        // Combined verification (Phase 1 optimization)
-        let current_admin = storage::get_admin(&env);
-        if admin != current_admin {
-            return Err(Error::Unauthorized);
+        let pending = storage::get_pending_treasury(&env).ok_or(Error::ChangeNotFound)?;
+
+        if storage::is_proposal_expired(&env, pending.expires_at) {
+            return Err(Error::ChangeNotFound);
+        }
+        if env.ledger().timestamp() < pending.effective_at {
+            return Err(Error::TimelockNotExpired);
         }
+        let admin = storage::try_get_admin(&env)?;
+        storage::check_treasury_separation(&env, &admin, &pending.treasury)?;
 
-        storage::set_paused(&env, false);
+        storage::set_treasury(&env, &pending.treasury);
+        storage::clear_pending_treasury(&env);
 
-        // Use optimized event
-        events::emit_unpause(&env, &admin);
+        events::emit_treasury_updated(&env, &pending.treasury);
 
         Ok(())
     }
 
-    /// Check if contract is currently paused
+    /// Set how long admin and treasury proposals stay valid (admin only)
     ///
-    /// Returns the current pause state of the contract.
+    /// A pending admin must accept within `ttl_seconds` of the proposal; a
+    /// pending treasury must be applied within `ttl_seconds` of becoming
+    /// effective. Expired proposals are void. Applies to proposals made
+    /// after the call. 0 disables expiry.
     ///
     /// # Arguments
-    /// * `env` - The contract environment
+    /// * `admin` - Admin address (must authorize)
+    /// * `ttl_seconds` - Proposal lifetime (0 = never expire)
     ///
-    /// # Returns
-    /// Returns `true` if paused, `false` if operational
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the admin
+    pub fn set_proposal_ttl(env: Env, admin: Address, ttl_seconds: u64) -> Result<(), Error> {
+        storage::require_admin(&env, &admin)?;
+        storage::ensure_not_killed(&env)?;
+
+        storage::set_proposal_ttl(&env, ttl_seconds);
+        Ok(())
+    }
+
+    /// Withdraw the pending admin proposal (admin only)
     ///
-    /// # Examples
-    /// ```
-    /// if factory.is_paused(&env) {
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the admin
+    /// * `Error::ChangeNotFound` - No admin proposal is pending
+    pub fn cancel_pending_admin(env: Env, admin: Address) -> Result<(), Error> {
+        storage::require_admin(&env, &admin)?;
+        storage::ensure_not_killed(&env)?;
+
+        if !storage::has_pending_admin(&env) {
+            return Err(Error::ChangeNotFound);
+        }
+        storage::clear_pending_admin(&env);
+        Ok(())
+    }
+
+    /// Withdraw the pending treasury proposal (admin only)
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the admin
+    /// * `Error::ChangeNotFound` - No treasury change is pending
+    pub fn cancel_pending_treasury(env: Env, admin: Address) -> Result<(), Error> {
+        storage::require_admin(&env, &admin)?;
+        storage::ensure_not_killed(&env)?;
+
+        storage::get_pending_treasury(&env).ok_or(Error::ChangeNotFound)?;
+        storage::clear_pending_treasury(&env);
+        Ok(())
+    }
+
+    /// Get the current treasury and any pending change
+    ///
+    /// # Returns
+    /// Returns a `TreasuryStatus` with the current treasury, the pending
+    /// treasury and its effective timestamp (both `None` when nothing is pending)
+    ///
+    /// # Errors
+    /// * `Error::NotInitialized` - Contract has not been initialized
+    pub fn get_treasury_status(env: Env) -> Result<types::TreasuryStatus, Error> {
+        let pending = storage::get_pending_treasury(&env);
+        Ok(types::TreasuryStatus {
+            current: storage::try_get_treasury(&env)?,
+            pending: pending.as_ref().map(|p| p.treasury.clone()),
+            effective_at: pending.map(|p| p.effective_at),
+        })
+    }
+
+    /// Route penalty and burn-fee revenue to a separate treasury (admin only)
+    ///
+    /// Creation fees keep going to the main treasury. `None` clears the
+    /// override so penalties fall back to the main treasury as well.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `admin` - Admin address (must authorize)
+    /// * `treasury` - Penalty treasury, or `None` to clear
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the admin
+    /// * `Error::InvalidParameters` - `treasury` is the admin while
+    ///   separation is required
+    pub fn set_penalty_treasury(
+        env: Env,
+        admin: Address,
+        treasury: Option<Address>,
+    ) -> Result<(), Error> {
+        storage::require_admin(&env, &admin)?;
+        storage::ensure_not_killed(&env)?;
+
+        if let Some(ref treasury) = treasury {
+            storage::check_treasury_separation(&env, &admin, treasury)?;
+        }
+        storage::set_penalty_treasury(&env, treasury);
+        Ok(())
+    }
+
+    /// Get the address that receives penalty and burn-fee revenue
+    ///
+    /// # Returns
+    /// Returns the penalty treasury, or the main treasury when none is set
+    ///
+    /// # Errors
+    /// * `Error::NotInitialized` - Contract has not been initialized
+    pub fn get_penalty_treasury(env: Env) -> Result<Address, Error> {
+        storage::get_penalty_destination(&env)
+    }
+
+    /// Pause the contract (admin only)
+    ///
+    /// Halts critical operations like token creation and metadata updates.
+    /// Admin functions like fee updates remain operational during pause.
+    /// This is a safety mechanism for emergency situations.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `admin` - Admin address (must authorize and match stored admin)
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the admin
+    ///
+    /// # Examples
+    /// ```
+    /// // Emergency pause
+    /// factory.pause(&env, admin_address)?;
+    /// assert!(factory.is_paused(&env));
+    /// ```
+    pub fn pause(env: Env, admin: Address) -> Result<(), Error> {
+        // Combined verification (Phase 1 optimization)
+        storage::require_admin(&env, &admin)?;
+        storage::ensure_not_killed(&env)?;
+
+        storage::set_paused(&env, true);
+
+        // Use optimized event
+        events::emit_pause(&env, &admin);
+
+        Ok(())
+    }
+
+    /// Unpause the contract (admin only)
+    ///
+    /// Resumes normal operations after a pause. All previously
+    /// restricted operations become available again.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `admin` - Admin address (must authorize and match stored admin)
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the admin
+    ///
+    /// # Examples
+    /// ```
+    /// // Resume operations
+    /// factory.unpause(&env, admin_address)?;
+    /// assert!(!factory.is_paused(&env));
+    /// ```
+    pub fn unpause(env: Env, admin: Address) -> Result<(), Error> {
+        // Combined verification (Phase 1 optimization)
+        storage::require_admin(&env, &admin)?;
+        storage::ensure_not_killed(&env)?;
+
+        storage::set_paused(&env, false);
+
+        // Use optimized event
+        events::emit_unpause(&env, &admin);
+
+        Ok(())
+    }
+
+    /// Check if contract is currently paused
+    ///
+    /// Returns the current pause state of the contract.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    ///
+    /// # Returns
+    /// Returns `true` if paused, `false` if operational
+    ///
+    /// # Examples
+    /// ```
+    /// if factory.is_paused(&env) {
     ///     // Handle paused state
     ///     return Err(Error::ContractPaused);
     /// }
@@ -477,6 +1004,100 @@ impl TokenFactory {
         storage::is_paused(&env)
     }
 
+    /// Engage or release the emergency kill switch (admin only)
+    ///
+    /// A last-resort switch beyond `pause` and the per-token pauses: while
+    /// engaged, every state-changing entry point, including admin
+    /// configuration, fails with `Error::ContractKilled`. Reads, including
+    /// admin-gated ones, keep working. Only this function, `upgrade` and
+    /// `bump_tokens_ttl` remain available among mutations, so the admin can
+    /// recover without letting storage expire.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `killed` - `true` to engage, `false` to release
+    /// * `caller` - Admin address (must authorize and match stored admin)
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the admin
+    pub fn set_killed(env: Env, killed: bool, caller: Address) -> Result<(), Error> {
+        storage::require_admin(&env, &caller)?;
+
+        storage::set_killed(&env, killed);
+        events::emit_kill_switch(&env, &caller, killed);
+        Ok(())
+    }
+
+    /// Check whether the emergency kill switch is engaged
+    pub fn is_killed(env: Env) -> bool {
+        storage::is_killed(&env)
+    }
+
+    /// Upgrade the contract code in place (admin only)
+    ///
+    /// Swaps the executable for an already-uploaded WASM while keeping the
+    /// contract address and all of its storage (tokens, balances, burn
+    /// history). The new code takes effect from the next invocation, and
+    /// the stored contract version is bumped by one.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `new_wasm_hash` - Hash of the WASM previously uploaded to the ledger
+    /// * `caller` - Admin address (must authorize and match stored admin)
+    ///
+    /// # Returns
+    /// Returns the new contract version
+    ///
+    /// # Errors
+    /// * `Error::NotInitialized` - Contract has not been initialized
+    /// * `Error::UpgradeUnauthorized` - Caller is not the admin
+    ///
+    /// # Events
+    /// Emits `upgrd_v1` with the caller, WASM hash and new version
+    pub fn upgrade(env: Env, new_wasm_hash: BytesN<32>, caller: Address) -> Result<u32, Error> {
+        caller.require_auth();
+
+        let current_admin = storage::try_get_admin(&env)?;
+        if caller != current_admin {
+            return Err(Error::UpgradeUnauthorized);
+        }
+
+        let version = storage::get_contract_version(&env)
+            .checked_add(1)
+            .ok_or(Error::ArithmeticError)?;
+        storage::set_contract_version(&env, version);
+
+        env.deployer()
+            .update_current_contract_wasm(new_wasm_hash.clone());
+
+        events::emit_contract_upgraded(&env, &caller, &new_wasm_hash, version);
+
+        Ok(version)
+    }
+
+    /// Get the contract code version
+    ///
+    /// Starts at 1 on deployment and increases by one with every
+    /// successful `upgrade`.
+    ///
+    /// # Returns
+    /// Returns the current contract version
+    pub fn version(env: Env) -> u32 {
+        storage::get_contract_version(&env)
+    }
+
+    /// Summarize contract health for monitoring
+    ///
+    /// Reports whether the contract is initialized and paused, and whether
+    /// the token counter and a bounded sample of token supplies are
+    /// consistent. Never errors, so it can be polled before `initialize`.
+    ///
+    /// # Returns
+    /// Returns a `HealthStatus`
+    pub fn health(env: Env) -> types::HealthStatus {
+        validation::health(&env)
+    }
+
     /// Update fee structure (admin only)
     ///
     /// Allows the admin to update either or both deployment fees.
@@ -509,19 +1130,18 @@ impl TokenFactory {
         base_fee: Option<i128>,
         metadata_fee: Option<i128>,
     ) -> Result<(), Error> {
-        admin.require_auth();
-
         // Early return on unauthorized (Phase 1 optimization)
-        let current_admin = storage::get_admin(&env);
-        if admin != current_admin {
-            return Err(Error::Unauthorized);
-        }
+        storage::require_admin(&env, &admin)?;
+        storage::ensure_not_killed(&env)?;
 
         // Early return if no changes requested
         if base_fee.is_none() && metadata_fee.is_none() {
             return Err(Error::InvalidParameters);
         }
 
+        let old_base_fee = storage::try_get_base_fee(&env)?;
+        let old_metadata_fee = storage::try_get_metadata_fee(&env)?;
+
         // Validate fees before updating (Phase 1 optimization)
         if let Some(fee) = base_fee {
             if fee < 0 {
@@ -541,554 +1161,1880 @@ impl TokenFactory {
         validation::validate_fees(&env)?;
 
         // Get updated fees for event
-        let new_base_fee = base_fee.unwrap_or_else(|| storage::get_base_fee(&env));
-        let new_metadata_fee = metadata_fee.unwrap_or_else(|| storage::get_metadata_fee(&env));
+        let new_base_fee = match base_fee {
+            Some(fee) => fee,
+            None => storage::try_get_base_fee(&env)?,
+        };
+        let new_metadata_fee = match metadata_fee {
+            Some(fee) => fee,
+            None => storage::try_get_metadata_fee(&env)?,
+        };
+
+        storage::add_fee_change(
+            &env,
+            &types::FeeChange {
+                old_base_fee,
+                old_metadata_fee,
+                new_base_fee,
+                new_metadata_fee,
+                changed_by: admin,
+                timestamp: env.ledger().timestamp(),
+            },
+        )?;
 
         // Emit optimized event
         events::emit_fees_updated(&env, new_base_fee, new_metadata_fee);
         Ok(())
     }
 
-    /// Get token info by index
-    pub fn get_token_info(env: Env, index: u32) -> Result<TokenInfo, Error> {
-        let mut info = storage::get_token_info(&env, index).ok_or(Error::TokenNotFound)?;
-        info.is_paused = storage::is_token_paused(&env, index);
-        Ok(info)
-    }
-
-    /// Batch update admin operations (Phase 2 optimization)
+    /// Set both deployment fees in one call (admin only)
     ///
-    /// Updates multiple admin parameters in a single transaction,
-    /// reducing gas costs by combining verification and storage operations.
-    /// Provides 40-50% gas savings compared to separate function calls.
+    /// Validates and writes both fees atomically, emitting a single
+    /// `fee_up_v1` event and a single `FeeChange` audit entry.
     ///
     /// # Arguments
     /// * `env` - The contract environment
-    /// * `admin` - Admin address (must authorize and match stored admin)
-    /// * `base_fee` - Optional new base fee in stroops (None = no change)
-    /// * `metadata_fee` - Optional new metadata fee in stroops (None = no change)
-    /// * `paused` - Optional new pause state (None = no change)
+    /// * `base_fee` - New base fee in stroops (must be >= 0)
+    /// * `metadata_fee` - New metadata fee in stroops (must be >= 0)
+    /// * `caller` - Admin address (must authorize and match stored admin)
     ///
     /// # Returns
     /// Returns `Ok(())` on success
     ///
     /// # Errors
     /// * `Error::Unauthorized` - Caller is not the admin
-    /// * `Error::InvalidParameters` - All parameters are None or any fee is negative
+    /// * `Error::InvalidParameters` - Either fee is negative
+    pub fn set_fees(
+        env: Env,
+        base_fee: i128,
+        metadata_fee: i128,
+        caller: Address,
+    ) -> Result<(), Error> {
+        Self::update_fees(env, caller, Some(base_fee), Some(metadata_fee))
+    }
+
+    /// Get the number of entries in the fee change audit log
+    pub fn get_fee_change_count(env: Env) -> u32 {
+        storage::get_fee_change_count(&env)
+    }
+
+    /// Get a fee change audit entry by index
+    pub fn get_fee_change(env: Env, index: u32) -> Option<types::FeeChange> {
+        storage::get_fee_change(&env, index)
+    }
+
+    /// Configure metadata length limits (admin only)
     ///
-    /// # Gas Savings
-    /// - Batch both fee updates: -2,000 to 3,000 CPU instructions
-    /// - Combined with pause: -1,000 additional CPU instructions
-    /// - Total savings vs separate calls: 40-50% for combined operations
+    /// Replaces the built-in defaults (256-byte URIs, 1024-byte descriptions)
+    /// enforced by the metadata setters and by token creation.
     ///
-    /// # Examples
-    /// ```
-    /// // Update fees and pause in one transaction
-    /// factory.batch_update_admin(
-    ///     &env,
-    ///     admin,
-    ///     Some(2_000_000),
-    ///     Some(1_000_000),
-    ///     Some(true),
-    /// )?;
-    /// ```
-    pub fn batch_update_admin(
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `admin` - Admin address (must authorize)
+    /// * `max_uri_len` - Optional new maximum URI length (None = keep current)
+    /// * `max_description_len` - Optional new maximum description length (None = keep current)
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the admin
+    /// * `Error::InvalidParameters` - No change requested, or a limit is zero
+    pub fn set_metadata_limits(
         env: Env,
         admin: Address,
-        base_fee: Option<i128>,
-        metadata_fee: Option<i128>,
-        paused: Option<bool>,
+        max_uri_len: Option<u32>,
+        max_description_len: Option<u32>,
     ) -> Result<(), Error> {
-        admin.require_auth();
+        storage::require_admin(&env, &admin)?;
+        storage::ensure_not_killed(&env)?;
 
-        // Single admin verification (Phase 2 optimization)
-        let current_admin = storage::get_admin(&env);
-        if admin != current_admin {
-            return Err(Error::Unauthorized);
-        }
-
-        // Early return if no changes
-        if base_fee.is_none() && metadata_fee.is_none() && paused.is_none() {
+        if max_uri_len.is_none() && max_description_len.is_none() {
             return Err(Error::InvalidParameters);
         }
 
-        // Validate all inputs before any storage writes (Phase 2 optimization)
-        if let Some(fee) = base_fee {
-            if fee < 0 {
+        if let Some(len) = max_uri_len {
+            if len == 0 {
                 return Err(Error::InvalidParameters);
             }
-            storage::set_base_fee(&env, fee);
+            storage::set_max_metadata_uri_len(&env, len);
         }
 
-        if let Some(fee) = metadata_fee {
-            if fee < 0 {
+        if let Some(len) = max_description_len {
+            if len == 0 {
                 return Err(Error::InvalidParameters);
             }
-            storage::set_metadata_fee(&env, fee);
-        }
-
-        if let Some(pause_state) = paused {
-            storage::set_paused(&env, pause_state);
+            storage::set_max_description_len(&env, len);
         }
 
-        // Validate fees after update
-        validation::validate_fees(&env)?;
+        events::emit_metadata_limits_updated(
+            &env,
+            storage::get_max_metadata_uri_len(&env),
+            storage::get_max_description_len(&env),
+        );
+        Ok(())
+    }
 
-        // Get final state for event
-        let final_base_fee = storage::get_base_fee(&env);
-        let final_metadata_fee = storage::get_metadata_fee(&env);
-
-        // Emit single consolidated event (Phase 2 optimization)
-        events::emit_fees_updated(&env, final_base_fee, final_metadata_fee);
+    /// Require a metadata URI on every new token (admin only)
+    ///
+    /// While on, every creation path rejects tokens without a
+    /// `metadata_uri`. Existing tokens are unaffected.
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the admin
+    pub fn set_require_metadata(env: Env, admin: Address, required: bool) -> Result<(), Error> {
+        storage::require_admin(&env, &admin)?;
+        storage::ensure_not_killed(&env)?;
 
+        storage::set_metadata_required(&env, required);
         Ok(())
     }
 
-    /// Get token information by contract address
+    /// Configure token tag limits (admin only)
     ///
-    /// Retrieves complete information about a token using its
-    /// deployed contract address.
+    /// Replaces the built-in defaults (5 tags of up to 32 bytes each)
+    /// enforced by `set_token_tags`. Existing tag lists are not re-checked.
     ///
     /// # Arguments
     /// * `env` - The contract environment
-    /// * `token_address` - The token's contract address
-    ///
-    /// # Returns
-    /// Returns `Ok(TokenInfo)` with token details
+    /// * `admin` - Admin address (must authorize)
+    /// * `max_count` - Optional new maximum tags per token (None = keep current)
+    /// * `max_len` - Optional new maximum tag length (None = keep current)
     ///
     /// # Errors
-    /// * `Error::TokenNotFound` - Token address not found in registry
-    ///
-    /// # Examples
-    /// ```
-    /// let token = factory.get_token_info_by_address(&env, token_addr)?;
-    /// assert_eq!(token.creator, expected_creator);
-    /// ```
-    pub fn get_token_info_by_address(env: Env, token_address: Address) -> Result<TokenInfo, Error> {
-        storage::get_token_info_by_address(&env, &token_address).ok_or(Error::TokenNotFound)
+    /// * `Error::Unauthorized` - Caller is not the admin
+    /// * `Error::InvalidParameters` - No change requested, or a limit is zero
+    pub fn set_tag_limits(
+        env: Env,
+        admin: Address,
+        max_count: Option<u32>,
+        max_len: Option<u32>,
+    ) -> Result<(), Error> {
+        storage::require_admin(&env, &admin)?;
+        storage::ensure_not_killed(&env)?;
+
+        if max_count.is_none() && max_len.is_none() {
+            return Err(Error::InvalidParameters);
+        }
+        if max_count == Some(0) || max_len == Some(0) {
+            return Err(Error::InvalidParameters);
+        }
+
+        if let Some(count) = max_count {
+            storage::set_max_tag_count(&env, count);
+        }
+        if let Some(len) = max_len {
+            storage::set_max_tag_len(&env, len);
+        }
+        Ok(())
     }
 
-    // ── Game / Deployment History ─────────────────────────────────────────
+    /// Get the tag limits as `(max_count, max_len)`
+    pub fn get_tag_limits(env: Env) -> (u32, u32) {
+        (storage::get_max_tag_count(&env), storage::get_max_tag_len(&env))
+    }
 
-    /// Return the total number of deployment history records.
-    pub fn history_count(env: Env) -> u64 {
-        game_history::history_count(&env)
+    /// Whether new tokens must be created with a metadata URI
+    pub fn get_require_metadata(env: Env) -> bool {
+        storage::is_metadata_required(&env)
     }
 
-    /// Retrieve a single deployment history record by its history index.
+    /// Cap how many times each token's metadata can be updated (admin only)
     ///
-    /// Returns `None` if the index is out of range or has been pruned.
-    pub fn get_history_record(
-        env: Env,
-        history_index: u64,
-    ) -> Option<game_history::DeploymentRecord> {
-        game_history::get_history_record(&env, history_index)
+    /// Counts calls to `update_metadata` and `update_metadata_with_hash`;
+    /// the initial `set_metadata` is not an update. Once a token reaches
+    /// the cap, further updates fail with `Error::MetadataAlreadySet` until
+    /// the admin resets its counter. 0 removes the cap.
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the admin
+    pub fn set_max_metadata_updates(env: Env, admin: Address, max: u32) -> Result<(), Error> {
+        storage::require_admin(&env, &admin)?;
+        storage::ensure_not_killed(&env)?;
+
+        storage::set_max_metadata_updates(&env, max);
+        Ok(())
     }
 
-    /// Query deployment history for a specific creator address.
-    ///
-    /// Returns up to `limit` records (max 100) starting from `offset`.
+    /// Metadata updates allowed per token (0 = unlimited)
+    pub fn get_max_metadata_updates(env: Env) -> u32 {
+        storage::get_max_metadata_updates(&env)
+    }
+
+    /// Reset a token's metadata update counter (admin only)
     ///
     /// # Errors
-    /// `InvalidParameters` – `limit` is 0 or > 100.
-    pub fn query_by_creator(
+    /// * `Error::Unauthorized` - Caller is not the admin
+    /// * `Error::TokenNotFound` - Token index does not exist
+    pub fn reset_metadata_update_count(
         env: Env,
-        creator: Address,
-        offset: u64,
-        limit: u32,
-    ) -> Result<Vec<game_history::DeploymentRecord>, Error> {
-        game_history::query_by_creator(&env, &creator, offset, limit)
+        admin: Address,
+        token_index: u32,
+    ) -> Result<(), Error> {
+        storage::require_admin(&env, &admin)?;
+        storage::ensure_not_killed(&env)?;
+        storage::get_token_info(&env, token_index).ok_or(Error::TokenNotFound)?;
+
+        storage::reset_metadata_update_count(&env, token_index);
+        Ok(())
     }
 
-    /// Query deployment history within a ledger timestamp range `[from, to]`.
+    /// Metadata updates applied to a token since its counter was last reset
+    pub fn get_metadata_update_count(env: Env, token_index: u32) -> u32 {
+        storage::get_metadata_update_count(&env, token_index)
+    }
+
+    /// Waive the metadata fee for URIs supplied at creation (admin only)
     ///
-    /// Returns up to `limit` records (max 100).
+    /// While on, creating a token with a `metadata_uri` costs only the
+    /// base fee (still subject to the minimum creation fee), to encourage
+    /// complete listings from the start. The waiver does not apply to
+    /// metadata attached after creation via `set_metadata` or
+    /// `set_token_metadata`, which still charge the token's metadata fee.
     ///
     /// # Errors
-    /// `InvalidParameters` – `from > to`, `limit` is 0, or `limit > 100`.
-    pub fn query_by_time_range(
+    /// * `Error::Unauthorized` - Caller is not the admin
+    pub fn set_waive_md_fee_at_create(
         env: Env,
-        from: u64,
-        to: u64,
-        limit: u32,
-    ) -> Result<Vec<game_history::DeploymentRecord>, Error> {
-        game_history::query_by_time_range(&env, from, to, limit)
+        admin: Address,
+        waived: bool,
+    ) -> Result<(), Error> {
+        storage::require_admin(&env, &admin)?;
+        storage::ensure_not_killed(&env)?;
+
+        storage::set_metadata_fee_waived_at_creation(&env, waived);
+        Ok(())
     }
 
-    /// Replay history up to `up_to_index` and return a cumulative snapshot.
+    /// Whether the metadata fee is waived for URIs supplied at creation
+    pub fn get_waive_md_fee_at_create(env: Env) -> bool {
+        storage::is_metadata_fee_waived_at_creation(&env)
+    }
+
+    /// Set a floor on the per-token creation fee (admin only)
     ///
-    /// Useful for auditing: the snapshot's `token_count` and
-    /// `cumulative_supply` should match the live factory state at that point.
+    /// Every creation path charges at least this much per token, even when
+    /// the configured base and metadata fees add up to less (e.g. zero-fee
+    /// periods). 0 disables the floor.
+    ///
+    /// # Arguments
+    /// * `admin` - Admin address (must authorize)
+    /// * `min_fee` - Minimum fee per token in stroops (>= 0)
     ///
     /// # Errors
-    /// `InvalidParameters` – `up_to_index` is beyond the current history count.
-    pub fn replay(env: Env, up_to_index: u64) -> Result<game_history::HistorySnapshot, Error> {
-        game_history::replay(&env, up_to_index)
+    /// * `Error::Unauthorized` - Caller is not the admin
+    /// * `Error::InvalidParameters` - `min_fee` is negative
+    pub fn set_min_creation_fee(env: Env, admin: Address, min_fee: i128) -> Result<(), Error> {
+        storage::require_admin(&env, &admin)?;
+        storage::ensure_not_killed(&env)?;
+
+        if min_fee < 0 {
+            return Err(Error::InvalidParameters);
+        }
+
+        storage::set_min_creation_fee(&env, min_fee);
+        Ok(())
     }
 
-    /// Prune history records with index < `before_index` (admin only).
+    /// Round creation fees up to a denomination (admin only)
     ///
-    /// Removes records from persistent storage to reclaim ledger space.
-    /// The history count is NOT decremented.
+    /// Keeps charged fees "clean", e.g. a unit of 1_000_000 stroops makes
+    /// every fee a multiple of 0.1 XLM. Rounding is applied after the
+    /// `set_min_creation_fee` floor. 0 disables rounding.
     ///
-    /// # Returns
-    /// Number of records pruned.
+    /// # Arguments
+    /// * `admin` - Admin address (must authorize)
+    /// * `unit` - Rounding unit in stroops (>= 0)
     ///
     /// # Errors
-    /// `Unauthorized` – Caller is not the factory admin.
-    /// `InvalidParameters` – `before_index` is 0 or exceeds the history count.
-    pub fn prune_history(
-        env: Env,
-        admin: Address,
-        before_index: u64,
-    ) -> Result<u32, Error> {
-        game_history::prune_history(&env, &admin, before_index)
+    /// * `Error::Unauthorized` - Caller is not the admin
+    /// * `Error::InvalidParameters` - `unit` is negative
+    pub fn set_fee_rounding_unit(env: Env, admin: Address, unit: i128) -> Result<(), Error> {
+        storage::require_admin(&env, &admin)?;
+        storage::ensure_not_killed(&env)?;
+
+        if unit < 0 {
+            return Err(Error::InvalidParameters);
+        }
+
+        storage::set_fee_rounding_unit(&env, unit);
+        Ok(())
     }
 
-    // ── Referral / Affiliate System ───────────────────────────────────────
+    /// Get the creation fee rounding unit (0 = no rounding)
+    pub fn get_fee_rounding_unit(env: Env) -> i128 {
+        storage::get_fee_rounding_unit(&env)
+    }
 
-    /// Register a referral relationship.
+    /// Cap the number of tokens the registry may hold (admin only)
     ///
-    /// `referee` is the new user; `referrer` is the existing user who brought
-    /// them. A referee can only register once and cannot refer themselves.
+    /// Creations that would take the token count past the cap fail with
+    /// `Error::RegistryFull`; batches are rejected whole. 0 removes the cap.
+    /// A cap below the current count simply blocks further creation.
+    ///
+    /// # Arguments
+    /// * `admin` - Admin address (must authorize)
+    /// * `max_tokens` - Registry size limit (0 = unlimited)
     ///
     /// # Errors
-    /// `InvalidParameters` – self-referral or already registered.
-    pub fn register_referral(
-        env: Env,
-        referee: Address,
-        referrer: Address,
-    ) -> Result<(), Error> {
-        referee.require_auth();
-        referral::register_referral(&env, &referee, &referrer)
+    /// * `Error::Unauthorized` - Caller is not the admin
+    pub fn set_max_tokens(env: Env, admin: Address, max_tokens: u32) -> Result<(), Error> {
+        storage::require_admin(&env, &admin)?;
+        storage::ensure_not_killed(&env)?;
+
+        storage::set_max_tokens(&env, max_tokens);
+        Ok(())
     }
 
-    /// Return the referral info for a given referee address.
-    pub fn get_referral(
-        env: Env,
-        referee: Address,
-    ) -> Option<referral::ReferralInfo> {
-        referral::get_referral(&env, &referee)
+    /// Get the registry size limit (0 = unlimited)
+    pub fn get_max_tokens(env: Env) -> u32 {
+        storage::get_max_tokens(&env)
     }
 
-    /// Return the total commission earned (but not yet paid out) by a referrer.
-    pub fn get_referral_earned(env: Env, referrer: Address) -> i128 {
-        referral::get_earned(&env, &referrer)
+    /// Exempt a creator from creation fees (admin only)
+    ///
+    /// Exempt creators pay nothing on any creation path, including the
+    /// `set_min_creation_fee` floor. Exempting an address twice is a no-op.
+    ///
+    /// # Arguments
+    /// * `admin` - Admin address (must authorize)
+    /// * `creator` - Partner or admin address to exempt
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the admin
+    pub fn add_fee_exemption(env: Env, admin: Address, creator: Address) -> Result<(), Error> {
+        storage::require_admin(&env, &admin)?;
+        storage::ensure_not_killed(&env)?;
+
+        storage::set_fee_exempt(&env, &creator, true);
+        Ok(())
     }
 
-    /// Return the current commission rate in basis points.
-    pub fn get_commission_rate(env: Env) -> u32 {
-        referral::get_commission_rate_bps(&env)
+    /// Remove a creator's fee exemption (admin only)
+    ///
+    /// Idempotent; the creator pays regular fees from the next creation.
+    ///
+    /// # Arguments
+    /// * `admin` - Admin address (must authorize)
+    /// * `creator` - Address losing the exemption
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the admin
+    pub fn remove_fee_exemption(env: Env, admin: Address, creator: Address) -> Result<(), Error> {
+        storage::require_admin(&env, &admin)?;
+        storage::ensure_not_killed(&env)?;
+
+        storage::set_fee_exempt(&env, &creator, false);
+        Ok(())
     }
 
-    /// Update the referral commission rate (admin only).
+    /// Check whether a creator is exempt from creation fees
+    pub fn is_fee_exempt(env: Env, creator: Address) -> bool {
+        storage::is_fee_exempt(&env, &creator)
+    }
+
+    /// Configure initial-supply bounds for token creation (admin only)
+    ///
+    /// Guards downstream `i128` arithmetic against absurd supplies. Setting
+    /// `min_supply` to 0 allows zero-supply tokens; the default minimum of 1
+    /// rejects them.
     ///
     /// # Arguments
-    /// * `admin`    – Factory admin (must auth).
-    /// * `rate_bps` – New rate in basis points; max `MAX_COMMISSION_BPS` (2000).
+    /// * `env` - The contract environment
+    /// * `admin` - Admin address (must authorize)
+    /// * `min_supply` - Smallest accepted initial supply (>= 0)
+    /// * `max_supply` - Largest accepted initial supply (>= `min_supply`)
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success
     ///
     /// # Errors
-    /// `Unauthorized` – Caller is not the factory admin.
-    /// `InvalidParameters` – `rate_bps > 2000`.
-    pub fn set_commission_rate(
+    /// * `Error::Unauthorized` - Caller is not the admin
+    /// * `Error::InvalidParameters` - `min_supply` is negative or exceeds `max_supply`
+    pub fn set_initial_supply_bounds(
         env: Env,
         admin: Address,
-        rate_bps: u32,
+        min_supply: i128,
+        max_supply: i128,
     ) -> Result<(), Error> {
-        referral::set_commission_rate_bps(&env, &admin, rate_bps)
+        storage::require_admin(&env, &admin)?;
+        storage::ensure_not_killed(&env)?;
+
+        if min_supply < 0 || min_supply > max_supply {
+            return Err(Error::InvalidParameters);
+        }
+
+        storage::set_min_initial_supply(&env, min_supply);
+        storage::set_max_initial_supply(&env, max_supply);
+        Ok(())
     }
 
-    /// Pay out accumulated commission to a referrer (admin only).
+    /// Cap the number of burn-log records kept per token (admin only)
     ///
-    /// Resets the referrer's earned balance to zero.
+    /// Once a token reaches the cap, further burns still update its
+    /// `total_burned` and `burn_count` but are no longer appended to the
+    /// global burn log. Existing records are kept as-is.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `admin` - Admin address (must authorize)
+    /// * `max_records` - Records allowed per token (None = unlimited)
     ///
     /// # Returns
-    /// Amount paid out.
+    /// Returns `Ok(())` on success
     ///
     /// # Errors
-    /// `Unauthorized` – Caller is not the factory admin.
-    /// `InvalidParameters` – Referrer has no earned commission.
-    pub fn payout_commission(
+    /// * `Error::Unauthorized` - Caller is not the admin
+    pub fn set_max_burn_records_per_token(
         env: Env,
         admin: Address,
-        referrer: Address,
-    ) -> Result<i128, Error> {
-        referral::payout_commission(&env, &admin, &referrer)
+        max_records: Option<u32>,
+    ) -> Result<(), Error> {
+        storage::require_admin(&env, &admin)?;
+        storage::ensure_not_killed(&env)?;
+
+        storage::set_max_burn_records_per_token(&env, max_records);
+        Ok(())
     }
-    /// * `initial_supply` - Initial token supply
-    /// * `fee_payment` - Fee amount (must be >= base_fee)
-    /// Toggle clawback capability for a token (creator only)
+
+    /// Choose which account burns are credited to (admin only)
     ///
-    /// Allows the token creator to enable or disable clawback functionality.
-    /// When enabled, the creator can burn tokens from any holder's address.
-    /// This setting can be toggled multiple times by the creator.
+    /// Admin burns debit the holder but are executed by the admin. By
+    /// default (`false`) the holder's `get_account_burned` total is
+    /// credited; with `true` the executor's is. Self-burns credit the
+    /// same account either way. Totals already recorded are not moved.
     ///
     /// # Arguments
     /// * `env` - The contract environment
-    /// * `token_address` - The token's contract address
-    /// * `admin` - Token creator address (must authorize and match creator)
-    /// * `enabled` - True to enable clawback, false to disable
+    /// * `admin` - Admin address (must authorize)
+    /// * `credit_executor` - Credit the executor instead of the holder
     ///
     /// # Returns
     /// Returns `Ok(())` on success
     ///
     /// # Errors
-    /// * `Error::ContractPaused` - Contract is currently paused
-    /// * `Error::TokenNotFound` - Token address not found
-    /// * `Error::Unauthorized` - Caller is not the token creator
-    ///
-    /// # Examples
-    /// ```
-    /// // Enable clawback for emergency situations
-    /// factory.set_clawback(&env, token_addr, creator, true)?;
-    ///
-    /// // Disable clawback for decentralization
-    /// factory.set_clawback(&env, token_addr, creator, false)?;
-    /// ```
-    pub fn set_clawback(
+    /// * `Error::Unauthorized` - Caller is not the admin
+    pub fn set_burn_credit_executor(
         env: Env,
-        token_address: Address,
         admin: Address,
-        enabled: bool,
+        credit_executor: bool,
     ) -> Result<(), Error> {
-        // Early return if contract is paused (Phase 1 optimization)
-        if storage::is_paused(&env) {
-            return Err(Error::ContractPaused);
-        }
+        storage::require_admin(&env, &admin)?;
+        storage::ensure_not_killed(&env)?;
 
-        // Require admin authorization
-        admin.require_auth();
+        storage::set_credit_burn_executor(&env, credit_executor);
+        Ok(())
+    }
 
-        // Get token info
-        let mut token_info =
-            storage::get_token_info_by_address(&env, &token_address).ok_or(Error::TokenNotFound)?;
+    /// Whether burns are credited to the executor rather than the holder
+    pub fn get_burn_credit_executor(env: Env) -> bool {
+        storage::get_credit_burn_executor(&env)
+    }
+
+    /// Get the total burned of `token_index` credited to `account`
+    pub fn get_account_burned(env: Env, token_index: u32, account: Address) -> i128 {
+        storage::get_account_burned(&env, token_index, &account)
+    }
+
+    /// Get the total burned of the token at `token_address` credited to `account`
+    ///
+    /// Resolves the address through the token index; an unknown address
+    /// returns 0.
+    pub fn get_account_burned_for_token(
+        env: Env,
+        token_address: Address,
+        account: Address,
+    ) -> i128 {
+        storage::get_token_index_by_address(&env, &token_address).map_or(0, |token_index| {
+            storage::get_account_burned(&env, token_index, &account)
+        })
+    }
+
+    /// Check on-chain that an account has burned at least `min_amount`
+    ///
+    /// Intended for other contracts accepting proof-of-burn: a plain read
+    /// of the factory's own counters, with no auth and no side effects.
+    ///
+    /// # Arguments
+    /// * `token_index` - Index of the token
+    /// * `account` - Account whose credited burns are checked
+    /// * `min_amount` - Required burned amount (must be > 0)
+    ///
+    /// # Returns
+    /// Returns `true` if the account's `get_account_burned` total is at
+    /// least `min_amount`; `false` otherwise, for an unknown token, or when
+    /// `min_amount <= 0`
+    pub fn verify_burn(env: Env, token_index: u32, account: Address, min_amount: i128) -> bool {
+        burn::verify_burn(&env, token_index, &account, min_amount)
+    }
+
+    /// Set the fallback metadata URI for tokens without one (admin only)
+    ///
+    /// Only affects `get_effective_metadata_uri`; stored token metadata is
+    /// left untouched.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `admin` - Admin address (must authorize)
+    /// * `uri` - Default URI (None = clear the default)
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the admin
+    /// * `Error::InvalidParameters` - URI exceeds the configured length limit
+    pub fn set_default_metadata_uri(
+        env: Env,
+        admin: Address,
+        uri: Option<String>,
+    ) -> Result<(), Error> {
+        storage::require_admin(&env, &admin)?;
+        storage::ensure_not_killed(&env)?;
+
+        if let Some(ref uri) = uri {
+            validation::validate_metadata_uri(&env, uri)?;
+        }
+
+        storage::set_default_metadata_uri(&env, &uri);
+        Ok(())
+    }
+
+    /// Get token info by index
+    pub fn get_token_info(env: Env, index: u32) -> Result<TokenInfo, Error> {
+        let mut info = storage::get_token_info(&env, index).ok_or(Error::TokenNotFound)?;
+        info.is_paused = storage::is_token_paused(&env, index);
+        Ok(info)
+    }
+
+    /// Get the metadata URI wallets should display for a token
+    ///
+    /// Returns the token's own URI when set, otherwise the factory-wide
+    /// default from `set_default_metadata_uri`. Unknown tokens yield `None`.
+    pub fn get_effective_metadata_uri(env: Env, token_index: u32) -> Option<String> {
+        let info = storage::get_token_info(&env, token_index)?;
+        info.metadata_uri
+            .or_else(|| storage::get_default_metadata_uri(&env))
+    }
+
+    /// Dump every stored record for a token (admin only)
+    ///
+    /// Support and debugging read bundling `TokenInfo` with the token's
+    /// metadata state, freeze flag, burn and mint trackers and holder
+    /// figures. Its fields can be compared against the individual getters
+    /// to spot bookkeeping drift.
+    ///
+    /// # Arguments
+    /// * `admin` - Admin address (must authorize)
+    /// * `token_index` - Index of the token
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the admin
+    /// * `Error::TokenNotFound` - Token index does not exist
+    pub fn dump_token(env: Env, admin: Address, token_index: u32) -> Result<types::TokenDump, Error> {
+        storage::require_admin(&env, &admin)?;
+
+        storage::get_token_dump(&env, token_index).ok_or(Error::TokenNotFound)
+    }
+
+    /// Batch update admin operations (Phase 2 optimization)
+    ///
+    /// Updates multiple admin parameters in a single transaction,
+    /// reducing gas costs by combining verification and storage operations.
+    /// Provides 40-50% gas savings compared to separate function calls.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `admin` - Admin address (must authorize and match stored admin)
+    /// * `base_fee` - Optional new base fee in stroops (None = no change)
+    /// * `metadata_fee` - Optional new metadata fee in stroops (None = no change)
+    /// * `paused` - Optional new pause state (None = no change)
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the admin
+    /// * `Error::InvalidParameters` - All parameters are None or any fee is negative
+    ///
+    /// # Gas Savings
+    /// - Batch both fee updates: -2,000 to 3,000 CPU instructions
+    /// - Combined with pause: -1,000 additional CPU instructions
+    /// - Total savings vs separate calls: 40-50% for combined operations
+    ///
+    /// # Examples
+    /// ```
+    /// // Update fees and pause in one transaction
+    /// factory.batch_update_admin(
+    ///     &env,
+    ///     admin,
+    ///     Some(2_000_000),
+    ///     Some(1_000_000),
+    ///     Some(true),
+    /// )?;
+    /// ```
+    pub fn batch_update_admin(
+        env: Env,
+        admin: Address,
+        base_fee: Option<i128>,
+        metadata_fee: Option<i128>,
+        paused: Option<bool>,
+    ) -> Result<(), Error> {
+        // Single admin verification (Phase 2 optimization)
+        storage::require_admin(&env, &admin)?;
+        storage::ensure_not_killed(&env)?;
+
+        // Early return if no changes
+        if base_fee.is_none() && metadata_fee.is_none() && paused.is_none() {
+            return Err(Error::InvalidParameters);
+        }
+
+        // Validate all inputs before any storage writes (Phase 2 optimization)
+        if let Some(fee) = base_fee {
+            if fee < 0 {
+                return Err(Error::InvalidParameters);
+            }
+            storage::set_base_fee(&env, fee);
+        }
+
+        if let Some(fee) = metadata_fee {
+            if fee < 0 {
+                return Err(Error::InvalidParameters);
+            }
+            storage::set_metadata_fee(&env, fee);
+        }
+
+        if let Some(pause_state) = paused {
+            storage::set_paused(&env, pause_state);
+        }
+
+        // Validate fees after update
+        validation::validate_fees(&env)?;
+
+        // Get final state for event
+        let final_base_fee = storage::try_get_base_fee(&env)?;
+        let final_metadata_fee = storage::try_get_metadata_fee(&env)?;
+
+        // Emit single consolidated event (Phase 2 optimization)
+        events::emit_fees_updated(&env, final_base_fee, final_metadata_fee);
+
+        Ok(())
+    }
+
+    /// Get token information by contract address
+    ///
+    /// Retrieves complete information about a token using its
+    /// deployed contract address.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `token_address` - The token's contract address
+    ///
+    /// # Returns
+    /// Returns `Ok(TokenInfo)` with token details
+    ///
+    /// # Errors
+    /// * `Error::TokenNotFound` - Token address not found in registry
+    ///
+    /// # Examples
+    /// ```
+    /// let token = factory.get_token_info_by_address(&env, token_addr)?;
+    /// assert_eq!(token.creator, expected_creator);
+    /// ```
+    pub fn get_token_info_by_address(env: Env, token_address: Address) -> Result<TokenInfo, Error> {
+        storage::get_token_info_by_address(&env, &token_address).ok_or(Error::TokenNotFound)
+    }
+
+    // ── Game / Deployment History ─────────────────────────────────────────
+
+    /// Return the total number of deployment history records.
+    pub fn history_count(env: Env) -> u64 {
+        game_history::history_count(&env)
+    }
+
+    /// Retrieve a single deployment history record by its history index.
+    ///
+    /// Returns `None` if the index is out of range or has been pruned.
+    pub fn get_history_record(
+        env: Env,
+        history_index: u64,
+    ) -> Option<game_history::DeploymentRecord> {
+        game_history::get_history_record(&env, history_index)
+    }
+
+    /// Query deployment history for a specific creator address.
+    ///
+    /// Returns up to `limit` records (max 100) starting from `offset`.
+    ///
+    /// # Errors
+    /// `InvalidParameters` – `limit` is 0 or > 100.
+    pub fn query_by_creator(
+        env: Env,
+        creator: Address,
+        offset: u64,
+        limit: u32,
+    ) -> Result<Vec<game_history::DeploymentRecord>, Error> {
+        game_history::query_by_creator(&env, &creator, offset, limit)
+    }
+
+    /// Query deployment history within a ledger timestamp range `[from, to]`.
+    ///
+    /// Returns up to `limit` records (max 100).
+    ///
+    /// # Errors
+    /// `InvalidParameters` – `from > to`, `limit` is 0, or `limit > 100`.
+    pub fn query_by_time_range(
+        env: Env,
+        from: u64,
+        to: u64,
+        limit: u32,
+    ) -> Result<Vec<game_history::DeploymentRecord>, Error> {
+        game_history::query_by_time_range(&env, from, to, limit)
+    }
+
+    /// Replay history up to `up_to_index` and return a cumulative snapshot.
+    ///
+    /// Useful for auditing: the snapshot's `token_count` and
+    /// `cumulative_supply` should match the live factory state at that point.
+    ///
+    /// # Errors
+    /// `InvalidParameters` – `up_to_index` is beyond the current history count.
+    pub fn replay(env: Env, up_to_index: u64) -> Result<game_history::HistorySnapshot, Error> {
+        game_history::replay(&env, up_to_index)
+    }
+
+    /// Prune history records with index < `before_index` (admin only).
+    ///
+    /// Removes records from persistent storage to reclaim ledger space.
+    /// The history count is NOT decremented.
+    ///
+    /// # Returns
+    /// Number of records pruned.
+    ///
+    /// # Errors
+    /// `Unauthorized` – Caller is not the factory admin.
+    /// `InvalidParameters` – `before_index` is 0 or exceeds the history count.
+    pub fn prune_history(
+        env: Env,
+        admin: Address,
+        before_index: u64,
+    ) -> Result<u32, Error> {
+        game_history::prune_history(&env, &admin, before_index)
+    }
+
+    // ── Referral / Affiliate System ───────────────────────────────────────
+
+    /// Register a referral relationship.
+    ///
+    /// `referee` is the new user; `referrer` is the existing user who brought
+    /// them. A referee can only register once and cannot refer themselves.
+    ///
+    /// # Errors
+    /// `InvalidParameters` – self-referral or already registered.
+    pub fn register_referral(
+        env: Env,
+        referee: Address,
+        referrer: Address,
+    ) -> Result<(), Error> {
+        referee.require_auth();
+        storage::ensure_not_killed(&env)?;
+        referral::register_referral(&env, &referee, &referrer)
+    }
+
+    /// Return the referral info for a given referee address.
+    pub fn get_referral(
+        env: Env,
+        referee: Address,
+    ) -> Option<referral::ReferralInfo> {
+        referral::get_referral(&env, &referee)
+    }
+
+    /// Return the total commission earned (but not yet paid out) by a referrer.
+    pub fn get_referral_earned(env: Env, referrer: Address) -> i128 {
+        referral::get_earned(&env, &referrer)
+    }
+
+    /// Return the current commission rate in basis points.
+    pub fn get_commission_rate(env: Env) -> u32 {
+        referral::get_commission_rate_bps(&env)
+    }
+
+    /// Update the referral commission rate (admin only).
+    ///
+    /// # Arguments
+    /// * `admin`    – Factory admin (must auth).
+    /// * `rate_bps` – New rate in basis points; max `MAX_COMMISSION_BPS` (2000).
+    ///
+    /// # Errors
+    /// `Unauthorized` – Caller is not the factory admin.
+    /// `InvalidParameters` – `rate_bps > 2000`.
+    pub fn set_commission_rate(
+        env: Env,
+        admin: Address,
+        rate_bps: u32,
+    ) -> Result<(), Error> {
+        referral::set_commission_rate_bps(&env, &admin, rate_bps)
+    }
+
+    /// Pay out accumulated commission to a referrer (admin only).
+    ///
+    /// Resets the referrer's earned balance to zero.
+    ///
+    /// # Returns
+    /// Amount paid out.
+    ///
+    /// # Errors
+    /// `Unauthorized` – Caller is not the factory admin.
+    /// `InvalidParameters` – Referrer has no earned commission.
+    pub fn payout_commission(
+        env: Env,
+        admin: Address,
+        referrer: Address,
+    ) -> Result<i128, Error> {
+        referral::payout_commission(&env, &admin, &referrer)
+    }
+    /// * `initial_supply` - Initial token supply
+    /// * `fee_payment` - Fee amount (must be >= base_fee)
+    /// Toggle clawback capability for a token (creator only)
+    ///
+    /// Allows the token creator to enable or disable clawback functionality.
+    /// When enabled, the creator can burn tokens from any holder's address.
+    /// This setting can be toggled multiple times by the creator.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `token_address` - The token's contract address
+    /// * `admin` - Token creator address (must authorize and match creator)
+    /// * `enabled` - True to enable clawback, false to disable
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success
+    ///
+    /// # Errors
+    /// * `Error::ContractPaused` - Contract is currently paused
+    /// * `Error::TokenNotFound` - Token address not found
+    /// * `Error::Unauthorized` - Caller is not the token creator
+    ///
+    /// # Examples
+    /// ```
+    /// // Enable clawback for emergency situations
+    /// factory.set_clawback(&env, token_addr, creator, true)?;
+    ///
+    /// // Disable clawback for decentralization
+    /// factory.set_clawback(&env, token_addr, creator, false)?;
+    /// ```
+    pub fn set_clawback(
+        env: Env,
+        token_address: Address,
+        admin: Address,
+        enabled: bool,
+    ) -> Result<(), Error> {
+        // Early return if contract is paused (Phase 1 optimization)
+        if storage::is_paused(&env) {
+            return Err(Error::ContractPaused);
+        }
+
+        // Require admin authorization
+        admin.require_auth();
+        storage::ensure_not_killed(&env)?;
+
+        // Get token info
+        let mut token_info =
+            storage::get_token_info_by_address(&env, &token_address).ok_or(Error::TokenNotFound)?;
+
+        // Verify admin is the token creator
+        if token_info.creator != admin {
+            return Err(Error::Unauthorized);
+        }
+
+        // Update clawback setting
+        token_info.clawback_enabled = enabled;
+        storage::set_token_info_by_address(&env, &token_address, &token_info);
+
+        // Emit optimized event
+        events::emit_clawback_toggled(&env, &token_address, &admin, enabled);
+        Ok(())
+    }
+
+    // ═══════════════════════════════════════════════════════════════════════
+    // Transfer Restriction Functions (Whitelist / Blacklist via Freeze)
+    // ═══════════════════════════════════════════════════════════════════════
+
+    /// Enable or disable freeze (transfer restriction) capability for a token.
+    ///
+    /// When enabled, the token creator can freeze individual addresses, preventing
+    /// them from participating in transfers, burns, or mints (blacklist model).
+    /// When disabled, no new addresses can be frozen, but existing frozen state persists.
+    ///
+    /// # Arguments
+    /// * `token_address` - The token contract address
+    /// * `admin` - Token creator address (must authorize)
+    /// * `enabled` - `true` to enable freeze capability, `false` to disable
+    ///
+    /// # Errors
+    /// * `ContractPaused` - Contract is paused
+    /// * `TokenNotFound` - Token not found
+    /// * `Unauthorized` - Caller is not the token creator
+    pub fn set_freeze_enabled(
+        env: Env,
+        token_address: Address,
+        admin: Address,
+        enabled: bool,
+    ) -> Result<(), Error> {
+        freeze_functions::set_freeze_enabled(&env, &token_address, &admin, enabled)
+    }
+
+    /// Freeze (blacklist) an address for a specific token.
+    ///
+    /// A frozen address cannot send or receive tokens, burn, or mint.
+    /// Requires freeze to be enabled for the token.
+    ///
+    /// # Arguments
+    /// * `token_address` - The token contract address
+    /// * `admin` - Token creator address (must authorize)
+    /// * `address_to_freeze` - The address to blacklist
+    ///
+    /// # Errors
+    /// * `ContractPaused` - Contract is paused
+    /// * `TokenNotFound` - Token not found
+    /// * `Unauthorized` - Caller is not the token creator, or freeze not enabled
+    /// * `InvalidParameters` - Address is already frozen
+    pub fn freeze_address(
+        env: Env,
+        token_address: Address,
+        admin: Address,
+        address_to_freeze: Address,
+    ) -> Result<(), Error> {
+        freeze_functions::freeze_address(&env, &token_address, &admin, &address_to_freeze)
+    }
+
+    /// Unfreeze (remove from blacklist) an address for a specific token.
+    ///
+    /// Restores normal transfer capability for a previously frozen address.
+    ///
+    /// # Arguments
+    /// * `token_address` - The token contract address
+    /// * `admin` - Token creator address (must authorize)
+    /// * `address_to_unfreeze` - The address to remove from blacklist
+    ///
+    /// # Errors
+    /// * `ContractPaused` - Contract is paused
+    /// * `TokenNotFound` - Token not found
+    /// * `Unauthorized` - Caller is not the token creator, or freeze not enabled
+    /// * `InvalidParameters` - Address is not frozen
+    pub fn unfreeze_address(
+        env: Env,
+        token_address: Address,
+        admin: Address,
+        address_to_unfreeze: Address,
+    ) -> Result<(), Error> {
+        freeze_functions::unfreeze_address(&env, &token_address, &admin, &address_to_unfreeze)
+    }
+
+    /// Check whether an address is frozen (blacklisted) for a specific token.
+    ///
+    /// # Arguments
+    /// * `token_address` - The token contract address
+    /// * `address` - The address to check
+    ///
+    /// # Returns
+    /// `true` if the address is frozen, `false` otherwise
+    pub fn is_address_frozen(env: Env, token_address: Address, address: Address) -> bool {
+        freeze_functions::is_frozen(&env, &token_address, &address)
+    }
+
+    /// Burn tokens from caller's own balance
+    ///
+    /// Allows a token holder to permanently destroy tokens from their
+    /// own balance, reducing the total supply.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `caller` - Address burning tokens (must authorize)
+    /// * `token_index` - Index of the token to burn
+    /// * `amount` - Amount to burn (must be > 0 and <= balance)
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success
+    ///
+    /// # Errors
+    /// * `Error::TokenNotFound` - Token index is invalid
+    /// * `Error::InvalidParameters` - Amount is zero or negative
+    /// * `Error::InsufficientBalance` - Caller balance is less than amount
+    /// * `Error::ArithmeticError` - Numeric overflow/underflow
+    ///
+    /// # Examples
+    /// ```
+    /// // Burn 1000 tokens
+    /// factory.burn(&env, caller, 0, 1_000_0000000)?;
+    /// ```
+    pub fn burn(env: Env, caller: Address, token_index: u32, amount: i128) -> Result<(), Error> {
+        burn::burn(&env, caller, token_index, amount)
+    }
+
+    /// Burn tokens from caller's own balance, tagged with a correlation id
+    ///
+    /// Behaves like `burn`, and additionally indexes the resulting burn-log
+    /// record under `correlation_id` for `get_burn_by_correlation`.
+    ///
+    /// # Arguments
+    /// * `caller` - Address burning tokens (must authorize)
+    /// * `token_index` - Index of the token to burn
+    /// * `amount` - Amount to burn (must be > 0 and <= balance)
+    /// * `correlation_id` - Integrator-chosen id, unique across all burns
+    ///
+    /// # Errors
+    /// * `Error::InvalidParameters` - Amount is not positive, or `correlation_id` was already used
+    /// * Otherwise the same errors as `burn`
+    pub fn burn_with_correlation(
+        env: Env,
+        caller: Address,
+        token_index: u32,
+        amount: i128,
+        correlation_id: u64,
+    ) -> Result<(), Error> {
+        burn::burn_with_correlation(&env, caller, token_index, amount, correlation_id)
+    }
+
+    /// Batch burn tokens from multiple holders (admin only)
+    ///
+    /// Allows the admin to burn tokens from multiple addresses in a single
+    /// transaction. All burns must succeed or the entire batch fails.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `admin` - Admin address (must authorize and match stored admin)
+    /// * `token_index` - Index of the token to burn
+    /// * `burns` - Vector of (holder_address, amount) tuples (max 100 entries)
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the admin
+    /// * `Error::BatchTooLarge` - More than 100 burn entries
+    /// * `Error::InvalidParameters` - Empty batch or invalid amounts
+    /// * `Error::TokenNotFound` - Token index is invalid
+    /// * `Error::InsufficientBalance` - Any holder has insufficient balance
+    /// * `Error::ArithmeticError` - Numeric overflow/underflow
+    ///
+    /// # Examples
+    /// ```
+    /// let burns = vec![
+    ///     &env,
+    ///     (holder1, 1_000_0000000),
+    ///     (holder2, 2_000_0000000),
+    /// ];
+    /// factory.batch_burn(&env, admin, 0, burns)?;
+    /// ```
+    pub fn batch_burn(
+        env: Env,
+        admin: Address,
+        token_index: u32,
+        burns: soroban_sdk::Vec<(Address, i128)>,
+    ) -> Result<(), Error> {
+        burn::batch_burn(&env, admin, token_index, burns)
+    }
+
+    /// Batch burn with per-entry outcomes instead of all-or-nothing (admin only)
+    ///
+    /// Entries that fail are skipped while the rest are applied, so a
+    /// partially applied batch is a normal outcome. Use `batch_burn` when
+    /// the batch must apply as a unit.
+    ///
+    /// # Arguments
+    /// * `admin` - Admin address (must authorize and match stored admin)
+    /// * `token_index` - Index of the token to burn
+    /// * `burns` - Vector of (holder_address, amount) tuples (max 100 entries)
+    ///
+    /// # Returns
+    /// Returns `(input_index, outcome)` for every entry, where `outcome` is
+    /// `BatchBurnOutcome::Applied` or `BatchBurnOutcome::Rejected` with the
+    /// code of the error that rejected it (e.g. `Error::InsufficientBalance`)
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the admin
+    /// * `Error::BatchTooLarge` - More than 100 burn entries
+    /// * `Error::InvalidParameters` - Empty batch
+    /// * `Error::TokenNotFound` - Token index is invalid
+    /// * `Error::TokenPaused` / `Error::TokenFrozen` - Token cannot be burned
+    pub fn batch_burn_lenient(
+        env: Env,
+        admin: Address,
+        token_index: u32,
+        burns: soroban_sdk::Vec<(Address, i128)>,
+    ) -> Result<soroban_sdk::Vec<(u32, types::BatchBurnOutcome)>, Error> {
+        burn::batch_burn_lenient(&env, admin, token_index, burns)
+    }
+
+    /// Get the total number of burn operations for a token
+    ///
+    /// Returns the count of all burn operations (both user and admin burns)
+    /// performed on the specified token.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `token_index` - Index of the token
+    ///
+    /// # Returns
+    /// Returns the burn count as a u32
+    ///
+    /// # Examples
+    /// ```
+    /// let burn_count = factory.get_burn_count(&env, 0);
+    /// assert!(burn_count > 0);
+    /// ```
+    pub fn get_burn_count(env: Env, token_index: u32) -> u32 {
+        burn::get_burn_count(&env, token_index)
+    }
+
+    /// Get a token's burn figures in one read
+    ///
+    /// # Arguments
+    /// * `token_index` - Index of the token
+    ///
+    /// # Returns
+    /// Returns a `BurnSummary` with total burned, burn count, share burned
+    /// in basis points of issued supply, largest burn and last burn time
+    ///
+    /// # Errors
+    /// * `Error::TokenNotFound` - Token index is invalid
+    pub fn get_burn_summary(env: Env, token_index: u32) -> Result<types::BurnSummary, Error> {
+        burn::get_burn_summary(&env, token_index)
+    }
+
+    /// Normalize burn fields on legacy token entries (admin only)
+    ///
+    /// Migration helper for entries stored before `initial_supply`,
+    /// `total_burned` and `burn_count` were added to `TokenInfo`. Run in
+    /// pages over the registry; entries that are already consistent are
+    /// left untouched.
+    ///
+    /// # Arguments
+    /// * `start` - First token index to examine
+    /// * `limit` - Maximum tokens to examine (capped at 100)
+    /// * `caller` - Admin address (must authorize)
+    ///
+    /// # Returns
+    /// Returns the number of entries rewritten
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the admin
+    /// * `Error::ArithmeticError` - Reconstructing `initial_supply` overflows
+    pub fn reinitialize_burn_fields(
+        env: Env,
+        start: u32,
+        limit: u32,
+        caller: Address,
+    ) -> Result<u32, Error> {
+        burn::reinitialize_burn_fields(&env, start, limit, caller)
+    }
+
+    /// Get how much more of a token can be burned
+    ///
+    /// # Arguments
+    /// * `token_index` - Index of the token
+    ///
+    /// # Returns
+    /// Returns the lesser of the current supply and the allowance left under
+    /// the token's `max_burn_bps` cap (the supply alone when uncapped)
+    ///
+    /// # Errors
+    /// * `Error::TokenNotFound` - Token index is invalid
+    pub fn get_remaining_burnable(env: Env, token_index: u32) -> Result<i128, Error> {
+        burn::get_remaining_burnable(&env, token_index)
+    }
+
+    /// Get the amount of a token burned during one UTC day
+    ///
+    /// Maintained on every burn, so charts need no log scan.
+    ///
+    /// # Arguments
+    /// * `token_index` - Index of the token
+    /// * `day` - Days since the Unix epoch (`timestamp / 86_400`)
+    ///
+    /// # Returns
+    /// Returns the total burned that day, 0 for days without burns
+    pub fn get_daily_burn(env: Env, token_index: u32, day: u32) -> i128 {
+        storage::get_daily_burn(&env, token_index, day)
+    }
+
+    /// Get the number of burns across all tokens during one UTC day
+    ///
+    /// Each holder debited counts once, so a batch burn adds one per entry.
+    ///
+    /// # Arguments
+    /// * `day` - Days since the Unix epoch (`timestamp / 86_400`)
+    ///
+    /// # Returns
+    /// Returns the burn count for that day, 0 for days without burns
+    pub fn get_daily_burn_count(env: Env, day: u32) -> u32 {
+        storage::get_daily_burn_count(&env, day)
+    }
+
+    /// Get the number of burns across all tokens since deployment
+    pub fn get_global_burn_count(env: Env) -> u32 {
+        storage::get_global_burn_count(&env)
+    }
+
+    /// Get the amount of a token burned over a trailing window
+    ///
+    /// Built on the daily aggregates, so the window is rounded out to whole
+    /// UTC days and capped at 90 days.
+    ///
+    /// # Arguments
+    /// * `token_index` - Index of the token
+    /// * `window_secs` - Length of the trailing window in seconds
+    ///
+    /// # Returns
+    /// Returns the total burned in the window (0 for an empty window);
+    /// divide by `window_secs` for a rate
+    ///
+    /// # Errors
+    /// * `Error::TokenNotFound` - Token index is invalid
+    pub fn get_burn_velocity(env: Env, token_index: u32, window_secs: u64) -> Result<i128, Error> {
+        burn::get_burn_velocity(&env, token_index, window_secs)
+    }
+
+    /// Get a token's logged burns for one UTC day
+    ///
+    /// Jumps straight to the day's first record through a per-day index, so
+    /// feeds need not scan the whole log. Each call reads a bounded window of
+    /// the log, so a page may hold fewer than `limit` records even when the
+    /// day has more; keep following `next`.
+    ///
+    /// # Arguments
+    /// * `token_index` - Index of the token
+    /// * `day` - Days since the Unix epoch (`timestamp / 86_400`)
+    /// * `start` - Global log index to resume from (`0`, then the previous `next`)
+    /// * `limit` - Maximum records to return (capped at 50)
+    ///
+    /// # Returns
+    /// Returns a `BurnRecordPage` of the day's burn records oldest first;
+    /// `next` is `None` once the day has been fully read
+    pub fn get_burns_for_day(
+        env: Env,
+        token_index: u32,
+        day: u32,
+        start: u32,
+        limit: u32,
+    ) -> types::BurnRecordPage {
+        burn::get_burns_for_day(&env, token_index, day, start, limit)
+    }
+
+    /// Audit a token's supply bookkeeping
+    ///
+    /// Checks `initial_supply + total_minted - total_burned == total_supply`
+    /// and that the burn tracker agrees with the token's `total_burned`.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `token_index` - Index of the token
+    ///
+    /// # Returns
+    /// Returns `true` if consistent, `false` on any mismatch or unknown token
+    pub fn verify_supply_invariant(env: Env, token_index: u32) -> bool {
+        mint::verify_supply_invariant(&env, token_index)
+    }
+
+    /// Cap lifetime burns at a share of initial supply (creator only)
+    ///
+    /// Afterwards any burn that would take `total_burned` past
+    /// `initial_supply * max_burn_bps / 10_000` fails. The cap can be
+    /// tightened later but never raised or removed, so holders can rely on
+    /// it.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `creator` - Token creator address (must authorize)
+    /// * `token_index` - Index of the token
+    /// * `max_burn_bps` - Cap in basis points of initial supply (0-10000)
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success
+    ///
+    /// # Errors
+    /// * `Error::TokenNotFound` - Token index does not exist
+    /// * `Error::Unauthorized` - Caller is not the token creator
+    /// * `Error::InvalidParameters` - Above 10000 or looser than the current cap
+    pub fn set_max_burn_bps(
+        env: Env,
+        creator: Address,
+        token_index: u32,
+        max_burn_bps: u32,
+    ) -> Result<(), Error> {
+        let mut info = storage::require_creator(&env, token_index, &creator)?;
+        storage::ensure_not_killed(&env)?;
+
+        if max_burn_bps > 10_000 || info.max_burn_bps.is_some_and(|bps| max_burn_bps > bps) {
+            return Err(Error::InvalidParameters);
+        }
+
+        info.max_burn_bps = Some(max_burn_bps);
+        storage::set_token_info(&env, token_index, &info);
+        storage::set_token_info_by_address(&env, &info.address, &info);
+        Ok(())
+    }
+
+    /// Mark or unmark a token as official (admin only)
+    ///
+    /// Tokens created by the admin are marked official automatically; this
+    /// covers tokens deployed on the protocol's behalf from another account.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `admin` - Admin address (must authorize)
+    /// * `token_index` - Index of the token
+    /// * `official` - New marker value
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the admin
+    /// * `Error::TokenNotFound` - Token index does not exist
+    pub fn set_official(
+        env: Env,
+        admin: Address,
+        token_index: u32,
+        official: bool,
+    ) -> Result<(), Error> {
+        storage::require_admin(&env, &admin)?;
+        storage::ensure_not_killed(&env)?;
+
+        let mut info = storage::get_token_info(&env, token_index).ok_or(Error::TokenNotFound)?;
+        info.official = official;
+        storage::set_token_info(&env, token_index, &info);
+        storage::set_token_info_by_address(&env, &info.address, &info);
+        Ok(())
+    }
 
-        // Verify admin is the token creator
-        if token_info.creator != admin {
-            return Err(Error::Unauthorized);
-        }
+    /// Choose what burns of a token do (creator only)
+    ///
+    /// `ReduceSupply` (the default) takes burned amounts out of
+    /// `total_supply`. `SendToDead(address)` moves them to `address`
+    /// instead, leaving supply unchanged. Every burn path honours the mode
+    /// and still logs a `BurnRecord` and updates the burn counters.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `creator` - Token creator address (must authorize)
+    /// * `token_index` - Index of the token
+    /// * `mode` - New burn mode
+    ///
+    /// # Errors
+    /// * `Error::TokenNotFound` - Token index does not exist
+    /// * `Error::Unauthorized` - Caller is not the token creator
+    /// * `Error::InvalidParameters` - Dead address is the factory itself
+    pub fn set_burn_mode(
+        env: Env,
+        creator: Address,
+        token_index: u32,
+        mode: BurnMode,
+    ) -> Result<(), Error> {
+        storage::require_creator(&env, token_index, &creator)?;
+        storage::ensure_not_killed(&env)?;
+        storage::ensure_not_retired(&env, token_index)?;
 
-        // Update clawback setting
-        token_info.clawback_enabled = enabled;
-        storage::set_token_info_by_address(&env, &token_address, &token_info);
+        if let BurnMode::SendToDead(ref dead) = mode {
+            if *dead == env.current_contract_address() {
+                return Err(Error::InvalidParameters);
+            }
+        }
 
-        // Emit optimized event
-        events::emit_clawback_toggled(&env, &token_address, &admin, enabled);
+        storage::set_burn_mode(&env, token_index, &mode);
         Ok(())
     }
 
-    // ═══════════════════════════════════════════════════════════════════════
-    // Transfer Restriction Functions (Whitelist / Blacklist via Freeze)
-    // ═══════════════════════════════════════════════════════════════════════
-
-    /// Enable or disable freeze (transfer restriction) capability for a token.
+    /// Restrict burning of a token to whitelisted accounts (creator only)
     ///
-    /// When enabled, the token creator can freeze individual addresses, preventing
-    /// them from participating in transfers, burns, or mints (blacklist model).
-    /// When disabled, no new addresses can be frozen, but existing frozen state persists.
+    /// While restricted, every burn path rejects executors that are neither
+    /// on the token's burn whitelist nor the admin.
     ///
     /// # Arguments
-    /// * `token_address` - The token contract address
-    /// * `admin` - Token creator address (must authorize)
-    /// * `enabled` - `true` to enable freeze capability, `false` to disable
+    /// * `env` - The contract environment
+    /// * `creator` - Token creator address (must authorize)
+    /// * `token_index` - Index of the token
+    /// * `restricted` - Whether burning is restricted
     ///
     /// # Errors
-    /// * `ContractPaused` - Contract is paused
-    /// * `TokenNotFound` - Token not found
-    /// * `Unauthorized` - Caller is not the token creator
-    pub fn set_freeze_enabled(
+    /// * `Error::TokenNotFound` - Token index does not exist
+    /// * `Error::Unauthorized` - Caller is not the token creator
+    pub fn set_burn_restricted(
         env: Env,
-        token_address: Address,
-        admin: Address,
-        enabled: bool,
+        creator: Address,
+        token_index: u32,
+        restricted: bool,
     ) -> Result<(), Error> {
-        freeze_functions::set_freeze_enabled(&env, &token_address, &admin, enabled)
+        storage::require_creator(&env, token_index, &creator)?;
+        storage::ensure_not_killed(&env)?;
+        storage::set_burn_restricted(&env, token_index, restricted);
+        Ok(())
     }
 
-    /// Freeze (blacklist) an address for a specific token.
+    /// Add or remove an account from a token's burn whitelist (creator only)
     ///
-    /// A frozen address cannot send or receive tokens, burn, or mint.
-    /// Requires freeze to be enabled for the token.
+    /// The whitelist only takes effect while the token is burn-restricted.
     ///
     /// # Arguments
-    /// * `token_address` - The token contract address
-    /// * `admin` - Token creator address (must authorize)
-    /// * `address_to_freeze` - The address to blacklist
+    /// * `env` - The contract environment
+    /// * `creator` - Token creator address (must authorize)
+    /// * `token_index` - Index of the token
+    /// * `account` - Account to update
+    /// * `allowed` - Whether `account` may burn
     ///
     /// # Errors
-    /// * `ContractPaused` - Contract is paused
-    /// * `TokenNotFound` - Token not found
-    /// * `Unauthorized` - Caller is not the token creator, or freeze not enabled
-    /// * `InvalidParameters` - Address is already frozen
-    pub fn freeze_address(
+    /// * `Error::TokenNotFound` - Token index does not exist
+    /// * `Error::Unauthorized` - Caller is not the token creator
+    pub fn set_burn_whitelisted(
         env: Env,
-        token_address: Address,
-        admin: Address,
-        address_to_freeze: Address,
+        creator: Address,
+        token_index: u32,
+        account: Address,
+        allowed: bool,
     ) -> Result<(), Error> {
-        freeze_functions::freeze_address(&env, &token_address, &admin, &address_to_freeze)
+        storage::require_creator(&env, token_index, &creator)?;
+        storage::ensure_not_killed(&env)?;
+        storage::set_burn_whitelisted(&env, token_index, &account, allowed);
+        Ok(())
     }
 
-    /// Unfreeze (remove from blacklist) an address for a specific token.
+    /// Whether burning of a token is restricted to its whitelist
+    pub fn is_burn_restricted(env: Env, token_index: u32) -> bool {
+        storage::is_burn_restricted(&env, token_index)
+    }
+
+    /// Whether `account` is on a token's burn whitelist
+    pub fn is_burn_whitelisted(env: Env, token_index: u32, account: Address) -> bool {
+        storage::is_burn_whitelisted(&env, token_index, &account)
+    }
+
+    /// Require tokens to age before holders can burn them (admin only)
     ///
-    /// Restores normal transfer capability for a previously frozen address.
+    /// Holder burns (`burn`, `burn_with_correlation`, `burn_with_sig`) of a
+    /// token fail with `Error::TokenTooNew` until `created_at + seconds`.
+    /// This stops instant create-and-burn spam on burn leaderboards. Admin
+    /// burns are not delayed. 0 removes the delay.
     ///
     /// # Arguments
-    /// * `token_address` - The token contract address
-    /// * `admin` - Token creator address (must authorize)
-    /// * `address_to_unfreeze` - The address to remove from blacklist
+    /// * `admin` - Admin address (must authorize)
+    /// * `seconds` - Minimum token age before holder burns
     ///
     /// # Errors
-    /// * `ContractPaused` - Contract is paused
-    /// * `TokenNotFound` - Token not found
-    /// * `Unauthorized` - Caller is not the token creator, or freeze not enabled
-    /// * `InvalidParameters` - Address is not frozen
-    pub fn unfreeze_address(
+    /// * `Error::Unauthorized` - Caller is not the admin
+    pub fn set_min_age_before_burn(env: Env, admin: Address, seconds: u64) -> Result<(), Error> {
+        storage::require_admin(&env, &admin)?;
+        storage::ensure_not_killed(&env)?;
+
+        storage::set_min_age_before_burn(&env, seconds);
+        Ok(())
+    }
+
+    /// Get the minimum token age before holder burns (0 = no delay)
+    pub fn get_min_age_before_burn(env: Env) -> u64 {
+        storage::get_min_age_before_burn(&env)
+    }
+
+    /// Burn mode of a token (`ReduceSupply` unless the creator changed it)
+    pub fn get_burn_mode(env: Env, token_index: u32) -> BurnMode {
+        storage::get_burn_mode(&env, token_index)
+    }
+
+    /// Admin-initiated burn from any holder's balance
+    ///
+    /// Allows the admin to burn tokens from any holder's address.
+    /// This is a privileged operation that requires admin authentication.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `admin` - Admin address (must authorize and match stored admin)
+    /// * `token_index` - Index of the token to burn
+    /// * `holder` - Address holding the tokens to burn
+    /// * `amount` - Amount to burn (must be > 0 and <= holder's balance)
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the admin
+    /// * `Error::TokenNotFound` - Token index is invalid
+    /// * `Error::InvalidParameters` - Amount is zero or negative
+    /// * `Error::InsufficientBalance` - Holder balance is less than amount
+    /// * `Error::ArithmeticError` - Numeric overflow/underflow
+    ///
+    /// # Examples
+    /// ```
+    /// // Admin burns 1000 tokens from a holder
+    /// factory.admin_burn(&env, admin, 0, holder, 1_000_0000000)?;
+    /// ```
+    pub fn admin_burn(
         env: Env,
-        token_address: Address,
         admin: Address,
-        address_to_unfreeze: Address,
+        token_index: u32,
+        holder: Address,
+        amount: i128,
     ) -> Result<(), Error> {
-        freeze_functions::unfreeze_address(&env, &token_address, &admin, &address_to_unfreeze)
+        burn::admin_burn(&env, admin, token_index, holder, amount)
     }
 
-    /// Check whether an address is frozen (blacklisted) for a specific token.
+    /// Check whether a burn would succeed without performing it
+    ///
+    /// Runs the validation of `burn` (when `burned_by == from`) or
+    /// `admin_burn` (otherwise) without requiring authorization or writing
+    /// state. Intended to be called through simulation before submitting.
     ///
     /// # Arguments
-    /// * `token_address` - The token contract address
-    /// * `address` - The address to check
+    /// * `env` - The contract environment
+    /// * `token_index` - Index of the token to burn
+    /// * `from` - Holder whose balance would be debited
+    /// * `amount` - Amount to burn
+    /// * `burned_by` - Executor of the burn: `from` itself or the admin
     ///
     /// # Returns
-    /// `true` if the address is frozen, `false` otherwise
-    pub fn is_address_frozen(env: Env, token_address: Address, address: Address) -> bool {
-        freeze_functions::is_frozen(&env, &token_address, &address)
+    /// Returns `Ok(())` if the burn would currently succeed
+    ///
+    /// # Errors
+    /// The same error the corresponding burn call would return
+    pub fn can_burn(
+        env: Env,
+        token_index: u32,
+        from: Address,
+        amount: i128,
+        burned_by: Address,
+    ) -> Result<(), Error> {
+        burn::can_burn(&env, token_index, &from, amount, &burned_by)
     }
 
-    /// Burn tokens from caller's own balance
+    /// Schedule a time-locked burn from a holder (admin only)
     ///
-    /// Allows a token holder to permanently destroy tokens from their
-    /// own balance, reducing the total supply.
+    /// The burn cannot execute before `unlock_time`; after that anyone may
+    /// trigger it with `execute_burn_schedule`.
     ///
     /// # Arguments
     /// * `env` - The contract environment
-    /// * `caller` - Address burning tokens (must authorize)
+    /// * `admin` - Admin address (must authorize)
     /// * `token_index` - Index of the token to burn
-    /// * `amount` - Amount to burn (must be > 0 and <= balance)
+    /// * `from` - Holder whose balance will be burned
+    /// * `amount` - Amount to burn (must be > 0)
+    /// * `unlock_time` - Earliest execution timestamp (must be in the future)
     ///
     /// # Returns
-    /// Returns `Ok(())` on success
+    /// Returns the new schedule id
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the admin
+    /// * `Error::ContractPaused` - Contract is paused
+    /// * `Error::InvalidParameters` - Non-positive amount or unlock time not in the future
+    /// * `Error::TokenNotFound` - Token index does not exist
+    pub fn schedule_burn(
+        env: Env,
+        admin: Address,
+        token_index: u32,
+        from: Address,
+        amount: i128,
+        unlock_time: u64,
+    ) -> Result<u64, Error> {
+        burn::schedule_burn(&env, admin, token_index, from, amount, unlock_time)
+    }
+
+    /// Execute a scheduled burn whose unlock time has passed
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `executor` - Address triggering execution (must authorize)
+    /// * `id` - Schedule id
+    ///
+    /// # Errors
+    /// * `Error::BurnScheduleNotFound` - No schedule with this id
+    /// * `Error::BurnScheduleAlreadyExecuted` - Schedule already executed
+    /// * `Error::BurnCancelled` - Schedule was cancelled
+    /// * `Error::BurnScheduleLocked` - Unlock time has not been reached
+    /// * Otherwise the same errors as `admin_burn`
+    pub fn execute_burn_schedule(env: Env, executor: Address, id: u64) -> Result<(), Error> {
+        burn::execute_burn_schedule(&env, executor, id)
+    }
+
+    /// Cancel a pending scheduled burn (schedule creator or admin)
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `index` - Schedule id
+    /// * `caller` - Schedule creator or admin (must authorize)
+    ///
+    /// # Errors
+    /// * `Error::BurnScheduleNotFound` - No schedule with this id
+    /// * `Error::BurnScheduleAlreadyExecuted` - Schedule already executed
+    /// * `Error::BurnCancelled` - Schedule already cancelled
+    /// * `Error::Unauthorized` - Caller is neither the creator nor the admin
+    pub fn cancel_scheduled_burn(env: Env, index: u64, caller: Address) -> Result<(), Error> {
+        burn::cancel_scheduled_burn(&env, index, caller)
+    }
+
+    /// Get a scheduled burn by id
+    pub fn get_burn_schedule(env: Env, id: u64) -> Option<types::BurnSchedule> {
+        storage::get_burn_schedule(&env, id)
+    }
+
+    /// Number of burn schedules ever created
+    pub fn get_burn_schedule_count(env: Env) -> u64 {
+        storage::next_burn_schedule_id(&env)
+    }
+
+    /// Number of burn schedules created for a token
+    pub fn get_burn_schedule_count_by_token(env: Env, token_index: u32) -> u32 {
+        storage::get_burn_schedule_count_by_token(&env, token_index)
+    }
+
+    /// Schedule id at `local_index` in a token's schedule list
+    pub fn get_burn_schedule_id_by_token(
+        env: Env,
+        token_index: u32,
+        local_index: u32,
+    ) -> Option<u64> {
+        storage::get_burn_schedule_id_by_token(&env, token_index, local_index)
+    }
+
+    /// Atomically burn from one holder and mint to another (admin only)
+    ///
+    /// Supports consolidation-style tokenomics where supply moves between
+    /// accounts with an optional net change. Both legs succeed or neither
+    /// does: the mint is checked against the token's `max_supply` after the
+    /// burn, and a failure rolls back the burn.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `admin` - Admin address (must authorize and match stored admin)
+    /// * `token_index` - Index of the token to rebase
+    /// * `from` - Address whose tokens are burned
+    /// * `burn_amount` - Amount to burn (must be > 0 and <= `from` balance)
+    /// * `to` - Address receiving the minted tokens
+    /// * `mint_amount` - Amount to mint (must be > 0)
+    ///
+    /// # Returns
+    /// Returns the token's total supply after both legs
     ///
     /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the admin
     /// * `Error::TokenNotFound` - Token index is invalid
-    /// * `Error::InvalidParameters` - Amount is zero or negative
-    /// * `Error::InsufficientBalance` - Caller balance is less than amount
-    /// * `Error::ArithmeticError` - Numeric overflow/underflow
+    /// * `Error::InvalidParameters` - Either amount is zero or negative
+    /// * `Error::TokenPaused` / `Error::TokenFrozen` - Token is paused or frozen
+    /// * `Error::InsufficientBalance` - `from` balance is less than `burn_amount`
+    /// * `Error::MaxSupplyExceeded` - Mint leg would exceed the supply cap
     ///
     /// # Examples
     /// ```
-    /// // Burn 1000 tokens
-    /// factory.burn(&env, caller, 0, 1_000_0000000)?;
+    /// // Consolidate 500 tokens from `old` into `new`
+    /// let supply = factory.rebase(&env, admin, 0, old, 500, new, 500)?;
     /// ```
-    pub fn burn(env: Env, caller: Address, token_index: u32, amount: i128) -> Result<(), Error> {
-        burn::burn(&env, caller, token_index, amount)
+    pub fn rebase(
+        env: Env,
+        admin: Address,
+        token_index: u32,
+        from: Address,
+        burn_amount: i128,
+        to: Address,
+        mint_amount: i128,
+    ) -> Result<i128, Error> {
+        burn::rebase(&env, admin, token_index, from, burn_amount, to, mint_amount)
+    }
+
+    /// Burn on behalf of an account using an off-chain signature
+    ///
+    /// Enables gasless burns: a relayer submits the transaction while `from`
+    /// authorizes it by signing `(factory, token_index, from, amount, nonce)`
+    /// with its ed25519 key. The account nonce is consumed, so each signature
+    /// is valid exactly once. The burn is recorded with `burned_by = from`.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `token_index` - Index of the token to burn
+    /// * `from` - Account whose tokens are burned (ed25519 account address)
+    /// * `amount` - Amount to burn (must be > 0)
+    /// * `nonce` - Must equal the account's current nonce
+    /// * `signature` - ed25519 signature over the burn payload
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success
+    ///
+    /// # Errors
+    /// * `Error::InvalidSignature` - `from` is not an ed25519 account
+    /// * `Error::BadNonce` - `nonce` does not match the stored nonce
+    /// * `Error::InvalidParameters` - Amount is zero or negative
+    /// * `Error::TokenNotFound` - Token index is invalid
+    /// * `Error::InsufficientBalance` - Balance is less than amount
+    ///
+    /// A signature that does not verify traps in the host instead of returning
+    /// an `Error`: plain calls panic and `try_` calls yield the host error
+    /// converted to `Error::InvalidParameters`. The nonce is not consumed,
+    /// since the whole invocation is rolled back.
+    pub fn burn_with_sig(
+        env: Env,
+        token_index: u32,
+        from: Address,
+        amount: i128,
+        nonce: u64,
+        signature: BytesN<64>,
+    ) -> Result<(), Error> {
+        burn::burn_with_sig(&env, token_index, from, amount, nonce, signature)
+    }
+
+    /// Get the current signed-operation nonce for an account
+    ///
+    /// The nonce starts at 0 and increments each time a signed operation
+    /// (such as `burn_with_sig`) succeeds for the account. Clients sign
+    /// their next operation with this value, and may also use it to drive
+    /// optimistic UI updates.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `account` - Account to query
+    ///
+    /// # Returns
+    /// Returns the next nonce the account must use
+    pub fn get_nonce(env: Env, account: Address) -> u64 {
+        storage::get_nonce(&env, &account)
+    }
+
+    /// Get the number of entries in the global burn log
+    pub fn get_burn_record_count(env: Env) -> u32 {
+        storage::get_burn_record_count(&env)
+    }
+
+    /// Get a burn log entry by its global index
+    pub fn get_burn_record(env: Env, index: u32) -> Option<types::BurnRecord> {
+        storage::get_burn_record(&env, index)
+    }
+
+    /// Get a burn log entry's amount scaled to whole tokens
+    ///
+    /// Divides the recorded base-unit amount by `10^decimals` of the burned
+    /// token, rounding down.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `index` - Global burn log index
+    ///
+    /// # Returns
+    /// Returns the display amount, or `None` if the record does not exist
+    pub fn get_burn_display_amount(env: Env, index: u32) -> Option<i128> {
+        burn::get_burn_display_amount(&env, index)
     }
 
-    /// Batch burn tokens from multiple holders (admin only)
+    /// Get the burn log entry tagged with `correlation_id`
     ///
-    /// Allows the admin to burn tokens from multiple addresses in a single
-    /// transaction. All burns must succeed or the entire batch fails.
+    /// Returns `None` for unknown ids, and for burns whose record was not
+    /// written because the token hit its burn-record cap.
+    pub fn get_burn_by_correlation(env: Env, correlation_id: u64) -> Option<types::BurnRecord> {
+        burn::get_burn_by_correlation(&env, correlation_id)
+    }
+
+    /// Get the number of burn-log entries written for a token
+    ///
+    /// Stops growing once `MaxBurnRecordsPerToken` is reached, while
+    /// `get_burn_count` keeps counting every burn.
+    pub fn get_token_burn_record_count(env: Env, token_index: u32) -> u32 {
+        storage::get_token_burn_record_count(&env, token_index)
+    }
+
+    /// Get the most recent burn records, newest first
+    ///
+    /// Returns at most `limit` records (capped at 50) for burn feeds. When
+    /// the log holds fewer records, all of them are returned.
     ///
     /// # Arguments
     /// * `env` - The contract environment
-    /// * `admin` - Admin address (must authorize and match stored admin)
-    /// * `token_index` - Index of the token to burn
-    /// * `burns` - Vector of (holder_address, amount) tuples (max 100 entries)
+    /// * `limit` - Maximum number of records to return
     ///
     /// # Returns
-    /// Returns `Ok(())` on success
+    /// Returns a vector of `BurnRecord` in reverse-chronological order
+    pub fn get_burn_records_recent(env: Env, limit: u32) -> Vec<types::BurnRecord> {
+        burn::get_burn_records_recent(&env, limit)
+    }
+
+    /// Get logged burns of at least `min_amount`, oldest first
     ///
-    /// # Errors
-    /// * `Error::Unauthorized` - Caller is not the admin
-    /// * `Error::BatchTooLarge` - More than 100 burn entries
-    /// * `Error::InvalidParameters` - Empty batch or invalid amounts
-    /// * `Error::TokenNotFound` - Token index is invalid
-    /// * `Error::InsufficientBalance` - Any holder has insufficient balance
-    /// * `Error::ArithmeticError` - Numeric overflow/underflow
+    /// Reads a bounded window of the log per call, so a page may hold fewer
+    /// than `limit` matches even when more exist; keep following `next`.
+    /// Returns a `BurnRecordPage` rather than a plain `Vec<BurnRecord>` so
+    /// callers get that resume cursor.
     ///
-    /// # Examples
-    /// ```
-    /// let burns = vec![
-    ///     &env,
-    ///     (holder1, 1_000_0000000),
-    ///     (holder2, 2_000_0000000),
-    /// ];
-    /// factory.batch_burn(&env, admin, 0, burns)?;
-    /// ```
-    pub fn batch_burn(
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `min_amount` - Smallest burn amount to include
+    /// * `start` - Global log index to scan from (`0`, then the previous `next`)
+    /// * `limit` - Maximum records to return (capped at 50)
+    ///
+    /// # Returns
+    /// Returns a `BurnRecordPage` of matches in chronological order; `next`
+    /// is `None` once the whole log has been scanned
+    pub fn get_large_burns(
         env: Env,
-        admin: Address,
-        token_index: u32,
-        burns: soroban_sdk::Vec<(Address, i128)>,
-    ) -> Result<(), Error> {
-        burn::batch_burn(&env, admin, token_index, burns)
+        min_amount: i128,
+        start: u32,
+        limit: u32,
+    ) -> types::BurnRecordPage {
+        burn::get_large_burns(&env, min_amount, start, limit)
     }
 
-    /// Get the total number of burn operations for a token
+    /// Get a token's combined mint and burn history, oldest first
     ///
-    /// Returns the count of all burn operations (both user and admin burns)
-    /// performed on the specified token.
+    /// Mints made after creation and logged burns are merged in the order
+    /// they happened. The initial supply is not an event. Takes the token
+    /// index rather than its address, like the other per-token burn queries.
     ///
     /// # Arguments
     /// * `env` - The contract environment
     /// * `token_index` - Index of the token
+    /// * `start` - Number of the token's events to skip
+    /// * `limit` - Maximum events to return (capped at 45)
     ///
     /// # Returns
-    /// Returns the burn count as a u32
+    /// Returns a vector of `SupplyEvent` in chronological order
+    pub fn get_token_ledger(
+        env: Env,
+        token_index: u32,
+        start: u32,
+        limit: u32,
+    ) -> Vec<types::SupplyEvent> {
+        burn::get_token_ledger(&env, token_index, start, limit)
+    }
+
+    /// Get one page of the global burn log, oldest first
     ///
-    /// # Examples
-    /// ```
-    /// let burn_count = factory.get_burn_count(&env, 0);
-    /// assert!(burn_count > 0);
-    /// ```
-    pub fn get_burn_count(env: Env, token_index: u32) -> u32 {
-        burn::get_burn_count(&env, token_index)
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `start` - Global log index of the first record to return
+    /// * `limit` - Maximum records to return (capped at 50)
+    ///
+    /// # Returns
+    /// Returns a `BurnRecordPage`; `next` is `None` on the last page
+    pub fn get_burn_records_page(env: Env, start: u32, limit: u32) -> types::BurnRecordPage {
+        burn::get_burn_records_page(&env, start, limit)
     }
 
-    /// Admin-initiated burn from any holder's balance
+    /// Get the largest single burn across all tokens
     ///
-    /// Allows the admin to burn tokens from any holder's address.
-    /// This is a privileged operation that requires admin authentication.
+    /// Maintained on every burn, so no log scan is needed. Unaffected by
+    /// `MaxBurnRecordsPerToken`.
+    ///
+    /// # Returns
+    /// Returns the record of the largest burn, or `None` if nothing was burned
+    pub fn get_largest_burn(env: Env) -> Option<types::BurnRecord> {
+        storage::get_largest_burn(&env)
+    }
+
+    /// Get the combined amount burned across several tokens
     ///
     /// # Arguments
     /// * `env` - The contract environment
-    /// * `admin` - Admin address (must authorize and match stored admin)
-    /// * `token_index` - Index of the token to burn
-    /// * `holder` - Address holding the tokens to burn
-    /// * `amount` - Amount to burn (must be > 0 and <= holder's balance)
+    /// * `tokens` - Token addresses to sum over (max 45); addresses without a
+    ///   registered token count as 0
     ///
     /// # Returns
-    /// Returns `Ok(())` on success
+    /// Returns the sum of `total_burned` for the given tokens
     ///
     /// # Errors
-    /// * `Error::Unauthorized` - Caller is not the admin
-    /// * `Error::TokenNotFound` - Token index is invalid
-    /// * `Error::InvalidParameters` - Amount is zero or negative
-    /// * `Error::InsufficientBalance` - Holder balance is less than amount
-    /// * `Error::ArithmeticError` - Numeric overflow/underflow
-    ///
-    /// # Examples
-    /// ```
-    /// // Admin burns 1000 tokens from a holder
-    /// factory.admin_burn(&env, admin, 0, holder, 1_000_0000000)?;
-    /// ```
-    pub fn admin_burn(
-        env: Env,
-        admin: Address,
-        token_index: u32,
-        holder: Address,
-        amount: i128,
-    ) -> Result<(), Error> {
-        burn::admin_burn(&env, admin, token_index, holder, amount)
+    /// * `Error::BatchTooLarge` - More than 45 tokens requested
+    /// * `Error::ArithmeticError` - Sum overflows
+    pub fn get_total_burned_multi(env: Env, tokens: Vec<Address>) -> Result<i128, Error> {
+        burn::get_total_burned_multi(&env, &tokens)
     }
 
     /// Set metadata URI for a token (one-time only)
@@ -1204,6 +3150,7 @@ impl TokenFactory {
     /// * `env` - The contract environment
     /// * `token_index` - Index of the token
     /// * `metadata_uri` - IPFS URI to set (e.g., "ipfs://Qm...")
+    /// * `fee_payment` - Payment covering the token's metadata fee
     ///
     /// # Returns
     /// Returns `Ok(())` on success
@@ -1212,24 +3159,32 @@ impl TokenFactory {
     /// * `Error::TokenNotFound` - Token index does not exist
     /// * `Error::TokenPaused` - Token is currently paused
     /// * `Error::MetadataAlreadySet` - Metadata already set for this token
+    /// * `Error::TokenRetired` - Token has been retired
+    /// * `Error::InsufficientFee` - Payment below the token's metadata fee
     pub fn set_metadata(
         env: Env,
         token_index: u32,
         metadata_uri: String,
+        fee_payment: i128,
     ) -> Result<(), Error> {
         let token_info =
             storage::get_token_info(&env, token_index).ok_or(Error::TokenNotFound)?;
         let creator = token_info.creator.clone();
         creator.require_auth();
+        storage::ensure_not_killed(&env)?;
 
         if storage::is_token_paused(&env, token_index) {
             return Err(Error::TokenPaused);
         }
+        storage::ensure_not_retired(&env, token_index)?;
 
-        if token_info.metadata_uri.is_some() {
+        if token_info.metadata_uri.is_some() || storage::is_metadata_locked(&env, token_index) {
             return Err(Error::MetadataAlreadySet);
         }
 
+        validation::validate_metadata_uri(&env, &metadata_uri)?;
+        token_creation::charge_late_metadata_fee(&env, &creator, token_index, fee_payment)?;
+
         let mut info = token_info;
         info.metadata_uri = Some(metadata_uri.clone());
         info.metadata_version = 1;
@@ -1252,15 +3207,19 @@ impl TokenFactory {
 
     /// Set metadata for a token
     /// 
-    /// Allows the token creator to set metadata URI once
+    /// Allows the token creator to set metadata URI once. The token's
+    /// metadata fee is charged from `fee_payment`, even while the
+    /// creation-time waiver is on.
     pub fn set_token_metadata(
         env: Env,
         admin: Address,
         token_index: u32,
         metadata_uri: String,
+        fee_payment: i128,
     ) -> Result<(), Error> {
         // Require admin authorization
         admin.require_auth();
+        storage::ensure_not_killed(&env)?;
 
         // Get token info
         let mut token_info =
@@ -1277,12 +3236,18 @@ impl TokenFactory {
         if storage::is_token_paused(&env, token_index) {
             return Err(Error::TokenPaused);
         }
+        storage::ensure_not_retired(&env, token_index)?;
 
-        // Enforce immutability: metadata can only be set once
-        if token_info.metadata_uri.is_some() {
+        // Enforce immutability: metadata can only be set once, and not
+        // after the edit window has closed
+        if token_info.metadata_uri.is_some() || storage::is_metadata_locked(&env, token_index) {
             return Err(Error::MetadataAlreadySet);
         }
 
+        // Enforce the configured URI length limit
+        validation::validate_metadata_uri(&env, &metadata_uri)?;
+        token_creation::charge_late_metadata_fee(&env, &admin, token_index, fee_payment)?;
+
         // Set metadata URI and initialize version to 1
         token_info.metadata_uri = Some(metadata_uri.clone());
         token_info.metadata_version = 1;
@@ -1330,6 +3295,9 @@ impl TokenFactory {
     /// * `Error::TokenNotFound` - Token index is invalid
     /// * `Error::Unauthorized` - Caller is not the token creator
     /// * `Error::MetadataNotSet` - Metadata has never been set; call `set_token_metadata` first
+    /// * `Error::TokenRetired` - Token has been retired
+    /// * `Error::InvalidParameters` - A content hash is committed and the URI changed;
+    ///   use `update_metadata_with_hash`
     ///
     /// # Events
     /// Emits `meta_upd` with token address, admin, new URI, and new version number
@@ -1337,7 +3305,7 @@ impl TokenFactory {
     /// # Examples
     /// ```
     /// // First set metadata
-    /// factory.set_token_metadata(&env, creator, 0, String::from_str(&env, "ipfs://QmV1"))?;
+    /// factory.set_token_metadata(&env, creator, 0, String::from_str(&env, "ipfs://QmV1"), fee)?;
     ///
     /// // Later update it
     /// let v = factory.update_metadata(&env, creator, 0, String::from_str(&env, "ipfs://QmV2"))?;
@@ -1348,16 +3316,45 @@ impl TokenFactory {
         admin: Address,
         token_index: u32,
         new_metadata_uri: String,
+    ) -> Result<u32, Error> {
+        Self::update_metadata_inner(&env, admin, token_index, new_metadata_uri, None)
+    }
+
+    /// Update a token's metadata URI together with its content hash
+    ///
+    /// Same rules as `update_metadata`; use this once a hash has been
+    /// committed, since a URI change must come with a different hash.
+    ///
+    /// # Errors
+    /// Those of `update_metadata`, plus:
+    /// * `Error::InvalidParameters` - URI changed but `new_hash` equals the committed hash
+    pub fn update_metadata_with_hash(
+        env: Env,
+        admin: Address,
+        token_index: u32,
+        new_metadata_uri: String,
+        new_hash: BytesN<32>,
+    ) -> Result<u32, Error> {
+        Self::update_metadata_inner(&env, admin, token_index, new_metadata_uri, Some(new_hash))
+    }
+
+    fn update_metadata_inner(
+        env: &Env,
+        admin: Address,
+        token_index: u32,
+        new_metadata_uri: String,
+        new_hash: Option<BytesN<32>>,
     ) -> Result<u32, Error> {
         // Check contract pause state before auth to fail fast
-        if storage::is_paused(&env) {
+        if storage::is_paused(env) {
             return Err(Error::ContractPaused);
         }
 
         admin.require_auth();
+        storage::ensure_not_killed(env)?;
 
         let mut token_info =
-            storage::get_token_info(&env, token_index).ok_or(Error::TokenNotFound)?;
+            storage::get_token_info(env, token_index).ok_or(Error::TokenNotFound)?;
 
         // Only the token creator may update metadata
         if token_info.creator != admin {
@@ -1365,49 +3362,297 @@ impl TokenFactory {
         }
 
         // Reject if the token is individually paused
-        if storage::is_token_paused(&env, token_index) {
+        if storage::is_token_paused(env, token_index) {
             return Err(Error::TokenPaused);
         }
+        storage::ensure_not_retired(env, token_index)?;
 
         // Metadata must have been set at least once
         if token_info.metadata_uri.is_none() {
             return Err(Error::MetadataNotSet);
         }
 
-        // Compute new version before any mutation
-        let new_version = token_info
-            .metadata_version
-            .checked_add(1)
-            .ok_or(Error::ArithmeticError)?;
+        // Frozen, past the token's edit window, or out of updates
+        if storage::is_metadata_locked(env, token_index) {
+            return Err(Error::MetadataAlreadySet);
+        }
+        storage::check_metadata_update_allowed(env, token_index)?;
+
+        // A committed content hash has to move with the URI, otherwise
+        // clients would verify the new content against the old commitment
+        let uri_changed = token_info.metadata_uri.as_ref() != Some(&new_metadata_uri);
+        if let Some(committed) = storage::get_metadata_hash(env, token_index) {
+            if uri_changed && (new_hash.is_none() || new_hash.as_ref() == Some(&committed)) {
+                return Err(Error::InvalidParameters);
+            }
+        }
+
+        // Enforce the configured URI length limit
+        validation::validate_metadata_uri(env, &new_metadata_uri)?;
+
+        // Compute new version before any mutation
+        let new_version = token_info
+            .metadata_version
+            .checked_add(1)
+            .ok_or(Error::ArithmeticError)?;
+
+        // Record history entry for the new version
+        let record = types::MetadataRecord {
+            uri: new_metadata_uri.clone(),
+            updated_at: env.ledger().timestamp(),
+            updated_by: admin.clone(),
+        };
+        // push_metadata_history reads current version from storage, so update
+        // token_info first then persist before calling it.
+        token_info.metadata_uri = Some(new_metadata_uri.clone());
+        token_info.metadata_version = new_version;
+        storage::set_token_info(env, token_index, &token_info);
+        storage::set_token_info_by_address(env, &token_info.address, &token_info);
+        if new_hash.is_some() {
+            storage::set_metadata_hash(env, token_index, new_hash.as_ref());
+        }
+        storage::increment_metadata_update_count(env, token_index)?;
+
+        // Persist history record (uses the already-updated version in storage)
+        env.storage().persistent().set(
+            &types::DataKey::MetadataHistory(token_index, new_version),
+            &record,
+        );
+
+        events::emit_metadata_updated(
+            env,
+            &token_info.address,
+            &admin,
+            &new_metadata_uri,
+            new_version,
+        );
+
+        Ok(new_version)
+    }
+
+    /// Set a deadline after which token metadata can no longer change
+    ///
+    /// Lets a creator keep metadata editable for a launch window. Once the
+    /// ledger time passes `editable_until`, `set_metadata`,
+    /// `set_token_metadata` and `update_metadata` fail as if metadata were
+    /// frozen. The window can be moved or removed until it closes.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `creator` - Token creator address (must authorize and match creator)
+    /// * `token_index` - Index of the token
+    /// * `editable_until` - Last timestamp at which edits are accepted (None = no deadline)
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success
+    ///
+    /// # Errors
+    /// * `Error::TokenNotFound` - Token index does not exist
+    /// * `Error::Unauthorized` - Caller is not the token creator
+    /// * `Error::MetadataAlreadySet` - Metadata is already frozen or the window has closed
+    pub fn set_metadata_editable_until(
+        env: Env,
+        creator: Address,
+        token_index: u32,
+        editable_until: Option<u64>,
+    ) -> Result<(), Error> {
+        storage::require_creator(&env, token_index, &creator)?;
+        storage::ensure_not_killed(&env)?;
+
+        if storage::is_metadata_locked(&env, token_index) {
+            return Err(Error::MetadataAlreadySet);
+        }
+
+        storage::set_metadata_editable_until(&env, token_index, editable_until);
+        Ok(())
+    }
+
+    /// Permanently freeze token metadata (creator only)
+    ///
+    /// Closes the edit window early; subsequent metadata setters return
+    /// `Error::MetadataAlreadySet`.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `creator` - Token creator address (must authorize and match creator)
+    /// * `token_index` - Index of the token
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success
+    ///
+    /// # Errors
+    /// * `Error::TokenNotFound` - Token index does not exist
+    /// * `Error::Unauthorized` - Caller is not the token creator
+    /// * `Error::MetadataAlreadySet` - Metadata is already locked
+    ///
+    /// # Events
+    /// Emits `md_frz_v1` with the token index and creator
+    pub fn freeze_metadata(env: Env, creator: Address, token_index: u32) -> Result<(), Error> {
+        storage::require_creator(&env, token_index, &creator)?;
+        storage::ensure_not_killed(&env)?;
+
+        if storage::is_metadata_locked(&env, token_index) {
+            return Err(Error::MetadataAlreadySet);
+        }
+
+        storage::set_metadata_frozen(&env, token_index);
+        events::emit_metadata_frozen(&env, token_index, &creator);
+        Ok(())
+    }
+
+    /// Freeze each token's metadata on its first burn (admin only)
+    ///
+    /// When enabled, the first burn of a token through any burn path
+    /// freezes its metadata as if the creator had called `freeze_metadata`.
+    /// Tokens that burned before the flag was enabled freeze on their next
+    /// burn. Disabling the flag does not unfreeze anything.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `admin` - Admin address (must authorize)
+    /// * `enabled` - New flag value
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the admin
+    pub fn set_auto_freeze_metadata_on_burn(
+        env: Env,
+        admin: Address,
+        enabled: bool,
+    ) -> Result<(), Error> {
+        storage::require_admin(&env, &admin)?;
+        storage::ensure_not_killed(&env)?;
+        storage::set_auto_freeze_metadata_on_burn(&env, enabled);
+        Ok(())
+    }
+
+    /// Whether the first burn of a token freezes its metadata
+    pub fn get_auto_freeze_metadata_on_burn(env: Env) -> bool {
+        storage::get_auto_freeze_metadata_on_burn(&env)
+    }
+
+    /// Rename a token shortly after launch (creator only)
+    ///
+    /// Lets the creator fix a name or symbol typo within
+    /// `token_creation::RENAME_WINDOW_SECS` (24 hours) of creation.
+    ///
+    /// # Arguments
+    /// * `token_index` - Index of the token
+    /// * `new_name` - Replacement name (1-32 characters)
+    /// * `new_symbol` - Replacement symbol (1-12 characters, unused by other tokens)
+    /// * `caller` - Token creator (must authorize)
+    ///
+    /// # Errors
+    /// * `Error::TokenNotFound` - Token index does not exist
+    /// * `Error::Unauthorized` - Caller is not the creator, or the window has closed
+    /// * `Error::InvalidTokenParams` - Name or symbol length is invalid
+    /// * `Error::InvalidParameters` - Another token already uses `new_symbol`
+    ///
+    /// # Events
+    /// Emits `tok_rn_v1` with the old and new name and symbol
+    pub fn rename_token(
+        env: Env,
+        token_index: u32,
+        new_name: String,
+        new_symbol: String,
+        caller: Address,
+    ) -> Result<(), Error> {
+        token_creation::rename_token(&env, token_index, new_name, new_symbol, caller)
+    }
+
+    /// Commit to (or clear) a hash of a token's off-chain metadata (creator only)
+    ///
+    /// Clients fetch the content at `metadata_uri` and compare its SHA-256
+    /// against this value. Subject to the same lock as the URI.
+    ///
+    /// # Arguments
+    /// * `creator` - Token creator address (must authorize and match creator)
+    /// * `token_index` - Index of the token
+    /// * `hash` - SHA-256 of the metadata content (None to clear)
+    ///
+    /// # Errors
+    /// * `Error::TokenNotFound` - Token index does not exist
+    /// * `Error::Unauthorized` - Caller is not the token creator
+    /// * `Error::MetadataNotSet` - The token has no metadata URI yet
+    /// * `Error::MetadataAlreadySet` - Metadata is frozen or its edit window has closed
+    /// * `Error::TokenRetired` - Token has been retired
+    pub fn set_metadata_hash(
+        env: Env,
+        creator: Address,
+        token_index: u32,
+        hash: Option<BytesN<32>>,
+    ) -> Result<(), Error> {
+        let token_info = storage::require_creator(&env, token_index, &creator)?;
+        storage::ensure_not_killed(&env)?;
+        storage::ensure_not_retired(&env, token_index)?;
+        if token_info.metadata_uri.is_none() {
+            return Err(Error::MetadataNotSet);
+        }
+        if storage::is_metadata_locked(&env, token_index) {
+            return Err(Error::MetadataAlreadySet);
+        }
+
+        storage::set_metadata_hash(&env, token_index, hash.as_ref());
+        Ok(())
+    }
+
+    /// Get the committed metadata content hash for a token, if any
+    pub fn get_metadata_hash(env: Env, token_index: u32) -> Option<BytesN<32>> {
+        storage::get_metadata_hash(&env, token_index)
+    }
+
+    /// Replace a token's discovery tags (creator only)
+    ///
+    /// An empty list clears the tags. Tags are not covered by the metadata
+    /// lock, so creators can re-categorise a frozen listing.
+    ///
+    /// # Arguments
+    /// * `creator` - Token creator address (must authorize and match creator)
+    /// * `token_index` - Index of the token
+    /// * `tags` - New tag list
+    ///
+    /// # Errors
+    /// * `Error::TokenNotFound` - Token index does not exist
+    /// * `Error::Unauthorized` - Caller is not the token creator
+    /// * `Error::TooManyTags` - More tags than the configured maximum
+    /// * `Error::InvalidParameters` - A tag is empty or over the length limit
+    /// * `Error::TokenRetired` - Token has been retired
+    pub fn set_token_tags(
+        env: Env,
+        creator: Address,
+        token_index: u32,
+        tags: Vec<String>,
+    ) -> Result<(), Error> {
+        storage::require_creator(&env, token_index, &creator)?;
+        storage::ensure_not_killed(&env)?;
+        storage::ensure_not_retired(&env, token_index)?;
+        validation::validate_tags(&env, &tags)?;
 
-        // Record history entry for the new version
-        let record = types::MetadataRecord {
-            uri: new_metadata_uri.clone(),
-            updated_at: env.ledger().timestamp(),
-            updated_by: admin.clone(),
-        };
-        // push_metadata_history reads current version from storage, so update
-        // token_info first then persist before calling it.
-        token_info.metadata_uri = Some(new_metadata_uri.clone());
-        token_info.metadata_version = new_version;
-        storage::set_token_info(&env, token_index, &token_info);
-        storage::set_token_info_by_address(&env, &token_info.address, &token_info);
+        storage::set_token_tags(&env, token_index, &tags);
+        Ok(())
+    }
 
-        // Persist history record (uses the already-updated version in storage)
-        env.storage().persistent().set(
-            &types::DataKey::MetadataHistory(token_index, new_version),
-            &record,
-        );
+    /// Get a token's discovery tags (empty when none are set)
+    pub fn get_token_tags(env: Env, token_index: u32) -> Vec<String> {
+        storage::get_token_tags(&env, token_index)
+    }
 
-        events::emit_metadata_updated(
-            &env,
-            &token_info.address,
-            &admin,
-            &new_metadata_uri,
-            new_version,
-        );
+    /// Check whether token metadata can still be changed
+    ///
+    /// # Returns
+    /// Returns `true` once metadata is frozen or its edit window has closed
+    pub fn is_metadata_locked(env: Env, token_index: u32) -> bool {
+        storage::is_metadata_locked(&env, token_index)
+    }
 
-        Ok(new_version)
+    /// Check whether token metadata has been permanently frozen
+    ///
+    /// Unlike `is_metadata_locked`, an expired edit window alone does not
+    /// count; only `freeze_metadata` or auto-freeze on burn set the flag.
+    ///
+    /// # Returns
+    /// Returns `true` once frozen; `false` for tokens never frozen
+    pub fn is_metadata_frozen(env: Env, token_index: u32) -> bool {
+        storage::is_metadata_frozen(&env, token_index)
     }
 
     /// Get a historical metadata record for a token
@@ -1439,19 +3684,28 @@ impl TokenFactory {
     /// * `env` - The contract environment
     /// * `creator` - Address creating the token (must authorize)
     /// * `name` - Token name (1–32 chars)
-    /// * `symbol` - Token symbol (1–12 chars)
+    /// * `symbol` - Token symbol (1–12 chars), stored trimmed and uppercased
     /// * `decimals` - Decimal places (0–18)
     /// * `initial_supply` - Initial supply (must be > 0)
     /// * `metadata_uri` - Optional IPFS URI
     /// * `fee_payment` - Fee in stroops (must be >= base_fee [+ metadata_fee])
     ///
     /// # Returns
-    /// Returns the new token's contract address
+    /// Returns a `CreationReceipt` with the token address, assigned index,
+    /// fee charged and creation timestamp
     ///
     /// # Errors
     /// * `Error::ContractPaused` - Contract is paused
     /// * `Error::InsufficientFee` - Fee too low
     /// * `Error::InvalidTokenParams` - Invalid name/symbol/decimals/supply
+    /// * `Error::CreatorNotAllowed` - Allowlist mode is on and `creator` is
+    ///   neither listed nor the admin
+    /// * `Error::InvalidParameters` - Metadata is required and `metadata_uri` is None
+    ///
+    /// The positional arguments are the deployed ABI that clients build
+    /// calls against (see the frontend's `buildCreateTokenArgs`), so they
+    /// are not folded into a params struct.
+    #[allow(clippy::too_many_arguments)]
     pub fn create_token(
         env: Env,
         creator: Address,
@@ -1461,7 +3715,7 @@ impl TokenFactory {
         initial_supply: i128,
         metadata_uri: Option<String>,
         fee_payment: i128,
-    ) -> Result<Address, Error> {
+    ) -> Result<types::CreationReceipt, Error> {
         token_creation::create_token(
             &env,
             creator,
@@ -1474,31 +3728,254 @@ impl TokenFactory {
         )
     }
 
-    /// Pause a specific token (admin only)
+    /// Create a token on behalf of another creator (admin only)
+    ///
+    /// The admin authorizes and pays; the token, its initial supply and
+    /// creator rights belong to `creator`. Works regardless of allowlist
+    /// mode, and `creator` does not need to be listed.
+    ///
+    /// # Arguments
+    /// * `admin` - Admin address (must authorize)
+    /// * `creator` - Address the token is attributed to
+    ///
+    /// Other arguments, the return value and errors as for `create_token`,
+    /// plus `Error::Unauthorized` when `admin` is not the admin.
+    #[allow(clippy::too_many_arguments)]
+    pub fn create_for(
+        env: Env,
+        admin: Address,
+        creator: Address,
+        name: String,
+        symbol: String,
+        decimals: u32,
+        initial_supply: i128,
+        metadata_uri: Option<String>,
+        fee_payment: i128,
+    ) -> Result<types::CreationReceipt, Error> {
+        if admin != storage::try_get_admin(&env)? {
+            return Err(Error::Unauthorized);
+        }
+        token_creation::create_token_for(
+            &env,
+            &admin,
+            creator,
+            name,
+            symbol,
+            decimals,
+            initial_supply,
+            metadata_uri,
+            fee_payment,
+        )
+    }
+
+    /// Turn creator allowlist mode on or off (admin only)
+    ///
+    /// While on, only allowlisted addresses and the admin may create tokens
+    /// on any creation path.
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the admin
+    pub fn set_creator_allowlist_enabled(
+        env: Env,
+        admin: Address,
+        enabled: bool,
+    ) -> Result<(), Error> {
+        storage::require_admin(&env, &admin)?;
+        storage::ensure_not_killed(&env)?;
+
+        storage::set_creator_allowlist_enabled(&env, enabled);
+        Ok(())
+    }
+
+    /// Add or remove a creator on the creation allowlist (admin only)
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the admin
+    pub fn set_allowed_creator(
+        env: Env,
+        admin: Address,
+        creator: Address,
+        allowed: bool,
+    ) -> Result<(), Error> {
+        storage::require_admin(&env, &admin)?;
+        storage::ensure_not_killed(&env)?;
+
+        storage::set_allowed_creator(&env, &creator, allowed);
+        Ok(())
+    }
+
+    /// Whether creator allowlist mode is on
+    pub fn is_creator_allowlist_enabled(env: Env) -> bool {
+        storage::is_creator_allowlist_enabled(&env)
+    }
+
+    /// Whether `creator` is on the creation allowlist
+    ///
+    /// The admin is allowed implicitly and is not reported here unless
+    /// listed explicitly.
+    pub fn is_allowed_creator(env: Env, creator: Address) -> bool {
+        storage::is_allowed_creator(&env, &creator)
+    }
+
+    /// Turn emission of non-essential events on or off (admin only)
+    ///
+    /// Burn, mint, creation, metadata, stream, vault and campaign events are
+    /// suppressed while off. Admin, treasury, pause/freeze, role, timelock
+    /// and upgrade events are always emitted, as is `evt_tgl` for this call.
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the admin
+    pub fn set_events_enabled(env: Env, admin: Address, enabled: bool) -> Result<(), Error> {
+        storage::require_admin(&env, &admin)?;
+        storage::ensure_not_killed(&env)?;
+
+        storage::set_events_enabled(&env, enabled);
+        events::emit_events_toggled(&env, &admin, enabled);
+        Ok(())
+    }
+
+    /// Whether non-essential events are emitted
+    pub fn events_enabled(env: Env) -> bool {
+        storage::events_enabled(&env)
+    }
+
+    /// Check whether an account could create a token right now
+    ///
+    /// Read-only preflight for UIs: applies the creator-level gates of the
+    /// creation paths without charging anything. Parameter-dependent checks
+    /// (name, symbol, supply, metadata, fee amount) are not included.
+    ///
+    /// # Errors
+    /// * `Error::NotInitialized` - Contract has not been initialized
+    /// * `Error::ContractPaused` - Contract is paused
+    /// * `Error::CreatorNotAllowed` - Allowlist mode is on and `creator` is not listed
+    /// * `Error::RegistryFull` - The token registry is at its cap
+    pub fn can_create(env: Env, creator: Address) -> Result<(), Error> {
+        token_creation::can_create(&env, &creator)
+    }
+
+    /// Create a single token, falling back to the default decimals
+    ///
+    /// Same as `create_token` except that the token parameters come as one
+    /// struct whose `decimals` may be omitted, in which case
+    /// `default_decimals` is used.
+    ///
+    /// # Arguments
+    /// * `creator` - Address creating the token (must authorize)
+    /// * `params` - Name, symbol, optional decimals, initial supply and
+    ///   metadata URI, with the same rules as for `create_token`
+    /// * `fee_payment` - Fee in stroops (must be >= base_fee [+ metadata_fee])
+    ///
+    /// The return value and errors are as for `create_token`.
+    pub fn create_token_opt_decimals(
+        env: Env,
+        creator: Address,
+        params: types::OptDecimalsTokenParams,
+        fee_payment: i128,
+    ) -> Result<types::CreationReceipt, Error> {
+        let decimals = params
+            .decimals
+            .unwrap_or_else(|| storage::get_default_decimals(&env));
+        token_creation::create_token(
+            &env,
+            creator,
+            params.name,
+            params.symbol,
+            decimals,
+            params.initial_supply,
+            params.metadata_uri,
+            fee_payment,
+        )
+    }
+
+    /// Get the decimals used when a creation call omits them
+    pub fn default_decimals(env: Env) -> u32 {
+        storage::get_default_decimals(&env)
+    }
+
+    /// Set the decimals used when a creation call omits them (admin only)
+    ///
+    /// # Arguments
+    /// * `admin` - Admin address (must authorize)
+    /// * `decimals` - New default (0–18)
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the admin
+    /// * `Error::InvalidParameters` - `decimals` is out of range
+    pub fn set_default_decimals(env: Env, admin: Address, decimals: u32) -> Result<(), Error> {
+        storage::require_admin(&env, &admin)?;
+        storage::ensure_not_killed(&env)?;
+
+        if !token_creation::is_valid_decimals(decimals) {
+            return Err(Error::InvalidParameters);
+        }
+
+        storage::set_default_decimals(&env, decimals);
+        Ok(())
+    }
+
+    /// Set the fewest decimals a new token may have (admin only)
+    ///
+    /// Creations below the minimum fail with `Error::InvalidParameters`,
+    /// including ones that fall back to the default decimals. Existing
+    /// tokens are unaffected. 0 removes the minimum.
+    ///
+    /// # Arguments
+    /// * `admin` - Admin address (must authorize)
+    /// * `min_decimals` - New minimum (0–18)
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the admin
+    /// * `Error::InvalidParameters` - `min_decimals` is out of range
+    pub fn set_min_decimals(env: Env, admin: Address, min_decimals: u32) -> Result<(), Error> {
+        storage::require_admin(&env, &admin)?;
+        storage::ensure_not_killed(&env)?;
+
+        if !token_creation::is_valid_decimals(min_decimals) {
+            return Err(Error::InvalidParameters);
+        }
+
+        storage::set_min_decimals(&env, min_decimals);
+        Ok(())
+    }
+
+    /// Get the minimum decimals enforced at creation (0 = no minimum)
+    pub fn get_min_decimals(env: Env) -> u32 {
+        storage::get_min_decimals(&env)
+    }
+
+    /// Check whether `decimals` would be accepted at creation (0–18)
+    pub fn is_valid_decimals(_env: Env, decimals: u32) -> bool {
+        token_creation::is_valid_decimals(decimals)
+    }
+
+    /// Pause a specific token (admin, creator or Pauser role)
     ///
     /// Halts all mutable operations on the token — minting, burning, and
     /// metadata updates — until `unpause_token` is called. Read-only queries
     /// (`get_token_info`, `get_token_stats`) remain available.
     ///
-    /// This is an emergency control intended for incident response.
+    /// Creators can use this for maintenance windows. A pause placed by the
+    /// factory admin overrides theirs: only the admin can lift it.
     ///
     /// # Arguments
     /// * `env` - The contract environment
-    /// * `admin` - Factory admin address (must authorize)
+    /// * `admin` - Admin, creator or Pauser address (must authorize)
     /// * `token_index` - Index of the token to pause
     ///
     /// # Returns
     /// Returns `Ok(())` on success
     ///
     /// # Errors
-    /// * `Error::Unauthorized` - Caller is not the factory admin
+    /// * `Error::Unauthorized` - Caller is not the admin, creator or a Pauser
     /// * `Error::TokenNotFound` - Token index does not exist
     ///
     /// # Events
     /// Emits `tok_paus` with token_index and admin address
     pub fn pause_token(env: Env, admin: Address, token_index: u32) -> Result<(), Error> {
         admin.require_auth();
-        let stored_admin = storage::get_admin(&env);
+        storage::ensure_not_killed(&env)?;
+        let stored_admin = storage::try_get_admin(&env)?;
         let token_info =
             storage::get_token_info(&env, token_index).ok_or(Error::TokenNotFound)?;
         // Allow: factory admin, token creator, or address with Pauser role
@@ -1509,31 +3986,37 @@ impl TokenFactory {
             return Err(Error::Unauthorized);
         }
         storage::set_token_paused(&env, token_index, true);
+        if admin == stored_admin {
+            storage::set_token_admin_paused(&env, token_index, true);
+        }
         events::emit_token_paused(&env, token_index, &admin);
         Ok(())
     }
 
-    /// Unpause a specific token (admin only)
+    /// Unpause a specific token (admin, creator or Pauser role)
     ///
-    /// Resumes all mutable operations on the token after an emergency pause.
+    /// Resumes all mutable operations on the token after a pause. The admin
+    /// can always unpause; others cannot lift a pause the admin placed.
     ///
     /// # Arguments
     /// * `env` - The contract environment
-    /// * `admin` - Factory admin address (must authorize)
+    /// * `admin` - Admin, creator or Pauser address (must authorize)
     /// * `token_index` - Index of the token to unpause
     ///
     /// # Returns
     /// Returns `Ok(())` on success
     ///
     /// # Errors
-    /// * `Error::Unauthorized` - Caller is not the factory admin
+    /// * `Error::Unauthorized` - Caller is not the admin, creator or a Pauser,
+    ///   or the pause was placed by the admin
     /// * `Error::TokenNotFound` - Token index does not exist
     ///
     /// # Events
     /// Emits `tok_unpas` with token_index and admin address
     pub fn unpause_token(env: Env, admin: Address, token_index: u32) -> Result<(), Error> {
         admin.require_auth();
-        let stored_admin = storage::get_admin(&env);
+        storage::ensure_not_killed(&env)?;
+        let stored_admin = storage::try_get_admin(&env)?;
         let token_info =
             storage::get_token_info(&env, token_index).ok_or(Error::TokenNotFound)?;
         // Allow: factory admin, token creator, or address with Pauser role
@@ -1543,7 +4026,11 @@ impl TokenFactory {
         {
             return Err(Error::Unauthorized);
         }
+        if admin != stored_admin && storage::is_token_admin_paused(&env, token_index) {
+            return Err(Error::Unauthorized);
+        }
         storage::set_token_paused(&env, token_index, false);
+        storage::set_token_admin_paused(&env, token_index, false);
         events::emit_token_unpaused(&env, token_index, &admin);
         Ok(())
     }
@@ -1552,12 +4039,145 @@ impl TokenFactory {
     ///
     /// # Arguments
     /// * `env` - The contract environment
-    /// * `token_index` - Index of the token to check
+    /// * `token_index` - Index of the token to check
+    ///
+    /// # Returns
+    /// Returns `true` if the token is paused, `false` otherwise
+    pub fn is_token_paused(env: Env, token_index: u32) -> bool {
+        storage::is_token_paused(&env, token_index)
+    }
+
+    /// Freeze a specific token (admin only)
+    ///
+    /// Compliance lock for incident response. While frozen, every burn path
+    /// (`burn`, `admin_burn`, `batch_burn`) is rejected. Unlike `pause_token`,
+    /// only the factory admin may freeze or unfreeze.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `admin` - Factory admin address (must authorize)
+    /// * `token_index` - Index of the token to freeze
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the factory admin
+    /// * `Error::TokenNotFound` - Token index does not exist
+    ///
+    /// # Events
+    /// Emits `tok_frz` with token_index and admin address
+    pub fn freeze_token(env: Env, admin: Address, token_index: u32) -> Result<(), Error> {
+        storage::require_admin(&env, &admin)?;
+        storage::ensure_not_killed(&env)?;
+        storage::get_token_info(&env, token_index).ok_or(Error::TokenNotFound)?;
+        storage::set_token_frozen(&env, token_index, true);
+        events::emit_token_frozen(&env, token_index, &admin);
+        Ok(())
+    }
+
+    /// Unfreeze a specific token (admin only)
+    ///
+    /// Lifts a compliance freeze placed by `freeze_token`.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `admin` - Factory admin address (must authorize)
+    /// * `token_index` - Index of the token to unfreeze
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the factory admin
+    /// * `Error::TokenNotFound` - Token index does not exist
+    ///
+    /// # Events
+    /// Emits `tok_unfrz` with token_index and admin address
+    pub fn unfreeze_token(env: Env, admin: Address, token_index: u32) -> Result<(), Error> {
+        storage::require_admin(&env, &admin)?;
+        storage::ensure_not_killed(&env)?;
+        storage::get_token_info(&env, token_index).ok_or(Error::TokenNotFound)?;
+        storage::set_token_frozen(&env, token_index, false);
+        events::emit_token_unfrozen(&env, token_index, &admin);
+        Ok(())
+    }
+
+    /// Check whether a specific token is currently frozen
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `token_index` - Index of the token to check
+    ///
+    /// # Returns
+    /// Returns `true` if the token is frozen, `false` otherwise
+    pub fn is_token_frozen(env: Env, token_index: u32) -> bool {
+        storage::is_token_frozen(&env, token_index)
+    }
+
+    /// Permanently retire a token (admin only)
+    ///
+    /// Unlike a freeze this cannot be undone: burns, mints and metadata
+    /// changes on the token fail with `Error::TokenRetired` from then on.
+    /// Read-only queries keep working.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `admin` - Factory admin address (must authorize)
+    /// * `token_index` - Index of the token to retire
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the factory admin
+    /// * `Error::TokenNotFound` - Token index does not exist
+    /// * `Error::TokenRetired` - Token is already retired
+    ///
+    /// # Events
+    /// Emits `tok_ret` with token_index and admin address
+    pub fn retire_token(env: Env, admin: Address, token_index: u32) -> Result<(), Error> {
+        storage::require_admin(&env, &admin)?;
+        storage::ensure_not_killed(&env)?;
+        storage::get_token_info(&env, token_index).ok_or(Error::TokenNotFound)?;
+        storage::ensure_not_retired(&env, token_index)?;
+        storage::set_token_retired(&env, token_index);
+        events::emit_token_retired(&env, token_index, &admin);
+        Ok(())
+    }
+
+    /// Check whether a token has been retired
+    pub fn is_token_retired(env: Env, token_index: u32) -> bool {
+        storage::is_token_retired(&env, token_index)
+    }
+
+    /// Move a token's burn history to its re-registered successor (admin only)
+    ///
+    /// For migrations that re-register a token at a new address: the new
+    /// token inherits the old one's `total_burned` and `burn_count` on top
+    /// of its own, and the old token is retired.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `from_index` - Token being replaced
+    /// * `to_index` - Token that inherits the aggregates
+    /// * `caller` - Admin address (must authorize)
     ///
-    /// # Returns
-    /// Returns `true` if the token is paused, `false` otherwise
-    pub fn is_token_paused(env: Env, token_index: u32) -> bool {
-        storage::is_token_paused(&env, token_index)
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the admin
+    /// * `Error::InvalidParameters` - Both indices are the same token
+    /// * `Error::TokenNotFound` - Either token does not exist
+    /// * `Error::TokenRetired` - Either token is already retired
+    ///
+    /// # Events
+    /// Emits `tok_ret` for the old token, then `agg_migr`
+    pub fn migrate_token_aggregates(
+        env: Env,
+        from_index: u32,
+        to_index: u32,
+        caller: Address,
+    ) -> Result<(), Error> {
+        burn::migrate_token_aggregates(&env, from_index, to_index, caller)
     }
 
     // ═══════════════════════════════════════════════════════════════════════
@@ -1598,14 +4218,8 @@ impl TokenFactory {
         grantee: Address,
         role: types::Role,
     ) -> Result<(), Error> {
-        creator.require_auth();
-
-        let token_info =
-            storage::get_token_info(&env, token_index).ok_or(Error::TokenNotFound)?;
-
-        if token_info.creator != creator {
-            return Err(Error::Unauthorized);
-        }
+        storage::require_creator(&env, token_index, &creator)?;
+        storage::ensure_not_killed(&env)?;
 
         storage::grant_role(&env, token_index, &grantee, role);
         events::emit_role_granted(&env, token_index, &creator, &grantee, role);
@@ -1640,14 +4254,8 @@ impl TokenFactory {
         revokee: Address,
         role: types::Role,
     ) -> Result<(), Error> {
-        creator.require_auth();
-
-        let token_info =
-            storage::get_token_info(&env, token_index).ok_or(Error::TokenNotFound)?;
-
-        if token_info.creator != creator {
-            return Err(Error::Unauthorized);
-        }
+        storage::require_creator(&env, token_index, &creator)?;
+        storage::ensure_not_killed(&env)?;
 
         storage::revoke_role(&env, token_index, &revokee, role);
         events::emit_role_revoked(&env, token_index, &creator, &revokee, role);
@@ -2088,6 +4696,220 @@ impl TokenFactory {
         pagination::get_creator_token_count(&env, &creator)
     }
 
+    /// Get the total amount burned across all of a creator's tokens
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `creator` - Address of the token creator
+    ///
+    /// # Returns
+    /// Returns the sum of `total_burned` over the creator's tokens (0 if none)
+    pub fn get_creator_total_burned(env: Env, creator: Address) -> i128 {
+        pagination::get_creator_total_burned(&env, &creator)
+    }
+
+    /// Get tokens created within a timestamp range
+    ///
+    /// Returns tokens whose `created_at` lies in `[from, to]` (both inclusive),
+    /// in creation order. Useful for reporting such as "tokens launched this
+    /// week".
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `from` - Range start timestamp (inclusive)
+    /// * `to` - Range end timestamp (inclusive)
+    /// * `start` - Number of matching tokens to skip
+    /// * `limit` - Maximum tokens to return (capped at 100)
+    ///
+    /// # Returns
+    /// Returns a vector of `TokenInfo`; empty when `from > to`
+    ///
+    /// # Examples
+    /// ```
+    /// let week = factory.get_tokens_created_between(&env, now - 604_800, now, 0, 20);
+    /// ```
+    pub fn get_tokens_created_between(
+        env: Env,
+        from: u64,
+        to: u64,
+        start: u32,
+        limit: u32,
+    ) -> Vec<TokenInfo> {
+        pagination::get_tokens_created_between(&env, from, to, start, limit)
+    }
+
+    /// Get tokens that have no metadata URI
+    ///
+    /// For curation: finds listings still waiting on `set_metadata`.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `start` - Number of matching tokens to skip
+    /// * `limit` - Maximum tokens to return (capped at 100)
+    ///
+    /// # Returns
+    /// Returns a vector of `TokenInfo` in creation order
+    pub fn get_tokens_without_metadata(env: Env, start: u32, limit: u32) -> Vec<TokenInfo> {
+        pagination::get_tokens_without_metadata(&env, start, limit)
+    }
+
+    /// Get tokens marked official
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `start` - Number of matching tokens to skip
+    /// * `limit` - Maximum tokens to return (capped at 100)
+    ///
+    /// # Returns
+    /// Returns a vector of `TokenInfo` in creation order
+    pub fn get_official_tokens(env: Env, start: u32, limit: u32) -> Vec<TokenInfo> {
+        pagination::get_official_tokens(&env, start, limit)
+    }
+
+    /// Get tokens whose current supply falls within a range
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `min` - Minimum `total_supply` (inclusive)
+    /// * `max` - Maximum `total_supply` (inclusive)
+    /// * `start` - Number of matching tokens to skip
+    /// * `limit` - Maximum tokens to return (capped at 100)
+    ///
+    /// # Returns
+    /// Returns a vector of `TokenInfo` in creation order; empty if `min > max`
+    pub fn get_tokens_by_supply_range(
+        env: Env,
+        min: i128,
+        max: i128,
+        start: u32,
+        limit: u32,
+    ) -> Vec<TokenInfo> {
+        pagination::get_tokens_by_supply_range(&env, min, max, start, limit)
+    }
+
+    /// Get distinct token creators
+    ///
+    /// Each creator appears once however many tokens they launched,
+    /// ordered by their first token.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `start` - Number of creators to skip
+    /// * `limit` - Maximum creators to return (capped at 100)
+    ///
+    /// # Returns
+    /// Returns a vector of creator addresses
+    pub fn get_creators(env: Env, start: u32, limit: u32) -> Vec<Address> {
+        pagination::get_creators(&env, start, limit)
+    }
+
+    /// Number of distinct token creators
+    pub fn get_creator_count(env: Env) -> u32 {
+        storage::get_creator_count(&env)
+    }
+
+    /// Get one page of the token registry
+    ///
+    /// Offset-based pagination in ascending index order. Pass the returned
+    /// `next` as `start` to fetch the following page.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `start` - Offset of the first token to return
+    /// * `limit` - Maximum tokens to return (capped at 100)
+    ///
+    /// # Returns
+    /// Returns a `TokenPage`; `next` is `None` on the last page
+    pub fn get_tokens_page(env: Env, start: u32, limit: u32) -> types::TokenPage {
+        pagination::get_tokens_page(&env, start, limit)
+    }
+
+    /// Get tokens in reverse creation order
+    ///
+    /// Offset-based pagination for newest-first listings; `start` counts
+    /// back from the most recently created token.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `start` - Number of newest tokens to skip
+    /// * `limit` - Maximum tokens to return (capped at 20)
+    ///
+    /// # Returns
+    /// Returns up to `limit` tokens, newest first; empty past the oldest token
+    pub fn get_tokens_desc(env: Env, start: u32, limit: u32) -> Vec<TokenInfo> {
+        pagination::get_tokens_desc(&env, start, limit)
+    }
+
+    /// Get the most recently created token
+    ///
+    /// # Returns
+    /// Returns the highest-index `TokenInfo`, or `None` for an empty registry
+    pub fn get_latest_token(env: Env) -> Option<TokenInfo> {
+        storage::last_token_index(&env).and_then(|index| storage::get_token_info(&env, index))
+    }
+
+    /// Estimate how many storage entries a token occupies
+    ///
+    /// Bounded approximation for cost planning and pruning decisions: token
+    /// info, metadata history and settings, burn aggregates and burn log
+    /// records. Balances and snapshots are not counted.
+    ///
+    /// # Errors
+    /// * `Error::TokenNotFound` - Token index is invalid
+    pub fn estimate_token_storage(env: Env, token_index: u32) -> Result<u32, Error> {
+        storage::estimate_token_storage(&env, token_index).ok_or(Error::TokenNotFound)
+    }
+
+    /// Report token addresses registered under more than one index
+    ///
+    /// Diagnostic for migration bugs; intended to be called through
+    /// simulation since it scans the entire registry.
+    ///
+    /// # Returns
+    /// Returns each duplicated address once; empty for a clean registry
+    pub fn find_duplicate_registrations(env: Env) -> Vec<Address> {
+        pagination::find_duplicate_registrations(&env)
+    }
+
+    /// Extend storage TTLs for a range of tokens (admin only)
+    ///
+    /// Extends the instance entry (which holds `TokenInfo`) and every
+    /// present per-token persistent entry for tokens in
+    /// `[start, start + limit)`. At most `MAX_TTL_BUMP_BATCH` tokens are
+    /// visited per call, which keeps one call within the ledger footprint
+    /// limit.
+    ///
+    /// # Arguments
+    /// * `caller` - Must be the admin
+    /// * `start` - First token index to visit
+    /// * `limit` - Number of tokens to visit (clamped)
+    /// * `threshold` - Only entries whose TTL is below this are extended
+    /// * `extend_to` - New TTL in ledgers
+    ///
+    /// # Returns
+    /// Returns the number of tokens visited
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the admin
+    /// * `Error::InvalidParameters` - `threshold > extend_to` or `extend_to`
+    ///   exceeds the network's maximum TTL
+    pub fn bump_tokens_ttl(
+        env: Env,
+        caller: Address,
+        start: u32,
+        limit: u32,
+        threshold: u32,
+        extend_to: u32,
+    ) -> Result<u32, Error> {
+        storage::require_admin(&env, &caller)?;
+
+        if threshold > extend_to || extend_to > env.storage().max_ttl() {
+            return Err(Error::InvalidParameters);
+        }
+
+        Ok(storage::bump_tokens_ttl(&env, start, limit, threshold, extend_to))
+    }
+
     // ═══════════════════════════════════════════════════════════════════════
     // Minting Functions
     // ═══════════════════════════════════════════════════════════════════════
@@ -2096,11 +4918,11 @@ impl TokenFactory {
     ///
     /// Increases the total supply and the recipient's balance.
     /// Enforces max supply constraints if set for the token.
-    /// Only the token creator can mint new tokens.
+    /// Only the token creator or one of its mint authorities can mint.
     ///
     /// # Arguments
     /// * `env` - The contract environment
-    /// * `creator` - Token creator address (must authorize)
+    /// * `creator` - Token creator or mint authority address (must authorize)
     /// * `token_index` - Index of the token to mint
     /// * `to` - Address to receive the minted tokens
     /// * `amount` - Amount to mint (must be > 0)
@@ -2109,7 +4931,7 @@ impl TokenFactory {
     /// Returns `Ok(())` on success
     ///
     /// # Errors
-    /// * `Error::Unauthorized` - Caller is not the token creator
+    /// * `Error::Unauthorized` - Caller is neither the creator nor a mint authority
     /// * `Error::TokenNotFound` - Token doesn't exist
     /// * `Error::InvalidAmount` - Amount is zero or negative
     /// * `Error::MaxSupplyExceeded` - Would exceed max supply cap
@@ -2143,10 +4965,10 @@ impl TokenFactory {
 
         creator.require_auth();
 
-        // Verify caller is the token creator or holds the Minter role
+        // Verify caller is the token creator or one of its mint authorities
         let token_info = storage::get_token_info(&env, token_index).ok_or(Error::TokenNotFound)?;
 
-        if token_info.creator != creator {
+        if !storage::can_mint(&env, &token_info, token_index, &creator) {
             storage::release_reentrancy_lock(&env);
             return Err(Error::Unauthorized);
         }
@@ -2157,6 +4979,170 @@ impl TokenFactory {
         result
     }
 
+    /// Add a mint authority for a token (creator only)
+    ///
+    /// Lets additional addresses, such as a reward distributor or a team
+    /// wallet, call `mint` and `batch_settle` for this token. Adding an
+    /// existing authority is a no-op and emits no event.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `creator` - Token creator address (must authorize)
+    /// * `token_index` - Index of the token
+    /// * `authority` - Address allowed to mint
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success
+    ///
+    /// # Errors
+    /// * `Error::TokenNotFound` - Token index does not exist
+    /// * `Error::Unauthorized` - Caller is not the token creator
+    ///
+    /// # Events
+    /// Emits `mnt_add` with token_index, creator and authority when the
+    /// authority was not already present
+    pub fn add_mint_authority(
+        env: Env,
+        creator: Address,
+        token_index: u32,
+        authority: Address,
+    ) -> Result<(), Error> {
+        storage::require_creator(&env, token_index, &creator)?;
+        storage::ensure_not_killed(&env)?;
+
+        if storage::is_mint_authority(&env, token_index, &authority) {
+            return Ok(());
+        }
+
+        storage::add_mint_authority(&env, token_index, &authority);
+        events::emit_mint_authority_added(&env, token_index, &creator, &authority);
+        Ok(())
+    }
+
+    /// Remove a mint authority from a token (creator only)
+    ///
+    /// Idempotent: removing an address that is not an authority is a no-op
+    /// and emits no event. The creator's own minting right cannot be removed.
+    ///
+    /// # Arguments
+    /// * `env` - The contract environment
+    /// * `creator` - Token creator address (must authorize)
+    /// * `token_index` - Index of the token
+    /// * `authority` - Address losing the right to mint
+    ///
+    /// # Returns
+    /// Returns `Ok(())` on success
+    ///
+    /// # Errors
+    /// * `Error::TokenNotFound` - Token index does not exist
+    /// * `Error::Unauthorized` - Caller is not the token creator
+    ///
+    /// # Events
+    /// Emits `mnt_rm` with token_index, creator and authority when an
+    /// authority was removed
+    pub fn remove_mint_authority(
+        env: Env,
+        creator: Address,
+        token_index: u32,
+        authority: Address,
+    ) -> Result<(), Error> {
+        storage::require_creator(&env, token_index, &creator)?;
+        storage::ensure_not_killed(&env)?;
+
+        if !storage::is_mint_authority(&env, token_index, &authority) {
+            return Ok(());
+        }
+
+        storage::remove_mint_authority(&env, token_index, &authority);
+        events::emit_mint_authority_removed(&env, token_index, &creator, &authority);
+        Ok(())
+    }
+
+    /// Check whether an address is a mint authority for a token
+    ///
+    /// Does not include the creator, who can always mint.
+    pub fn is_mint_authority(env: Env, token_index: u32, authority: Address) -> bool {
+        storage::is_mint_authority(&env, token_index, &authority)
+    }
+
+    /// Set the address allowed to report holder changes for a token (admin only)
+    ///
+    /// Typically the token contract itself. The admin can always report.
+    ///
+    /// # Arguments
+    /// * `admin` - Admin address (must authorize)
+    /// * `token_index` - Index of the token
+    /// * `reporter` - Address that may call `record_holder_added`/`record_holder_removed`
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the admin
+    /// * `Error::TokenNotFound` - Token index is invalid
+    pub fn set_holder_reporter(
+        env: Env,
+        admin: Address,
+        token_index: u32,
+        reporter: Address,
+    ) -> Result<(), Error> {
+        storage::require_admin(&env, &admin)?;
+        storage::ensure_not_killed(&env)?;
+        storage::get_token_info(&env, token_index).ok_or(Error::TokenNotFound)?;
+
+        storage::set_holder_reporter(&env, token_index, &reporter);
+        Ok(())
+    }
+
+    /// Get the holder reporter for a token, if one is set
+    pub fn get_holder_reporter(env: Env, token_index: u32) -> Option<Address> {
+        storage::get_holder_reporter(&env, token_index)
+    }
+
+    /// Get the reported number of holders of a token
+    pub fn get_holder_count(env: Env, token_index: u32) -> u32 {
+        storage::get_holder_count(&env, token_index)
+    }
+
+    /// Record that a token gained a holder (reporter or admin only)
+    ///
+    /// # Returns
+    /// Returns the updated holder count
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is neither the token's reporter nor the admin
+    /// * `Error::TokenNotFound` - Token index is invalid
+    /// * `Error::ArithmeticError` - Count overflows
+    pub fn record_holder_added(env: Env, caller: Address, token_index: u32) -> Result<u32, Error> {
+        Self::require_holder_reporter(&env, &caller, token_index)?;
+
+        let count = storage::get_holder_count(&env, token_index)
+            .checked_add(1)
+            .ok_or(Error::ArithmeticError)?;
+        storage::set_holder_count(&env, token_index, count);
+        Ok(count)
+    }
+
+    /// Record that a token lost a holder (reporter or admin only)
+    ///
+    /// # Returns
+    /// Returns the updated holder count
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is neither the token's reporter nor the admin
+    /// * `Error::TokenNotFound` - Token index is invalid
+    /// * `Error::ArithmeticError` - Count is already zero
+    pub fn record_holder_removed(
+        env: Env,
+        caller: Address,
+        token_index: u32,
+    ) -> Result<u32, Error> {
+        Self::require_holder_reporter(&env, &caller, token_index)?;
+
+        let count = storage::get_holder_count(&env, token_index)
+            .checked_sub(1)
+            .ok_or(Error::ArithmeticError)?;
+        storage::set_holder_count(&env, token_index, count);
+        Ok(count)
+    }
+
     /// Get remaining mintable supply for a token
     ///
     /// Returns how many more tokens can be minted before hitting the max supply.
@@ -2216,12 +5202,8 @@ impl TokenFactory {
         daily_cap: Option<i128>,
         allowlist_enabled: bool,
     ) -> Result<(), Error> {
-        admin.require_auth();
-
-        let current_admin = storage::get_admin(&env);
-        if admin != current_admin {
-            return Err(Error::Unauthorized);
-        }
+        storage::require_admin(&env, &admin)?;
+        storage::ensure_not_killed(&env)?;
 
         treasury::initialize_treasury_policy(&env, daily_cap, allowlist_enabled)
     }
@@ -2386,6 +5368,35 @@ impl TokenFactory {
         treasury::get_treasury_policy(&env)
     }
 
+    /// Set the collected-fee total that triggers a `fee_threshold` alert (admin only)
+    ///
+    /// The event fires once when creation fees take the running total from
+    /// below `threshold` to at or above it. Pass 0 to disable.
+    ///
+    /// # Errors
+    /// * `Error::Unauthorized` - Caller is not the admin
+    /// * `Error::InvalidParameters` - `threshold` is negative
+    pub fn set_fee_alert_threshold(env: Env, admin: Address, threshold: i128) -> Result<(), Error> {
+        storage::require_admin(&env, &admin)?;
+        storage::ensure_not_killed(&env)?;
+        if threshold < 0 {
+            return Err(Error::InvalidParameters);
+        }
+
+        storage::set_fee_alert_threshold(&env, threshold);
+        Ok(())
+    }
+
+    /// Get the collected-fee alert threshold (0 when disabled)
+    pub fn get_fee_alert_threshold(env: Env) -> i128 {
+        storage::get_fee_alert_threshold(&env)
+    }
+
+    /// Get the running total of creation fees collected
+    pub fn get_collected_fees(env: Env) -> i128 {
+        storage::get_collected_fees(&env)
+    }
+
     /// Check if address is allowed recipient
     ///
     /// Returns true if the address can receive treasury withdrawals.
@@ -2422,6 +5433,7 @@ impl TokenFactory {
         milestone_hash: BytesN<32>,
     ) -> Result<u64, Error> {
         creator.require_auth();
+        storage::ensure_not_killed(&env)?;
 
         if storage::is_paused(&env) {
             return Err(Error::ContractPaused);
@@ -2507,6 +5519,7 @@ impl TokenFactory {
         proof: Option<Bytes>,
     ) -> Result<i128, Error> {
         owner.require_auth();
+        storage::ensure_not_killed(&env)?;
 
         if storage::is_paused(&env) {
             return Err(Error::ContractPaused);
@@ -2611,13 +5624,14 @@ impl TokenFactory {
     /// - Remaining amount is permanently unclaimable.
     pub fn cancel_vault(env: Env, vault_id: u64, actor: Address) -> Result<(), Error> {
         actor.require_auth();
+        storage::ensure_not_killed(&env)?;
 
         if storage::is_paused(&env) {
             return Err(Error::ContractPaused);
         }
 
         let mut vault = storage::get_vault(&env, vault_id).ok_or(Error::TokenNotFound)?;
-        let admin = storage::get_admin(&env);
+        let admin = storage::try_get_admin(&env)?;
         if actor != vault.creator && actor != admin {
             return Err(Error::Unauthorized);
         }
@@ -2712,6 +5726,7 @@ impl TokenFactory {
     ) -> Result<(), Error> {
         // Require updater authorization
         updater.require_auth();
+        storage::ensure_not_killed(&env)?;
 
         // Early return if contract is paused
         if storage::is_paused(&env) {
@@ -2722,7 +5737,7 @@ impl TokenFactory {
         let mut stream = storage::get_stream(&env, stream_id.into()).ok_or(Error::TokenNotFound)?;
 
         // Verify authorization: only creator or admin can update
-        let admin = storage::get_admin(&env);
+        let admin = storage::try_get_admin(&env)?;
         if updater != stream.creator && updater != admin {
             return Err(Error::Unauthorized);
         }
@@ -2940,9 +5955,10 @@ impl TokenFactory {
         target_token: Address,
     ) -> Result<u64, Error> {
         creator.require_auth();
+        storage::ensure_not_killed(&env)?;
 
         // Allow only factory admin or token creator.
-        let admin = storage::get_admin(&env);
+        let admin = storage::try_get_admin(&env)?;
         let token = storage::get_token_info(&env, token_index).ok_or(Error::TokenNotFound)?;
         if creator != admin && creator != token.creator {
             return Err(Error::Unauthorized);
@@ -3138,12 +6154,8 @@ impl TokenFactory {
         signers: Vec<Address>,
         threshold: u32,
     ) -> Result<(), Error> {
-        admin.require_auth();
-
-        let stored_admin = storage::get_admin(&env);
-        if admin != stored_admin {
-            return Err(Error::Unauthorized);
-        }
+        storage::require_admin(&env, &admin)?;
+        storage::ensure_not_killed(&env)?;
 
         let signer_count = signers.len();
         if threshold == 0 || threshold > signer_count {
@@ -3189,6 +6201,7 @@ impl TokenFactory {
         payload: Bytes,
     ) -> Result<u64, Error> {
         proposer.require_auth();
+        storage::ensure_not_killed(&env)?;
 
         let config = storage::get_multisig_config(&env)
             .ok_or(Error::MultiSigNotConfigured)?;
@@ -3245,6 +6258,7 @@ impl TokenFactory {
         proposal_id: u64,
     ) -> Result<(), Error> {
         approver.require_auth();
+        storage::ensure_not_killed(&env)?;
 
         let config = storage::get_multisig_config(&env)
             .ok_or(Error::MultiSigNotConfigured)?;
@@ -3303,6 +6317,7 @@ impl TokenFactory {
         proposal_id: u64,
     ) -> Result<(), Error> {
         executor.require_auth();
+        storage::ensure_not_killed(&env)?;
 
         let config = storage::get_multisig_config(&env)
             .ok_or(Error::MultiSigNotConfigured)?;
@@ -3347,6 +6362,7 @@ impl TokenFactory {
         proposal_id: u64,
     ) -> Result<(), Error> {
         canceller.require_auth();
+        storage::ensure_not_killed(&env)?;
 
         let mut proposal = storage::get_multisig_proposal(&env, proposal_id)
             .ok_or(Error::MultiSigProposalNotFound)?;
@@ -3359,7 +6375,7 @@ impl TokenFactory {
         }
 
         // Only admin or the original proposer may cancel
-        let admin = storage::get_admin(&env);
+        let admin = storage::try_get_admin(&env)?;
         if canceller != admin && canceller != proposal.proposer {
             return Err(Error::Unauthorized);
         }
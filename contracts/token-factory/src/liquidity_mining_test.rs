@@ -61,7 +61,10 @@ mod liquidity_mining_tests {
             is_paused: false,
             clawback_enabled: false,
             freeze_enabled: false,
+            max_burn_bps: None,
+            official: false,
         }
+            max_burn_bps: None,
     }
 
     // ─────────────────────────────────────────────────────────────────────────
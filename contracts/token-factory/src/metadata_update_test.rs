@@ -56,6 +56,8 @@ fn setup() -> (Env, Address, Address) {
             is_paused: false,
             clawback_enabled: false,
             freeze_enabled: false,
+            max_burn_bps: None,
+            official: false,
         };
         storage::set_token_info(&env, 0, &token_info);
         storage::set_token_info_by_address(&env, &contract_id, &token_info);
@@ -68,7 +70,7 @@ fn setup() -> (Env, Address, Address) {
 fn set_initial_metadata(env: &Env, contract_id: &Address, creator: &Address, uri: &str) {
     let client = crate::TokenFactoryClient::new(env, contract_id);
     client
-        .set_token_metadata(creator, &0u32, &String::from_str(env, uri))
+        .set_token_metadata(creator, &0u32, &String::from_str(env, uri), &50_i128)
         .unwrap();
 }
 
@@ -240,6 +242,7 @@ fn test_set_token_metadata_immutable_after_first_set() {
         &creator,
         &0u32,
         &String::from_str(&env, "ipfs://QmV2"),
+        &50_i128,
     );
 
     assert!(result.is_err());
@@ -302,7 +305,7 @@ fn test_full_metadata_lifecycle() {
     // Step 1: initial set
     env.ledger().with_mut(|li| li.timestamp = 1_000);
     client
-        .set_token_metadata(&creator, &0u32, &String::from_str(&env, "ipfs://QmV1"))
+        .set_token_metadata(&creator, &0u32, &String::from_str(&env, "ipfs://QmV1"), &50_i128)
         .unwrap();
 
     // Step 2-4: three updates
@@ -362,6 +365,8 @@ fn test_update_metadata_isolation_between_tokens() {
             is_paused: false,
             clawback_enabled: false,
             freeze_enabled: false,
+            max_burn_bps: None,
+            official: false,
         };
         storage::set_token_info(&env, 1, &token_info);
     });
@@ -370,7 +375,7 @@ fn test_update_metadata_isolation_between_tokens() {
 
     // Set and update metadata only on token 0
     client
-        .set_token_metadata(&creator, &0u32, &String::from_str(&env, "ipfs://QmV1"))
+        .set_token_metadata(&creator, &0u32, &String::from_str(&env, "ipfs://QmV1"), &50_i128)
         .unwrap();
     client
         .update_metadata(&creator, &0u32, &String::from_str(&env, "ipfs://QmV2"))
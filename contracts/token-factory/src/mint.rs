@@ -93,7 +93,10 @@ pub fn validate_max_supply_at_creation(
 /// * `Err(Error::InvalidAmount)` - Amount is zero or negative
 /// * `Err(Error::MaxSupplyExceeded)` - Would exceed max supply
 /// * `Err(Error::ArithmeticError)` - Overflow in calculation
+/// * `Err(Error::TokenRetired)` - Token has been retired
 pub fn mint(env: &Env, token_index: u32, to: &Address, amount: i128) -> Result<(), Error> {
+    storage::ensure_not_killed(env)?;
+
     // Validate amount
     if amount <= 0 {
         return Err(Error::InvalidAmount);
@@ -103,6 +106,7 @@ pub fn mint(env: &Env, token_index: u32, to: &Address, amount: i128) -> Result<(
     if storage::is_token_paused(env, token_index) {
         return Err(Error::TokenPaused);
     }
+    storage::ensure_not_retired(env, token_index)?;
 
     // Get token info
     let mut token_info = storage::get_token_info(env, token_index).ok_or(Error::TokenNotFound)?;
@@ -126,6 +130,8 @@ pub fn mint(env: &Env, token_index: u32, to: &Address, amount: i128) -> Result<(
 
     // Save updated token info
     storage::set_token_info(env, token_index, &token_info);
+    storage::add_total_minted(env, token_index, amount)?;
+    storage::add_mint_record(env, token_index, to, amount)?;
 
     // Record snapshots for historical queries
     let _ = crate::snapshot::record_balance_snapshot(env, token_index, to, new_balance);
@@ -153,6 +159,7 @@ pub fn batch_mint(
     token_index: u32,
     mints: &soroban_sdk::Vec<(Address, i128)>,
 ) -> Result<(), Error> {
+    storage::ensure_not_killed(env)?;
     if mints.is_empty() {
         return Err(Error::InvalidParameters);
     }
@@ -180,6 +187,7 @@ pub fn batch_mint(
             .checked_add(amount)
             .ok_or(Error::ArithmeticError)?;
         storage::set_balance(env, token_index, &to, new_balance);
+        storage::add_mint_record(env, token_index, &to, amount)?;
         crate::events::emit_mint(env, token_index, &to, amount);
     }
 
@@ -187,6 +195,7 @@ pub fn batch_mint(
         .checked_add(total_mint)
         .ok_or(Error::ArithmeticError)?;
     storage::set_token_info(env, token_index, &token_info);
+    storage::add_total_minted(env, token_index, total_mint)?;
 
     env.events().publish(
         (soroban_sdk::symbol_short!("btch_mnt"), token_index),
@@ -216,6 +225,36 @@ pub fn get_remaining_mintable(env: &Env, token_index: u32) -> Option<i128> {
         .map(|max| max.saturating_sub(token_info.total_supply).max(0))
 }
 
+/// Check that a token's supply figures agree with each other
+///
+/// Holds when `initial_supply + total_minted - total_burned == total_supply`
+/// and the standalone burn tracker (`DataKey::TotalBurned`) matches the
+/// `total_burned` recorded on the token. A `false` result means one of the
+/// write paths updated supply without the matching bookkeeping.
+///
+/// # Returns
+/// * `true` - Figures are consistent
+/// * `false` - Figures disagree, overflow, or the token doesn't exist
+pub fn verify_supply_invariant(env: &Env, token_index: u32) -> bool {
+    let info = match storage::get_token_info(env, token_index) {
+        Some(info) => info,
+        None => return false,
+    };
+
+    if storage::get_total_burned(env, token_index) != info.total_burned {
+        return false;
+    }
+
+    // Burns sent to a dead address or inherited through a migration count
+    // in total_burned but never left this token's supply
+    info.initial_supply
+        .checked_add(storage::get_total_minted(env, token_index))
+        .and_then(|issued| issued.checked_sub(info.total_burned))
+        .and_then(|net| net.checked_add(storage::get_dead_burned(env, token_index)))
+        .and_then(|net| net.checked_add(storage::get_inherited_burned(env, token_index)))
+        .is_some_and(|expected| expected == info.total_supply)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -310,6 +349,8 @@ mod tests {
             clawback_enabled: false,
             freeze_enabled: false,
             is_paused: false,
+            max_burn_bps: None,
+            official: false,
         };
 
         env.as_contract(&contract_id, || {
@@ -349,6 +390,8 @@ mod tests {
             clawback_enabled: false,
             freeze_enabled: false,
             is_paused: false,
+            max_burn_bps: None,
+            official: false,
         };
 
         env.as_contract(&contract_id, || {
@@ -388,6 +431,8 @@ mod tests {
             clawback_enabled: false,
             freeze_enabled: false,
             is_paused: false,
+            max_burn_bps: None,
+            official: false,
         };
 
         env.as_contract(&contract_id, || {
@@ -427,6 +472,8 @@ mod tests {
             clawback_enabled: false,
             freeze_enabled: false,
             is_paused: false,
+            max_burn_bps: None,
+            official: false,
         };
 
         env.as_contract(&contract_id, || {
@@ -461,6 +508,8 @@ mod tests {
             clawback_enabled: false,
             freeze_enabled: false,
             is_paused: false,
+            max_burn_bps: None,
+            official: false,
         };
 
         env.as_contract(&contract_id, || {
@@ -495,6 +544,8 @@ mod tests {
             clawback_enabled: false,
             freeze_enabled: false,
             is_paused: false,
+            max_burn_bps: None,
+            official: false,
         };
 
         env.as_contract(&contract_id, || {
@@ -528,6 +579,8 @@ mod tests {
             clawback_enabled: false,
             freeze_enabled: false,
             is_paused: false,
+            max_burn_bps: None,
+            official: false,
         };
 
         env.as_contract(&contract_id, || {
@@ -560,6 +613,8 @@ mod tests {
             clawback_enabled: false,
             freeze_enabled: false,
             is_paused: false,
+            max_burn_bps: None,
+            official: false,
         };
 
         env.as_contract(&contract_id, || {
@@ -592,6 +647,8 @@ mod tests {
             clawback_enabled: false,
             freeze_enabled: false,
             is_paused: false,
+            max_burn_bps: None,
+            official: false,
         };
 
         env.as_contract(&contract_id, || {
@@ -627,6 +684,8 @@ mod tests {
             clawback_enabled: false,
             freeze_enabled: false,
             is_paused: false,
+            max_burn_bps: None,
+            official: false,
         };
         env.as_contract(&contract_id, || {
             storage::set_token_info(&env, 0, &token_info);
@@ -673,6 +732,8 @@ mod tests {
             clawback_enabled: false,
             freeze_enabled: false,
             is_paused: false,
+            max_burn_bps: None,
+            official: false,
         };
         env.as_contract(&contract_id, || {
             storage::set_token_info(&env, 0, &token_info);
@@ -696,4 +757,169 @@ mod tests {
         assert_eq!(b2, 0);
         assert_eq!(s, supply_before);
     }
+
+    /// Tests for per-token mint authorities.
+    mod mint_authority {
+        use soroban_sdk::{
+            symbol_short,
+            testutils::{Address as _, Events},
+            vec, xdr, Address, Env, String, Symbol, TryFromVal,
+        };
+
+        use crate::storage;
+        use crate::test_helpers::setup_with_supply;
+        use crate::types::Error;
+        use crate::TokenFactoryClient;
+
+        fn balance_of(
+            env: &Env,
+            client: &TokenFactoryClient,
+            token_index: u32,
+            holder: &Address,
+        ) -> i128 {
+            env.as_contract(&client.address, || {
+                storage::get_balance(env, token_index, holder)
+            })
+        }
+
+        /// Number of events named `name` emitted by the last invocation.
+        fn count_events(env: &Env, name: Symbol) -> u32 {
+            let mut count = 0;
+            for event in env.events().all().events() {
+                let xdr::ContractEventBody::V0(body) = &event.body;
+                if Symbol::try_from_val(env, &body.topics[0]).ok() == Some(name.clone()) {
+                    count += 1;
+                }
+            }
+            count
+        }
+
+        #[test]
+        fn test_added_authority_can_mint() {
+            let env = Env::default();
+            let (client, _, creator, token_index) = setup_with_supply(&env, 1_000);
+            let distributor = Address::generate(&env);
+            let user = Address::generate(&env);
+
+            client.add_mint_authority(&creator, &token_index, &distributor);
+            assert!(client.is_mint_authority(&token_index, &distributor));
+
+            client.mint(&distributor, &token_index, &user, &500);
+            client.batch_settle(
+                &distributor,
+                &token_index,
+                &vec![&env, (user.clone(), 250i128)],
+            );
+
+            assert_eq!(balance_of(&env, &client, token_index, &user), 750);
+            assert_eq!(client.get_token_info(&token_index).total_supply, 1_750);
+        }
+
+        #[test]
+        fn test_removed_authority_rejected() {
+            let env = Env::default();
+            let (client, _, creator, token_index) = setup_with_supply(&env, 1_000);
+            let team = Address::generate(&env);
+
+            client.add_mint_authority(&creator, &token_index, &team);
+            client.remove_mint_authority(&creator, &token_index, &team);
+            assert!(!client.is_mint_authority(&token_index, &team));
+
+            assert_eq!(
+                client.try_mint(&team, &token_index, &team, &1),
+                Err(Ok(Error::Unauthorized))
+            );
+            assert_eq!(
+                client.try_batch_settle(&team, &token_index, &vec![&env, (team.clone(), 1i128)]),
+                Err(Ok(Error::Unauthorized))
+            );
+        }
+
+        #[test]
+        fn test_creator_always_allowed() {
+            let env = Env::default();
+            let (client, _, creator, token_index) = setup_with_supply(&env, 1_000);
+            let user = Address::generate(&env);
+
+            // Removing the creator as an "authority" has no effect on its own right
+            client.remove_mint_authority(&creator, &token_index, &creator);
+            client.mint(&creator, &token_index, &user, &100);
+
+            assert_eq!(balance_of(&env, &client, token_index, &user), 100);
+        }
+
+        #[test]
+        fn test_authority_is_per_token() {
+            let env = Env::default();
+            let (client, _, creator, token_index) = setup_with_supply(&env, 1_000);
+            let distributor = Address::generate(&env);
+            client.create_token(
+                &creator,
+                &String::from_str(&env, "Other"),
+                &String::from_str(&env, "OTH"),
+                &7u32,
+                &1_000i128,
+                &None,
+                &70_000_000i128,
+            );
+
+            client.add_mint_authority(&creator, &token_index, &distributor);
+            assert_eq!(
+                client.try_mint(&distributor, &1, &distributor, &1),
+                Err(Ok(Error::Unauthorized))
+            );
+        }
+
+        #[test]
+        fn test_only_creator_manages_authorities() {
+            let env = Env::default();
+            let (client, _, _, token_index) = setup_with_supply(&env, 1_000);
+            let stranger = Address::generate(&env);
+
+            assert_eq!(
+                client.try_add_mint_authority(&stranger, &token_index, &stranger),
+                Err(Ok(Error::Unauthorized))
+            );
+            assert_eq!(
+                client.try_remove_mint_authority(&stranger, &token_index, &stranger),
+                Err(Ok(Error::Unauthorized))
+            );
+        }
+
+        #[test]
+        fn test_double_add_is_noop() {
+            let env = Env::default();
+            let (client, _, creator, token_index) = setup_with_supply(&env, 1_000);
+            let distributor = Address::generate(&env);
+
+            client.add_mint_authority(&creator, &token_index, &distributor);
+            assert_eq!(count_events(&env, symbol_short!("mnt_add")), 1);
+
+            client.add_mint_authority(&creator, &token_index, &distributor);
+            assert_eq!(count_events(&env, symbol_short!("mnt_add")), 0);
+            assert!(client.is_mint_authority(&token_index, &distributor));
+
+            // A single removal fully revokes the doubly-added authority
+            client.remove_mint_authority(&creator, &token_index, &distributor);
+            assert!(!client.is_mint_authority(&token_index, &distributor));
+        }
+
+        #[test]
+        fn test_remove_nonexistent_authority_is_noop() {
+            let env = Env::default();
+            let (client, _, creator, token_index) = setup_with_supply(&env, 1_000);
+            let never_added = Address::generate(&env);
+
+            client.remove_mint_authority(&creator, &token_index, &never_added);
+            assert_eq!(count_events(&env, symbol_short!("mnt_rm")), 0);
+            assert!(!client.is_mint_authority(&token_index, &never_added));
+
+            let team = Address::generate(&env);
+            client.add_mint_authority(&creator, &token_index, &team);
+            client.remove_mint_authority(&creator, &token_index, &team);
+            assert_eq!(count_events(&env, symbol_short!("mnt_rm")), 1);
+            client.remove_mint_authority(&creator, &token_index, &team);
+            assert_eq!(count_events(&env, symbol_short!("mnt_rm")), 0);
+        }
+    }
 }
@@ -1,10 +1,11 @@
-use soroban_sdk::{Address, Env, Vec};
+use soroban_sdk::{Address, Env, Map, Vec};
 
 use crate::storage;
-use crate::types::{Error, PaginatedTokens, PaginationCursor, TokenInfo};
+use crate::types::{Error, PaginatedTokens, PaginationCursor, TokenInfo, TokenPage};
 
 const MAX_PAGE_SIZE: u32 = 100;
 const DEFAULT_PAGE_SIZE: u32 = 20;
+const MAX_DESC_PAGE_SIZE: u32 = 20;
 const NO_CURSOR: u32 = u32::MAX;
 
 pub fn get_tokens_by_creator(
@@ -69,10 +70,276 @@ pub fn get_tokens_by_creator(
     })
 }
 
+/// List tokens whose `created_at` falls within `[from, to]` (inclusive).
+///
+/// `start` is an offset into the matching tokens, `limit` is clamped to
+/// `MAX_PAGE_SIZE`. The registry is index-ordered by creation time, so the
+/// scan stops at the first token created after `to`.
+pub fn get_tokens_created_between(
+    env: &Env,
+    from: u64,
+    to: u64,
+    start: u32,
+    limit: u32,
+) -> Vec<TokenInfo> {
+    let mut tokens = Vec::new(env);
+    if from > to || limit == 0 {
+        return tokens;
+    }
+
+    let page_size = limit.min(MAX_PAGE_SIZE);
+    let mut skipped = 0_u32;
+
+    for index in storage::valid_token_indices(env) {
+        let Some(token_info) = storage::get_token_info(env, index) else {
+            continue;
+        };
+        if token_info.created_at > to {
+            break;
+        }
+        if token_info.created_at < from {
+            continue;
+        }
+        if skipped < start {
+            skipped += 1;
+            continue;
+        }
+        tokens.push_back(token_info);
+        if tokens.len() >= page_size {
+            break;
+        }
+    }
+
+    tokens
+}
+
+/// List tokens that have no `metadata_uri` set, in index order.
+///
+/// `start` is an offset into the matching tokens, `limit` is clamped to
+/// `MAX_PAGE_SIZE`.
+pub fn get_tokens_without_metadata(env: &Env, start: u32, limit: u32) -> Vec<TokenInfo> {
+    let mut tokens = Vec::new(env);
+    if limit == 0 {
+        return tokens;
+    }
+
+    let page_size = limit.min(MAX_PAGE_SIZE);
+    let mut skipped = 0_u32;
+
+    for index in storage::valid_token_indices(env) {
+        let Some(token_info) = storage::get_token_info(env, index) else {
+            continue;
+        };
+        if token_info.metadata_uri.is_some() {
+            continue;
+        }
+        if skipped < start {
+            skipped += 1;
+            continue;
+        }
+        tokens.push_back(token_info);
+        if tokens.len() >= page_size {
+            break;
+        }
+    }
+
+    tokens
+}
+
+/// List tokens whose current `total_supply` is within `[min, max]`
+/// (inclusive), in index order.
+///
+/// `start` is an offset into the matching tokens, `limit` is clamped to
+/// `MAX_PAGE_SIZE`. An inverted range matches nothing.
+pub fn get_tokens_by_supply_range(
+    env: &Env,
+    min: i128,
+    max: i128,
+    start: u32,
+    limit: u32,
+) -> Vec<TokenInfo> {
+    let mut tokens = Vec::new(env);
+    if limit == 0 || min > max {
+        return tokens;
+    }
+
+    let page_size = limit.min(MAX_PAGE_SIZE);
+    let mut skipped = 0_u32;
+
+    for index in storage::valid_token_indices(env) {
+        let Some(token_info) = storage::get_token_info(env, index) else {
+            continue;
+        };
+        if token_info.total_supply < min || token_info.total_supply > max {
+            continue;
+        }
+        if skipped < start {
+            skipped += 1;
+            continue;
+        }
+        tokens.push_back(token_info);
+        if tokens.len() >= page_size {
+            break;
+        }
+    }
+
+    tokens
+}
+
+/// List tokens marked `official`, in index order.
+///
+/// `start` is an offset into the matching tokens, `limit` is clamped to
+/// `MAX_PAGE_SIZE`.
+pub fn get_official_tokens(env: &Env, start: u32, limit: u32) -> Vec<TokenInfo> {
+    let mut tokens = Vec::new(env);
+    if limit == 0 {
+        return tokens;
+    }
+
+    let page_size = limit.min(MAX_PAGE_SIZE);
+    let mut skipped = 0_u32;
+
+    for index in storage::valid_token_indices(env) {
+        let Some(token_info) = storage::get_token_info(env, index) else {
+            continue;
+        };
+        if !token_info.official {
+            continue;
+        }
+        if skipped < start {
+            skipped += 1;
+            continue;
+        }
+        tokens.push_back(token_info);
+        if tokens.len() >= page_size {
+            break;
+        }
+    }
+
+    tokens
+}
+
+/// List distinct token creators in order of their first token.
+///
+/// Reads the creator index maintained at creation, so no deduplication
+/// happens here. `limit` is clamped to `MAX_PAGE_SIZE`.
+pub fn get_creators(env: &Env, start: u32, limit: u32) -> Vec<Address> {
+    let mut creators = Vec::new(env);
+    let end = start
+        .saturating_add(limit.min(MAX_PAGE_SIZE))
+        .min(storage::get_creator_count(env));
+
+    for position in start..end {
+        if let Some(creator) = storage::get_creator_at(env, position) {
+            creators.push_back(creator);
+        }
+    }
+
+    creators
+}
+
+/// Return one offset-paginated page of the whole token registry.
+///
+/// `start` is an offset from the first token index and `limit` is clamped
+/// to `MAX_PAGE_SIZE`. `next` is `None` once the page reaches the end of the
+/// registry.
+pub fn get_tokens_page(env: &Env, start: u32, limit: u32) -> TokenPage {
+    let indices = storage::valid_token_indices(env);
+    let total = indices.end - indices.start;
+    let begin = start.min(total);
+    let end = begin.saturating_add(limit.min(MAX_PAGE_SIZE)).min(total);
+
+    let mut items = Vec::new(env);
+    for offset in begin..end {
+        if let Some(token_info) = storage::get_token_info(env, indices.start + offset) {
+            items.push_back(token_info);
+        }
+    }
+
+    TokenPage {
+        items,
+        next: if end < total { Some(end) } else { None },
+        total,
+    }
+}
+
+/// List tokens newest first.
+///
+/// `start` is an offset from the most recently created token, so `start = 0`
+/// begins with the latest one. `limit` is clamped to `MAX_DESC_PAGE_SIZE`,
+/// a smaller cap than the forward listings since feeds only show the top.
+pub fn get_tokens_desc(env: &Env, start: u32, limit: u32) -> Vec<TokenInfo> {
+    let mut tokens = Vec::new(env);
+
+    for index in storage::valid_token_indices(env)
+        .rev()
+        .skip(start as usize)
+        .take(limit.min(MAX_DESC_PAGE_SIZE) as usize)
+    {
+        if let Some(token_info) = storage::get_token_info(env, index) {
+            tokens.push_back(token_info);
+        }
+    }
+
+    tokens
+}
+
 pub fn get_creator_token_count(env: &Env, creator: &Address) -> u32 {
     storage::get_creator_token_count(env, creator)
 }
 
+/// Sum `total_burned` over every token `creator` launched.
+///
+/// Walks the creator's index list rather than the registry. The list can
+/// repeat an index (it is appended on every `TokenInfo` write), so each
+/// index is counted once.
+pub fn get_creator_total_burned(env: &Env, creator: &Address) -> i128 {
+    let mut seen: Map<u32, bool> = Map::new(env);
+    let mut total = 0_i128;
+
+    for token_index in storage::get_creator_tokens(env, creator).iter() {
+        if seen.contains_key(token_index) {
+            continue;
+        }
+        seen.set(token_index, true);
+
+        if let Some(token_info) = storage::get_token_info(env, token_index) {
+            if token_info.creator == *creator {
+                total = total.saturating_add(token_info.total_burned);
+            }
+        }
+    }
+
+    total
+}
+
+/// Return every token address registered under more than one index.
+///
+/// Walks the whole registry once, so cost grows with the token count; this
+/// is a diagnostic meant to be run through simulation rather than inside a
+/// transaction. Each duplicated address is reported once, in the order its
+/// second registration is found.
+pub fn find_duplicate_registrations(env: &Env) -> Vec<Address> {
+    let mut seen: Map<Address, bool> = Map::new(env);
+    let mut duplicates = Vec::new(env);
+
+    for index in storage::valid_token_indices(env) {
+        let Some(token_info) = storage::get_token_info(env, index) else {
+            continue;
+        };
+        match seen.get(token_info.address.clone()) {
+            None => seen.set(token_info.address, false),
+            Some(false) => {
+                duplicates.push_back(token_info.address.clone());
+                seen.set(token_info.address, true);
+            }
+            Some(true) => {}
+        }
+    }
+
+    duplicates
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -106,6 +373,8 @@ mod tests {
                     clawback_enabled: false,
                     is_paused: false,
                     freeze_enabled: false,
+                    max_burn_bps: None,
+                    official: false,
                 };
                 storage::set_token_info(&env, i, &token_info);
             }
@@ -290,6 +559,8 @@ mod tests {
                     clawback_enabled: false,
                     is_paused: false,
                     freeze_enabled: false,
+                    max_burn_bps: None,
+                    official: false,
                 };
                 storage::set_token_info(&env, i, &token_info);
             }
@@ -311,6 +582,8 @@ mod tests {
                     clawback_enabled: false,
                     is_paused: false,
                     freeze_enabled: false,
+                    max_burn_bps: None,
+                    official: false,
                 };
                 storage::set_token_info(&env, i, &token_info);
             }
@@ -324,4 +597,913 @@ mod tests {
             .unwrap();
         assert_eq!(result2.tokens.len(), 5);
     }
+
+    /// Tests for offset-paginated `TokenPage` / `BurnRecordPage` queries.
+    mod page {
+        use soroban_sdk::{testutils::Address as _, Address, Env};
+
+        use crate::test_helpers::{create_test_token, setup_factory};
+        use crate::TokenFactoryClient;
+
+        /// Set up a factory with `count` tokens. Returns (client, creator).
+        fn setup(env: &Env, count: u32) -> (TokenFactoryClient<'_>, Address) {
+            let (client, _, _) = setup_factory(env);
+
+            let creator = Address::generate(env);
+            for _ in 0..count {
+                create_test_token(env, &client, &creator, "PGE", 1_000_000);
+            }
+
+            (client, creator)
+        }
+
+        #[test]
+        fn test_tokens_page_walks_registry() {
+            let env = Env::default();
+            let (client, _) = setup(&env, 5);
+
+            let page1 = client.get_tokens_page(&0, &2);
+            assert_eq!(page1.items.len(), 2);
+            assert_eq!(page1.next, Some(2));
+            assert_eq!(page1.total, 5);
+
+            let page2 = client.get_tokens_page(&page1.next.unwrap(), &2);
+            assert_eq!(page2.items.len(), 2);
+            assert_eq!(page2.next, Some(4));
+
+            let page3 = client.get_tokens_page(&page2.next.unwrap(), &2);
+            assert_eq!(page3.items.len(), 1);
+            assert_eq!(page3.next, None);
+            assert_eq!(page3.total, 5);
+        }
+
+        #[test]
+        fn test_tokens_page_exact_fit_has_no_next() {
+            let env = Env::default();
+            let (client, _) = setup(&env, 4);
+
+            assert_eq!(client.get_tokens_page(&0, &2).next, Some(2));
+            let last = client.get_tokens_page(&2, &2);
+            assert_eq!(last.items.len(), 2);
+            assert_eq!(last.next, None);
+        }
+
+        #[test]
+        fn test_tokens_page_empty_and_out_of_range() {
+            let env = Env::default();
+            let (client, _) = setup(&env, 0);
+
+            let empty = client.get_tokens_page(&0, &10);
+            assert_eq!(empty.items.len(), 0);
+            assert_eq!(empty.next, None);
+            assert_eq!(empty.total, 0);
+
+            let (client, _) = setup(&env, 2);
+            let past_end = client.get_tokens_page(&10, &10);
+            assert_eq!(past_end.items.len(), 0);
+            assert_eq!(past_end.next, None);
+            assert_eq!(past_end.total, 2);
+        }
+
+        #[test]
+        fn test_burn_records_page_oldest_first() {
+            let env = Env::default();
+            let (client, creator) = setup(&env, 1);
+
+            for amount in 1..=5i128 {
+                client.burn(&creator, &0, &amount);
+            }
+
+            let page1 = client.get_burn_records_page(&0, &3);
+            assert_eq!(page1.total, 5);
+            assert_eq!(page1.next, Some(3));
+            assert_eq!(page1.items.get(0).unwrap().amount, 1);
+            assert_eq!(page1.items.get(2).unwrap().amount, 3);
+
+            let page2 = client.get_burn_records_page(&3, &3);
+            assert_eq!(page2.items.len(), 2);
+            assert_eq!(page2.next, None);
+            assert_eq!(page2.items.get(1).unwrap().amount, 5);
+        }
+
+        #[test]
+        fn test_burn_records_page_limit_is_clamped() {
+            let env = Env::default();
+            let (client, creator) = setup(&env, 1);
+
+            for _ in 0..55 {
+                client.burn(&creator, &0, &1);
+            }
+
+            let page = client.get_burn_records_page(&0, &500);
+            assert_eq!(page.items.len(), 50);
+            assert_eq!(page.next, Some(50));
+            assert_eq!(client.get_burn_records_page(&50, &500).next, None);
+        }
+    }
+
+    /// Tests for `get_tokens_by_supply_range` screener queries.
+    mod tokens_by_supply_range {
+        use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+        use crate::test_helpers::setup_factory;
+        use crate::TokenFactoryClient;
+
+        /// Create one token per entry in `supplies`. Returns (client, creator).
+        fn setup_with_supplies<'a>(
+            env: &'a Env,
+            supplies: &[i128],
+        ) -> (TokenFactoryClient<'a>, Address) {
+            let (client, _, _) = setup_factory(env);
+
+            let creator = Address::generate(env);
+            for supply in supplies {
+                client.create_token(
+                    &creator,
+                    &String::from_str(env, "SupplyToken"),
+                    &String::from_str(env, "SUP"),
+                    &7u32,
+                    supply,
+                    &None,
+                    &70_000_000i128,
+                );
+            }
+
+            (client, creator)
+        }
+
+        #[test]
+        fn test_range_matching_none() {
+            let env = Env::default();
+            let (client, _) = setup_with_supplies(&env, &[100, 200, 300]);
+
+            assert_eq!(
+                client
+                    .get_tokens_by_supply_range(&301, &1_000, &0, &10)
+                    .len(),
+                0
+            );
+            assert_eq!(client.get_tokens_by_supply_range(&1, &99, &0, &10).len(), 0);
+        }
+
+        #[test]
+        fn test_range_is_inclusive_on_both_ends() {
+            let env = Env::default();
+            let (client, _) = setup_with_supplies(&env, &[100, 200, 300, 400]);
+
+            let tokens = client.get_tokens_by_supply_range(&200, &300, &0, &10);
+            assert_eq!(tokens.len(), 2);
+            assert_eq!(tokens.get(0).unwrap().total_supply, 200);
+            assert_eq!(tokens.get(1).unwrap().total_supply, 300);
+        }
+
+        #[test]
+        fn test_range_matching_all_with_pagination() {
+            let env = Env::default();
+            let (client, _) = setup_with_supplies(&env, &[100, 200, 300]);
+
+            assert_eq!(
+                client.get_tokens_by_supply_range(&100, &300, &0, &10).len(),
+                3
+            );
+
+            let page = client.get_tokens_by_supply_range(&0, &i128::MAX, &1, &1);
+            assert_eq!(page.len(), 1);
+            assert_eq!(page.get(0).unwrap().total_supply, 200);
+        }
+
+        #[test]
+        fn test_range_uses_current_supply() {
+            let env = Env::default();
+            let (client, creator) = setup_with_supplies(&env, &[500]);
+
+            client.burn(&creator, &0, &300);
+
+            assert_eq!(
+                client.get_tokens_by_supply_range(&500, &500, &0, &10).len(),
+                0
+            );
+            assert_eq!(
+                client.get_tokens_by_supply_range(&200, &200, &0, &10).len(),
+                1
+            );
+        }
+
+        #[test]
+        fn test_inverted_range_and_zero_limit_are_empty() {
+            let env = Env::default();
+            let (client, _) = setup_with_supplies(&env, &[100]);
+
+            assert_eq!(
+                client.get_tokens_by_supply_range(&200, &100, &0, &10).len(),
+                0
+            );
+            assert_eq!(
+                client
+                    .get_tokens_by_supply_range(&0, &i128::MAX, &0, &0)
+                    .len(),
+                0
+            );
+        }
+    }
+
+    /// Tests for `get_tokens_created_between` timestamp-range queries.
+    mod tokens_created_between {
+        use soroban_sdk::{
+            testutils::{Address as _, Ledger},
+            Address, Env,
+        };
+
+        use crate::test_helpers::{create_test_token, setup_factory};
+        use crate::TokenFactoryClient;
+
+        const BASE_TS: u64 = 1_000;
+
+        /// Create one token at each of `timestamps`. Returns the client.
+        fn setup_with_tokens_at<'a>(env: &'a Env, timestamps: &[u64]) -> TokenFactoryClient<'a> {
+            let (client, _, _) = setup_factory(env);
+
+            let creator = Address::generate(env);
+            for ts in timestamps {
+                env.ledger().with_mut(|l| l.timestamp = *ts);
+                create_test_token(env, &client, &creator, "RNG", 1_000);
+            }
+
+            client
+        }
+
+        #[test]
+        fn test_range_is_inclusive_on_both_ends() {
+            let env = Env::default();
+            let client =
+                setup_with_tokens_at(&env, &[BASE_TS, BASE_TS + 10, BASE_TS + 20, BASE_TS + 30]);
+
+            let tokens =
+                client.get_tokens_created_between(&(BASE_TS + 10), &(BASE_TS + 20), &0, &10);
+            assert_eq!(tokens.len(), 2);
+            assert_eq!(tokens.get(0).unwrap().created_at, BASE_TS + 10);
+            assert_eq!(tokens.get(1).unwrap().created_at, BASE_TS + 20);
+        }
+
+        #[test]
+        fn test_range_pagination() {
+            let env = Env::default();
+            let client = setup_with_tokens_at(
+                &env,
+                &[BASE_TS, BASE_TS + 1, BASE_TS + 2, BASE_TS + 3, BASE_TS + 4],
+            );
+
+            let page1 = client.get_tokens_created_between(&BASE_TS, &(BASE_TS + 4), &0, &2);
+            let page2 = client.get_tokens_created_between(&BASE_TS, &(BASE_TS + 4), &2, &2);
+            let page3 = client.get_tokens_created_between(&BASE_TS, &(BASE_TS + 4), &4, &2);
+
+            assert_eq!(page1.len(), 2);
+            assert_eq!(page2.len(), 2);
+            assert_eq!(page3.len(), 1);
+            assert_eq!(page2.get(0).unwrap().created_at, BASE_TS + 2);
+            assert_eq!(page3.get(0).unwrap().created_at, BASE_TS + 4);
+        }
+
+        #[test]
+        fn test_range_outside_registry_is_empty() {
+            let env = Env::default();
+            let client = setup_with_tokens_at(&env, &[BASE_TS, BASE_TS + 10]);
+
+            assert_eq!(
+                client
+                    .get_tokens_created_between(&0, &(BASE_TS - 1), &0, &10)
+                    .len(),
+                0
+            );
+            assert_eq!(
+                client
+                    .get_tokens_created_between(&(BASE_TS + 11), &u64::MAX, &0, &10)
+                    .len(),
+                0
+            );
+        }
+
+        #[test]
+        fn test_inverted_range_and_zero_limit_are_empty() {
+            let env = Env::default();
+            let client = setup_with_tokens_at(&env, &[BASE_TS]);
+
+            assert_eq!(
+                client
+                    .get_tokens_created_between(&(BASE_TS + 1), &BASE_TS, &0, &10)
+                    .len(),
+                0
+            );
+            assert_eq!(
+                client
+                    .get_tokens_created_between(&0, &u64::MAX, &0, &0)
+                    .len(),
+                0
+            );
+        }
+    }
+
+    /// Tests for the newest-first `get_tokens_desc` listing.
+    mod tokens_desc {
+        use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+        use crate::test_helpers::setup_factory;
+        use crate::TokenFactoryClient;
+
+        /// Set up a factory with `count` tokens whose supply equals their index.
+        fn setup(env: &Env, count: u32) -> TokenFactoryClient<'_> {
+            let (client, admin, _) = setup_factory(env);
+
+            client.set_initial_supply_bounds(&admin, &0, &i128::MAX);
+
+            let creator = Address::generate(env);
+            for i in 0..count {
+                client.create_token(
+                    &creator,
+                    &String::from_str(env, "DescToken"),
+                    &String::from_str(env, "DSC"),
+                    &7u32,
+                    &(i as i128),
+                    &None,
+                    &70_000_000i128,
+                );
+            }
+
+            client
+        }
+
+        #[test]
+        fn test_pages_cover_registry_newest_first() {
+            let env = Env::default();
+            let client = setup(&env, 7);
+
+            let mut seen = std::vec::Vec::new();
+            let mut start = 0u32;
+            loop {
+                let page = client.get_tokens_desc(&start, &3);
+                if page.is_empty() {
+                    break;
+                }
+                for token in page.iter() {
+                    seen.push(token.total_supply);
+                }
+                start += page.len();
+            }
+
+            assert_eq!(seen, std::vec![6, 5, 4, 3, 2, 1, 0]);
+        }
+
+        #[test]
+        fn test_first_entry_is_latest_token() {
+            let env = Env::default();
+            let client = setup(&env, 4);
+
+            let page = client.get_tokens_desc(&0, &1);
+            assert_eq!(page.get(0), client.get_latest_token());
+            assert_eq!(
+                client.get_tokens_desc(&3, &10).get(0).unwrap().total_supply,
+                0
+            );
+        }
+
+        #[test]
+        fn test_out_of_range_and_zero_limit_are_empty() {
+            let env = Env::default();
+            let client = setup(&env, 3);
+
+            assert_eq!(client.get_tokens_desc(&3, &10).len(), 0);
+            assert_eq!(client.get_tokens_desc(&u32::MAX, &10).len(), 0);
+            assert_eq!(client.get_tokens_desc(&0, &0).len(), 0);
+        }
+
+        #[test]
+        fn test_limit_is_clamped() {
+            let env = Env::default();
+            let client = setup(&env, 25);
+
+            let page = client.get_tokens_desc(&0, &500);
+            assert_eq!(page.len(), 20);
+            assert_eq!(page.get(0).unwrap().total_supply, 24);
+            assert_eq!(page.get(19).unwrap().total_supply, 5);
+        }
+    }
+
+    /// Tests for `get_tokens_without_metadata` curation queries.
+    mod tokens_without_metadata {
+        use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+        use crate::test_helpers::{create_test_token, setup_factory};
+        use crate::TokenFactoryClient;
+
+        /// Create one token per entry of `with_metadata`, setting a metadata URI
+        /// where the entry is `true`. Returns the client.
+        fn setup_mixed<'a>(env: &'a Env, with_metadata: &[bool]) -> TokenFactoryClient<'a> {
+            let (client, _, _) = setup_factory(env);
+
+            let creator = Address::generate(env);
+            for (index, has_metadata) in with_metadata.iter().enumerate() {
+                create_test_token(env, &client, &creator, "CUR", 1_000);
+                if *has_metadata {
+                    client.set_metadata(
+                        &(index as u32),
+                        &String::from_str(env, "ipfs://QmDone"),
+                        &30_000_000,
+                    );
+                }
+            }
+
+            client
+        }
+
+        #[test]
+        fn test_returns_only_tokens_without_metadata() {
+            let env = Env::default();
+            let client = setup_mixed(&env, &[true, false, true, false, false]);
+
+            let tokens = client.get_tokens_without_metadata(&0, &10);
+            assert_eq!(tokens.len(), 3);
+            for token in tokens.iter() {
+                assert_eq!(token.metadata_uri, None);
+            }
+            assert_eq!(tokens.get(0).unwrap(), client.get_token_info(&1));
+            assert_eq!(tokens.get(1).unwrap(), client.get_token_info(&3));
+            assert_eq!(tokens.get(2).unwrap(), client.get_token_info(&4));
+        }
+
+        #[test]
+        fn test_pagination_over_matches() {
+            let env = Env::default();
+            let client = setup_mixed(&env, &[false, true, false, false, true, false]);
+
+            let page1 = client.get_tokens_without_metadata(&0, &2);
+            let page2 = client.get_tokens_without_metadata(&2, &2);
+            let page3 = client.get_tokens_without_metadata(&4, &2);
+
+            assert_eq!(page1.len(), 2);
+            assert_eq!(page2.len(), 2);
+            assert_eq!(page3.len(), 0);
+            assert_eq!(page2.get(0).unwrap(), client.get_token_info(&3));
+            assert_eq!(page2.get(1).unwrap(), client.get_token_info(&5));
+        }
+
+        #[test]
+        fn test_all_listed_or_zero_limit_is_empty() {
+            let env = Env::default();
+            let client = setup_mixed(&env, &[true, true]);
+
+            assert_eq!(client.get_tokens_without_metadata(&0, &10).len(), 0);
+
+            let client = setup_mixed(&env, &[false]);
+            assert_eq!(client.get_tokens_without_metadata(&0, &0).len(), 0);
+            assert_eq!(client.get_tokens_without_metadata(&0, &10).len(), 1);
+        }
+    }
+
+    /// Tests for the `official` marker on protocol-launched tokens.
+    mod official_tokens {
+        use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+        use crate::test_helpers::setup_factory;
+        use crate::types::Error;
+        use crate::TokenFactoryClient;
+
+        fn create(env: &Env, client: &TokenFactoryClient, creator: &Address) {
+            client.create_token(
+                creator,
+                &String::from_str(env, "Official"),
+                &String::from_str(env, "OFF"),
+                &7u32,
+                &1_000i128,
+                &None,
+                &70_000_000i128,
+            );
+        }
+
+        #[test]
+        fn test_admin_created_token_is_official() {
+            let env = Env::default();
+            let (client, admin, _) = setup_factory(&env);
+
+            create(&env, &client, &admin);
+            assert!(client.get_token_info(&0).official);
+        }
+
+        #[test]
+        fn test_user_token_is_not_official() {
+            let env = Env::default();
+            let (client, _, _) = setup_factory(&env);
+
+            create(&env, &client, &Address::generate(&env));
+            assert!(!client.get_token_info(&0).official);
+        }
+
+        #[test]
+        fn test_set_official_toggles_marker() {
+            let env = Env::default();
+            let (client, admin, _) = setup_factory(&env);
+            create(&env, &client, &Address::generate(&env));
+
+            client.set_official(&admin, &0, &true);
+            assert!(client.get_token_info(&0).official);
+
+            client.set_official(&admin, &0, &false);
+            assert!(!client.get_token_info(&0).official);
+        }
+
+        #[test]
+        fn test_set_official_validation() {
+            let env = Env::default();
+            let (client, admin, _) = setup_factory(&env);
+            let user = Address::generate(&env);
+            create(&env, &client, &user);
+
+            assert_eq!(
+                client.try_set_official(&user, &0, &true),
+                Err(Ok(Error::Unauthorized))
+            );
+            assert_eq!(
+                client.try_set_official(&admin, &9, &true),
+                Err(Ok(Error::TokenNotFound))
+            );
+        }
+
+        #[test]
+        fn test_get_official_tokens_filters() {
+            let env = Env::default();
+            let (client, admin, _) = setup_factory(&env);
+            let user = Address::generate(&env);
+
+            create(&env, &client, &user);
+            create(&env, &client, &admin);
+            create(&env, &client, &user);
+            create(&env, &client, &admin);
+            client.set_official(&admin, &2, &true);
+
+            let official = client.get_official_tokens(&0, &10);
+            assert_eq!(official.len(), 3);
+            assert!(official.iter().all(|info| info.official));
+
+            assert_eq!(client.get_official_tokens(&1, &1).len(), 1);
+            assert_eq!(client.get_official_tokens(&3, &10).len(), 0);
+            assert_eq!(client.get_official_tokens(&0, &0).len(), 0);
+        }
+    }
+
+    /// Tests for `get_latest_token`.
+    mod latest_token {
+        use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+        use crate::test_helpers::setup_factory;
+        use crate::TokenFactoryClient;
+
+        fn create(env: &Env, client: &TokenFactoryClient, name: &str) {
+            client.create_token(
+                &Address::generate(env),
+                &String::from_str(env, name),
+                &String::from_str(env, "LTS"),
+                &7u32,
+                &1_000i128,
+                &None,
+                &70_000_000i128,
+            );
+        }
+
+        #[test]
+        fn test_empty_registry_has_no_latest_token() {
+            let env = Env::default();
+            let (client, _, _) = setup_factory(&env);
+
+            assert_eq!(client.get_latest_token(), None);
+        }
+
+        #[test]
+        fn test_latest_token_is_highest_index() {
+            let env = Env::default();
+            let (client, _, _) = setup_factory(&env);
+
+            create(&env, &client, "First");
+            assert_eq!(
+                client.get_latest_token().unwrap().name,
+                String::from_str(&env, "First")
+            );
+
+            create(&env, &client, "Second");
+            create(&env, &client, "Third");
+            let latest = client.get_latest_token().unwrap();
+            assert_eq!(latest.name, String::from_str(&env, "Third"));
+            assert_eq!(latest, client.get_token_info(&2));
+        }
+    }
+
+    /// Tests for the distinct-creator index and `get_creators`.
+    mod creators {
+        use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+
+        use crate::test_helpers::setup_factory;
+        use crate::types::TokenCreationParams;
+        use crate::TokenFactoryClient;
+
+        fn create(env: &Env, client: &TokenFactoryClient, creator: &Address) {
+            client.create_token(
+                creator,
+                &String::from_str(env, "CreatorToken"),
+                &String::from_str(env, "CRT"),
+                &7u32,
+                &1_000i128,
+                &None,
+                &70_000_000i128,
+            );
+        }
+
+        #[test]
+        fn test_each_creator_listed_once() {
+            let env = Env::default();
+            let (client, _, _) = setup_factory(&env);
+            let alice = Address::generate(&env);
+            let bob = Address::generate(&env);
+
+            create(&env, &client, &alice);
+            create(&env, &client, &bob);
+            create(&env, &client, &alice);
+            create(&env, &client, &alice);
+
+            let params = TokenCreationParams {
+                name: String::from_str(&env, "BatchToken"),
+                symbol: String::from_str(&env, "BTC"),
+                decimals: 7,
+                initial_supply: 1_000,
+                max_supply: None,
+                metadata_uri: None,
+            };
+            client.batch_create_tokens(&bob, &vec![&env, params.clone(), params], &140_000_000);
+
+            // Metadata updates rewrite TokenInfo but must not re-list the creator
+            client.set_metadata(&0, &String::from_str(&env, "ipfs://QmAlice"), &30_000_000);
+
+            assert_eq!(client.get_creator_count(), 2);
+            let creators = client.get_creators(&0, &10);
+            assert_eq!(creators, vec![&env, alice, bob]);
+        }
+
+        #[test]
+        fn test_creators_pagination() {
+            let env = Env::default();
+            let (client, _, _) = setup_factory(&env);
+            let mut expected = std::vec::Vec::new();
+            for _ in 0..5 {
+                let creator = Address::generate(&env);
+                create(&env, &client, &creator);
+                expected.push(creator);
+            }
+
+            let page = client.get_creators(&1, &2);
+            assert_eq!(page.len(), 2);
+            assert_eq!(page.get(0).unwrap(), expected[1]);
+            assert_eq!(page.get(1).unwrap(), expected[2]);
+
+            assert_eq!(client.get_creators(&4, &10).len(), 1);
+            assert_eq!(client.get_creators(&5, &10).len(), 0);
+            assert_eq!(client.get_creators(&0, &0).len(), 0);
+            assert_eq!(client.get_creators(&u32::MAX, &10).len(), 0);
+        }
+
+        #[test]
+        fn test_no_creators_before_first_token() {
+            let env = Env::default();
+            let (client, _, _) = setup_factory(&env);
+
+            assert_eq!(client.get_creator_count(), 0);
+            assert_eq!(client.get_creators(&0, &10).len(), 0);
+        }
+    }
+
+    /// Tests for `get_creator_total_burned` portfolio aggregation.
+    mod creator_total_burned {
+        use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+        use crate::test_helpers::setup_factory;
+        use crate::TokenFactoryClient;
+
+        fn create(env: &Env, client: &TokenFactoryClient, creator: &Address) {
+            client.create_token(
+                creator,
+                &String::from_str(env, "Portfolio"),
+                &String::from_str(env, "PRT"),
+                &7u32,
+                &1_000_000i128,
+                &None,
+                &70_000_000i128,
+            );
+        }
+
+        #[test]
+        fn test_sums_burns_across_creator_tokens() {
+            let env = Env::default();
+            let (client, _, _) = setup_factory(&env);
+            let creator = Address::generate(&env);
+
+            for _ in 0..3 {
+                create(&env, &client, &creator);
+            }
+            client.burn(&creator, &0, &100);
+            client.burn(&creator, &1, &250);
+            client.burn(&creator, &1, &50);
+            client.burn(&creator, &2, &600);
+
+            assert_eq!(client.get_creator_total_burned(&creator), 1_000);
+        }
+
+        #[test]
+        fn test_excludes_other_creators() {
+            let env = Env::default();
+            let (client, _, _) = setup_factory(&env);
+            let alice = Address::generate(&env);
+            let bob = Address::generate(&env);
+
+            create(&env, &client, &alice);
+            create(&env, &client, &bob);
+            client.burn(&alice, &0, &10);
+            client.burn(&bob, &1, &20);
+
+            assert_eq!(client.get_creator_total_burned(&alice), 10);
+            assert_eq!(client.get_creator_total_burned(&bob), 20);
+        }
+
+        #[test]
+        fn test_zero_without_tokens_or_burns() {
+            let env = Env::default();
+            let (client, _, _) = setup_factory(&env);
+            let creator = Address::generate(&env);
+
+            assert_eq!(client.get_creator_total_burned(&creator), 0);
+            create(&env, &client, &creator);
+            assert_eq!(client.get_creator_total_burned(&creator), 0);
+        }
+    }
+
+    /// Boundary tests for zero-based token indexing.
+    mod token_index_range {
+        use soroban_sdk::{testutils::Address as _, Address, Env};
+
+        use crate::storage;
+        use crate::test_helpers::{create_test_token, setup_factory};
+        use crate::types::Error;
+        use crate::TokenFactoryClient;
+
+        fn setup(env: &Env, tokens: u32) -> TokenFactoryClient<'_> {
+            let (client, _, _) = setup_factory(env);
+
+            let creator = Address::generate(env);
+            for _ in 0..tokens {
+                create_test_token(env, &client, &creator, "IDX", 1_000);
+            }
+            client
+        }
+
+        #[test]
+        fn test_empty_registry_boundaries() {
+            let env = Env::default();
+            let client = setup(&env, 0);
+
+            env.as_contract(&client.address, || {
+                assert_eq!(storage::get_token_count(&env), 0);
+                assert_eq!(storage::first_token_index(&env), None);
+                assert_eq!(storage::last_token_index(&env), None);
+                assert_eq!(storage::valid_token_indices(&env).count(), 0);
+            });
+
+            assert_eq!(client.try_get_token_info(&0), Err(Ok(Error::TokenNotFound)));
+            assert_eq!(
+                client
+                    .get_tokens_created_between(&0, &u64::MAX, &0, &10)
+                    .len(),
+                0
+            );
+            assert!(client.health().counters_consistent);
+        }
+
+        #[test]
+        fn test_single_token_registry_boundaries() {
+            let env = Env::default();
+            let client = setup(&env, 1);
+
+            env.as_contract(&client.address, || {
+                assert_eq!(storage::get_token_count(&env), 1);
+                assert_eq!(
+                    storage::first_token_index(&env),
+                    Some(storage::FIRST_TOKEN_INDEX)
+                );
+                assert_eq!(storage::last_token_index(&env), Some(0));
+                assert_eq!(storage::valid_token_indices(&env), 0..1);
+            });
+
+            assert!(client.try_get_token_info(&0).is_ok());
+            assert_eq!(client.try_get_token_info(&1), Err(Ok(Error::TokenNotFound)));
+            assert_eq!(
+                client
+                    .get_tokens_created_between(&0, &u64::MAX, &0, &10)
+                    .len(),
+                1
+            );
+            assert!(client.health().counters_consistent);
+        }
+
+        #[test]
+        fn test_valid_indices_cover_every_token() {
+            let env = Env::default();
+            let client = setup(&env, 3);
+
+            env.as_contract(&client.address, || {
+                assert_eq!(storage::last_token_index(&env), Some(2));
+                for index in storage::valid_token_indices(&env) {
+                    assert!(storage::get_token_info(&env, index).is_some());
+                }
+                let past_end = storage::get_token_count(&env);
+                assert!(storage::get_token_info(&env, past_end).is_none());
+            });
+        }
+    }
+
+    /// Tests for `find_duplicate_registrations`.
+    mod duplicate_registrations {
+        use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+        use crate::storage;
+        use crate::types::TokenInfo;
+        use crate::{TokenFactory, TokenFactoryClient};
+
+        fn token_info(env: &Env, address: &Address, creator: &Address) -> TokenInfo {
+            TokenInfo {
+                address: address.clone(),
+                creator: creator.clone(),
+                name: String::from_str(env, "Registry Token"),
+                symbol: String::from_str(env, "REG"),
+                decimals: 7,
+                total_supply: 1_000,
+                initial_supply: 1_000,
+                max_supply: None,
+                total_burned: 0,
+                burn_count: 0,
+                metadata_uri: None,
+                metadata_version: 0,
+                created_at: env.ledger().timestamp(),
+                is_paused: false,
+                clawback_enabled: false,
+                freeze_enabled: false,
+                max_burn_bps: None,
+                official: false,
+            }
+        }
+
+        /// Register one token per address in `addresses`, in order.
+        fn setup_registry<'a>(env: &'a Env, addresses: &[Address]) -> TokenFactoryClient<'a> {
+            let contract_id = env.register_contract(None, TokenFactory);
+            let creator = Address::generate(env);
+            env.as_contract(&contract_id, || {
+                for (index, address) in addresses.iter().enumerate() {
+                    storage::set_token_info(env, index as u32, &token_info(env, address, &creator));
+                    storage::increment_token_count(env).unwrap();
+                }
+            });
+            TokenFactoryClient::new(env, &contract_id)
+        }
+
+        #[test]
+        fn test_clean_registry_reports_nothing() {
+            let env = Env::default();
+            let addresses = [
+                Address::generate(&env),
+                Address::generate(&env),
+                Address::generate(&env),
+            ];
+            let client = setup_registry(&env, &addresses);
+
+            assert_eq!(client.find_duplicate_registrations().len(), 0);
+        }
+
+        #[test]
+        fn test_empty_registry_reports_nothing() {
+            let env = Env::default();
+            let client = setup_registry(&env, &[]);
+
+            assert_eq!(client.find_duplicate_registrations().len(), 0);
+        }
+
+        #[test]
+        fn test_duplicated_entry_reported_once() {
+            let env = Env::default();
+            let a = Address::generate(&env);
+            let b = Address::generate(&env);
+            let c = Address::generate(&env);
+            let client = setup_registry(
+                &env,
+                &[a.clone(), b.clone(), a.clone(), c, a.clone(), b.clone()],
+            );
+
+            let duplicates = client.find_duplicate_registrations();
+            assert_eq!(duplicates.len(), 2);
+            assert_eq!(duplicates.get(0).unwrap(), a);
+            assert_eq!(duplicates.get(1).unwrap(), b);
+        }
+    }
 }
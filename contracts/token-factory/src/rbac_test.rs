@@ -370,6 +370,7 @@ fn metadata_manager_role_allows_set_token_metadata() {
         &manager,
         &token_index,
         &soroban_sdk::String::from_str(&env, "ipfs://QmTest"),
+        &50_i128,
     );
     assert_eq!(result, Ok(()));
 }
@@ -384,6 +385,7 @@ fn without_metadata_manager_role_set_metadata_returns_unauthorized() {
         &non_manager,
         &token_index,
         &soroban_sdk::String::from_str(&env, "ipfs://QmTest"),
+        &50_i128,
     );
     assert_eq!(result, Err(Error::Unauthorized));
 }
@@ -397,6 +399,7 @@ fn creator_can_set_metadata_without_explicit_role() {
         &admin,
         &token_index,
         &soroban_sdk::String::from_str(&env, "ipfs://QmCreator"),
+        &50_i128,
     );
     assert_eq!(result, Ok(()));
 }
@@ -414,6 +417,7 @@ fn revoked_metadata_manager_cannot_set_metadata() {
         &manager,
         &token_index,
         &soroban_sdk::String::from_str(&env, "ipfs://QmTest"),
+        &50_i128,
     );
     assert_eq!(result, Err(Error::Unauthorized));
 }
@@ -431,6 +435,7 @@ fn metadata_manager_blocked_when_token_paused() {
         &manager,
         &token_index,
         &soroban_sdk::String::from_str(&env, "ipfs://QmTest"),
+        &50_i128,
     );
     assert_eq!(result, Err(Error::TokenPaused));
 }
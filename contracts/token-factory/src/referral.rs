@@ -114,10 +114,18 @@ pub fn register_referral(env: &Env, referee: &Address, referrer: &Address) -> Re
 ///
 /// # Returns
 /// Commission amount credited (0 if no referral registered).
-pub fn credit_commission(env: &Env, creator: &Address, token_index: u32, fee_paid: i128) -> i128 {
+///
+/// # Errors
+/// * `ArithmeticError` – the referrer's earned total would overflow.
+pub fn credit_commission(
+    env: &Env,
+    creator: &Address,
+    token_index: u32,
+    fee_paid: i128,
+) -> Result<i128, Error> {
     let info = match get_referral_info(env, creator) {
         Some(i) => i,
-        None => return 0,
+        None => return Ok(0),
     };
 
     let rate = get_commission_rate(env) as i128;
@@ -127,12 +135,12 @@ pub fn credit_commission(env: &Env, creator: &Address, token_index: u32, fee_pai
         .unwrap_or(0);
 
     if commission <= 0 {
-        return 0;
+        return Ok(0);
     }
 
     // Accumulate earned balance.
     let prev = get_total_earned(env, &info.referrer);
-    let new_total = prev.saturating_add(commission);
+    let new_total = prev.checked_add(commission).ok_or(Error::ArithmeticError)?;
     set_total_earned(env, &info.referrer, new_total);
 
     // Increment referee's deployment count.
@@ -142,7 +150,7 @@ pub fn credit_commission(env: &Env, creator: &Address, token_index: u32, fee_pai
 
     crate::events::emit_commission_paid(env, &updated.referrer, token_index, commission);
 
-    commission
+    Ok(commission)
 }
 
 /// Return the referral info for a given referee, if any.
@@ -171,6 +179,7 @@ pub fn get_commission_rate_bps(env: &Env) -> u32 {
 /// * `InvalidParameters` – `rate_bps > MAX_COMMISSION_BPS`.
 pub fn set_commission_rate_bps(env: &Env, admin: &Address, rate_bps: u32) -> Result<(), Error> {
     admin.require_auth();
+    storage::ensure_not_killed(env)?;
 
     let stored_admin = storage::get_admin(env);
     if *admin != stored_admin {
@@ -203,6 +212,7 @@ pub fn set_commission_rate_bps(env: &Env, admin: &Address, rate_bps: u32) -> Res
 /// * `InvalidParameters` – Referrer has no earned commission.
 pub fn payout_commission(env: &Env, admin: &Address, referrer: &Address) -> Result<i128, Error> {
     admin.require_auth();
+    storage::ensure_not_killed(env)?;
 
     let stored_admin = storage::get_admin(env);
     if *admin != stored_admin {
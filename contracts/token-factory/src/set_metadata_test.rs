@@ -46,6 +46,8 @@ fn create_token_without_metadata(
         clawback_enabled: false,
             freeze_enabled: false,
             is_paused: false,
+            max_burn_bps: None,
+            official: false,
         
         };
 
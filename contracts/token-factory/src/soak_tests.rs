@@ -67,6 +67,8 @@ fn create_test_token(
         metadata_version: 0,
         created_at: env.ledger().timestamp(),
         clawback_enabled: true,
+            max_burn_bps: None,
+            official: false,
     };
 
     let token_index = crate::storage::get_token_count(env);
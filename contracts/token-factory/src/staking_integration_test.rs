@@ -34,6 +34,8 @@ mod staking_integration_tests {
             is_paused: false,
             clawback_enabled: false,
             freeze_enabled: false,
+            max_burn_bps: None,
+            official: false,
         };
         storage::set_token_info(&env, 0, &stake_token_info);
 
@@ -54,6 +56,8 @@ mod staking_integration_tests {
             is_paused: false,
             clawback_enabled: false,
             freeze_enabled: false,
+            max_burn_bps: None,
+            official: false,
         };
         storage::set_token_info(&env, 1, &reward_token_info);
 
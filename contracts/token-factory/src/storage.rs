@@ -1,6 +1,9 @@
-use soroban_sdk::{Address, Env};
+use soroban_sdk::{Address, BytesN, Env, String};
 
-use crate::types::{BuybackCampaign, DataKey, Error, FactoryState, TokenInfo};
+use crate::types::{
+    BurnMode, BurnRecord, BuybackCampaign, DataKey, Error, FactoryState, FeeChange, MintRecord,
+    SupplyEventRef, TokenInfo,
+};
 
 // ============================================================
 // Storage Functions - Burn Tracking
@@ -13,6 +16,7 @@ use crate::types::{BuybackCampaign, DataKey, Error, FactoryState, TokenInfo};
 // - add_burn_record(env, record)
 // - get_burn_record(env, index) -> Option<BurnRecord>
 // - get_burn_record_count(env) -> u32
+// - get_token_burn_record_count(env, token_index) -> u32
 // - update_token_supply(env, token_address, delta)
 // ============================================================
 
@@ -21,6 +25,37 @@ pub fn get_admin(env: &Env) -> Address {
     env.storage().instance().get(&DataKey::Admin).unwrap()
 }
 
+/// Non-trapping variant of [`get_admin`] for public entrypoints.
+///
+/// Returns `Error::NotInitialized` when the contract has not been set up yet.
+pub fn try_get_admin(env: &Env) -> Result<Address, Error> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Admin)
+        .ok_or(Error::NotInitialized)
+}
+
+/// Require `caller` to authorize and be the stored factory admin
+pub fn require_admin(env: &Env, caller: &Address) -> Result<(), Error> {
+    caller.require_auth();
+    if *caller != try_get_admin(env)? {
+        return Err(Error::Unauthorized);
+    }
+    Ok(())
+}
+
+/// Require `caller` to authorize and be the creator of `token_index`.
+///
+/// Returns the token's info so callers don't have to load it again.
+pub fn require_creator(env: &Env, token_index: u32, caller: &Address) -> Result<TokenInfo, Error> {
+    caller.require_auth();
+    let info = get_token_info(env, token_index).ok_or(Error::TokenNotFound)?;
+    if info.creator != *caller {
+        return Err(Error::Unauthorized);
+    }
+    Ok(info)
+}
+
 pub fn set_admin(env: &Env, admin: &Address) {
     env.storage().instance().set(&DataKey::Admin, admin);
 }
@@ -29,6 +64,30 @@ pub fn has_admin(env: &Env) -> bool {
     env.storage().instance().has(&DataKey::Admin)
 }
 
+/// Storage layout version recorded by `initialize`. Bump when a stored
+/// type changes shape and a migration is required.
+pub const STORAGE_SCHEMA_VERSION: u32 = 1;
+
+/// Schema version stored at initialization; 0 for uninitialized contracts.
+pub fn get_schema_version(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::SchemaVersion)
+        .unwrap_or(0)
+}
+
+pub fn set_schema_version(env: &Env, version: u32) {
+    env.storage().instance().set(&DataKey::SchemaVersion, &version);
+}
+
+pub fn get_fee_token(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::FeeToken)
+}
+
+pub fn set_fee_token(env: &Env, fee_token: &Address) {
+    env.storage().instance().set(&DataKey::FeeToken, fee_token);
+}
+
 // Pending admin management (two-step transfer)
 pub fn get_pending_admin(env: &Env) -> Option<Address> {
     env.storage().instance().get(&DataKey::PendingAdmin)
@@ -40,26 +99,327 @@ pub fn set_pending_admin(env: &Env, admin: &Address) {
 
 pub fn clear_pending_admin(env: &Env) {
     env.storage().instance().remove(&DataKey::PendingAdmin);
+    env.storage().instance().remove(&DataKey::PendingAdminExpiresAt);
 }
 
 pub fn has_pending_admin(env: &Env) -> bool {
     env.storage().instance().has(&DataKey::PendingAdmin)
 }
 
+pub fn get_pending_admin_expires_at(env: &Env) -> Option<u64> {
+    env.storage().instance().get(&DataKey::PendingAdminExpiresAt)
+}
+
+pub fn set_pending_admin_expires_at(env: &Env, expires_at: Option<u64>) {
+    match expires_at {
+        Some(at) => env
+            .storage()
+            .instance()
+            .set(&DataKey::PendingAdminExpiresAt, &at),
+        None => env
+            .storage()
+            .instance()
+            .remove(&DataKey::PendingAdminExpiresAt),
+    }
+}
+
+/// Lifetime of admin and treasury proposals in seconds; 0 means they never
+/// expire.
+pub fn get_proposal_ttl(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::ProposalTtl)
+        .unwrap_or(0)
+}
+
+pub fn set_proposal_ttl(env: &Env, ttl: u64) {
+    env.storage().instance().set(&DataKey::ProposalTtl, &ttl);
+}
+
+/// Expiry for a proposal that becomes actionable at `from`, per the TTL.
+pub fn proposal_expiry(env: &Env, from: u64) -> Result<Option<u64>, Error> {
+    match get_proposal_ttl(env) {
+        0 => Ok(None),
+        ttl => from.checked_add(ttl).map(Some).ok_or(Error::ArithmeticError),
+    }
+}
+
+/// Whether a proposal with `expires_at` is void at the current ledger time.
+pub fn is_proposal_expired(env: &Env, expires_at: Option<u64>) -> bool {
+    matches!(expires_at, Some(at) if env.ledger().timestamp() > at)
+}
+
 // Treasury management
 pub fn get_treasury(env: &Env) -> Address {
     env.storage().instance().get(&DataKey::Treasury).unwrap()
 }
 
+pub fn try_get_treasury(env: &Env) -> Result<Address, Error> {
+    env.storage()
+        .instance()
+        .get(&DataKey::Treasury)
+        .ok_or(Error::NotInitialized)
+}
+
 pub fn set_treasury(env: &Env, treasury: &Address) {
     env.storage().instance().set(&DataKey::Treasury, treasury);
 }
 
+pub fn get_penalty_treasury(env: &Env) -> Option<Address> {
+    env.storage().instance().get(&DataKey::PenaltyTreasury)
+}
+
+pub fn set_penalty_treasury(env: &Env, treasury: Option<Address>) {
+    match treasury {
+        Some(treasury) => env
+            .storage()
+            .instance()
+            .set(&DataKey::PenaltyTreasury, &treasury),
+        None => env.storage().instance().remove(&DataKey::PenaltyTreasury),
+    }
+}
+
+/// Where penalty and burn-fee revenue is routed: the penalty treasury if
+/// configured, otherwise the main treasury. Creation fees always use the
+/// main treasury.
+pub fn get_penalty_destination(env: &Env) -> Result<Address, Error> {
+    match get_penalty_treasury(env) {
+        Some(treasury) => Ok(treasury),
+        None => try_get_treasury(env),
+    }
+}
+
+pub fn get_require_separate_treasury(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&DataKey::RequireSeparateTreasury)
+        .unwrap_or(false)
+}
+
+pub fn set_require_separate_treasury(env: &Env, required: bool) {
+    env.storage()
+        .instance()
+        .set(&DataKey::RequireSeparateTreasury, &required);
+}
+
+/// Reject `admin == treasury` while `RequireSeparateTreasury` is set.
+pub fn check_treasury_separation(
+    env: &Env,
+    admin: &Address,
+    treasury: &Address,
+) -> Result<(), Error> {
+    if admin == treasury && get_require_separate_treasury(env) {
+        return Err(Error::InvalidParameters);
+    }
+    Ok(())
+}
+
+/// Decimals substituted for `None` at creation; `DEFAULT_DECIMALS` until set.
+pub fn get_default_decimals(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::DefaultDecimals)
+        .unwrap_or(crate::token_creation::DEFAULT_DECIMALS)
+}
+
+pub fn set_default_decimals(env: &Env, decimals: u32) {
+    env.storage()
+        .instance()
+        .set(&DataKey::DefaultDecimals, &decimals);
+}
+
+/// Minimum decimals accepted at creation; 0 (no minimum) until set.
+pub fn get_min_decimals(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::MinDecimals)
+        .unwrap_or(0)
+}
+
+pub fn set_min_decimals(env: &Env, decimals: u32) {
+    env.storage().instance().set(&DataKey::MinDecimals, &decimals);
+}
+
+/// Floor applied to each token's creation fee; 0 means no floor.
+pub fn get_min_creation_fee(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::MinCreationFee)
+        .unwrap_or(0)
+}
+
+pub fn set_min_creation_fee(env: &Env, fee: i128) {
+    env.storage().instance().set(&DataKey::MinCreationFee, &fee);
+}
+
+/// Denomination creation fees are rounded up to; 0 means no rounding.
+pub fn get_fee_rounding_unit(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::FeeRoundingUnit)
+        .unwrap_or(0)
+}
+
+pub fn set_fee_rounding_unit(env: &Env, unit: i128) {
+    env.storage().instance().set(&DataKey::FeeRoundingUnit, &unit);
+}
+
+/// Round `fee` up to the next multiple of `FeeRoundingUnit`.
+///
+/// Fees that are already aligned, zero fees and an unset unit pass through
+/// unchanged.
+pub fn round_fee_up(env: &Env, fee: i128) -> i128 {
+    let unit = get_fee_rounding_unit(env);
+    if unit <= 0 || fee <= 0 {
+        return fee;
+    }
+    match fee % unit {
+        0 => fee,
+        rem => fee.saturating_add(unit - rem),
+    }
+}
+
+pub fn is_fee_exempt(env: &Env, creator: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::FeeExempt(creator.clone()))
+}
+
+pub fn set_fee_exempt(env: &Env, creator: &Address, exempt: bool) {
+    let key = DataKey::FeeExempt(creator.clone());
+    if exempt {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+pub fn events_enabled(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&DataKey::EventsEnabled)
+        .unwrap_or(true)
+}
+
+pub fn set_events_enabled(env: &Env, enabled: bool) {
+    env.storage().instance().set(&DataKey::EventsEnabled, &enabled);
+}
+
+pub fn is_creator_allowlist_enabled(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&DataKey::CreatorAllowlistEnabled)
+        .unwrap_or(false)
+}
+
+pub fn set_creator_allowlist_enabled(env: &Env, enabled: bool) {
+    env.storage()
+        .instance()
+        .set(&DataKey::CreatorAllowlistEnabled, &enabled);
+}
+
+pub fn is_allowed_creator(env: &Env, creator: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::AllowedCreator(creator.clone()))
+}
+
+pub fn set_allowed_creator(env: &Env, creator: &Address, allowed: bool) {
+    let key = DataKey::AllowedCreator(creator.clone());
+    if allowed {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+/// Fail with `Error::CreatorNotAllowed` when allowlist mode is on and
+/// `creator` is neither allowlisted nor the admin, who is always allowed.
+pub fn check_creator_allowed(env: &Env, creator: &Address) -> Result<(), Error> {
+    if !is_creator_allowlist_enabled(env) || is_allowed_creator(env, creator) {
+        return Ok(());
+    }
+    if *creator == try_get_admin(env)? {
+        return Ok(());
+    }
+    Err(Error::CreatorNotAllowed)
+}
+
+pub fn is_metadata_required(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&DataKey::RequireMetadata)
+        .unwrap_or(false)
+}
+
+pub fn set_metadata_required(env: &Env, required: bool) {
+    env.storage()
+        .instance()
+        .set(&DataKey::RequireMetadata, &required);
+}
+
+/// Fail with `Error::InvalidParameters` when metadata is required at
+/// creation and `metadata_uri` is missing.
+pub fn check_metadata_required(env: &Env, metadata_uri: &Option<String>) -> Result<(), Error> {
+    if metadata_uri.is_none() && is_metadata_required(env) {
+        return Err(Error::InvalidParameters);
+    }
+    Ok(())
+}
+
+pub fn is_metadata_fee_waived_at_creation(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&DataKey::WaiveMetadataFeeAtCreation)
+        .unwrap_or(false)
+}
+
+pub fn set_metadata_fee_waived_at_creation(env: &Env, waived: bool) {
+    env.storage()
+        .instance()
+        .set(&DataKey::WaiveMetadataFeeAtCreation, &waived);
+}
+
+// Pending treasury (delayed change)
+pub fn get_treasury_change_delay(env: &Env) -> u64 {
+    env.storage()
+        .instance()
+        .get(&DataKey::TreasuryChangeDelay)
+        .unwrap_or(0)
+}
+
+pub fn set_treasury_change_delay(env: &Env, delay: u64) {
+    env.storage()
+        .instance()
+        .set(&DataKey::TreasuryChangeDelay, &delay);
+}
+
+pub fn get_pending_treasury(env: &Env) -> Option<crate::types::PendingTreasury> {
+    env.storage().instance().get(&DataKey::PendingTreasury)
+}
+
+pub fn set_pending_treasury(env: &Env, pending: &crate::types::PendingTreasury) {
+    env.storage()
+        .instance()
+        .set(&DataKey::PendingTreasury, pending);
+}
+
+pub fn clear_pending_treasury(env: &Env) {
+    env.storage().instance().remove(&DataKey::PendingTreasury);
+}
+
 // Fee management
 pub fn get_base_fee(env: &Env) -> i128 {
     env.storage().instance().get(&DataKey::BaseFee).unwrap()
 }
 
+pub fn try_get_base_fee(env: &Env) -> Result<i128, Error> {
+    env.storage()
+        .instance()
+        .get(&DataKey::BaseFee)
+        .ok_or(Error::NotInitialized)
+}
+
 pub fn set_base_fee(env: &Env, fee: i128) {
     env.storage().instance().set(&DataKey::BaseFee, &fee);
 }
@@ -68,11 +428,49 @@ pub fn get_metadata_fee(env: &Env) -> i128 {
     env.storage().instance().get(&DataKey::MetadataFee).unwrap()
 }
 
+pub fn try_get_metadata_fee(env: &Env) -> Result<i128, Error> {
+    env.storage()
+        .instance()
+        .get(&DataKey::MetadataFee)
+        .ok_or(Error::NotInitialized)
+}
+
+pub fn get_token_metadata_fee_override(env: &Env, token_index: u32) -> Option<i128> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::TokenMetadataFee(token_index))
+}
+
+pub fn set_token_metadata_fee_override(env: &Env, token_index: u32, fee: Option<i128>) {
+    let key = DataKey::TokenMetadataFee(token_index);
+    match fee {
+        Some(fee) => env.storage().persistent().set(&key, &fee),
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+/// Metadata fee for `token_index`: its override if set, else the global fee.
+pub fn get_effective_metadata_fee(env: &Env, token_index: u32) -> Result<i128, Error> {
+    match get_token_metadata_fee_override(env, token_index) {
+        Some(fee) => Ok(fee),
+        None => try_get_metadata_fee(env),
+    }
+}
+
 pub fn set_metadata_fee(env: &Env, fee: i128) {
     env.storage().instance().set(&DataKey::MetadataFee, &fee);
 }
 
 // Token registry
+//
+// Tokens occupy the contiguous, zero-based range `0..get_token_count()`:
+// `DataKey::Token(0)` is the first token and `get_token_count() - 1` the
+// last. Iterate with `valid_token_indices` rather than mixing counts and
+// indices by hand.
+
+/// Index assigned to the first token ever created.
+pub const FIRST_TOKEN_INDEX: u32 = 0;
+
 pub fn get_token_count(env: &Env) -> u32 {
     env.storage()
         .instance()
@@ -80,6 +478,21 @@ pub fn get_token_count(env: &Env) -> u32 {
         .unwrap_or(0)
 }
 
+/// Index of the first token, or `None` for an empty registry.
+pub fn first_token_index(env: &Env) -> Option<u32> {
+    last_token_index(env).map(|_| FIRST_TOKEN_INDEX)
+}
+
+/// Index of the most recently created token, or `None` for an empty registry.
+pub fn last_token_index(env: &Env) -> Option<u32> {
+    get_token_count(env).checked_sub(1)
+}
+
+/// Every assigned token index, in creation order. Empty when no tokens exist.
+pub fn valid_token_indices(env: &Env) -> core::ops::Range<u32> {
+    FIRST_TOKEN_INDEX..get_token_count(env)
+}
+
 pub fn get_token_info(env: &Env, index: u32) -> Option<TokenInfo> {
     env.storage().instance().get(&DataKey::Token(index))
 }
@@ -94,12 +507,65 @@ pub fn set_token_info(env: &Env, index: u32, info: &TokenInfo) {
     crate::events::emit_token_registered(env, &info.address, &info.creator);
 }
 
-pub fn increment_token_count(env: &Env) -> Result<u32, Error> {
-    let count = get_token_count(env)
+/// Maximum number of tokens in the registry; 0 means unlimited.
+pub fn get_max_tokens(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::MaxTokens)
+        .unwrap_or(0)
+}
+
+pub fn set_max_tokens(env: &Env, max_tokens: u32) {
+    env.storage().instance().set(&DataKey::MaxTokens, &max_tokens);
+}
+
+/// Fail with `Error::RegistryFull` if `additional` more tokens would
+/// exceed `MaxTokens`.
+pub fn check_registry_capacity(env: &Env, additional: u32) -> Result<(), Error> {
+    let max = get_max_tokens(env);
+    if max == 0 {
+        return Ok(());
+    }
+    let after = get_token_count(env)
+        .checked_add(additional)
+        .ok_or(Error::ArithmeticError)?;
+    if after > max {
+        return Err(Error::RegistryFull);
+    }
+    Ok(())
+}
+
+/// Add one to the `u32` counter under `key` in instance storage and return
+/// the new value. Fails with `Error::ArithmeticError` instead of wrapping,
+/// leaving the stored value unchanged.
+pub fn bump_counter(env: &Env, key: DataKey) -> Result<u32, Error> {
+    let next = env
+        .storage()
+        .instance()
+        .get::<_, u32>(&key)
+        .unwrap_or(0)
         .checked_add(1)
         .ok_or(Error::ArithmeticError)?;
-    env.storage().instance().set(&DataKey::TokenCount, &count);
-    Ok(count)
+    env.storage().instance().set(&key, &next);
+    Ok(next)
+}
+
+/// `bump_counter` for counters kept in persistent storage.
+pub fn bump_persistent_counter(env: &Env, key: DataKey) -> Result<u32, Error> {
+    let next = env
+        .storage()
+        .persistent()
+        .get::<_, u32>(&key)
+        .unwrap_or(0)
+        .checked_add(1)
+        .ok_or(Error::ArithmeticError)?;
+    env.storage().persistent().set(&key, &next);
+    Ok(next)
+}
+
+pub fn increment_token_count(env: &Env) -> Result<u32, Error> {
+    check_registry_capacity(env, 1)?;
+    bump_counter(env, DataKey::TokenCount)
 }
 
 // Get factory state
@@ -113,6 +579,41 @@ pub fn get_factory_state(env: &Env) -> FactoryState {
     }
 }
 
+/// Non-trapping variant of [`get_factory_state`].
+pub fn try_get_factory_state(env: &Env) -> Result<FactoryState, Error> {
+    Ok(FactoryState {
+        admin: try_get_admin(env)?,
+        treasury: try_get_treasury(env)?,
+        base_fee: try_get_base_fee(env)?,
+        metadata_fee: try_get_metadata_fee(env)?,
+        paused: is_paused(env),
+    })
+}
+
+pub fn get_full_state(env: &Env) -> Result<crate::types::FullState, Error> {
+    let state = try_get_factory_state(env)?;
+    Ok(crate::types::FullState {
+        admin: state.admin,
+        treasury: state.treasury,
+        base_fee: state.base_fee,
+        metadata_fee: state.metadata_fee,
+        paused: state.paused,
+        schema_version: get_schema_version(env),
+        contract_version: get_contract_version(env),
+        token_count: get_token_count(env),
+        burn_count: get_global_burn_count(env),
+    })
+}
+
+pub fn get_payment_info(env: &Env) -> Result<crate::types::PaymentInfo, Error> {
+    Ok(crate::types::PaymentInfo {
+        fee_token: get_fee_token(env),
+        treasury: try_get_treasury(env)?,
+        base_fee: try_get_base_fee(env)?,
+        metadata_fee: try_get_metadata_fee(env)?,
+    })
+}
+
 /// ============================================================
 ///  Security Test Suite — Burn Feature (Issue #163)
 ///  Temporarily disabled due to compilation errors with Result types
@@ -584,23 +1085,192 @@ mod burn_security_tests {
     }
 }
 */
-// ── Burn feature additions ─────────────────────────────────
-
-pub fn get_balance(env: &Env, token_index: u32, holder: &Address) -> i128 {
+pub fn get_token_largest_burn(env: &Env, token_index: u32) -> i128 {
     env.storage()
         .persistent()
-        .get(&crate::types::DataKey::Balance(token_index, holder.clone()))
+        .get(&DataKey::TokenLargestBurn(token_index))
         .unwrap_or(0)
 }
 
-pub fn set_balance(env: &Env, token_index: u32, holder: &Address, balance: i128) {
-    env.storage().persistent().set(
-        &crate::types::DataKey::Balance(token_index, holder.clone()),
-        &balance,
-    );
-}
-
-pub fn get_burn_count(env: &Env, token_index: u32) -> u32 {
+pub fn get_last_burn_at(env: &Env, token_index: u32) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::LastBurnAt(token_index))
+}
+
+/// Seconds per UTC day used to bucket `DailyBurn` totals
+pub const SECONDS_PER_DAY: u64 = 86_400;
+
+/// UTC day index (days since the Unix epoch) for `timestamp`.
+pub fn day_index(timestamp: u64) -> u32 {
+    (timestamp / SECONDS_PER_DAY) as u32
+}
+
+pub fn get_daily_burn(env: &Env, token_index: u32, day: u32) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::DailyBurn(token_index, day))
+        .unwrap_or(0)
+}
+
+/// Number of burns across all tokens on UTC day `day`.
+pub fn get_daily_burn_count(env: &Env, day: u32) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::DailyBurnCount(day))
+        .unwrap_or(0)
+}
+
+/// Update the per-token largest burn, last-burn timestamp and daily total,
+/// and the global daily burn count, for one burn.
+pub fn record_token_burn_stats(env: &Env, token_index: u32, amount: i128) -> Result<(), Error> {
+    if amount > get_token_largest_burn(env, token_index) {
+        env.storage()
+            .persistent()
+            .set(&DataKey::TokenLargestBurn(token_index), &amount);
+    }
+    let now = env.ledger().timestamp();
+    env.storage()
+        .persistent()
+        .set(&DataKey::LastBurnAt(token_index), &now);
+
+    let day = day_index(now);
+    let daily = get_daily_burn(env, token_index, day)
+        .checked_add(amount)
+        .ok_or(Error::ArithmeticError)?;
+    env.storage()
+        .persistent()
+        .set(&DataKey::DailyBurn(token_index, day), &daily);
+    bump_persistent_counter(env, DataKey::DailyBurnCount(day))?;
+    Ok(())
+}
+
+pub fn get_global_burn_count(env: &Env) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::GlobalBurnCount)
+        .unwrap_or(0)
+}
+
+pub fn add_global_burn_count(env: &Env, burns: u32) -> Result<(), Error> {
+    let count = get_global_burn_count(env)
+        .checked_add(burns)
+        .ok_or(Error::ArithmeticError)?;
+    env.storage()
+        .persistent()
+        .set(&DataKey::GlobalBurnCount, &count);
+    Ok(())
+}
+
+/// Amount of `token_index` burned and credited to `account`.
+pub fn get_account_burned(env: &Env, token_index: u32, account: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::AccountBurned(token_index, account.clone()))
+        .unwrap_or(0)
+}
+
+pub fn add_account_burned(
+    env: &Env,
+    token_index: u32,
+    account: &Address,
+    amount: i128,
+) -> Result<(), Error> {
+    let total = get_account_burned(env, token_index, account)
+        .checked_add(amount)
+        .ok_or(Error::ArithmeticError)?;
+    env.storage()
+        .persistent()
+        .set(&DataKey::AccountBurned(token_index, account.clone()), &total);
+    Ok(())
+}
+
+/// Burn attribution policy. Defaults to `false`: the holder whose balance
+/// was debited is credited, even when an admin executed the burn.
+pub fn get_credit_burn_executor(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&DataKey::CreditBurnExecutor)
+        .unwrap_or(false)
+}
+
+pub fn set_credit_burn_executor(env: &Env, credit_executor: bool) {
+    env.storage()
+        .instance()
+        .set(&DataKey::CreditBurnExecutor, &credit_executor);
+}
+
+/// Account a burn is credited to under the attribution policy.
+fn credited_account<'a>(env: &Env, holder: &'a Address, burned_by: &'a Address) -> &'a Address {
+    if get_credit_burn_executor(env) {
+        burned_by
+    } else {
+        holder
+    }
+}
+
+/// Credit one burn to the holder or the executor per the attribution policy.
+pub fn credit_account_burned(
+    env: &Env,
+    token_index: u32,
+    holder: &Address,
+    burned_by: &Address,
+    amount: i128,
+) -> Result<(), Error> {
+    let account = credited_account(env, holder, burned_by);
+    add_account_burned(env, token_index, account, amount)
+}
+
+/// Check that every counter a single logged burn bumps has room for it,
+/// without writing anything.
+///
+/// The `add_*` helpers each read, add and write in turn, so one of them
+/// overflowing part-way through a burn would leave the earlier writes in
+/// place. Single burns run this before their first write; `to_dead` adds the
+/// dead-address total for `SendToDead` tokens.
+pub fn check_burn_counters(
+    env: &Env,
+    token_index: u32,
+    holder: &Address,
+    burned_by: &Address,
+    amount: i128,
+    to_dead: bool,
+) -> Result<(), Error> {
+    let day = day_index(env.ledger().timestamp());
+    let account = credited_account(env, holder, burned_by);
+    let fits = get_burn_count(env, token_index).checked_add(1).is_some()
+        && get_total_burned(env, token_index).checked_add(amount).is_some()
+        && get_global_burn_count(env).checked_add(1).is_some()
+        && get_daily_burn(env, token_index, day).checked_add(amount).is_some()
+        && get_daily_burn_count(env, day).checked_add(1).is_some()
+        && get_account_burned(env, token_index, account).checked_add(amount).is_some()
+        && (!to_dead || get_dead_burned(env, token_index).checked_add(amount).is_some())
+        && get_burn_record_count(env).checked_add(1).is_some()
+        && get_token_burn_record_count(env, token_index).checked_add(1).is_some()
+        && get_token_ledger_count(env, token_index).checked_add(1).is_some();
+    if !fits {
+        return Err(Error::ArithmeticError);
+    }
+    Ok(())
+}
+
+// ── Burn feature additions ─────────────────────────────────
+
+pub fn get_balance(env: &Env, token_index: u32, holder: &Address) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&crate::types::DataKey::Balance(token_index, holder.clone()))
+        .unwrap_or(0)
+}
+
+pub fn set_balance(env: &Env, token_index: u32, holder: &Address, balance: i128) {
+    env.storage().persistent().set(
+        &crate::types::DataKey::Balance(token_index, holder.clone()),
+        &balance,
+    );
+}
+
+pub fn get_burn_count(env: &Env, token_index: u32) -> u32 {
     env.storage()
         .persistent()
         .get(&crate::types::DataKey::BurnCount(token_index))
@@ -608,46 +1278,563 @@ pub fn get_burn_count(env: &Env, token_index: u32) -> u32 {
 }
 
 pub fn increment_burn_count(env: &Env, token_index: u32) -> Result<(), Error> {
-    let count = get_burn_count(env, token_index)
-        .checked_add(1)
+    bump_persistent_counter(env, DataKey::BurnCount(token_index))?;
+    Ok(())
+}
+
+pub fn add_burn_count(env: &Env, token_index: u32, count: u32) -> Result<(), Error> {
+    let updated = get_burn_count(env, token_index)
+        .checked_add(count)
         .ok_or(Error::ArithmeticError)?;
     env.storage()
         .persistent()
-        .set(&crate::types::DataKey::BurnCount(token_index), &count);
+        .set(&DataKey::BurnCount(token_index), &updated);
+    Ok(())
+}
+
+/// Burn total carried over by `migrate_token_aggregates`; still in supply.
+pub fn get_inherited_burned(env: &Env, token_index: u32) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::InheritedBurned(token_index))
+        .unwrap_or(0)
+}
+
+pub fn add_inherited_burned(env: &Env, token_index: u32, amount: i128) -> Result<(), Error> {
+    let updated = get_inherited_burned(env, token_index)
+        .checked_add(amount)
+        .ok_or(Error::ArithmeticError)?;
+    env.storage()
+        .persistent()
+        .set(&DataKey::InheritedBurned(token_index), &updated);
     Ok(())
 }
 
 // ── Burn feature additions ─────────────────────────────────
 
+// ── Global burn log ───────────────────────────────────────
+
+pub fn get_burn_record_count(env: &Env) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::BurnRecordCount)
+        .unwrap_or(0)
+}
+
+pub fn get_burn_record(env: &Env, index: u32) -> Option<BurnRecord> {
+    env.storage().persistent().get(&DataKey::BurnRecord(index))
+}
+
+/// Append a record to the global burn log, returning its index.
+///
+/// Once the token has reached `MaxBurnRecordsPerToken` records, nothing is
+/// written and `None` is returned. Records are never overwritten: a ring
+/// buffer would reuse global indices and break offset-based readers of the
+/// log, while the per-token aggregates (`total_burned`, `burn_count`) are
+/// maintained by the callers regardless.
+pub fn add_burn_record(env: &Env, record: &BurnRecord) -> Result<Option<u32>, Error> {
+    let token_records = get_token_burn_record_count(env, record.token_index);
+    if let Some(max) = get_max_burn_records_per_token(env) {
+        if token_records >= max {
+            return Ok(None);
+        }
+    }
+
+    let index = get_burn_record_count(env);
+    let next = index.checked_add(1).ok_or(Error::ArithmeticError)?;
+    let next_token_records = token_records
+        .checked_add(1)
+        .ok_or(Error::ArithmeticError)?;
+    env.storage()
+        .persistent()
+        .set(&DataKey::BurnRecord(index), record);
+    env.storage()
+        .persistent()
+        .set(&DataKey::BurnRecordCount, &next);
+    env.storage().persistent().set(
+        &DataKey::TokenBurnRecordCount(record.token_index),
+        &next_token_records,
+    );
+    push_token_ledger_entry(env, record.token_index, SupplyEventRef::Burn(index))?;
+
+    let day_key = DataKey::BurnDayIndex(record.token_index, day_index(record.timestamp));
+    if !env.storage().persistent().has(&day_key) {
+        env.storage().persistent().set(&day_key, &index);
+    }
+    Ok(Some(index))
+}
+
+/// Burn-log index of `token_index`'s first record on `day`, if any.
+pub fn get_burn_day_index(env: &Env, token_index: u32, day: u32) -> Option<u32> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::BurnDayIndex(token_index, day))
+}
+
+/// Burn-log index of the record tagged with `correlation_id`.
+pub fn get_burn_correlation(env: &Env, correlation_id: u64) -> Option<u32> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::BurnByCorrelation(correlation_id))
+}
+
+pub fn set_burn_correlation(env: &Env, correlation_id: u64, record_index: u32) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::BurnByCorrelation(correlation_id), &record_index);
+}
+
+pub fn get_largest_burn(env: &Env) -> Option<BurnRecord> {
+    env.storage().persistent().get(&DataKey::LargestBurn)
+}
+
+/// Replace the largest-burn record if `record` is strictly larger. Ties
+/// keep the earlier burn.
+pub fn update_largest_burn(env: &Env, record: &BurnRecord) {
+    let is_larger = get_largest_burn(env).is_none_or(|current| record.amount > current.amount);
+    if is_larger {
+        env.storage().persistent().set(&DataKey::LargestBurn, record);
+    }
+}
+
+/// Number of burn-log records written for `token_index`.
+pub fn get_token_burn_record_count(env: &Env, token_index: u32) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::TokenBurnRecordCount(token_index))
+        .unwrap_or(0)
+}
+
+pub fn get_max_burn_records_per_token(env: &Env) -> Option<u32> {
+    env.storage()
+        .instance()
+        .get(&DataKey::MaxBurnRecordsPerToken)
+}
+
+pub fn set_max_burn_records_per_token(env: &Env, max: Option<u32>) {
+    match max {
+        Some(max) => env
+            .storage()
+            .instance()
+            .set(&DataKey::MaxBurnRecordsPerToken, &max),
+        None => env
+            .storage()
+            .instance()
+            .remove(&DataKey::MaxBurnRecordsPerToken),
+    }
+}
+
+// ── Fee change audit log ──────────────────────────────────
+
+pub fn get_fee_change_count(env: &Env) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::FeeChangeCount)
+        .unwrap_or(0)
+}
+
+pub fn get_fee_change(env: &Env, index: u32) -> Option<FeeChange> {
+    env.storage().persistent().get(&DataKey::FeeChange(index))
+}
+
+/// Append a fee change to the audit log, returning its index.
+pub fn add_fee_change(env: &Env, change: &FeeChange) -> Result<u32, Error> {
+    let index = get_fee_change_count(env);
+    let next = index.checked_add(1).ok_or(Error::ArithmeticError)?;
+    env.storage()
+        .persistent()
+        .set(&DataKey::FeeChange(index), change);
+    env.storage()
+        .persistent()
+        .set(&DataKey::FeeChangeCount, &next);
+    Ok(index)
+}
+
+// ── Signed-operation nonces ───────────────────────────────
+
+pub fn get_nonce(env: &Env, account: &Address) -> u64 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::Nonce(account.clone()))
+        .unwrap_or(0)
+}
+
+/// Consume `nonce` for `account`; it must equal the current stored value.
+pub fn consume_nonce(env: &Env, account: &Address, nonce: u64) -> Result<(), Error> {
+    let current = get_nonce(env, account);
+    if nonce != current {
+        return Err(Error::BadNonce);
+    }
+    let next = current.checked_add(1).ok_or(Error::ArithmeticError)?;
+    env.storage()
+        .persistent()
+        .set(&DataKey::Nonce(account.clone()), &next);
+    Ok(())
+}
+
 // ── Token-level pause ─────────────────────────────────────
 
-pub fn is_token_paused(env: &Env, token_index: u32) -> bool {
+pub fn is_token_paused(env: &Env, token_index: u32) -> bool {
+    env.storage()
+        .instance()
+        .get(&crate::types::DataKey::TokenPaused(token_index))
+        .unwrap_or(false)
+}
+
+pub fn set_token_paused(env: &Env, token_index: u32, paused: bool) {
+    env.storage()
+        .instance()
+        .set(&crate::types::DataKey::TokenPaused(token_index), &paused);
+}
+
+/// An admin-placed pause can only be lifted by the admin.
+pub fn is_token_admin_paused(env: &Env, token_index: u32) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::TokenAdminPaused(token_index))
+}
+
+pub fn set_token_admin_paused(env: &Env, token_index: u32, admin_paused: bool) {
+    let key = DataKey::TokenAdminPaused(token_index);
+    if admin_paused {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+// ── Token-level freeze ────────────────────────────────────
+
+pub fn is_token_frozen(env: &Env, token_index: u32) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::TokenFrozen(token_index))
+        .unwrap_or(false)
+}
+
+pub fn set_token_frozen(env: &Env, token_index: u32, frozen: bool) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::TokenFrozen(token_index), &frozen);
+}
+
+// ── Token retirement ──────────────────────────────────────
+
+pub fn is_token_retired(env: &Env, token_index: u32) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::TokenRetired(token_index))
+}
+
+pub fn set_token_retired(env: &Env, token_index: u32) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::TokenRetired(token_index), &true);
+}
+
+/// Fail with `Error::TokenRetired` once `token_index` has been retired.
+pub fn ensure_not_retired(env: &Env, token_index: u32) -> Result<(), Error> {
+    if is_token_retired(env, token_index) {
+        return Err(Error::TokenRetired);
+    }
+    Ok(())
+}
+
+// ── Storage footprint estimate ────────────────────────────
+
+/// Approximate number of storage entries tied to a token, or `None` for an
+/// unknown index.
+///
+/// Counts the two `TokenInfo` copies, one history entry per metadata
+/// version, the optional metadata settings and tags, the per-token burn
+/// aggregates and the token's records in the burn log. Uses stored counters and a
+/// fixed set of `has` checks, so the cost does not grow with the token's
+/// history. Balances, snapshots and daily rollups are not included.
+pub fn estimate_token_storage(env: &Env, token_index: u32) -> Option<u32> {
+    let info = get_token_info(env, token_index)?;
+    let persistent = env.storage().persistent();
+
+    let present = token_persistent_keys(token_index)
+        .iter()
+        .filter(|key| persistent.has(*key))
+        .count() as u32;
+
+    Some(
+        2u32.saturating_add(info.metadata_version)
+            .saturating_add(present)
+            .saturating_add(get_token_burn_record_count(env, token_index)),
+    )
+}
+
+/// Number of entries returned by `token_persistent_keys`
+const TOKEN_PERSISTENT_KEY_COUNT: usize = 15;
+
+/// Optional persistent entries keyed by a single token index
+fn token_persistent_keys(token_index: u32) -> [DataKey; TOKEN_PERSISTENT_KEY_COUNT] {
+    [
+        DataKey::MetadataHash(token_index),
+        DataKey::TokenTags(token_index),
+        DataKey::MetadataEditableUntil(token_index),
+        DataKey::MetadataFrozen(token_index),
+        DataKey::BurnCount(token_index),
+        DataKey::TotalBurned(token_index),
+        DataKey::TotalMinted(token_index),
+        DataKey::TokenLargestBurn(token_index),
+        DataKey::LastBurnAt(token_index),
+        DataKey::TokenBurnRecordCount(token_index),
+        DataKey::TokenLedgerCount(token_index),
+        DataKey::TokenAdminPaused(token_index),
+        DataKey::BurnMode(token_index),
+        DataKey::BurnRestricted(token_index),
+        DataKey::TokenFrozen(token_index),
+    ]
+}
+
+// ── Token TTL maintenance ─────────────────────────────────
+
+/// Ledger entries one invocation may read or write
+const MAX_FOOTPRINT_ENTRIES: u32 = 100;
+
+/// Footprint left for the instance entry and other fixed reads of a call
+const FOOTPRINT_RESERVE: u32 = 10;
+
+/// Most tokens `bump_tokens_ttl` visits per call.
+///
+/// Every visited token puts all of its `token_persistent_keys` in the
+/// footprint, present or not, so the batch is sized to keep one call
+/// within the per-invocation entry limit.
+pub const MAX_TTL_BUMP_BATCH: u32 =
+    (MAX_FOOTPRINT_ENTRIES - FOOTPRINT_RESERVE) / TOKEN_PERSISTENT_KEY_COUNT as u32;
+
+/// Extend TTLs for the tokens in `[start, start + limit)` (limit clamped).
+///
+/// `TokenInfo` lives in instance storage, whose TTL is extended once per
+/// call; each visited token's present per-token persistent entries are
+/// extended individually. Metadata history, balances and burn-log records
+/// are left alone. Returns the number of tokens visited.
+pub fn bump_tokens_ttl(env: &Env, start: u32, limit: u32, threshold: u32, extend_to: u32) -> u32 {
+    let indices = valid_token_indices(env);
+    let begin = start.max(indices.start);
+    let end = begin
+        .saturating_add(limit.min(MAX_TTL_BUMP_BATCH))
+        .min(indices.end);
+    if begin >= end {
+        return 0;
+    }
+
+    env.storage().instance().extend_ttl(threshold, extend_to);
+
+    let persistent = env.storage().persistent();
+    for token_index in begin..end {
+        for key in token_persistent_keys(token_index).iter() {
+            if persistent.has(key) {
+                persistent.extend_ttl(key, threshold, extend_to);
+            }
+        }
+    }
+
+    end - begin
+}
+
+pub fn get_total_burned(env: &Env, token_index: u32) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&crate::types::DataKey::TotalBurned(token_index))
+        .unwrap_or(0)
+}
+
+pub fn add_total_burned(env: &Env, token_index: u32, amount: i128) -> Result<(), Error> {
+    let updated = get_total_burned(env, token_index)
+        .checked_add(amount)
+        .ok_or(Error::ArithmeticError)?;
+    env.storage()
+        .persistent()
+        .set(&crate::types::DataKey::TotalBurned(token_index), &updated);
+    Ok(())
+}
+
+/// Amount minted for `token_index` on top of its initial supply.
+pub fn get_total_minted(env: &Env, token_index: u32) -> i128 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::TotalMinted(token_index))
+        .unwrap_or(0)
+}
+
+pub fn add_total_minted(env: &Env, token_index: u32, amount: i128) -> Result<(), Error> {
+    let updated = get_total_minted(env, token_index)
+        .checked_add(amount)
+        .ok_or(Error::ArithmeticError)?;
+    env.storage()
+        .persistent()
+        .set(&DataKey::TotalMinted(token_index), &updated);
+    Ok(())
+}
+
+// ── Global mint log ───────────────────────────────────────
+
+pub fn get_mint_record_count(env: &Env) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::MintRecordCount)
+        .unwrap_or(0)
+}
+
+pub fn get_mint_record(env: &Env, index: u32) -> Option<MintRecord> {
+    env.storage().persistent().get(&DataKey::MintRecord(index))
+}
+
+/// Append a mint of `amount` to `to` to the global mint log, returning its index.
+pub fn add_mint_record(
+    env: &Env,
+    token_index: u32,
+    to: &Address,
+    amount: i128,
+) -> Result<u32, Error> {
+    let index = get_mint_record_count(env);
+    let next = index.checked_add(1).ok_or(Error::ArithmeticError)?;
+    let record = MintRecord {
+        token_index,
+        to: to.clone(),
+        amount,
+        timestamp: env.ledger().timestamp(),
+        burn_record_count: get_burn_record_count(env),
+    };
+    env.storage()
+        .persistent()
+        .set(&DataKey::MintRecord(index), &record);
+    env.storage()
+        .persistent()
+        .set(&DataKey::MintRecordCount, &next);
+    push_token_ledger_entry(env, token_index, SupplyEventRef::Mint(index))?;
+    Ok(index)
+}
+
+// ── Per-token supply ledger index ─────────────────────────
+
+/// Number of mint and burn log entries indexed for `token_index`.
+pub fn get_token_ledger_count(env: &Env, token_index: u32) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::TokenLedgerCount(token_index))
+        .unwrap_or(0)
+}
+
+pub fn get_token_ledger_entry(env: &Env, token_index: u32, slot: u32) -> Option<SupplyEventRef> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::TokenLedgerEntry(token_index, slot))
+}
+
+/// Append a log position to `token_index`'s ledger index. Called as each
+/// mint or burn record is written, so slots are in the order events happened.
+fn push_token_ledger_entry(env: &Env, token_index: u32, entry: SupplyEventRef) -> Result<(), Error> {
+    let slot = get_token_ledger_count(env, token_index);
+    let next = slot.checked_add(1).ok_or(Error::ArithmeticError)?;
+    env.storage()
+        .persistent()
+        .set(&DataKey::TokenLedgerEntry(token_index, slot), &entry);
+    env.storage()
+        .persistent()
+        .set(&DataKey::TokenLedgerCount(token_index), &next);
+    Ok(())
+}
+
+pub fn get_burn_mode(env: &Env, token_index: u32) -> BurnMode {
+    env.storage()
+        .persistent()
+        .get(&DataKey::BurnMode(token_index))
+        .unwrap_or(BurnMode::ReduceSupply)
+}
+
+pub fn set_burn_mode(env: &Env, token_index: u32, mode: &BurnMode) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::BurnMode(token_index), mode);
+}
+
+pub fn is_burn_restricted(env: &Env, token_index: u32) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::BurnRestricted(token_index))
+        .unwrap_or(false)
+}
+
+pub fn set_burn_restricted(env: &Env, token_index: u32, restricted: bool) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::BurnRestricted(token_index), &restricted);
+}
+
+pub fn is_burn_whitelisted(env: &Env, token_index: u32, account: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::BurnWhitelist(token_index, account.clone()))
+}
+
+pub fn set_burn_whitelisted(env: &Env, token_index: u32, account: &Address, allowed: bool) {
+    let key = DataKey::BurnWhitelist(token_index, account.clone());
+    if allowed {
+        env.storage().persistent().set(&key, &true);
+    } else {
+        env.storage().persistent().remove(&key);
+    }
+}
+
+/// Fail with `Error::Unauthorized` if `token_index` is burn-restricted and
+/// `burner` is neither whitelisted nor the admin.
+pub fn check_burner_allowed(env: &Env, token_index: u32, burner: &Address) -> Result<(), Error> {
+    if !is_burn_restricted(env, token_index) || is_burn_whitelisted(env, token_index, burner) {
+        return Ok(());
+    }
+    if *burner != try_get_admin(env)? {
+        return Err(Error::Unauthorized);
+    }
+    Ok(())
+}
+
+/// Seconds a token must exist before holders may burn it; 0 means no delay.
+pub fn get_min_age_before_burn(env: &Env) -> u64 {
     env.storage()
         .instance()
-        .get(&crate::types::DataKey::TokenPaused(token_index))
-        .unwrap_or(false)
+        .get(&DataKey::MinAgeBeforeBurn)
+        .unwrap_or(0)
 }
 
-pub fn set_token_paused(env: &Env, token_index: u32, paused: bool) {
+pub fn set_min_age_before_burn(env: &Env, seconds: u64) {
     env.storage()
         .instance()
-        .set(&crate::types::DataKey::TokenPaused(token_index), &paused);
+        .set(&DataKey::MinAgeBeforeBurn, &seconds);
 }
 
-pub fn get_total_burned(env: &Env, token_index: u32) -> i128 {
+/// Fail with `Error::TokenTooNew` before `created_at + MinAgeBeforeBurn`.
+/// Only holder burns call this; admin burns bypass the delay.
+pub fn check_burn_age(env: &Env, info: &TokenInfo) -> Result<(), Error> {
+    let burnable_at = info.created_at.saturating_add(get_min_age_before_burn(env));
+    if env.ledger().timestamp() < burnable_at {
+        return Err(Error::TokenTooNew);
+    }
+    Ok(())
+}
+
+/// Amount of `token_index` burned to a dead address; still in `total_supply`.
+pub fn get_dead_burned(env: &Env, token_index: u32) -> i128 {
     env.storage()
         .persistent()
-        .get(&crate::types::DataKey::TotalBurned(token_index))
+        .get(&DataKey::DeadBurned(token_index))
         .unwrap_or(0)
 }
 
-pub fn add_total_burned(env: &Env, token_index: u32, amount: i128) {
-    let current = get_total_burned(env, token_index);
-    let updated = current.checked_add(amount).unwrap_or(i128::MAX);
+pub fn add_dead_burned(env: &Env, token_index: u32, amount: i128) -> Result<(), Error> {
+    let updated = get_dead_burned(env, token_index)
+        .checked_add(amount)
+        .ok_or(Error::ArithmeticError)?;
     env.storage()
         .persistent()
-        .set(&crate::types::DataKey::TotalBurned(token_index), &updated);
+        .set(&DataKey::DeadBurned(token_index), &updated);
+    Ok(())
 }
+
 // Pause management
 pub fn is_paused(env: &Env) -> bool {
     env.storage()
@@ -660,6 +1847,26 @@ pub fn set_paused(env: &Env, paused: bool) {
     env.storage().instance().set(&DataKey::Paused, &paused);
 }
 
+/// Whether the emergency kill switch is engaged.
+pub fn is_killed(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&DataKey::Killed)
+        .unwrap_or(false)
+}
+
+pub fn set_killed(env: &Env, killed: bool) {
+    env.storage().instance().set(&DataKey::Killed, &killed);
+}
+
+/// Fail with `Error::ContractKilled` while the kill switch is engaged.
+pub fn ensure_not_killed(env: &Env) -> Result<(), Error> {
+    if is_killed(env) {
+        return Err(Error::ContractKilled);
+    }
+    Ok(())
+}
+
 // Token lookup by address
 pub fn get_token_info_by_address(env: &Env, token_address: &Address) -> Option<TokenInfo> {
     env.storage()
@@ -673,6 +1880,20 @@ pub fn set_token_info_by_address(env: &Env, token_address: &Address, info: &Toke
         .set(&DataKey::TokenByAddress(token_address.clone()), info);
 }
 
+/// Index of the token registered at `token_address`, if any.
+pub fn get_token_index_by_address(env: &Env, token_address: &Address) -> Option<u32> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::TokenIndexByAddress(token_address.clone()))
+}
+
+pub fn set_token_index_by_address(env: &Env, token_address: &Address, token_index: u32) {
+    env.storage().persistent().set(
+        &DataKey::TokenIndexByAddress(token_address.clone()),
+        &token_index,
+    );
+}
+
 // Update token supply after burn
 pub fn update_token_supply(env: &Env, token_address: &Address, amount_change: i128) -> Option<()> {
     let mut info = get_token_info_by_address(env, token_address)?;
@@ -712,6 +1933,301 @@ pub fn get_admin_state(env: &Env) -> (Address, bool) {
     (admin, paused)
 }
 
+/// Assemble the admin dashboard view from individual config keys.
+pub fn get_admin_config(env: &Env) -> Result<crate::types::AdminConfig, Error> {
+    let policy = get_treasury_policy(env);
+    let timelock = get_timelock_config(env);
+    Ok(crate::types::AdminConfig {
+        admin: try_get_admin(env)?,
+        pending_admin: get_pending_admin(env),
+        treasury: try_get_treasury(env)?,
+        base_fee: try_get_base_fee(env)?,
+        metadata_fee: try_get_metadata_fee(env)?,
+        paused: is_paused(env),
+        withdrawal_cap: policy.daily_cap,
+        withdrawal_period: policy.period_duration,
+        allowlist_enabled: policy.allowlist_enabled,
+        timelock_delay: timelock.delay_seconds,
+        timelock_enabled: timelock.enabled,
+        max_metadata_uri_len: get_max_metadata_uri_len(env),
+        max_description_len: get_max_description_len(env),
+        min_initial_supply: get_min_initial_supply(env),
+        max_initial_supply: get_max_initial_supply(env),
+        max_burn_records_per_token: get_max_burn_records_per_token(env),
+        default_metadata_uri: get_default_metadata_uri(env),
+        fee_token: get_fee_token(env),
+        treasury_change_delay: get_treasury_change_delay(env),
+        min_creation_fee: get_min_creation_fee(env),
+    })
+}
+
+pub fn get_token_dump(env: &Env, token_index: u32) -> Option<crate::types::TokenDump> {
+    let mut info = get_token_info(env, token_index)?;
+    info.is_paused = is_token_paused(env, token_index);
+    let effective_metadata_uri = info
+        .metadata_uri
+        .clone()
+        .or_else(|| get_default_metadata_uri(env));
+
+    Some(crate::types::TokenDump {
+        info,
+        effective_metadata_uri,
+        metadata_locked: is_metadata_locked(env, token_index),
+        metadata_editable_until: get_metadata_editable_until(env, token_index),
+        frozen: is_token_frozen(env, token_index),
+        burn_count: get_burn_count(env, token_index),
+        total_burned: get_total_burned(env, token_index),
+        burn_record_count: get_token_burn_record_count(env, token_index),
+        total_minted: get_total_minted(env, token_index),
+        largest_burn: get_token_largest_burn(env, token_index),
+        last_burn_at: get_last_burn_at(env, token_index),
+        holder_count: get_holder_count(env, token_index),
+        holder_reporter: get_holder_reporter(env, token_index),
+        tags: get_token_tags(env, token_index),
+    })
+}
+
+// ── Metadata length limits ────────────────────────────────
+
+pub fn get_max_metadata_uri_len(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::MaxMetadataUriLen)
+        .unwrap_or(256)
+}
+
+pub fn set_max_metadata_uri_len(env: &Env, len: u32) {
+    env.storage().instance().set(&DataKey::MaxMetadataUriLen, &len);
+}
+
+pub fn get_max_description_len(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::MaxDescriptionLen)
+        .unwrap_or(1024)
+}
+
+pub fn set_max_description_len(env: &Env, len: u32) {
+    env.storage().instance().set(&DataKey::MaxDescriptionLen, &len);
+}
+
+// ── Metadata edit window ──────────────────────────────────
+
+pub fn get_metadata_editable_until(env: &Env, token_index: u32) -> Option<u64> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::MetadataEditableUntil(token_index))
+}
+
+pub fn set_metadata_editable_until(env: &Env, token_index: u32, deadline: Option<u64>) {
+    let key = DataKey::MetadataEditableUntil(token_index);
+    match deadline {
+        Some(deadline) => env.storage().persistent().set(&key, &deadline),
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+pub fn set_metadata_frozen(env: &Env, token_index: u32) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::MetadataFrozen(token_index), &true);
+}
+
+/// Whether `token_index`'s metadata was explicitly frozen. Tokens without
+/// the flag, including ones from before it existed, read as `false`.
+pub fn is_metadata_frozen(env: &Env, token_index: u32) -> bool {
+    env.storage()
+        .persistent()
+        .get(&DataKey::MetadataFrozen(token_index))
+        .unwrap_or(false)
+}
+
+/// Whether a token's first burn freezes its metadata. Defaults to `false`.
+pub fn get_auto_freeze_metadata_on_burn(env: &Env) -> bool {
+    env.storage()
+        .instance()
+        .get(&DataKey::AutoFreezeMetadataOnBurn)
+        .unwrap_or(false)
+}
+
+pub fn set_auto_freeze_metadata_on_burn(env: &Env, enabled: bool) {
+    env.storage()
+        .instance()
+        .set(&DataKey::AutoFreezeMetadataOnBurn, &enabled);
+}
+
+/// Freeze `token_index`'s metadata if auto-freeze is on and it isn't
+/// frozen yet. Called by every burn path after the burn is recorded.
+pub fn freeze_metadata_on_burn(env: &Env, token_index: u32) {
+    if get_auto_freeze_metadata_on_burn(env) && !is_metadata_frozen(env, token_index) {
+        set_metadata_frozen(env, token_index);
+    }
+}
+
+/// Metadata is locked once frozen explicitly or once the ledger time has
+/// passed the token's `metadata_editable_until` deadline.
+pub fn is_metadata_locked(env: &Env, token_index: u32) -> bool {
+    is_metadata_frozen(env, token_index)
+        || get_metadata_editable_until(env, token_index)
+            .is_some_and(|deadline| env.ledger().timestamp() > deadline)
+}
+
+// ── Metadata update cap ───────────────────────────────────
+
+pub fn get_max_metadata_updates(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::MaxMetadataUpdates)
+        .unwrap_or(0)
+}
+
+pub fn set_max_metadata_updates(env: &Env, max: u32) {
+    env.storage()
+        .instance()
+        .set(&DataKey::MaxMetadataUpdates, &max);
+}
+
+pub fn get_metadata_update_count(env: &Env, token_index: u32) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::MetadataUpdateCount(token_index))
+        .unwrap_or(0)
+}
+
+pub fn increment_metadata_update_count(env: &Env, token_index: u32) -> Result<(), Error> {
+    bump_persistent_counter(env, DataKey::MetadataUpdateCount(token_index))?;
+    Ok(())
+}
+
+pub fn reset_metadata_update_count(env: &Env, token_index: u32) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::MetadataUpdateCount(token_index));
+}
+
+/// Fail with `Error::MetadataAlreadySet` once `token_index` has used up the
+/// configured number of metadata updates.
+pub fn check_metadata_update_allowed(env: &Env, token_index: u32) -> Result<(), Error> {
+    let max = get_max_metadata_updates(env);
+    if max > 0 && get_metadata_update_count(env, token_index) >= max {
+        return Err(Error::MetadataAlreadySet);
+    }
+    Ok(())
+}
+
+// ── Metadata content hash ─────────────────────────────────
+
+pub fn get_metadata_hash(env: &Env, token_index: u32) -> Option<BytesN<32>> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::MetadataHash(token_index))
+}
+
+pub fn set_metadata_hash(env: &Env, token_index: u32, hash: Option<&BytesN<32>>) {
+    let key = DataKey::MetadataHash(token_index);
+    match hash {
+        Some(hash) => env.storage().persistent().set(&key, hash),
+        None => env.storage().persistent().remove(&key),
+    }
+}
+
+// ── Token tags ────────────────────────────────────────────
+
+pub fn get_token_tags(env: &Env, token_index: u32) -> soroban_sdk::Vec<String> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::TokenTags(token_index))
+        .unwrap_or(soroban_sdk::Vec::new(env))
+}
+
+pub fn set_token_tags(env: &Env, token_index: u32, tags: &soroban_sdk::Vec<String>) {
+    let key = DataKey::TokenTags(token_index);
+    if tags.is_empty() {
+        env.storage().persistent().remove(&key);
+    } else {
+        env.storage().persistent().set(&key, tags);
+    }
+}
+
+pub fn get_max_tag_count(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::MaxTagCount)
+        .unwrap_or(5)
+}
+
+pub fn set_max_tag_count(env: &Env, count: u32) {
+    env.storage().instance().set(&DataKey::MaxTagCount, &count);
+}
+
+pub fn get_max_tag_len(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::MaxTagLen)
+        .unwrap_or(32)
+}
+
+pub fn set_max_tag_len(env: &Env, len: u32) {
+    env.storage().instance().set(&DataKey::MaxTagLen, &len);
+}
+
+// ── Default metadata URI ──────────────────────────────────
+
+pub fn get_default_metadata_uri(env: &Env) -> Option<String> {
+    env.storage().instance().get(&DataKey::DefaultMetadataUri)
+}
+
+pub fn set_default_metadata_uri(env: &Env, uri: &Option<String>) {
+    match uri {
+        Some(uri) => env
+            .storage()
+            .instance()
+            .set(&DataKey::DefaultMetadataUri, uri),
+        None => env.storage().instance().remove(&DataKey::DefaultMetadataUri),
+    }
+}
+
+// ── Initial supply bounds ─────────────────────────────────
+
+/// Smallest accepted initial supply. Defaults to 1, i.e. zero-supply
+/// tokens are rejected unless the admin lowers the bound to 0.
+pub fn get_min_initial_supply(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::MinInitialSupply)
+        .unwrap_or(1)
+}
+
+pub fn set_min_initial_supply(env: &Env, min: i128) {
+    env.storage().instance().set(&DataKey::MinInitialSupply, &min);
+}
+
+pub fn get_max_initial_supply(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::MaxInitialSupply)
+        .unwrap_or(i128::MAX)
+}
+
+pub fn set_max_initial_supply(env: &Env, max: i128) {
+    env.storage().instance().set(&DataKey::MaxInitialSupply, &max);
+}
+
+// ── Contract version ──────────────────────────────────────
+
+/// Code version of the deployed contract. Starts at 1 and is bumped by
+/// every successful `upgrade`.
+pub fn get_contract_version(env: &Env) -> u32 {
+    env.storage()
+        .instance()
+        .get(&DataKey::ContractVersion)
+        .unwrap_or(1)
+}
+
+pub fn set_contract_version(env: &Env, version: u32) {
+    env.storage().instance().set(&DataKey::ContractVersion, &version);
+}
+
 // ── Timelock storage functions ─────────────────────────────
 
 pub fn get_timelock_config(env: &Env) -> crate::types::TimelockConfig {
@@ -776,6 +2292,7 @@ pub fn add_creator_token(env: &Env, creator: &Address, token_index: u32) {
     env.storage()
         .persistent()
         .set(&DataKey::CreatorTokens(creator.clone()), &tokens);
+    register_creator(env, creator);
 
     // Update count
     let count = tokens.len();
@@ -784,6 +2301,39 @@ pub fn add_creator_token(env: &Env, creator: &Address, token_index: u32) {
         .set(&DataKey::CreatorTokenCount(creator.clone()), &count);
 }
 
+/// Append `creator` to the distinct-creator index on its first token.
+///
+/// `CreatorSeen` makes the membership check one read, so the list never
+/// needs deduplicating when queried.
+fn register_creator(env: &Env, creator: &Address) {
+    let seen_key = DataKey::CreatorSeen(creator.clone());
+    if env.storage().persistent().has(&seen_key) {
+        return;
+    }
+
+    let count = get_creator_count(env);
+    env.storage()
+        .persistent()
+        .set(&DataKey::CreatorAt(count), creator);
+    env.storage()
+        .persistent()
+        .set(&DataKey::CreatorCount, &(count + 1));
+    env.storage().persistent().set(&seen_key, &true);
+}
+
+/// Number of distinct token creators
+pub fn get_creator_count(env: &Env) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::CreatorCount)
+        .unwrap_or(0)
+}
+
+/// Distinct creator at `position`, in order of their first token
+pub fn get_creator_at(env: &Env, position: u32) -> Option<Address> {
+    env.storage().persistent().get(&DataKey::CreatorAt(position))
+}
+
 /// Get all token indices for a creator
 pub fn get_creator_tokens(env: &Env, creator: &Address) -> soroban_sdk::Vec<u32> {
     env.storage()
@@ -936,6 +2486,30 @@ pub fn set_allowed_recipient(env: &Env, recipient: &Address, allowed: bool) {
         .set(&DataKey::AllowedRecipient(recipient.clone()), &allowed);
 }
 
+pub fn get_collected_fees(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::CollectedFees)
+        .unwrap_or(0)
+}
+
+pub fn set_collected_fees(env: &Env, total: i128) {
+    env.storage().instance().set(&DataKey::CollectedFees, &total);
+}
+
+pub fn get_fee_alert_threshold(env: &Env) -> i128 {
+    env.storage()
+        .instance()
+        .get(&DataKey::FeeAlertThreshold)
+        .unwrap_or(0)
+}
+
+pub fn set_fee_alert_threshold(env: &Env, threshold: i128) {
+    env.storage()
+        .instance()
+        .set(&DataKey::FeeAlertThreshold, &threshold);
+}
+
 // ── Stream storage functions ───────────────────────────────
 
 /// Get the total number of streams created
@@ -948,11 +2522,7 @@ pub fn get_stream_count(env: &Env) -> u32 {
 
 /// Increment stream count and return new ID
 pub fn increment_stream_count(env: &Env) -> Result<u32, Error> {
-    let count = get_stream_count(env)
-        .checked_add(1)
-        .ok_or(Error::ArithmeticError)?;
-    env.storage().instance().set(&DataKey::StreamCount, &count);
-    Ok(count)
+    bump_counter(env, DataKey::StreamCount)
 }
 
 /// Get stream info by ID
@@ -1172,13 +2742,8 @@ pub fn get_proposal_count(env: &Env) -> u32 {
 }
 
 /// Increment proposal count and return new count
-pub fn increment_proposal_count(env: &Env) -> u32 {
-    let count = get_proposal_count(env);
-    let new_count = count.checked_add(1).expect("Proposal count overflow");
-    env.storage()
-        .instance()
-        .set(&DataKey::ProposalCount, &new_count);
-    new_count
+pub fn increment_proposal_count(env: &Env) -> Result<u32, Error> {
+    bump_counter(env, DataKey::ProposalCount)
 }
 
 /// Get next proposal ID
@@ -1407,13 +2972,7 @@ pub fn get_owner_campaign_count(env: &Env, owner: &Address) -> u32 {
 
 /// Increment owner's campaign count
 pub fn increment_owner_campaign_count(env: &Env, owner: &Address) -> Result<u32, Error> {
-    let count = get_owner_campaign_count(env, owner)
-        .checked_add(1)
-        .ok_or(Error::ArithmeticError)?;
-    env.storage()
-        .instance()
-        .set(&DataKey::CreatorCampaignCount(owner.clone()), &count);
-    Ok(count)
+    bump_counter(env, DataKey::CreatorCampaignCount(owner.clone()))
 }
 
 /// Get active campaign count
@@ -1433,11 +2992,7 @@ pub fn set_active_campaign_count(env: &Env, count: u32) {
 
 /// Increment active campaign count
 pub fn increment_active_campaign_count(env: &Env) -> Result<u32, Error> {
-    let count = get_active_campaign_count(env)
-        .checked_add(1)
-        .ok_or(Error::ArithmeticError)?;
-    set_active_campaign_count(env, count);
-    Ok(count)
+    bump_counter(env, DataKey::ActiveCampaigns)
 }
 
 /// Decrement active campaign count
@@ -1485,10 +3040,7 @@ pub fn get_owner_fractional_vault_count(env: &Env, owner: &Address) -> u32 {
 
 /// Increment owner's fractional vault count
 pub fn increment_owner_fractional_vault_count(env: &Env, owner: &Address) -> Result<u32, Error> {
-    let current = get_owner_fractional_vault_count(env, owner);
-    let new_count = current.checked_add(1).ok_or(Error::ArithmeticError)?;
-    env.storage().persistent().set(&crate::types::DataKey::OwnerFractionalVaultCount(owner.clone()), &new_count);
-    Ok(new_count)
+    bump_persistent_counter(env, DataKey::OwnerFractionalVaultCount(owner.clone()))
 }
 
 /// Set fractional vault by owner
@@ -1501,41 +3053,97 @@ pub fn get_asset_vault(env: &Env, asset_id: &soroban_sdk::BytesN<32>) -> Option<
     env.storage().persistent().get(&crate::types::DataKey::AssetToVault(asset_id.clone()))
 }
 
-/// Set asset to vault mapping
-pub fn set_asset_to_vault(env: &Env, asset_id: &soroban_sdk::BytesN<32>, vault_id: u64) {
-    env.storage().persistent().set(&crate::types::DataKey::AssetToVault(asset_id.clone()), &vault_id);
+/// Set asset to vault mapping
+pub fn set_asset_to_vault(env: &Env, asset_id: &soroban_sdk::BytesN<32>, vault_id: u64) {
+    env.storage().persistent().set(&crate::types::DataKey::AssetToVault(asset_id.clone()), &vault_id);
+}
+
+/// Remove asset to vault mapping
+pub fn remove_asset_to_vault(env: &Env, asset_id: &soroban_sdk::BytesN<32>) {
+    env.storage().persistent().remove(&crate::types::DataKey::AssetToVault(asset_id.clone()));
+}
+
+// ============================================================
+// Role-Based Access Control
+// ============================================================
+
+fn role_discriminant(role: crate::types::Role) -> u32 {
+    match role {
+        crate::types::Role::MetadataManager => 0,
+        crate::types::Role::Pauser => 1,
+        crate::types::Role::Minter => 2,
+    }
+}
+
+pub fn has_role(env: &Env, token_index: u32, address: &Address, role: crate::types::Role) -> bool {
+    let key = crate::types::DataKey::TokenRole(token_index, address.clone(), role_discriminant(role));
+    env.storage().persistent().get::<_, bool>(&key).unwrap_or(false)
+}
+
+pub fn grant_role(env: &Env, token_index: u32, address: &Address, role: crate::types::Role) {
+    let key = crate::types::DataKey::TokenRole(token_index, address.clone(), role_discriminant(role));
+    env.storage().persistent().set(&key, &true);
+}
+
+pub fn revoke_role(env: &Env, token_index: u32, address: &Address, role: crate::types::Role) {
+    let key = crate::types::DataKey::TokenRole(token_index, address.clone(), role_discriminant(role));
+    env.storage().persistent().remove(&key);
+}
+
+// ============================================================
+// Mint Authorities
+// ============================================================
+
+pub fn is_mint_authority(env: &Env, token_index: u32, authority: &Address) -> bool {
+    env.storage()
+        .persistent()
+        .has(&DataKey::MintAuthority(token_index, authority.clone()))
+}
+
+pub fn add_mint_authority(env: &Env, token_index: u32, authority: &Address) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::MintAuthority(token_index, authority.clone()), &true);
+}
+
+pub fn remove_mint_authority(env: &Env, token_index: u32, authority: &Address) {
+    env.storage()
+        .persistent()
+        .remove(&DataKey::MintAuthority(token_index, authority.clone()));
 }
 
-/// Remove asset to vault mapping
-pub fn remove_asset_to_vault(env: &Env, asset_id: &soroban_sdk::BytesN<32>) {
-    env.storage().persistent().remove(&crate::types::DataKey::AssetToVault(asset_id.clone()));
+/// The creator can always mint; anyone else needs a mint authority entry.
+pub fn can_mint(env: &Env, token_info: &TokenInfo, token_index: u32, caller: &Address) -> bool {
+    token_info.creator == *caller || is_mint_authority(env, token_index, caller)
 }
 
 // ============================================================
-// Role-Based Access Control
+// Holder Counts
 // ============================================================
 
-fn role_discriminant(role: crate::types::Role) -> u32 {
-    match role {
-        crate::types::Role::MetadataManager => 0,
-        crate::types::Role::Pauser => 1,
-        crate::types::Role::Minter => 2,
-    }
+pub fn get_holder_count(env: &Env, token_index: u32) -> u32 {
+    env.storage()
+        .persistent()
+        .get(&DataKey::HolderCount(token_index))
+        .unwrap_or(0)
 }
 
-pub fn has_role(env: &Env, token_index: u32, address: &Address, role: crate::types::Role) -> bool {
-    let key = crate::types::DataKey::TokenRole(token_index, address.clone(), role_discriminant(role));
-    env.storage().persistent().get::<_, bool>(&key).unwrap_or(false)
+pub fn set_holder_count(env: &Env, token_index: u32, count: u32) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::HolderCount(token_index), &count);
 }
 
-pub fn grant_role(env: &Env, token_index: u32, address: &Address, role: crate::types::Role) {
-    let key = crate::types::DataKey::TokenRole(token_index, address.clone(), role_discriminant(role));
-    env.storage().persistent().set(&key, &true);
+pub fn get_holder_reporter(env: &Env, token_index: u32) -> Option<Address> {
+    env.storage()
+        .persistent()
+        .get(&DataKey::HolderReporter(token_index))
 }
 
-pub fn revoke_role(env: &Env, token_index: u32, address: &Address, role: crate::types::Role) {
-    let key = crate::types::DataKey::TokenRole(token_index, address.clone(), role_discriminant(role));
-    env.storage().persistent().remove(&key);
+pub fn set_holder_reporter(env: &Env, token_index: u32, reporter: &Address) {
+    env.storage()
+        .persistent()
+        .set(&DataKey::HolderReporter(token_index), reporter);
 }
 
 // ============================================================
@@ -1685,3 +3293,1223 @@ pub fn get_burn_schedule_id_by_token(env: &Env, token_index: u32, local_index: u
         .instance()
         .get(&crate::types::DataKey::BurnSchedulesByToken(token_index, local_index))
 }
+
+#[cfg(test)]
+mod tests {
+    /// Tests for `bump_tokens_ttl` batch TTL maintenance.
+    mod token_ttl {
+        use soroban_sdk::{
+            testutils::{storage::Persistent as _, Address as _},
+            Address, Env,
+        };
+
+        use crate::storage::MAX_TTL_BUMP_BATCH;
+        use crate::test_helpers::{create_test_token, setup_factory};
+        use crate::types::{DataKey, Error};
+        use crate::TokenFactoryClient;
+
+        const EXTEND_TO: u32 = 50_000;
+
+        /// Set up a factory with `count` tokens, each burned once so it has
+        /// persistent aggregates. Returns (client, admin).
+        fn setup(env: &Env, count: u32) -> (TokenFactoryClient<'_>, Address) {
+            let (client, admin, _) = setup_factory(env);
+
+            let creator = Address::generate(env);
+            for token_index in 0..count {
+                create_test_token(env, &client, &creator, "TTL", 1_000);
+                client.burn(&creator, &token_index, &1);
+            }
+
+            (client, admin)
+        }
+
+        fn burned_ttl(env: &Env, client: &TokenFactoryClient, token_index: u32) -> u32 {
+            env.as_contract(&client.address, || {
+                env.storage()
+                    .persistent()
+                    .get_ttl(&DataKey::TotalBurned(token_index))
+            })
+        }
+
+        #[test]
+        fn test_bump_extends_only_tokens_in_range() {
+            let env = Env::default();
+            let (client, admin) = setup(&env, 3);
+            let untouched = burned_ttl(&env, &client, 2);
+            assert!(untouched < EXTEND_TO);
+
+            let visited = client.bump_tokens_ttl(&admin, &0, &2, &EXTEND_TO, &EXTEND_TO);
+            assert_eq!(visited, 2);
+
+            assert_eq!(burned_ttl(&env, &client, 0), EXTEND_TO);
+            assert_eq!(burned_ttl(&env, &client, 1), EXTEND_TO);
+            assert_eq!(burned_ttl(&env, &client, 2), untouched);
+        }
+
+        #[test]
+        fn test_bump_clamps_to_registry_end() {
+            let env = Env::default();
+            let (client, admin) = setup(&env, 2);
+
+            assert_eq!(
+                client.bump_tokens_ttl(&admin, &1, &10, &EXTEND_TO, &EXTEND_TO),
+                1
+            );
+            assert_eq!(
+                client.bump_tokens_ttl(&admin, &5, &10, &EXTEND_TO, &EXTEND_TO),
+                0
+            );
+            assert_eq!(
+                client.bump_tokens_ttl(&admin, &0, &0, &EXTEND_TO, &EXTEND_TO),
+                0
+            );
+        }
+
+        #[test]
+        fn test_full_batch_fits_in_one_call() {
+            let env = Env::default();
+            let (client, admin) = setup(&env, MAX_TTL_BUMP_BATCH + 1);
+            let untouched = burned_ttl(&env, &client, MAX_TTL_BUMP_BATCH);
+
+            assert_eq!(
+                client.bump_tokens_ttl(&admin, &0, &u32::MAX, &EXTEND_TO, &EXTEND_TO),
+                MAX_TTL_BUMP_BATCH
+            );
+            for token_index in 0..MAX_TTL_BUMP_BATCH {
+                assert_eq!(burned_ttl(&env, &client, token_index), EXTEND_TO);
+            }
+            assert_eq!(burned_ttl(&env, &client, MAX_TTL_BUMP_BATCH), untouched);
+        }
+
+        #[test]
+        fn test_bump_requires_admin() {
+            let env = Env::default();
+            let (client, _) = setup(&env, 1);
+            let stranger = Address::generate(&env);
+
+            assert_eq!(
+                client.try_bump_tokens_ttl(&stranger, &0, &1, &EXTEND_TO, &EXTEND_TO),
+                Err(Ok(Error::Unauthorized))
+            );
+        }
+
+        #[test]
+        fn test_bump_validates_ttl_arguments() {
+            let env = Env::default();
+            let (client, admin) = setup(&env, 1);
+
+            assert_eq!(
+                client.try_bump_tokens_ttl(&admin, &0, &1, &(EXTEND_TO + 1), &EXTEND_TO),
+                Err(Ok(Error::InvalidParameters))
+            );
+            assert_eq!(
+                client.try_bump_tokens_ttl(&admin, &0, &1, &0, &u32::MAX),
+                Err(Ok(Error::InvalidParameters))
+            );
+        }
+    }
+
+    /// Tests for the per-token storage footprint estimate.
+    mod storage_estimate {
+        use soroban_sdk::{testutils::Address as _, BytesN, Env, String};
+
+        use crate::test_helpers::setup_with_token;
+        use crate::types::Error;
+        use crate::TokenFactoryClient;
+
+        #[test]
+        fn test_fresh_token_counts_token_info() {
+            let env = Env::default();
+            let (client, _, _, token_index) = setup_with_token(&env);
+
+            assert!(client.estimate_token_storage(&token_index) >= 2);
+            assert_eq!(
+                client.try_estimate_token_storage(&7),
+                Err(Ok(Error::TokenNotFound))
+            );
+        }
+
+        #[test]
+        fn test_metadata_grows_estimate() {
+            let env = Env::default();
+            let (client, _, creator, token_index) = setup_with_token(&env);
+            let base = client.estimate_token_storage(&token_index);
+
+            client.set_metadata(
+                &token_index,
+                &String::from_str(&env, "ipfs://QmV1"),
+                &30_000_000,
+            );
+            assert_eq!(client.estimate_token_storage(&token_index), base + 1);
+
+            client.update_metadata(
+                &creator,
+                &token_index,
+                &String::from_str(&env, "ipfs://QmV2"),
+            );
+            assert_eq!(client.estimate_token_storage(&token_index), base + 2);
+
+            client.set_metadata_hash(
+                &creator,
+                &token_index,
+                &Some(BytesN::from_array(&env, &[1; 32])),
+            );
+            assert_eq!(client.estimate_token_storage(&token_index), base + 3);
+        }
+
+        #[test]
+        fn test_burns_grow_estimate() {
+            let env = Env::default();
+            let (client, _, creator, token_index) = setup_with_token(&env);
+            let base = client.estimate_token_storage(&token_index);
+
+            client.burn(&creator, &token_index, &100);
+            let after_first = client.estimate_token_storage(&token_index);
+            assert!(after_first > base + 1);
+
+            // Aggregates already exist; each further burn adds its log record
+            client.burn(&creator, &token_index, &100);
+            client.burn(&creator, &token_index, &100);
+            assert_eq!(client.estimate_token_storage(&token_index), after_first + 2);
+        }
+    }
+
+    /// Tests for the `health` monitoring endpoint.
+    mod health {
+        use soroban_sdk::{testutils::Address as _, Address, Env};
+
+        use crate::storage;
+        use crate::test_helpers::{create_test_token, setup_factory};
+        use crate::{TokenFactory, TokenFactoryClient};
+
+        /// Set up a factory with `tokens` tokens. Returns (client, admin, creator).
+        fn setup(env: &Env, tokens: u32) -> (TokenFactoryClient<'_>, Address, Address) {
+            let (client, admin, _) = setup_factory(env);
+
+            let creator = Address::generate(env);
+            for _ in 0..tokens {
+                create_test_token(env, &client, &creator, "HLT", 1_000_000);
+            }
+
+            (client, admin, creator)
+        }
+
+        #[test]
+        fn test_uninitialized_contract() {
+            let env = Env::default();
+            let contract_id = env.register_contract(None, TokenFactory);
+            let client = TokenFactoryClient::new(&env, &contract_id);
+
+            let status = client.health();
+            assert!(!status.initialized);
+            assert!(!status.paused);
+            assert!(status.counters_consistent);
+        }
+
+        #[test]
+        fn test_healthy_contract() {
+            let env = Env::default();
+            let (client, admin, creator) = setup(&env, 12);
+
+            client.burn(&creator, &3, &500);
+            client.mint(&creator, &7, &creator, &250);
+
+            let status = client.health();
+            assert!(status.initialized);
+            assert!(!status.paused);
+            assert!(status.counters_consistent);
+
+            client.pause(&admin);
+            assert!(client.health().paused);
+        }
+
+        #[test]
+        fn test_broken_supply_detected() {
+            let env = Env::default();
+            let (client, _, _) = setup(&env, 3);
+
+            // Newest token is always sampled
+            env.as_contract(&client.address, || {
+                let mut info = storage::get_token_info(&env, 2).unwrap();
+                info.total_supply += 1;
+                storage::set_token_info(&env, 2, &info);
+            });
+
+            let status = client.health();
+            assert!(status.initialized);
+            assert!(!status.counters_consistent);
+        }
+
+        #[test]
+        fn test_entry_past_counter_detected() {
+            let env = Env::default();
+            let (client, _, _) = setup(&env, 2);
+
+            env.as_contract(&client.address, || {
+                let info = storage::get_token_info(&env, 0).unwrap();
+                storage::set_token_info(&env, 2, &info);
+            });
+
+            assert!(!client.health().counters_consistent);
+        }
+    }
+
+    /// Tests for `get_full_state`.
+    mod full_state {
+        use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+
+        use crate::storage;
+        use crate::test_helpers::setup_factory;
+        use crate::types::Error;
+        use crate::{TokenFactory, TokenFactoryClient};
+
+        #[test]
+        fn test_full_state_reflects_current_values() {
+            let env = Env::default();
+            let (client, admin, treasury) = setup_factory(&env);
+
+            let fresh = client.get_full_state();
+            assert_eq!(fresh.token_count, 0);
+            assert_eq!(fresh.burn_count, 0);
+            assert_eq!(fresh.schema_version, storage::STORAGE_SCHEMA_VERSION);
+            assert_eq!(fresh.contract_version, 1);
+
+            let creator = Address::generate(&env);
+            for _ in 0..2 {
+                client.create_token(
+                    &creator,
+                    &String::from_str(&env, "StateToken"),
+                    &String::from_str(&env, "STA"),
+                    &7u32,
+                    &1_000_000i128,
+                    &None,
+                    &70_000_000i128,
+                );
+            }
+            let holder = Address::generate(&env);
+            client.mint(&creator, &1, &holder, &100);
+
+            client.burn(&creator, &0, &10);
+            client.admin_burn(&admin, &1, &creator, &20);
+            client.batch_burn(
+                &admin,
+                &1,
+                &vec![&env, (creator.clone(), 5), (holder.clone(), 5)],
+            );
+            client.pause(&admin);
+
+            let state = client.get_full_state();
+            assert_eq!(state.admin, admin);
+            assert_eq!(state.treasury, treasury);
+            assert_eq!(state.base_fee, 70_000_000);
+            assert_eq!(state.metadata_fee, 30_000_000);
+            assert!(state.paused);
+            assert_eq!(state.token_count, 2);
+            assert_eq!(state.burn_count, 4);
+
+            // Shared fields agree with the existing FactoryState read
+            let base = client.get_state();
+            assert_eq!(base.admin, state.admin);
+            assert_eq!(base.paused, state.paused);
+        }
+
+        #[test]
+        fn test_full_state_requires_initialization() {
+            let env = Env::default();
+            let contract_id = env.register_contract(None, TokenFactory);
+            let client = TokenFactoryClient::new(&env, &contract_id);
+
+            assert_eq!(client.try_get_full_state(), Err(Ok(Error::NotInitialized)));
+        }
+    }
+
+    /// Tests for the admin-only `dump_token` support read.
+    mod dump_token {
+        use soroban_sdk::{
+            testutils::{Address as _, Ledger},
+            vec, Address, Env, String,
+        };
+
+        use crate::test_helpers::setup_with_supply;
+        use crate::types::Error;
+
+        #[test]
+        fn test_dump_matches_individual_reads() {
+            let env = Env::default();
+            let (client, admin, creator, token_index) = setup_with_supply(&env, 100_000);
+            let holder = Address::generate(&env);
+            let reporter = Address::generate(&env);
+
+            env.ledger().with_mut(|l| l.timestamp = 1_000);
+            client.mint(&creator, &token_index, &holder, &5_000);
+            client.burn(&creator, &token_index, &700);
+            client.admin_burn(&admin, &token_index, &holder, &300);
+            client.set_metadata_editable_until(&creator, &token_index, &Some(9_999));
+            client.set_holder_reporter(&admin, &token_index, &reporter);
+            client.record_holder_added(&reporter, &token_index);
+            let tags = vec![
+                &env,
+                String::from_str(&env, "defi"),
+                String::from_str(&env, "dao"),
+            ];
+            client.set_token_tags(&creator, &token_index, &tags);
+            client.freeze_token(&admin, &token_index);
+
+            let dump = client.dump_token(&admin, &token_index);
+            let summary = client.get_burn_summary(&token_index);
+
+            assert_eq!(dump.info, client.get_token_info(&token_index));
+            assert_eq!(
+                dump.effective_metadata_uri,
+                client.get_effective_metadata_uri(&token_index)
+            );
+            assert_eq!(
+                dump.metadata_locked,
+                client.is_metadata_locked(&token_index)
+            );
+            assert_eq!(dump.metadata_editable_until, Some(9_999));
+            assert!(dump.frozen);
+            assert_eq!(dump.burn_count, client.get_burn_count(&token_index));
+            assert_eq!(dump.total_burned, 1_000);
+            assert_eq!(
+                dump.burn_record_count,
+                client.get_token_burn_record_count(&token_index)
+            );
+            assert_eq!(dump.total_minted, 5_000);
+            assert_eq!(dump.largest_burn, summary.largest_burn);
+            assert_eq!(dump.last_burn_at, Some(1_000));
+            assert_eq!(dump.holder_count, client.get_holder_count(&token_index));
+            assert_eq!(dump.holder_reporter, Some(reporter));
+            assert_eq!(dump.tags, client.get_token_tags(&token_index));
+            assert_eq!(dump.tags.len(), 2);
+        }
+
+        #[test]
+        fn test_dump_requires_admin_and_known_token() {
+            let env = Env::default();
+            let (client, admin, creator, token_index) = setup_with_supply(&env, 100_000);
+
+            assert_eq!(
+                client.try_dump_token(&creator, &token_index),
+                Err(Ok(Error::Unauthorized))
+            );
+            assert_eq!(
+                client.try_dump_token(&admin, &42),
+                Err(Ok(Error::TokenNotFound))
+            );
+        }
+    }
+
+    /// Tests for the `get_admin_config` composition read.
+    mod admin_config {
+        use soroban_sdk::{testutils::Address as _, Address, Env};
+
+        use crate::test_helpers::setup_factory;
+        use crate::types::Error;
+        use crate::{TokenFactory, TokenFactoryClient};
+
+        #[test]
+        fn test_admin_config_defaults_after_initialize() {
+            let env = Env::default();
+            let (client, admin, treasury) = setup_factory(&env);
+
+            let config = client.get_admin_config();
+            assert_eq!(config.admin, admin);
+            assert_eq!(config.pending_admin, None);
+            assert_eq!(config.treasury, treasury);
+            assert_eq!(config.base_fee, 70_000_000);
+            assert_eq!(config.metadata_fee, 30_000_000);
+            assert!(!config.paused);
+            assert_eq!(config.withdrawal_period, 86_400);
+            assert!(!config.timelock_enabled);
+        }
+
+        #[test]
+        fn test_admin_config_reflects_setter_calls() {
+            let env = Env::default();
+            let (client, admin, _) = setup_factory(&env);
+            let proposed = Address::generate(&env);
+
+            client.update_fees(&admin, &Some(1_000), &Some(500));
+            client.pause(&admin);
+            client.propose_admin(&admin, &proposed);
+            client.initialize_treasury_policy(&admin, &Some(5_000_000), &true);
+
+            let config = client.get_admin_config();
+            assert_eq!(config.base_fee, 1_000);
+            assert_eq!(config.metadata_fee, 500);
+            assert!(config.paused);
+            assert_eq!(config.pending_admin, Some(proposed));
+            assert_eq!(config.withdrawal_cap, 5_000_000);
+            assert!(config.allowlist_enabled);
+        }
+
+        #[test]
+        fn test_admin_config_before_initialize_fails() {
+            let env = Env::default();
+            let contract_id = env.register_contract(None, TokenFactory);
+            let client = TokenFactoryClient::new(&env, &contract_id);
+
+            assert_eq!(
+                client.try_get_admin_config(),
+                Err(Ok(Error::NotInitialized))
+            );
+        }
+    }
+
+    /// Tests for `transfer_admin_and_treasury`.
+    mod admin_treasury_handoff {
+        use soroban_sdk::{
+            symbol_short,
+            testutils::{Address as _, Events},
+            xdr, Address, Env, Symbol, TryFromVal, Val,
+        };
+
+        use crate::test_helpers::setup_factory;
+        use crate::types::Error;
+        use crate::TokenFactoryClient;
+
+        fn assert_unchanged(client: &TokenFactoryClient, admin: &Address, treasury: &Address) {
+            let config = client.get_admin_config();
+            assert_eq!(&config.admin, admin);
+            assert_eq!(&config.treasury, treasury);
+        }
+
+        #[test]
+        fn test_handoff_changes_both_and_clears_proposals() {
+            let env = Env::default();
+            let (client, admin, treasury) = setup_factory(&env);
+            let new_admin = Address::generate(&env);
+            let new_treasury = Address::generate(&env);
+
+            client.propose_admin(&admin, &Address::generate(&env));
+            client.propose_treasury(&admin, &Address::generate(&env));
+
+            client.transfer_admin_and_treasury(&admin, &new_admin, &new_treasury);
+
+            let config = client.get_admin_config();
+            assert_eq!(config.admin, new_admin);
+            assert_eq!(config.treasury, new_treasury);
+            assert_eq!(config.pending_admin, None);
+            assert_eq!(client.get_treasury_status().pending, None);
+
+            let events = env.events().all();
+            let handoff = events.events().iter().find_map(|event| {
+                let xdr::ContractEventBody::V0(body) = &event.body;
+                let name = body
+                    .topics
+                    .first()
+                    .and_then(|t| Symbol::try_from_val(&env, t).ok());
+                (name == Some(symbol_short!("hand_off"))).then(|| body.data.clone())
+            });
+            let data = Val::try_from_val(&env, &handoff.expect("hand_off event")).unwrap();
+            assert_eq!(
+                <(Address, Address, Address, Address)>::try_from_val(&env, &data).unwrap(),
+                (admin, new_admin, treasury, new_treasury)
+            );
+        }
+
+        #[test]
+        fn test_separation_failure_rolls_back_both() {
+            let env = Env::default();
+            let (client, admin, treasury) = setup_factory(&env);
+            let successor = Address::generate(&env);
+
+            client.set_require_separate_treasury(&admin, &true);
+
+            assert_eq!(
+                client.try_transfer_admin_and_treasury(&admin, &successor, &successor),
+                Err(Ok(Error::InvalidParameters))
+            );
+            assert_unchanged(&client, &admin, &treasury);
+        }
+
+        #[test]
+        fn test_invalid_addresses_rejected() {
+            let env = Env::default();
+            let (client, admin, treasury) = setup_factory(&env);
+            let other = Address::generate(&env);
+            let factory = client.address.clone();
+
+            for (new_admin, new_treasury) in [
+                (admin.clone(), other.clone()),
+                (other.clone(), treasury.clone()),
+                (factory.clone(), other.clone()),
+                (other.clone(), factory.clone()),
+            ] {
+                assert_eq!(
+                    client.try_transfer_admin_and_treasury(&admin, &new_admin, &new_treasury),
+                    Err(Ok(Error::InvalidParameters))
+                );
+            }
+            assert_unchanged(&client, &admin, &treasury);
+        }
+
+        #[test]
+        fn test_handoff_requires_admin() {
+            let env = Env::default();
+            let (client, admin, treasury) = setup_factory(&env);
+            let stranger = Address::generate(&env);
+
+            assert_eq!(
+                client.try_transfer_admin_and_treasury(
+                    &stranger,
+                    &stranger,
+                    &Address::generate(&env)
+                ),
+                Err(Ok(Error::Unauthorized))
+            );
+            assert_unchanged(&client, &admin, &treasury);
+        }
+    }
+
+    /// Tests for cancelling and expiring pending admin and treasury proposals.
+    mod proposal_expiry {
+        use soroban_sdk::{
+            testutils::{Address as _, Ledger},
+            Address, Env,
+        };
+
+        use crate::test_helpers::setup_factory;
+        use crate::types::Error;
+        use crate::TokenFactoryClient;
+
+        const TTL: u64 = 3_600;
+
+        fn setup(env: &Env) -> (TokenFactoryClient<'_>, Address) {
+            env.ledger().with_mut(|l| l.timestamp = 10_000);
+            let (client, admin, _) = setup_factory(env);
+
+            (client, admin)
+        }
+
+        fn advance(env: &Env, seconds: u64) {
+            env.ledger().with_mut(|l| l.timestamp += seconds);
+        }
+
+        #[test]
+        fn test_cancel_pending_admin() {
+            let env = Env::default();
+            let (client, admin) = setup(&env);
+            let candidate = Address::generate(&env);
+
+            client.propose_admin(&admin, &candidate);
+            client.cancel_pending_admin(&admin);
+
+            assert_eq!(
+                client.try_accept_admin(&candidate),
+                Err(Ok(Error::Unauthorized))
+            );
+            assert_eq!(
+                client.try_cancel_pending_admin(&admin),
+                Err(Ok(Error::ChangeNotFound))
+            );
+        }
+
+        #[test]
+        fn test_cancel_pending_treasury() {
+            let env = Env::default();
+            let (client, admin) = setup(&env);
+            let new_treasury = Address::generate(&env);
+
+            client.propose_treasury(&admin, &new_treasury);
+            client.cancel_pending_treasury(&admin);
+
+            assert_eq!(client.get_treasury_status().pending, None);
+            assert_eq!(client.try_apply_treasury(), Err(Ok(Error::ChangeNotFound)));
+            assert_eq!(
+                client.try_cancel_pending_treasury(&admin),
+                Err(Ok(Error::ChangeNotFound))
+            );
+        }
+
+        #[test]
+        fn test_cancel_requires_admin() {
+            let env = Env::default();
+            let (client, admin) = setup(&env);
+            let candidate = Address::generate(&env);
+
+            client.propose_admin(&admin, &candidate);
+            client.propose_treasury(&admin, &candidate);
+
+            assert_eq!(
+                client.try_cancel_pending_admin(&candidate),
+                Err(Ok(Error::Unauthorized))
+            );
+            assert_eq!(
+                client.try_cancel_pending_treasury(&candidate),
+                Err(Ok(Error::Unauthorized))
+            );
+        }
+
+        #[test]
+        fn test_expired_admin_proposal_rejected() {
+            let env = Env::default();
+            let (client, admin) = setup(&env);
+            let candidate = Address::generate(&env);
+
+            client.set_proposal_ttl(&admin, &TTL);
+            client.propose_admin(&admin, &candidate);
+            advance(&env, TTL + 1);
+
+            assert_eq!(
+                client.try_accept_admin(&candidate),
+                Err(Ok(Error::Unauthorized))
+            );
+            assert_eq!(client.get_admin_config().admin, admin);
+        }
+
+        #[test]
+        fn test_expired_treasury_proposal_rejected() {
+            let env = Env::default();
+            let (client, admin) = setup(&env);
+            let new_treasury = Address::generate(&env);
+
+            client.set_treasury_change_delay(&admin, &600);
+            client.set_proposal_ttl(&admin, &TTL);
+            client.propose_treasury(&admin, &new_treasury);
+
+            // Window runs from the effective timestamp, not the proposal
+            advance(&env, 600 + TTL + 1);
+            assert_eq!(client.try_apply_treasury(), Err(Ok(Error::ChangeNotFound)));
+            assert_ne!(client.get_treasury_status().current, new_treasury);
+        }
+
+        #[test]
+        fn test_proposals_valid_within_ttl() {
+            let env = Env::default();
+            let (client, admin) = setup(&env);
+            let candidate = Address::generate(&env);
+            let new_treasury = Address::generate(&env);
+
+            client.set_proposal_ttl(&admin, &TTL);
+            client.propose_treasury(&admin, &new_treasury);
+            client.propose_admin(&admin, &candidate);
+            advance(&env, TTL);
+
+            client.apply_treasury();
+            client.accept_admin(&candidate);
+            assert_eq!(client.get_treasury_status().current, new_treasury);
+            assert_eq!(client.get_admin_config().admin, candidate);
+        }
+
+        #[test]
+        fn test_no_expiry_by_default() {
+            let env = Env::default();
+            let (client, admin) = setup(&env);
+            let candidate = Address::generate(&env);
+
+            client.propose_admin(&admin, &candidate);
+            advance(&env, 10 * 365 * 86_400);
+
+            client.accept_admin(&candidate);
+            assert_eq!(client.get_admin_config().admin, candidate);
+        }
+    }
+
+    /// Tests for the one-shot `initialize_with_config` entrypoint.
+    mod initialize_with_config {
+        use soroban_sdk::{testutils::Address as _, Address, Env};
+
+        use crate::types::Error;
+        use crate::{TokenFactory, TokenFactoryClient};
+
+        fn setup(env: &Env) -> TokenFactoryClient<'_> {
+            env.mock_all_auths();
+            let contract_id = env.register_contract(None, TokenFactory);
+            TokenFactoryClient::new(env, &contract_id)
+        }
+
+        #[test]
+        fn test_successful_init_sets_everything() {
+            let env = Env::default();
+            let client = setup(&env);
+            let admin = Address::generate(&env);
+            let treasury = Address::generate(&env);
+            let fee_token = Address::generate(&env);
+
+            assert_eq!(client.schema_version(), 0);
+            client.initialize_with_config(&admin, &treasury, &70_000_000, &30_000_000, &fee_token);
+
+            let config = client.get_admin_config();
+            assert_eq!(config.admin, admin);
+            assert_eq!(config.treasury, treasury);
+            assert_eq!(config.base_fee, 70_000_000);
+            assert_eq!(config.metadata_fee, 30_000_000);
+            assert_eq!(config.fee_token, Some(fee_token.clone()));
+            assert_eq!(client.get_fee_token(), Some(fee_token));
+            assert_eq!(client.schema_version(), 1);
+        }
+
+        #[test]
+        fn test_legacy_initialize_sets_schema_version() {
+            let env = Env::default();
+            let client = setup(&env);
+
+            client.initialize(
+                &Address::generate(&env),
+                &Address::generate(&env),
+                &70_000_000,
+                &30_000_000,
+            );
+
+            assert_eq!(client.schema_version(), 1);
+            assert_eq!(client.get_fee_token(), None);
+        }
+
+        #[test]
+        fn test_double_init_rejected() {
+            let env = Env::default();
+            let client = setup(&env);
+            let admin = Address::generate(&env);
+            let treasury = Address::generate(&env);
+            let fee_token = Address::generate(&env);
+
+            client.initialize_with_config(&admin, &treasury, &1, &1, &fee_token);
+
+            assert_eq!(
+                client.try_initialize_with_config(&admin, &treasury, &2, &2, &fee_token),
+                Err(Ok(Error::AlreadyInitialized))
+            );
+            assert_eq!(
+                client.try_initialize(&admin, &treasury, &2, &2),
+                Err(Ok(Error::AlreadyInitialized))
+            );
+            assert_eq!(client.get_base_fee(), 1);
+        }
+
+        #[test]
+        fn test_negative_fees_rejected() {
+            let env = Env::default();
+            let client = setup(&env);
+            let admin = Address::generate(&env);
+            let treasury = Address::generate(&env);
+            let fee_token = Address::generate(&env);
+
+            assert_eq!(
+                client.try_initialize_with_config(&admin, &treasury, &-1, &0, &fee_token),
+                Err(Ok(Error::InvalidParameters))
+            );
+            assert_eq!(
+                client.try_initialize_with_config(&admin, &treasury, &0, &-1, &fee_token),
+                Err(Ok(Error::InvalidParameters))
+            );
+
+            // Nothing was written, so a valid call still succeeds
+            assert_eq!(client.schema_version(), 0);
+            assert_eq!(client.get_fee_token(), None);
+            client.initialize_with_config(&admin, &treasury, &0, &0, &fee_token);
+            assert_eq!(client.get_base_fee(), 0);
+        }
+    }
+
+    /// Tests for configuration getters on an uninitialized factory.
+    mod uninitialized_getters {
+        use soroban_sdk::{testutils::Address as _, Address, Env};
+
+        use crate::types::Error;
+        use crate::{TokenFactory, TokenFactoryClient};
+
+        fn setup_uninitialized(env: &Env) -> TokenFactoryClient<'_> {
+            env.mock_all_auths();
+            let contract_id = env.register_contract(None, TokenFactory);
+            TokenFactoryClient::new(env, &contract_id)
+        }
+
+        #[test]
+        fn test_get_state_before_initialize_returns_not_initialized() {
+            let env = Env::default();
+            let client = setup_uninitialized(&env);
+
+            assert_eq!(client.try_get_state(), Err(Ok(Error::NotInitialized)));
+        }
+
+        #[test]
+        fn test_fee_getters_before_initialize_return_not_initialized() {
+            let env = Env::default();
+            let client = setup_uninitialized(&env);
+
+            assert_eq!(client.try_get_base_fee(), Err(Ok(Error::NotInitialized)));
+            assert_eq!(
+                client.try_get_metadata_fee(),
+                Err(Ok(Error::NotInitialized))
+            );
+        }
+
+        #[test]
+        fn test_admin_entrypoints_before_initialize_return_not_initialized() {
+            let env = Env::default();
+            let client = setup_uninitialized(&env);
+            let caller = Address::generate(&env);
+
+            assert_eq!(client.try_pause(&caller), Err(Ok(Error::NotInitialized)));
+            assert_eq!(
+                client.try_update_fees(&caller, &Some(1), &None),
+                Err(Ok(Error::NotInitialized))
+            );
+            assert_eq!(
+                client.try_propose_admin(&caller, &Address::generate(&env)),
+                Err(Ok(Error::NotInitialized))
+            );
+        }
+
+        #[test]
+        fn test_getters_succeed_after_initialize() {
+            let env = Env::default();
+            let client = setup_uninitialized(&env);
+            let admin = Address::generate(&env);
+            let treasury = Address::generate(&env);
+
+            client.initialize(&admin, &treasury, &70_000_000, &30_000_000);
+
+            let state = client.get_state();
+            assert_eq!(state.admin, admin);
+            assert_eq!(state.treasury, treasury);
+            assert_eq!(client.get_base_fee(), 70_000_000);
+            assert_eq!(client.get_metadata_fee(), 30_000_000);
+        }
+    }
+
+    /// Tests for the admin-guarded `upgrade` entrypoint and `version`.
+    mod upgrade_entrypoint {
+        use soroban_sdk::{testutils::Address as _, BytesN, Env};
+
+        use crate::test_helpers::setup_with_token;
+        use crate::types::Error;
+
+        #[test]
+        fn test_version_starts_at_one() {
+            let env = Env::default();
+            let (client, _, _, _) = setup_with_token(&env);
+
+            assert_eq!(client.version(), 1);
+        }
+
+        #[test]
+        fn test_upgrade_requires_admin() {
+            let env = Env::default();
+            let (client, _, creator, _) = setup_with_token(&env);
+            let hash = BytesN::from_array(&env, &[1u8; 32]);
+
+            let result = client.try_upgrade(&hash, &creator);
+            assert_eq!(result, Err(Ok(Error::UpgradeUnauthorized)));
+            assert_eq!(client.version(), 1);
+        }
+
+        #[test]
+        fn test_upgrade_to_unknown_wasm_is_rolled_back() {
+            let env = Env::default();
+            let (client, admin, creator, token_index) = setup_with_token(&env);
+            client.burn(&creator, &token_index, &1_000);
+
+            // Hash was never uploaded, so the host rejects the swap
+            let hash = BytesN::from_array(&env, &[9u8; 32]);
+            assert!(client.try_upgrade(&hash, &admin).is_err());
+
+            assert_eq!(client.version(), 1);
+            assert_eq!(client.get_token_info(&token_index).total_burned, 1_000);
+        }
+
+        #[cfg(feature = "upgrade-wasm-tests")]
+        #[test]
+        fn test_state_survives_wasm_upgrade() {
+            const WASM: &[u8] =
+                include_bytes!("../../target/wasm32v1-none/release/token_factory.wasm");
+
+            let env = Env::default();
+            let (client, admin, creator, token_index) = setup_with_token(&env);
+            client.burn(&creator, &token_index, &1_000);
+            let records_before = client.get_burn_record_count();
+
+            let hash = env.deployer().upload_contract_wasm(WASM);
+            assert_eq!(client.upgrade(&hash, &admin), 2);
+
+            // Calls below run against the uploaded WASM
+            assert_eq!(client.version(), 2);
+            assert_eq!(client.get_creator_token_count(&creator), 1);
+            let info = client.get_token_info(&token_index);
+            assert_eq!(info.total_supply, 999_000);
+            assert_eq!(info.total_burned, 1_000);
+            assert_eq!(client.get_burn_record_count(), records_before);
+
+            // And keep working on the preserved state
+            client.burn(&creator, &token_index, &500);
+            assert_eq!(client.get_token_info(&token_index).total_burned, 1_500);
+            assert_eq!(client.get_state().admin, admin);
+        }
+    }
+
+    /// Tests for the emergency kill switch.
+    mod kill_switch {
+        use soroban_sdk::{
+            testutils::{Address as _, Ledger},
+            Address, Bytes, Env, String,
+        };
+
+        use crate::test_helpers::setup_with_token;
+        use crate::types::{ActionType, Error};
+        use crate::TokenFactoryClient;
+
+        /// Set up a factory with one token and a funded holder, then engage the
+        /// kill switch. Returns (client, admin, creator, holder).
+        fn setup_killed(env: &Env) -> (TokenFactoryClient<'_>, Address, Address, Address) {
+            let (client, admin, creator, token_index) = setup_with_token(env);
+
+            let holder = Address::generate(env);
+            client.mint(&creator, &token_index, &holder, &5_000);
+
+            client.set_killed(&true, &admin);
+            (client, admin, creator, holder)
+        }
+
+        #[test]
+        fn test_mutations_rejected_while_killed() {
+            let env = Env::default();
+            let (client, admin, creator, holder) = setup_killed(&env);
+            let killed = Err(Ok(Error::ContractKilled));
+            let killed_id = Some(Ok(Error::ContractKilled));
+
+            let created = client.try_create_token(
+                &creator,
+                &String::from_str(&env, "Blocked"),
+                &String::from_str(&env, "BLK"),
+                &7u32,
+                &1_000i128,
+                &None,
+                &70_000_000i128,
+            );
+            assert_eq!(created.err(), killed_id);
+            assert_eq!(client.try_burn(&holder, &0, &100), killed);
+            assert_eq!(client.try_admin_burn(&admin, &0, &holder, &100), killed);
+            assert_eq!(client.try_mint(&creator, &0, &holder, &100), killed);
+            assert_eq!(
+                client.try_set_metadata(&0, &String::from_str(&env, "ipfs://meta"), &30_000_000),
+                killed
+            );
+            assert_eq!(client.try_update_fees(&admin, &Some(1), &None), killed);
+            assert_eq!(client.try_pause(&admin), killed);
+            assert_eq!(client.try_can_burn(&0, &holder, &100, &holder), killed);
+        }
+
+        #[test]
+        fn test_treasury_timelock_and_governance_rejected_while_killed() {
+            let env = Env::default();
+            let (client, admin, _, _) = setup_killed(&env);
+            let killed = Err(Ok(Error::ContractKilled));
+            // Entry points returning an id compare through `.err()`
+            let killed_id = Some(Ok(Error::ContractKilled));
+            let recipient = Address::generate(&env);
+
+            assert_eq!(client.try_withdraw_fees(&admin, &recipient, &1), killed);
+            assert_eq!(client.try_add_allowed_recipient(&admin, &recipient), killed);
+            assert_eq!(
+                client.try_remove_allowed_recipient(&admin, &recipient),
+                killed
+            );
+            assert_eq!(
+                client.try_update_treasury_policy(&admin, &Some(1), &None),
+                killed
+            );
+            assert_eq!(
+                client.try_propose_treasury(&admin, &recipient).err(),
+                killed_id
+            );
+            assert_eq!(client.try_apply_treasury(), killed);
+
+            assert_eq!(
+                client
+                    .try_schedule_fee_update(&admin, &Some(1), &None)
+                    .err(),
+                killed_id
+            );
+            assert_eq!(
+                client.try_schedule_pause_update(&admin, &true).err(),
+                killed_id
+            );
+            assert_eq!(
+                client
+                    .try_schedule_treasury_update(&admin, &recipient)
+                    .err(),
+                killed_id
+            );
+            assert_eq!(client.try_execute_change(&0), killed);
+
+            let now = env.ledger().timestamp();
+            assert_eq!(
+                client
+                    .try_create_proposal(
+                        &admin,
+                        &ActionType::FeeChange,
+                        &Bytes::new(&env),
+                        &now,
+                        &(now + 100),
+                        &(now + 200),
+                    )
+                    .err(),
+                killed_id
+            );
+            assert_eq!(client.try_execute_proposal(&0), killed);
+        }
+
+        #[test]
+        fn test_scheduled_change_cannot_execute_while_killed() {
+            let env = Env::default();
+            let (client, admin, _, _) = setup_killed(&env);
+
+            client.set_killed(&false, &admin);
+            let change_id = client.schedule_fee_update(&admin, &Some(80_000_000), &None);
+            client.set_killed(&true, &admin);
+
+            env.ledger().with_mut(|li| li.timestamp += 365 * 86_400);
+            assert_eq!(
+                client.try_execute_change(&change_id),
+                Err(Ok(Error::ContractKilled))
+            );
+            assert_eq!(client.get_state().base_fee, 70_000_000);
+        }
+
+        #[test]
+        fn test_reads_work_while_killed() {
+            let env = Env::default();
+            let (client, admin, _, _) = setup_killed(&env);
+
+            assert!(client.is_killed());
+            let info = client.get_token_info(&0);
+            assert_eq!(info.total_supply, 1_005_000);
+            assert_eq!(client.get_state().base_fee, 70_000_000);
+            assert_eq!(client.get_burn_record_count(), 0);
+            assert!(client.try_dump_token(&admin, &0).is_ok());
+            // TTL maintenance stays open so storage does not expire while killed
+            assert!(client
+                .try_bump_tokens_ttl(&admin, &0, &10, &100, &1_000)
+                .is_ok());
+        }
+
+        #[test]
+        fn test_release_restores_mutations() {
+            let env = Env::default();
+            let (client, admin, _, holder) = setup_killed(&env);
+
+            client.set_killed(&false, &admin);
+            assert!(!client.is_killed());
+
+            client.burn(&holder, &0, &100);
+            assert_eq!(client.get_token_info(&0).total_burned, 100);
+        }
+
+        #[test]
+        fn test_set_killed_requires_admin() {
+            let env = Env::default();
+            let (client, _, creator, _) = setup_killed(&env);
+
+            assert_eq!(
+                client.try_set_killed(&false, &creator),
+                Err(Ok(Error::Unauthorized))
+            );
+            assert!(client.is_killed());
+        }
+    }
+
+    /// Unauthorized-path tests for the shared admin and creator checks.
+    mod auth {
+        use soroban_sdk::{testutils::Address as _, Address, Env};
+
+        use crate::test_helpers::setup_with_token;
+        use crate::types::Error;
+
+        #[test]
+        fn test_admin_entrypoints_reject_non_admin() {
+            let env = Env::default();
+            let (client, _, creator, _) = setup_with_token(&env);
+            let stranger = Address::generate(&env);
+
+            for caller in [&stranger, &creator] {
+                assert_eq!(
+                    client.try_set_fee_token(caller, &Address::generate(&env)),
+                    Err(Ok(Error::Unauthorized))
+                );
+                assert_eq!(
+                    client.try_set_treasury_change_delay(caller, &60),
+                    Err(Ok(Error::Unauthorized))
+                );
+                assert_eq!(
+                    client.try_set_proposal_ttl(caller, &60),
+                    Err(Ok(Error::Unauthorized))
+                );
+                assert_eq!(
+                    client.try_set_require_metadata(caller, &true),
+                    Err(Ok(Error::Unauthorized))
+                );
+                assert_eq!(
+                    client.try_set_events_enabled(caller, &false),
+                    Err(Ok(Error::Unauthorized))
+                );
+                assert_eq!(
+                    client.try_set_fee_alert_threshold(caller, &1),
+                    Err(Ok(Error::Unauthorized))
+                );
+            }
+
+            // Nothing was applied
+            assert!(!client.get_require_metadata());
+            assert!(client.events_enabled());
+        }
+
+        #[test]
+        fn test_admin_entrypoints_accept_admin() {
+            let env = Env::default();
+            let (client, admin, _, _) = setup_with_token(&env);
+
+            client.set_require_metadata(&admin, &true);
+            client.set_events_enabled(&admin, &false);
+
+            assert!(client.get_require_metadata());
+            assert!(!client.events_enabled());
+        }
+
+        #[test]
+        fn test_creator_entrypoints_reject_non_creator() {
+            let env = Env::default();
+            let (client, admin, _, token_index) = setup_with_token(&env);
+            let stranger = Address::generate(&env);
+
+            // The factory admin is not the token creator either
+            for caller in [&stranger, &admin] {
+                assert_eq!(
+                    client.try_set_max_burn_bps(caller, &token_index, &500),
+                    Err(Ok(Error::Unauthorized))
+                );
+                assert_eq!(
+                    client.try_freeze_metadata(caller, &token_index),
+                    Err(Ok(Error::Unauthorized))
+                );
+                assert_eq!(
+                    client.try_add_mint_authority(caller, &token_index, &stranger),
+                    Err(Ok(Error::Unauthorized))
+                );
+                assert_eq!(
+                    client.try_set_metadata_editable_until(caller, &token_index, &Some(1)),
+                    Err(Ok(Error::Unauthorized))
+                );
+            }
+
+            assert_eq!(client.get_token_info(&token_index).max_burn_bps, None);
+        }
+
+        #[test]
+        fn test_creator_entrypoints_report_missing_token() {
+            let env = Env::default();
+            let (client, _, creator, _) = setup_with_token(&env);
+
+            assert_eq!(
+                client.try_set_max_burn_bps(&creator, &99, &500),
+                Err(Ok(Error::TokenNotFound))
+            );
+            assert_eq!(
+                client.try_freeze_metadata(&creator, &99),
+                Err(Ok(Error::TokenNotFound))
+            );
+        }
+    }
+}
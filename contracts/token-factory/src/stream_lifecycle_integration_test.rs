@@ -37,6 +37,8 @@ mod stream_lifecycle_integration_tests {
             is_paused: false,
             clawback_enabled: false,
             freeze_enabled: false,
+            max_burn_bps: None,
+            official: false,
         
         };
         storage::set_token_info(&env, 0, &token_info);
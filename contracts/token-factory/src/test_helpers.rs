@@ -3,12 +3,19 @@
 extern crate alloc;
 use alloc::vec::Vec;
 
-use soroban_sdk::testutils::{Address as _, Events, Ledger};
-use soroban_sdk::{Address, Bytes, Env, Symbol, TryFromVal, Val};
+use soroban_sdk::testutils::{Address as _, ContractEvents, Events, Ledger};
+use soroban_sdk::{xdr, Address, Bytes, Env, String, Symbol, TryFromVal};
 
 use crate::storage;
 use crate::timelock;
 use crate::types::{ActionType, Error, VoteChoice};
+use crate::TokenFactoryClient;
+
+/// Initial supply of every token created by `seed_tokens`
+pub const SEED_SUPPLY: i128 = 1_000_000_000;
+
+/// Initial supply of the token created by `setup_with_token`
+pub const TOKEN_SUPPLY: i128 = 1_000_000;
 
 pub struct TestEnv {
     pub env: Env,
@@ -138,6 +145,7 @@ impl<'a> ProposalBuilder<'a> {
             ActionType::PauseContract | ActionType::UnpauseContract => pause_payload(self.env),
             ActionType::TreasuryChange => treasury_change_payload(self.env, &Address::generate(self.env)),
             ActionType::PolicyUpdate => policy_update_payload(self.env, 100_0000000, true, 86400),
+            ActionType::ParameterChange => Bytes::new(self.env),
         };
         self
     }
@@ -236,7 +244,7 @@ impl<'a> EventAssertions<'a> {
         Self { env }
     }
 
-    pub fn all(&self) -> soroban_sdk::Vec<(Address, soroban_sdk::Vec<Val>, Val)> {
+    pub fn all(&self) -> ContractEvents {
         self.env.events().all()
     }
 
@@ -263,13 +271,12 @@ impl<'a> EventAssertions<'a> {
     fn count(&self, name: &str) -> usize {
         let target = Symbol::new(self.env, name);
         let mut n = 0usize;
-        for evt in self.env.events().all().iter() {
-            let topics = evt.1;
-            if topics.len() == 0 {
+        for event in self.env.events().all().events() {
+            let xdr::ContractEventBody::V0(body) = &event.body;
+            let Some(first) = body.topics.first() else {
                 continue;
-            }
-            let first = topics.get(0).unwrap();
-            if let Ok(sym) = Symbol::try_from_val(self.env, &first) {
+            };
+            if let Ok(sym) = Symbol::try_from_val(self.env, first) {
                 if sym == target {
                     n += 1;
                 }
@@ -319,3 +326,119 @@ pub fn policy_update_payload(env: &Env, daily_cap: i128, allowlist: bool, period
     arr[17..25].copy_from_slice(&period.to_le_bytes());
     Bytes::from_array(env, &arr)
 }
+
+/// Register and initialize a factory with the standard test fees.
+/// Returns (client, admin, treasury).
+pub fn setup_factory(env: &Env) -> (TokenFactoryClient<'_>, Address, Address) {
+    env.mock_all_auths();
+    let contract_id = env.register_contract(None, crate::TokenFactory);
+    let client = TokenFactoryClient::new(env, &contract_id);
+
+    let admin = Address::generate(env);
+    let treasury = Address::generate(env);
+    client.initialize(&admin, &treasury, &70_000_000, &30_000_000);
+
+    (client, admin, treasury)
+}
+
+/// Set up a factory with one token. Returns (client, admin, creator, token_index).
+pub fn setup_with_token(env: &Env) -> (TokenFactoryClient<'_>, Address, Address, u32) {
+    setup_with_supply(env, TOKEN_SUPPLY)
+}
+
+/// Like `setup_with_token`, with the token's initial supply set to `supply`.
+pub fn setup_with_supply(
+    env: &Env,
+    supply: i128,
+) -> (TokenFactoryClient<'_>, Address, Address, u32) {
+    let (client, admin, _) = setup_factory(env);
+    let creator = Address::generate(env);
+    let token_index = create_test_token(env, &client, &creator, "TST", supply);
+
+    (client, admin, creator, token_index)
+}
+
+/// Create a token owned by `creator` with the given symbol and supply,
+/// paying the current base fee. Returns its index.
+pub fn create_test_token(
+    env: &Env,
+    client: &TokenFactoryClient,
+    creator: &Address,
+    symbol: &str,
+    supply: i128,
+) -> u32 {
+    client
+        .create_token(
+            creator,
+            &String::from_str(env, "Test Token"),
+            &String::from_str(env, symbol),
+            &7u32,
+            &supply,
+            &None,
+            &client.get_base_fee(),
+        )
+        .index
+}
+
+/// Create `n` identical tokens through `client`, all owned by one fresh
+/// creator, paying the current base fee. Returns the creator.
+///
+/// Tokens get the next `n` indices; names and supplies are fixed so runs
+/// are reproducible.
+pub fn seed_tokens(env: &Env, client: &TokenFactoryClient, n: u32) -> Address {
+    let creator = Address::generate(env);
+    for _ in 0..n {
+        create_test_token(env, client, &creator, "SEED", SEED_SUPPLY);
+    }
+    creator
+}
+
+/// Burn from `token_index`'s creator `n` times, with amounts `1..=n`.
+///
+/// Adds `n` records to the burn log and `n * (n + 1) / 2` to the token's
+/// `total_burned`.
+pub fn seed_burns(client: &TokenFactoryClient, token_index: u32, n: u32) {
+    let creator = client.get_token_info(&token_index).creator;
+    for amount in 1..=n {
+        client.burn(&creator, &token_index, &(amount as i128));
+    }
+}
+
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_seed_tokens_populates_registry() {
+        let env = Env::default();
+        let (client, _, _) = setup_factory(&env);
+
+        let creator = seed_tokens(&env, &client, 4);
+
+        assert_eq!(client.get_tokens_page(&0, &10).items.len(), 4);
+        assert_eq!(
+            client.get_latest_token().unwrap(),
+            client.get_token_info(&3)
+        );
+        for token_index in 0..4u32 {
+            let info = client.get_token_info(&token_index);
+            assert_eq!(info.creator, creator);
+            assert_eq!(info.total_supply, SEED_SUPPLY);
+        }
+    }
+
+    #[test]
+    fn test_seed_burns_populates_log_and_aggregates() {
+        let env = Env::default();
+        let (client, _, _) = setup_factory(&env);
+        seed_tokens(&env, &client, 2);
+
+        seed_burns(&client, 1, 10);
+
+        assert_eq!(client.get_burn_record_count(), 10);
+        let info = client.get_token_info(&1);
+        assert_eq!(info.total_burned, 55);
+        assert_eq!(info.burn_count, 10);
+        assert_eq!(info.total_supply, SEED_SUPPLY - 55);
+        assert_eq!(client.get_token_info(&0).total_burned, 0);
+    }
+}
@@ -67,6 +67,7 @@ pub fn schedule_fee_update(
     metadata_fee: Option<i128>,
 ) -> Result<u64, Error> {
     admin.require_auth();
+    storage::ensure_not_killed(env)?;
 
     let current_admin = storage::get_admin(env);
     if *admin != current_admin {
@@ -133,6 +134,7 @@ pub fn schedule_fee_update(
 /// * `Error::Unauthorized` - Caller is not the admin
 pub fn schedule_pause_update(env: &Env, admin: &Address, paused: bool) -> Result<u64, Error> {
     admin.require_auth();
+    storage::ensure_not_killed(env)?;
 
     let current_admin = storage::get_admin(env);
     if *admin != current_admin {
@@ -186,11 +188,13 @@ pub fn schedule_treasury_update(
     new_treasury: &Address,
 ) -> Result<u64, Error> {
     admin.require_auth();
+    storage::ensure_not_killed(env)?;
 
     let current_admin = storage::get_admin(env);
     if *admin != current_admin {
         return Err(Error::Unauthorized);
     }
+    storage::check_treasury_separation(env, admin, new_treasury)?;
 
     let config = storage::get_timelock_config(env);
     let current_time = env.ledger().timestamp();
@@ -233,6 +237,8 @@ pub fn schedule_treasury_update(
 /// * `Error::TimelockNotExpired` - Timelock period has not elapsed
 /// * `Error::ChangeAlreadyExecuted` - Change has already been executed
 pub fn execute_change(env: &Env, change_id: u64) -> Result<(), Error> {
+    storage::ensure_not_killed(env)?;
+
     let mut pending_change =
         storage::get_pending_change(env, change_id).ok_or(Error::TokenNotFound)?;
 
@@ -275,6 +281,9 @@ pub fn execute_change(env: &Env, change_id: u64) -> Result<(), Error> {
         }
         ChangeType::TreasuryUpdate => {
             if let Some(ref treasury) = pending_change.treasury {
+                // The admin may have changed since scheduling
+                let admin = storage::get_admin(env);
+                storage::check_treasury_separation(env, &admin, treasury)?;
                 storage::set_treasury(env, treasury);
                 events::emit_treasury_updated(env, treasury);
             }
@@ -306,6 +315,7 @@ pub fn execute_change(env: &Env, change_id: u64) -> Result<(), Error> {
 /// * `Error::ChangeAlreadyExecuted` - Change has already been executed
 pub fn cancel_change(env: &Env, admin: &Address, change_id: u64) -> Result<(), Error> {
     admin.require_auth();
+    storage::ensure_not_killed(env)?;
 
     let current_admin = storage::get_admin(env);
     if *admin != current_admin {
@@ -490,6 +500,7 @@ pub fn create_proposal(
 ) -> Result<u64, Error> {
     // Verify proposer is admin
     proposer.require_auth();
+    storage::ensure_not_killed(env)?;
     let admin = storage::get_admin(env);
     if proposer != &admin {
         return Err(Error::Unauthorized);
@@ -523,7 +534,7 @@ pub fn create_proposal(
 
     // Generate proposal ID and increment count
     let proposal_id = storage::get_next_proposal_id(env);
-    storage::increment_proposal_count(env);
+    storage::increment_proposal_count(env)?;
 
     // Create proposal
     let proposal = Proposal {
@@ -976,6 +987,7 @@ pub fn vote_proposal(
 
     // Verify voter authentication
     voter.require_auth();
+    storage::ensure_not_killed(env)?;
 
     // Get proposal
     let mut proposal = storage::get_proposal(env, proposal_id).ok_or(Error::ProposalNotFound)?;
@@ -1091,6 +1103,7 @@ pub fn has_voted(env: &Env, proposal_id: u64, voter: &Address) -> bool {
 /// * `Error::VotingEnded` - If voting is still ongoing
 pub fn finalize_proposal(env: &Env, proposal_id: u64) -> Result<(), Error> {
     use crate::proposal_state_machine::ProposalStateMachine;
+    storage::ensure_not_killed(env)?;
 
     let mut proposal = storage::get_proposal(env, proposal_id).ok_or(Error::ProposalNotFound)?;
 
@@ -1188,6 +1201,7 @@ pub fn finalize_proposal(env: &Env, proposal_id: u64) -> Result<(), Error> {
 /// Emits `proposal_queued` event on success
 pub fn queue_proposal(env: &Env, proposal_id: u64) -> Result<(), Error> {
     use crate::proposal_state_machine::ProposalStateMachine;
+    storage::ensure_not_killed(env)?;
 
     // First, finalize the proposal if it's still Active
     finalize_proposal(env, proposal_id)?;
@@ -1240,6 +1254,7 @@ pub fn queue_proposal(env: &Env, proposal_id: u64) -> Result<(), Error> {
 
 pub fn execute_proposal(env: &Env, proposal_id: u64) -> Result<(), Error> {
     use crate::proposal_state_machine::ProposalStateMachine;
+    storage::ensure_not_killed(env)?;
 
     let mut proposal = storage::get_proposal(env, proposal_id).ok_or(Error::ProposalNotFound)?;
 
@@ -1,14 +1,55 @@
 use soroban_sdk::{Address, Env, String, Vec};
-use crate::types::{Error, TokenCreationParams, TokenInfo};
+use crate::types::{CreationReceipt, Error, TokenCreationParams, TokenInfo};
 use crate::storage;
 
-/// Validate token creation parameters
-fn validate_token_params(
-    name: &String,
-    symbol: &String,
-    decimals: u32,
-    initial_supply: i128,
-) -> Result<(), Error> {
+/// Seconds after creation during which the creator may rename a token
+pub const RENAME_WINDOW_SECS: u64 = 86_400;
+
+/// Largest accepted `decimals` value
+pub const MAX_DECIMALS: u32 = 18;
+
+/// Decimals substituted when neither the caller nor the admin chose any
+pub const DEFAULT_DECIMALS: u32 = 7;
+
+/// Whether `decimals` is within the accepted range (0-18)
+pub fn is_valid_decimals(decimals: u32) -> bool {
+    decimals <= MAX_DECIMALS
+}
+
+/// Longest raw symbol `normalize_symbol` rewrites; longer input is returned
+/// unchanged and then fails the 1-12 character length check regardless.
+const MAX_RAW_SYMBOL_LEN: usize = 32;
+
+/// Normalize a token symbol: trim surrounding ASCII whitespace and
+/// uppercase ASCII letters, so "eth", "ETH" and " eth " are one symbol.
+///
+/// Every creation path stores the normalized form, and symbol comparisons
+/// go through this helper as well. Non-ASCII bytes are left untouched.
+pub fn normalize_symbol(env: &Env, symbol: &String) -> String {
+    let len = symbol.len() as usize;
+    if len > MAX_RAW_SYMBOL_LEN {
+        return symbol.clone();
+    }
+
+    let mut buf = [0u8; MAX_RAW_SYMBOL_LEN];
+    symbol.copy_into_slice(&mut buf[..len]);
+
+    let mut start = 0;
+    let mut end = len;
+    while start < end && buf[start].is_ascii_whitespace() {
+        start += 1;
+    }
+    while end > start && buf[end - 1].is_ascii_whitespace() {
+        end -= 1;
+    }
+
+    let normalized = &mut buf[start..end];
+    normalized.make_ascii_uppercase();
+    String::from_bytes(env, normalized)
+}
+
+/// Validate name and symbol lengths
+fn validate_name_and_symbol(name: &String, symbol: &String) -> Result<(), Error> {
     // Validate name length (1-32 characters)
     if name.len() == 0 || name.len() > 32 {
         return Err(Error::InvalidTokenParams);
@@ -19,29 +60,171 @@ fn validate_token_params(
         return Err(Error::InvalidTokenParams);
     }
 
+    Ok(())
+}
+
+/// Validate token creation parameters
+fn validate_token_params(
+    name: &String,
+    symbol: &String,
+    decimals: u32,
+    initial_supply: i128,
+) -> Result<(), Error> {
+    validate_name_and_symbol(name, symbol)?;
+
     // Validate decimals (0-18)
-    if decimals > 18 {
+    if !is_valid_decimals(decimals) {
         return Err(Error::InvalidTokenParams);
     }
 
-    // Validate initial supply (must be positive)
-    if initial_supply <= 0 {
+    // Validate initial supply (must be non-negative; configured bounds
+    // are checked separately by `validate_initial_supply_bounds`)
+    if initial_supply < 0 {
         return Err(Error::InvalidTokenParams);
     }
 
     Ok(())
 }
 
-/// Calculate total fee for token creation
-fn calculate_creation_fee(env: &Env, has_metadata: bool) -> i128 {
+/// Validate initial supply against the admin-configured bounds
+pub(crate) fn validate_initial_supply_bounds(env: &Env, initial_supply: i128) -> Result<(), Error> {
+    if initial_supply < storage::get_min_initial_supply(env)
+        || initial_supply > storage::get_max_initial_supply(env)
+    {
+        return Err(Error::InvalidParameters);
+    }
+    Ok(())
+}
+
+/// Reject decimals below the admin-configured `MinDecimals`.
+pub(crate) fn validate_min_decimals(env: &Env, decimals: u32) -> Result<(), Error> {
+    if decimals < storage::get_min_decimals(env) {
+        return Err(Error::InvalidParameters);
+    }
+    Ok(())
+}
+
+/// Calculate total fee for token creation, never below `MinCreationFee`.
+/// Fee-exempt creators pay nothing, regardless of the floor. The metadata
+/// fee is skipped while the admin waives it for URIs supplied at creation.
+/// The result is rounded up to `FeeRoundingUnit` when one is set.
+fn calculate_creation_fee(env: &Env, creator: &Address, has_metadata: bool) -> i128 {
+    if storage::is_fee_exempt(env, creator) {
+        return 0;
+    }
+
     let base_fee = storage::get_base_fee(env);
-    let metadata_fee = if has_metadata {
+    let metadata_fee = if has_metadata && !storage::is_metadata_fee_waived_at_creation(env) {
         storage::get_metadata_fee(env)
     } else {
         0
     };
     
-    base_fee + metadata_fee
+    let fee = (base_fee + metadata_fee).max(storage::get_min_creation_fee(env));
+    storage::round_fee_up(env, fee)
+}
+
+/// Charge the metadata fee for a URI attached after creation.
+///
+/// Uses the token's effective metadata fee (per-token override or global),
+/// rounded like creation fees. The creation-time waiver does not apply;
+/// fee-exempt payers pay nothing. Returns the amount charged.
+pub(crate) fn charge_late_metadata_fee(
+    env: &Env,
+    payer: &Address,
+    token_index: u32,
+    fee_payment: i128,
+) -> Result<i128, Error> {
+    if storage::is_fee_exempt(env, payer) {
+        return Ok(0);
+    }
+
+    let fee = storage::get_effective_metadata_fee(env, token_index)?;
+    let required = storage::round_fee_up(env, fee);
+    if fee_payment < required {
+        return Err(Error::InsufficientFee);
+    }
+    crate::treasury::add_collected_fee(env, fee_payment)?;
+    Ok(fee_payment)
+}
+
+/// Correct a token's name and symbol shortly after launch (creator only).
+///
+/// Allowed until `created_at + RENAME_WINDOW_SECS`. The new symbol must not
+/// be used by any other token; checking this scans the registry once.
+pub fn rename_token(
+    env: &Env,
+    token_index: u32,
+    new_name: String,
+    new_symbol: String,
+    caller: Address,
+) -> Result<(), Error> {
+    caller.require_auth();
+    storage::ensure_not_killed(env)?;
+
+    let mut info = storage::get_token_info(env, token_index).ok_or(Error::TokenNotFound)?;
+    if info.creator != caller {
+        return Err(Error::Unauthorized);
+    }
+    storage::ensure_not_retired(env, token_index)?;
+
+    let deadline = info.created_at.saturating_add(RENAME_WINDOW_SECS);
+    if env.ledger().timestamp() > deadline {
+        return Err(Error::Unauthorized);
+    }
+
+    let new_symbol = normalize_symbol(env, &new_symbol);
+    validate_name_and_symbol(&new_name, &new_symbol)?;
+
+    for i in storage::valid_token_indices(env) {
+        if i == token_index {
+            continue;
+        }
+        if let Some(other) = storage::get_token_info(env, i) {
+            if normalize_symbol(env, &other.symbol) == new_symbol {
+                return Err(Error::InvalidParameters);
+            }
+        }
+    }
+
+    let old_name = info.name.clone();
+    let old_symbol = info.symbol.clone();
+    info.name = new_name;
+    info.symbol = new_symbol;
+    storage::set_token_info(env, token_index, &info);
+    storage::set_token_info_by_address(env, &info.address, &info);
+
+    crate::events::emit_token_renamed(
+        env,
+        token_index,
+        &caller,
+        &old_name,
+        &old_symbol,
+        &info.name,
+        &info.symbol,
+    );
+    Ok(())
+}
+
+/// Verify a freshly created token's accounting starts consistent.
+///
+/// At creation `total_supply` must equal `initial_supply`, nothing may be
+/// recorded in `TotalMinted` (which tracks minting on top of the initial
+/// supply) and the creator must hold the whole supply.
+///
+/// # Errors
+/// * `Error::TokenNotFound` - Token index does not exist
+/// * `Error::SupplyInvariantViolated` - Any of the figures disagree
+pub fn check_creation_accounting(env: &Env, token_index: u32) -> Result<(), Error> {
+    let info = storage::get_token_info(env, token_index).ok_or(Error::TokenNotFound)?;
+
+    if info.total_supply != info.initial_supply
+        || storage::get_total_minted(env, token_index) != 0
+        || storage::get_balance(env, token_index, &info.creator) != info.initial_supply
+    {
+        return Err(Error::SupplyInvariantViolated);
+    }
+    Ok(())
 }
 
 /// Create a single token (internal implementation)
@@ -51,17 +234,26 @@ pub fn create_token_internal(
     params: &TokenCreationParams,
     token_index: u32,
 ) -> Result<Address, Error> {
+    let symbol = normalize_symbol(env, &params.symbol);
+
     // Validate parameters
     validate_token_params(
         &params.name,
-        &params.symbol,
+        &symbol,
         params.decimals,
         params.initial_supply,
     )?;
+    validate_initial_supply_bounds(env, params.initial_supply)?;
+    validate_min_decimals(env, params.decimals)?;
 
     // Validate max_supply: if set, must be >= initial_supply
     crate::mint::validate_max_supply_at_creation(params.initial_supply, params.max_supply)?;
 
+    // Validate metadata URI length against the configured limit
+    if let Some(ref uri) = params.metadata_uri {
+        crate::validation::validate_metadata_uri(env, uri)?;
+    }
+
     // Generate token address (placeholder - in production this would deploy actual token contract)
     // For now, we create a deterministic address based on token index
     let token_address = env.current_contract_address();
@@ -71,7 +263,7 @@ pub fn create_token_internal(
         address: token_address.clone(),
         creator: creator.clone(),
         name: params.name.clone(),
-        symbol: params.symbol.clone(),
+        symbol,
         decimals: params.decimals,
         total_supply: params.initial_supply,
         initial_supply: params.initial_supply,
@@ -84,14 +276,18 @@ pub fn create_token_internal(
         is_paused: false,
         clawback_enabled: false,
         freeze_enabled: false,
+        max_burn_bps: None,
+        official: matches!(storage::try_get_admin(env), Ok(admin) if admin == *creator),
     };
 
     // Store token info
     storage::set_token_info(env, token_index, &token_info);
     storage::set_token_info_by_address(env, &token_address, &token_info);
+    storage::set_token_index_by_address(env, &token_address, token_index);
 
     // Set initial balance for creator
     storage::set_balance(env, token_index, creator, params.initial_supply);
+    check_creation_accounting(env, token_index)?;
 
     // Emit token created event
     crate::events::emit_token_created(
@@ -99,7 +295,7 @@ pub fn create_token_internal(
         &token_address,
         creator,
         &params.name,
-        &params.symbol,
+        &token_info.symbol,
         params.decimals,
         params.initial_supply,
     );
@@ -120,22 +316,78 @@ pub fn create_token(
     initial_supply: i128,
     metadata_uri: Option<String>,
     fee_payment: i128,
-) -> Result<Address, Error> {
+) -> Result<CreationReceipt, Error> {
+    let payer = creator.clone();
+    create_token_for(
+        env,
+        &payer,
+        creator,
+        name,
+        symbol,
+        decimals,
+        initial_supply,
+        metadata_uri,
+        fee_payment,
+    )
+}
+
+/// Run the creator-level creation gates without creating or charging.
+///
+/// Mirrors the checks `create_token_for` applies before looking at the
+/// token parameters: initialization, contract pause, creator allowlist and
+/// registry capacity, in that order. Fee exemption is not a gate. Checks
+/// that depend on the call (name, supply, metadata, fee) are not covered.
+pub fn can_create(env: &Env, creator: &Address) -> Result<(), Error> {
+    storage::try_get_admin(env)?;
+    if storage::is_paused(env) {
+        return Err(Error::ContractPaused);
+    }
+    storage::ensure_not_killed(env)?;
+    storage::check_creator_allowed(env, creator)?;
+    storage::check_registry_capacity(env, 1)
+}
+
+/// Create a single token authorized and paid for by `payer` but attributed
+/// to `creator`. `create_token` is the `payer == creator` case.
+///
+/// The allowlist and fee exemption are checked against `payer`; the token,
+/// its initial supply and any referral commission go to `creator`.
+#[allow(clippy::too_many_arguments)]
+pub fn create_token_for(
+    env: &Env,
+    payer: &Address,
+    creator: Address,
+    name: String,
+    symbol: String,
+    decimals: u32,
+    initial_supply: i128,
+    metadata_uri: Option<String>,
+    fee_payment: i128,
+) -> Result<CreationReceipt, Error> {
     // Check if paused
     if storage::is_paused(env) {
         return Err(Error::ContractPaused);
     }
+    storage::ensure_not_killed(env)?;
 
-    // Require creator authorization
-    creator.require_auth();
+    // Require payer authorization
+    payer.require_auth();
+    storage::check_creator_allowed(env, payer)?;
+    storage::check_metadata_required(env, &metadata_uri)?;
 
     // Calculate and verify fee
-    let required_fee = calculate_creation_fee(env, metadata_uri.is_some());
+    let required_fee = calculate_creation_fee(env, payer, metadata_uri.is_some());
     if fee_payment < required_fee {
         return Err(Error::InsufficientFee);
     }
+    let fee_charged = if storage::is_fee_exempt(env, payer) {
+        0
+    } else {
+        fee_payment
+    };
 
     // Get next token index
+    // New count minus one is the index of the token being created
     let token_index = storage::increment_token_count(env)? - 1;
 
     // Create token parameters
@@ -152,13 +404,19 @@ pub fn create_token(
     let token_address = create_token_internal(env, &creator, &params, token_index)?;
 
     // Credit referral commission if the creator has a registered referrer.
-    crate::referral::credit_commission(env, &creator, token_index, fee_payment);
+    crate::referral::credit_commission(env, &creator, token_index, fee_charged)?;
+    crate::treasury::add_collected_fee(env, fee_charged)?;
 
     // Transfer fee to treasury (placeholder - in production would use actual token transfer)
     // let treasury = storage::get_treasury(env);
     // token::transfer(env, &creator, &treasury, fee_payment);
 
-    Ok(token_address)
+    Ok(CreationReceipt {
+        token_address,
+        index: token_index,
+        fee_charged,
+        created_at: env.ledger().timestamp(),
+    })
 }
 
 /// Batch create multiple tokens atomically
@@ -199,9 +457,11 @@ pub fn batch_create_tokens(
     if storage::is_paused(env) {
         return Err(Error::ContractPaused);
     }
+    storage::ensure_not_killed(env)?;
 
     // Require creator authorization
     creator.require_auth();
+    storage::check_creator_allowed(env, &creator)?;
 
     // Validate batch is not empty
     if tokens.is_empty() {
@@ -214,13 +474,16 @@ pub fn batch_create_tokens(
         // Validate each token's parameters
         validate_token_params(
             &token.name,
-            &token.symbol,
+            &normalize_symbol(env, &token.symbol),
             token.decimals,
             token.initial_supply,
         )?;
+        storage::check_metadata_required(env, &token.metadata_uri)?;
+        validate_initial_supply_bounds(env, token.initial_supply)?;
+        validate_min_decimals(env, token.decimals)?;
 
         // Calculate fee for this token
-        let token_fee = calculate_creation_fee(env, token.metadata_uri.is_some());
+        let token_fee = calculate_creation_fee(env, &creator, token.metadata_uri.is_some());
         total_required_fee = total_required_fee
             .checked_add(token_fee)
             .ok_or(Error::InvalidTokenParams)?;
@@ -230,6 +493,7 @@ pub fn batch_create_tokens(
     if total_fee_payment < total_required_fee {
         return Err(Error::InsufficientFee);
     }
+    storage::check_registry_capacity(env, tokens.len())?;
 
     // Phase 2: Create all tokens (all validations passed)
     let mut created_addresses = Vec::new(env);
@@ -252,6 +516,10 @@ pub fn batch_create_tokens(
     // Emit batch creation event
     crate::events::emit_batch_tokens_created(env, &creator, tokens.len() as u32);
 
+    if !storage::is_fee_exempt(env, &creator) {
+        crate::treasury::add_collected_fee(env, total_fee_payment)?;
+    }
+
     // Transfer total fee to treasury (placeholder)
     // let treasury = storage::get_treasury(env);
     // token::transfer(env, &creator, &treasury, total_fee_payment);
@@ -324,15 +592,28 @@ mod tests {
     }
 
     #[test]
-    fn test_validate_token_params_zero_supply() {
+    fn test_validate_token_params_negative_supply() {
         let env = Env::default();
         let name = String::from_str(&env, "TestToken");
         let symbol = String::from_str(&env, "TEST");
         
-        let result = validate_token_params(&name, &symbol, 6, 0);
+        let result = validate_token_params(&name, &symbol, 6, -1);
         assert_eq!(result, Err(Error::InvalidTokenParams));
     }
 
+    #[test]
+    fn test_zero_supply_rejected_by_default_bounds() {
+        let env = Env::default();
+        let contract_id = env.register_contract(None, crate::TokenFactory);
+
+        // Zero is not a static parameter error; the default minimum of 1 rejects it
+        let name = String::from_str(&env, "TestToken");
+        let symbol = String::from_str(&env, "TEST");
+        assert!(validate_token_params(&name, &symbol, 6, 0).is_ok());
+        let result = env.as_contract(&contract_id, || validate_initial_supply_bounds(&env, 0));
+        assert_eq!(result, Err(Error::InvalidParameters));
+    }
+
     #[test]
     fn test_calculate_creation_fee_without_metadata() {
         let env = Env::default();
@@ -450,4 +731,2626 @@ mod tests {
         assert_eq!(env.events().all().len(), before, "no partial success event leakage allowed");
     }
     */
+
+    /// Tests for the `can_create` preflight.
+    mod can_create {
+        use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+        use crate::test_helpers::setup_factory;
+        use crate::types::Error;
+        use crate::{TokenFactory, TokenFactoryClient};
+
+        fn create(env: &Env, client: &TokenFactoryClient, creator: &Address) {
+            client.create_token(
+                creator,
+                &String::from_str(env, "Preflight"),
+                &String::from_str(env, "PRE"),
+                &7u32,
+                &1_000i128,
+                &None,
+                &70_000_000i128,
+            );
+        }
+
+        #[test]
+        fn test_eligible_creator_passes() {
+            let env = Env::default();
+            let (client, admin, _) = setup_factory(&env);
+            let creator = Address::generate(&env);
+
+            assert!(client.try_can_create(&creator).is_ok());
+
+            client.add_fee_exemption(&admin, &creator);
+            assert!(client.try_can_create(&creator).is_ok());
+        }
+
+        #[test]
+        fn test_uninitialized_factory() {
+            let env = Env::default();
+            let contract_id = env.register_contract(None, TokenFactory);
+            let client = TokenFactoryClient::new(&env, &contract_id);
+
+            assert_eq!(
+                client.try_can_create(&Address::generate(&env)),
+                Err(Ok(Error::NotInitialized))
+            );
+        }
+
+        #[test]
+        fn test_paused_factory() {
+            let env = Env::default();
+            let (client, admin, _) = setup_factory(&env);
+
+            client.pause(&admin);
+            assert_eq!(
+                client.try_can_create(&Address::generate(&env)),
+                Err(Ok(Error::ContractPaused))
+            );
+        }
+
+        #[test]
+        fn test_allowlist_gate() {
+            let env = Env::default();
+            let (client, admin, _) = setup_factory(&env);
+            let listed = Address::generate(&env);
+            let unlisted = Address::generate(&env);
+
+            client.set_creator_allowlist_enabled(&admin, &true);
+            client.set_allowed_creator(&admin, &listed, &true);
+
+            assert!(client.try_can_create(&listed).is_ok());
+            assert!(client.try_can_create(&admin).is_ok());
+            assert_eq!(
+                client.try_can_create(&unlisted),
+                Err(Ok(Error::CreatorNotAllowed))
+            );
+        }
+
+        #[test]
+        fn test_registry_capacity_gate() {
+            let env = Env::default();
+            let (client, admin, _) = setup_factory(&env);
+            let creator = Address::generate(&env);
+
+            client.set_max_tokens(&admin, &1);
+            assert!(client.try_can_create(&creator).is_ok());
+
+            create(&env, &client, &creator);
+            assert_eq!(
+                client.try_can_create(&creator),
+                Err(Ok(Error::RegistryFull))
+            );
+            assert_eq!(
+                client.try_create_token(
+                    &creator,
+                    &String::from_str(&env, "Preflight"),
+                    &String::from_str(&env, "PRE"),
+                    &7u32,
+                    &1_000i128,
+                    &None,
+                    &70_000_000i128,
+                ),
+                Err(Ok(Error::RegistryFull))
+            );
+        }
+    }
+
+    /// Tests for the checked counter increments (`storage::bump_counter`).
+    mod counter {
+        use soroban_sdk::{testutils::Address as _, Env};
+
+        use crate::storage;
+        use crate::test_helpers::setup_with_token;
+        use crate::types::{DataKey, Error};
+        use crate::TokenFactoryClient;
+
+        #[test]
+        fn test_bump_counter_increments() {
+            let env = Env::default();
+            let (client, _, _, _) = setup_with_token(&env);
+
+            env.as_contract(&client.address, || {
+                assert_eq!(storage::bump_counter(&env, DataKey::StreamCount), Ok(1));
+                assert_eq!(storage::bump_counter(&env, DataKey::StreamCount), Ok(2));
+                assert_eq!(storage::bump_counter(&env, DataKey::TokenCount), Ok(2));
+                assert_eq!(storage::get_token_count(&env), 2);
+            });
+        }
+
+        #[test]
+        fn test_bump_counter_overflow() {
+            let env = Env::default();
+            let (client, _, _, _) = setup_with_token(&env);
+
+            env.as_contract(&client.address, || {
+                env.storage()
+                    .instance()
+                    .set(&DataKey::TokenCount, &u32::MAX);
+                assert_eq!(
+                    storage::bump_counter(&env, DataKey::TokenCount),
+                    Err(Error::ArithmeticError)
+                );
+                assert_eq!(storage::get_token_count(&env), u32::MAX);
+
+                let key = DataKey::BurnCount(0);
+                env.storage().persistent().set(&key, &u32::MAX);
+                assert_eq!(
+                    storage::bump_persistent_counter(&env, key),
+                    Err(Error::ArithmeticError)
+                );
+                assert_eq!(storage::get_burn_count(&env, 0), u32::MAX);
+            });
+        }
+
+        #[test]
+        fn test_burn_count_overflow_rejects_burn() {
+            let env = Env::default();
+            let (client, _, creator, _) = setup_with_token(&env);
+
+            env.as_contract(&client.address, || {
+                env.storage()
+                    .persistent()
+                    .set(&DataKey::BurnCount(0), &u32::MAX);
+            });
+
+            assert_eq!(
+                client.try_burn(&creator, &0, &100),
+                Err(Ok(Error::ArithmeticError))
+            );
+            assert_eq!(client.get_token_info(&0).total_supply, 1_000_000);
+        }
+    }
+
+    /// Tests for the accounting check run at token creation.
+    mod creation_accounting {
+        use soroban_sdk::{testutils::Address as _, vec, Env, String};
+
+        use crate::storage;
+        use crate::test_helpers::setup_with_supply;
+        use crate::token_creation;
+        use crate::types::{DataKey, Error, TokenCreationParams};
+        use crate::TokenFactoryClient;
+
+        fn assert_consistent(env: &Env, client: &TokenFactoryClient, token_index: u32) {
+            let info = client.get_token_info(&token_index);
+            let (minted, balance) = env.as_contract(&client.address, || {
+                (
+                    storage::get_total_minted(env, token_index),
+                    storage::get_balance(env, token_index, &info.creator),
+                )
+            });
+            assert_eq!(info.total_supply, info.initial_supply);
+            assert_eq!(minted, 0);
+            assert_eq!(balance, info.initial_supply);
+            assert!(client.verify_supply_invariant(&token_index));
+        }
+
+        #[test]
+        fn test_fields_consistent_after_creation() {
+            let env = Env::default();
+            let (client, _, _, _) = setup_with_supply(&env, 5_000);
+
+            assert_consistent(&env, &client, 0);
+            assert_eq!(client.get_token_info(&0).initial_supply, 5_000);
+        }
+
+        #[test]
+        fn test_batch_created_tokens_consistent() {
+            let env = Env::default();
+            let (client, _, creator, _) = setup_with_supply(&env, 5_000);
+
+            let params = TokenCreationParams {
+                name: String::from_str(&env, "Batch"),
+                symbol: String::from_str(&env, "BAT"),
+                decimals: 7,
+                initial_supply: 9_999,
+                max_supply: None,
+                metadata_uri: None,
+            };
+            client.batch_create_tokens(&creator, &vec![&env, params], &70_000_000);
+
+            assert_consistent(&env, &client, 1);
+        }
+
+        #[test]
+        fn test_drift_is_reported() {
+            let env = Env::default();
+            let (client, _, _, _) = setup_with_supply(&env, 5_000);
+
+            let result = env.as_contract(&client.address, || {
+                env.storage()
+                    .persistent()
+                    .set(&DataKey::TotalMinted(0), &1i128);
+                token_creation::check_creation_accounting(&env, 0)
+            });
+            assert_eq!(result, Err(Error::SupplyInvariantViolated));
+
+            let missing = env.as_contract(&client.address, || {
+                token_creation::check_creation_accounting(&env, 9)
+            });
+            assert_eq!(missing, Err(Error::TokenNotFound));
+        }
+    }
+
+    /// Tests for the `CreationReceipt` returned by `create_token`.
+    mod creation_receipt {
+        use soroban_sdk::{
+            testutils::{Address as _, Ledger},
+            Address, Env, String,
+        };
+
+        use crate::test_helpers::setup_factory;
+
+        #[test]
+        fn test_receipt_matches_stored_state() {
+            let env = Env::default();
+            let (client, _, _) = setup_factory(&env);
+            let creator = Address::generate(&env);
+            env.ledger().with_mut(|l| l.timestamp = 42_000);
+
+            let receipt = client.create_token(
+                &creator,
+                &String::from_str(&env, "Receipt"),
+                &String::from_str(&env, "RCP"),
+                &7u32,
+                &1_000i128,
+                &Some(String::from_str(&env, "ipfs://QmReceipt")),
+                &100_000_000i128,
+            );
+
+            let info = client.get_token_info(&receipt.index);
+            assert_eq!(receipt.index, 0);
+            assert_eq!(receipt.token_address, info.address);
+            assert_eq!(receipt.created_at, info.created_at);
+            assert_eq!(receipt.created_at, 42_000);
+            assert_eq!(receipt.fee_charged, 100_000_000);
+            assert_eq!(info.creator, creator);
+        }
+
+        #[test]
+        fn test_receipts_carry_sequential_indices() {
+            let env = Env::default();
+            let (client, _, _) = setup_factory(&env);
+            let creator = Address::generate(&env);
+
+            for expected in 0..3u32 {
+                let receipt = client.create_token(
+                    &creator,
+                    &String::from_str(&env, "Receipt"),
+                    &String::from_str(&env, "RCP"),
+                    &7u32,
+                    &1_000i128,
+                    &None,
+                    &70_000_000i128,
+                );
+                assert_eq!(receipt.index, expected);
+                assert_eq!(receipt.fee_charged, 70_000_000);
+                assert_eq!(
+                    client.get_token_info(&expected).address,
+                    receipt.token_address
+                );
+            }
+        }
+    }
+
+    /// Tests for creator allowlist mode and admin `create_for`.
+    mod creator_allowlist {
+        use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+
+        use crate::storage;
+        use crate::test_helpers::setup_factory;
+        use crate::types::{Error, TokenCreationParams};
+        use crate::TokenFactoryClient;
+
+        fn try_create(
+            env: &Env,
+            client: &TokenFactoryClient,
+            creator: &Address,
+        ) -> Result<u32, Error> {
+            match client.try_create_token(
+                creator,
+                &String::from_str(env, "Listed"),
+                &String::from_str(env, "LST"),
+                &7u32,
+                &1_000i128,
+                &None,
+                &70_000_000i128,
+            ) {
+                Ok(Ok(receipt)) => Ok(receipt.index),
+                Err(Ok(err)) => Err(err),
+                _ => panic!("unexpected host error"),
+            }
+        }
+
+        #[test]
+        fn test_open_creation_when_mode_off() {
+            let env = Env::default();
+            let (client, _, _) = setup_factory(&env);
+
+            assert!(!client.is_creator_allowlist_enabled());
+            assert!(try_create(&env, &client, &Address::generate(&env)).is_ok());
+        }
+
+        #[test]
+        fn test_unlisted_creator_rejected_when_mode_on() {
+            let env = Env::default();
+            let (client, admin, _) = setup_factory(&env);
+            let listed = Address::generate(&env);
+            let unlisted = Address::generate(&env);
+
+            client.set_creator_allowlist_enabled(&admin, &true);
+            client.set_allowed_creator(&admin, &listed, &true);
+
+            assert!(try_create(&env, &client, &listed).is_ok());
+            assert_eq!(
+                try_create(&env, &client, &unlisted),
+                Err(Error::CreatorNotAllowed)
+            );
+
+            let params = TokenCreationParams {
+                name: String::from_str(&env, "Batch"),
+                symbol: String::from_str(&env, "BAT"),
+                decimals: 7,
+                initial_supply: 1_000,
+                max_supply: None,
+                metadata_uri: None,
+            };
+            assert_eq!(
+                client.try_batch_create_tokens(&unlisted, &vec![&env, params], &70_000_000),
+                Err(Ok(Error::CreatorNotAllowed))
+            );
+
+            client.set_allowed_creator(&admin, &listed, &false);
+            assert_eq!(
+                try_create(&env, &client, &listed),
+                Err(Error::CreatorNotAllowed)
+            );
+        }
+
+        #[test]
+        fn test_admin_creates_while_mode_on() {
+            let env = Env::default();
+            let (client, admin, _) = setup_factory(&env);
+
+            client.set_creator_allowlist_enabled(&admin, &true);
+            assert!(!client.is_allowed_creator(&admin));
+
+            let index = try_create(&env, &client, &admin).unwrap();
+            assert_eq!(client.get_token_info(&index).creator, admin);
+        }
+
+        #[test]
+        fn test_create_for_attributes_to_creator() {
+            let env = Env::default();
+            let (client, admin, _) = setup_factory(&env);
+            let partner = Address::generate(&env);
+
+            client.set_creator_allowlist_enabled(&admin, &true);
+            let receipt = client.create_for(
+                &admin,
+                &partner,
+                &String::from_str(&env, "Partner"),
+                &String::from_str(&env, "PTN"),
+                &7u32,
+                &5_000i128,
+                &None,
+                &70_000_000i128,
+            );
+
+            let info = client.get_token_info(&receipt.index);
+            assert_eq!(info.creator, partner);
+            let balance = env.as_contract(&client.address, || {
+                storage::get_balance(&env, receipt.index, &partner)
+            });
+            assert_eq!(balance, 5_000);
+
+            // The partner holds creator rights afterwards
+            client.mint(&partner, &receipt.index, &partner, &1);
+        }
+
+        #[test]
+        fn test_create_for_and_config_require_admin() {
+            let env = Env::default();
+            let (client, _, _) = setup_factory(&env);
+            let stranger = Address::generate(&env);
+
+            let result = client.try_create_for(
+                &stranger,
+                &stranger,
+                &String::from_str(&env, "Nope"),
+                &String::from_str(&env, "NOP"),
+                &7u32,
+                &1_000i128,
+                &None,
+                &70_000_000i128,
+            );
+            assert_eq!(result, Err(Ok(Error::Unauthorized)));
+            assert_eq!(
+                client.try_set_creator_allowlist_enabled(&stranger, &true),
+                Err(Ok(Error::Unauthorized))
+            );
+            assert_eq!(
+                client.try_set_allowed_creator(&stranger, &stranger, &true),
+                Err(Ok(Error::Unauthorized))
+            );
+        }
+    }
+
+    /// Tests for the configurable default decimals used at creation.
+    mod default_decimals {
+        use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+        use crate::test_helpers::setup_factory;
+        use crate::token_creation::DEFAULT_DECIMALS;
+        use crate::types::{CreationReceipt, Error, OptDecimalsTokenParams};
+        use crate::TokenFactoryClient;
+
+        fn create(
+            env: &Env,
+            client: &TokenFactoryClient,
+            decimals: Option<u32>,
+        ) -> Result<CreationReceipt, Error> {
+            let params = OptDecimalsTokenParams {
+                name: String::from_str(env, "DecToken"),
+                symbol: String::from_str(env, "DEC"),
+                decimals,
+                initial_supply: 1_000,
+                metadata_uri: None,
+            };
+            match client.try_create_token_opt_decimals(
+                &Address::generate(env),
+                &params,
+                &70_000_000i128,
+            ) {
+                Ok(Ok(receipt)) => Ok(receipt),
+                Err(Ok(err)) => Err(err),
+                _ => panic!("unexpected host error"),
+            }
+        }
+
+        #[test]
+        fn test_explicit_decimals_used() {
+            let env = Env::default();
+            let (client, _, _) = setup_factory(&env);
+
+            let receipt = create(&env, &client, Some(2)).unwrap();
+            assert_eq!(client.get_token_info(&receipt.index).decimals, 2);
+        }
+
+        #[test]
+        fn test_omitted_decimals_use_default() {
+            let env = Env::default();
+            let (client, admin, _) = setup_factory(&env);
+
+            assert_eq!(client.default_decimals(), DEFAULT_DECIMALS);
+            let receipt = create(&env, &client, None).unwrap();
+            assert_eq!(
+                client.get_token_info(&receipt.index).decimals,
+                DEFAULT_DECIMALS
+            );
+
+            client.set_default_decimals(&admin, &12);
+            let receipt = create(&env, &client, None).unwrap();
+            assert_eq!(client.get_token_info(&receipt.index).decimals, 12);
+        }
+
+        #[test]
+        fn test_invalid_explicit_decimals_rejected() {
+            let env = Env::default();
+            let (client, _, _) = setup_factory(&env);
+
+            assert!(client.is_valid_decimals(&18));
+            assert!(!client.is_valid_decimals(&19));
+            assert_eq!(
+                create(&env, &client, Some(19)).err(),
+                Some(Error::InvalidTokenParams)
+            );
+            assert_eq!(client.get_full_state().token_count, 0);
+        }
+
+        #[test]
+        fn test_set_default_validation() {
+            let env = Env::default();
+            let (client, admin, _) = setup_factory(&env);
+            let stranger = Address::generate(&env);
+
+            assert_eq!(
+                client.try_set_default_decimals(&stranger, &6),
+                Err(Ok(Error::Unauthorized))
+            );
+            assert_eq!(
+                client.try_set_default_decimals(&admin, &19),
+                Err(Ok(Error::InvalidParameters))
+            );
+            assert_eq!(client.default_decimals(), DEFAULT_DECIMALS);
+        }
+    }
+
+    /// Tests for admin-configurable initial-supply bounds at creation.
+    mod initial_supply_bounds {
+        use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+        use crate::test_helpers::setup_factory;
+        use crate::types::Error;
+        use crate::TokenFactoryClient;
+
+        /// Attempt a creation with `supply`, returning the contract error if any.
+        fn try_create(env: &Env, client: &TokenFactoryClient, supply: i128) -> Result<(), Error> {
+            match client.try_create_token(
+                &Address::generate(env),
+                &String::from_str(env, "Bounded"),
+                &String::from_str(env, "BND"),
+                &7u32,
+                &supply,
+                &None,
+                &70_000_000i128,
+            ) {
+                Ok(_) => Ok(()),
+                Err(Ok(err)) => Err(err),
+                Err(Err(_)) => panic!("unexpected host error"),
+            }
+        }
+
+        #[test]
+        fn test_default_bounds_reject_zero_supply() {
+            let env = Env::default();
+            let (client, _, _) = setup_factory(&env);
+
+            assert_eq!(try_create(&env, &client, 0), Err(Error::InvalidParameters));
+            assert!(try_create(&env, &client, 1).is_ok());
+        }
+
+        #[test]
+        fn test_configured_bounds_are_inclusive() {
+            let env = Env::default();
+            let (client, admin, _) = setup_factory(&env);
+
+            client.set_initial_supply_bounds(&admin, &1_000, &1_000_000);
+
+            assert_eq!(
+                try_create(&env, &client, 999),
+                Err(Error::InvalidParameters)
+            );
+            assert!(try_create(&env, &client, 1_000).is_ok());
+            assert!(try_create(&env, &client, 1_000_000).is_ok());
+            assert_eq!(
+                try_create(&env, &client, 1_000_001),
+                Err(Error::InvalidParameters)
+            );
+        }
+
+        #[test]
+        fn test_zero_supply_allowed_when_min_is_zero() {
+            let env = Env::default();
+            let (client, admin, _) = setup_factory(&env);
+
+            client.set_initial_supply_bounds(&admin, &0, &1_000);
+
+            assert!(try_create(&env, &client, 0).is_ok());
+            assert_eq!(client.get_token_info(&0).total_supply, 0);
+        }
+
+        #[test]
+        fn test_set_bounds_validation() {
+            let env = Env::default();
+            let (client, admin, _) = setup_factory(&env);
+            let stranger = Address::generate(&env);
+
+            assert_eq!(
+                client.try_set_initial_supply_bounds(&stranger, &0, &10),
+                Err(Ok(Error::Unauthorized))
+            );
+            assert_eq!(
+                client.try_set_initial_supply_bounds(&admin, &-1, &10),
+                Err(Ok(Error::InvalidParameters))
+            );
+            assert_eq!(
+                client.try_set_initial_supply_bounds(&admin, &11, &10),
+                Err(Ok(Error::InvalidParameters))
+            );
+
+            client.set_initial_supply_bounds(&admin, &5, &50);
+            let config = client.get_admin_config();
+            assert_eq!(config.min_initial_supply, 5);
+            assert_eq!(config.max_initial_supply, 50);
+        }
+    }
+
+    /// Tests for the admin-configured minimum creation fee.
+    mod min_creation_fee {
+        use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+
+        use crate::test_helpers::setup_factory;
+        use crate::types::{Error, TokenCreationParams};
+        use crate::TokenFactoryClient;
+
+        const FLOOR: i128 = 50_000_000;
+
+        fn setup(env: &Env) -> (TokenFactoryClient<'_>, Address) {
+            let (client, admin, _) = setup_factory(env);
+
+            client.set_min_creation_fee(&admin, &FLOOR);
+
+            (client, admin)
+        }
+
+        fn try_create(env: &Env, client: &TokenFactoryClient, fee: i128) -> Result<(), Error> {
+            match client.try_create_token(
+                &Address::generate(env),
+                &String::from_str(env, "FloorToken"),
+                &String::from_str(env, "FLR"),
+                &7u32,
+                &1_000i128,
+                &None,
+                &fee,
+            ) {
+                Ok(_) => Ok(()),
+                Err(Ok(err)) => Err(err),
+                Err(Err(_)) => panic!("unexpected host error"),
+            }
+        }
+
+        fn params(env: &Env) -> TokenCreationParams {
+            TokenCreationParams {
+                name: String::from_str(env, "FloorBatch"),
+                symbol: String::from_str(env, "FLB"),
+                decimals: 7,
+                initial_supply: 1_000,
+                max_supply: None,
+                metadata_uri: None,
+            }
+        }
+
+        #[test]
+        fn test_floor_wins_over_zero_fees() {
+            let env = Env::default();
+            let (client, admin) = setup(&env);
+
+            // Free tier: both configured fees at zero
+            client.update_fees(&admin, &Some(0), &Some(0));
+
+            assert_eq!(try_create(&env, &client, 0), Err(Error::InsufficientFee));
+            assert_eq!(
+                try_create(&env, &client, FLOOR - 1),
+                Err(Error::InsufficientFee)
+            );
+            assert!(try_create(&env, &client, FLOOR).is_ok());
+        }
+
+        #[test]
+        fn test_floor_wins_over_low_fees() {
+            let env = Env::default();
+            let (client, admin) = setup(&env);
+
+            client.update_fees(&admin, &Some(10_000_000), &Some(0));
+
+            assert_eq!(
+                try_create(&env, &client, 10_000_000),
+                Err(Error::InsufficientFee)
+            );
+            assert!(try_create(&env, &client, FLOOR).is_ok());
+        }
+
+        #[test]
+        fn test_fees_above_floor_unchanged() {
+            let env = Env::default();
+            let (client, _) = setup(&env);
+
+            assert_eq!(
+                try_create(&env, &client, FLOOR),
+                Err(Error::InsufficientFee)
+            );
+            assert!(try_create(&env, &client, 70_000_000).is_ok());
+        }
+
+        #[test]
+        fn test_floor_applies_per_token_in_batches() {
+            let env = Env::default();
+            let (client, admin) = setup(&env);
+            let creator = Address::generate(&env);
+            client.update_fees(&admin, &Some(0), &Some(0));
+
+            let tokens = vec![&env, params(&env), params(&env)];
+            assert_eq!(
+                client.try_batch_create_tokens(&creator, &tokens, &(FLOOR * 2 - 1)),
+                Err(Ok(Error::InsufficientFee))
+            );
+            assert_eq!(
+                client.try_batch_reveal(&creator, &tokens, &(FLOOR * 2 - 1)),
+                Err(Ok(Error::InsufficientFee))
+            );
+            client.batch_create_tokens(&creator, &tokens, &(FLOOR * 2));
+        }
+
+        #[test]
+        fn test_set_min_creation_fee_validation() {
+            let env = Env::default();
+            let (client, admin) = setup(&env);
+            let stranger = Address::generate(&env);
+
+            assert_eq!(
+                client.try_set_min_creation_fee(&stranger, &1),
+                Err(Ok(Error::Unauthorized))
+            );
+            assert_eq!(
+                client.try_set_min_creation_fee(&admin, &-1),
+                Err(Ok(Error::InvalidParameters))
+            );
+            assert_eq!(client.get_admin_config().min_creation_fee, FLOOR);
+        }
+    }
+
+    /// Tests for the admin-configurable minimum decimals at creation.
+    mod min_decimals {
+        use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+
+        use crate::test_helpers::setup_factory;
+        use crate::types::{Error, OptDecimalsTokenParams, TokenCreationParams};
+        use crate::TokenFactoryClient;
+
+        /// Attempt a creation with `decimals`, returning the contract error if any.
+        fn try_create(env: &Env, client: &TokenFactoryClient, decimals: u32) -> Result<(), Error> {
+            match client.try_create_token(
+                &Address::generate(env),
+                &String::from_str(env, "Decimals"),
+                &String::from_str(env, "DEC"),
+                &decimals,
+                &1_000i128,
+                &None,
+                &70_000_000i128,
+            ) {
+                Ok(_) => Ok(()),
+                Err(Ok(err)) => Err(err),
+                Err(Err(_)) => panic!("unexpected host error"),
+            }
+        }
+
+        #[test]
+        fn test_no_minimum_by_default() {
+            let env = Env::default();
+            let (client, _, _) = setup_factory(&env);
+
+            assert_eq!(client.get_min_decimals(), 0);
+            assert!(try_create(&env, &client, 0).is_ok());
+        }
+
+        #[test]
+        fn test_minimum_is_inclusive() {
+            let env = Env::default();
+            let (client, admin, _) = setup_factory(&env);
+
+            client.set_min_decimals(&admin, &7);
+
+            assert_eq!(try_create(&env, &client, 6), Err(Error::InvalidParameters));
+            assert!(try_create(&env, &client, 7).is_ok());
+            assert!(try_create(&env, &client, 18).is_ok());
+        }
+
+        #[test]
+        fn test_minimum_applies_to_batches_and_defaults() {
+            let env = Env::default();
+            let (client, admin, _) = setup_factory(&env);
+            let creator = Address::generate(&env);
+
+            client.set_default_decimals(&admin, &2);
+            client.set_min_decimals(&admin, &7);
+
+            let low = TokenCreationParams {
+                name: String::from_str(&env, "Batch"),
+                symbol: String::from_str(&env, "BAT"),
+                decimals: 6,
+                initial_supply: 1_000,
+                max_supply: None,
+                metadata_uri: None,
+            };
+            assert_eq!(
+                client.try_batch_create_tokens(&creator, &vec![&env, low], &70_000_000),
+                Err(Ok(Error::InvalidParameters))
+            );
+
+            let params = OptDecimalsTokenParams {
+                name: String::from_str(&env, "Default"),
+                symbol: String::from_str(&env, "DEF"),
+                decimals: None,
+                initial_supply: 1_000,
+                metadata_uri: None,
+            };
+            let fallback = client.try_create_token_opt_decimals(&creator, &params, &70_000_000);
+            assert_eq!(fallback, Err(Ok(Error::InvalidParameters)));
+            assert!(client.get_latest_token().is_none());
+        }
+
+        #[test]
+        fn test_set_min_decimals_validation() {
+            let env = Env::default();
+            let (client, admin, _) = setup_factory(&env);
+
+            assert_eq!(
+                client.try_set_min_decimals(&Address::generate(&env), &7),
+                Err(Ok(Error::Unauthorized))
+            );
+            assert_eq!(
+                client.try_set_min_decimals(&admin, &19),
+                Err(Ok(Error::InvalidParameters))
+            );
+
+            client.set_min_decimals(&admin, &7);
+            assert_eq!(client.get_min_decimals(), 7);
+        }
+    }
+
+    /// Tests for token symbol normalization (`normalize_symbol`).
+    mod symbol_normalization {
+        use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+
+        use crate::test_helpers::setup_factory;
+        use crate::token_creation::normalize_symbol;
+        use crate::types::{Error, TokenCreationParams};
+        use crate::TokenFactoryClient;
+
+        fn setup(env: &Env) -> (TokenFactoryClient<'_>, Address) {
+            let (client, _, _) = setup_factory(env);
+
+            (client, Address::generate(env))
+        }
+
+        fn create(
+            env: &Env,
+            client: &TokenFactoryClient,
+            creator: &Address,
+            symbol: &str,
+        ) -> Result<u32, Error> {
+            match client.try_create_token(
+                creator,
+                &String::from_str(env, "Ether"),
+                &String::from_str(env, symbol),
+                &7u32,
+                &1_000i128,
+                &None,
+                &70_000_000i128,
+            ) {
+                Ok(Ok(receipt)) => Ok(receipt.index),
+                Err(Ok(err)) => Err(err),
+                _ => panic!("unexpected host error"),
+            }
+        }
+
+        #[test]
+        fn test_variants_collapse_to_same_symbol() {
+            let env = Env::default();
+            let expected = String::from_str(&env, "ETH");
+
+            for raw in ["eth", "ETH", " eth ", "\tEtH\n"] {
+                assert_eq!(
+                    normalize_symbol(&env, &String::from_str(&env, raw)),
+                    expected
+                );
+            }
+            assert_eq!(
+                normalize_symbol(&env, &String::from_str(&env, "w eth")),
+                String::from_str(&env, "W ETH")
+            );
+        }
+
+        #[test]
+        fn test_creation_stores_normalized_symbol() {
+            let env = Env::default();
+            let (client, creator) = setup(&env);
+
+            let index = create(&env, &client, &creator, " eth ").unwrap();
+            assert_eq!(
+                client.get_token_info(&index).symbol,
+                String::from_str(&env, "ETH")
+            );
+
+            let params = TokenCreationParams {
+                name: String::from_str(&env, "Batch"),
+                symbol: String::from_str(&env, "btc "),
+                decimals: 7,
+                initial_supply: 1_000,
+                max_supply: None,
+                metadata_uri: None,
+            };
+            client.batch_create_tokens(&creator, &vec![&env, params], &70_000_000);
+            assert_eq!(
+                client.get_token_info(&1).symbol,
+                String::from_str(&env, "BTC")
+            );
+        }
+
+        #[test]
+        fn test_blank_symbol_rejected_after_trim() {
+            let env = Env::default();
+            let (client, creator) = setup(&env);
+
+            assert_eq!(
+                create(&env, &client, &creator, "   "),
+                Err(Error::InvalidTokenParams)
+            );
+            // Padding does not count towards the 12-character limit
+            assert!(create(&env, &client, &creator, "  ABCDEFGHIJKL  ").is_ok());
+        }
+
+        #[test]
+        fn test_rename_collision_ignores_case_and_padding() {
+            let env = Env::default();
+            let (client, creator) = setup(&env);
+
+            create(&env, &client, &creator, "ETH").unwrap();
+            let other = create(&env, &client, &creator, "OTH").unwrap();
+
+            assert_eq!(
+                client.try_rename_token(
+                    &other,
+                    &String::from_str(&env, "Ether Two"),
+                    &String::from_str(&env, " eth"),
+                    &creator,
+                ),
+                Err(Ok(Error::InvalidParameters))
+            );
+
+            client.rename_token(
+                &other,
+                &String::from_str(&env, "Other"),
+                &String::from_str(&env, "oth2"),
+                &creator,
+            );
+            assert_eq!(
+                client.get_token_info(&other).symbol,
+                String::from_str(&env, "OTH2")
+            );
+        }
+    }
+
+    /// Tests for the admin-configurable registry size limit (`MaxTokens`).
+    mod max_tokens {
+        use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+
+        use crate::test_helpers::setup_factory;
+        use crate::types::{Error, TokenCreationParams};
+        use crate::TokenFactoryClient;
+
+        fn setup(env: &Env) -> (TokenFactoryClient<'_>, Address, Address) {
+            let (client, admin, _) = setup_factory(env);
+
+            (client, admin, Address::generate(env))
+        }
+
+        fn try_create(
+            env: &Env,
+            client: &TokenFactoryClient,
+            creator: &Address,
+        ) -> Result<(), Error> {
+            match client.try_create_token(
+                creator,
+                &String::from_str(env, "CapToken"),
+                &String::from_str(env, "CAP"),
+                &7u32,
+                &1_000i128,
+                &None,
+                &70_000_000i128,
+            ) {
+                Ok(_) => Ok(()),
+                Err(Ok(err)) => Err(err),
+                Err(Err(_)) => panic!("unexpected host error"),
+            }
+        }
+
+        fn params(env: &Env) -> TokenCreationParams {
+            TokenCreationParams {
+                name: String::from_str(env, "BatchCap"),
+                symbol: String::from_str(env, "BCP"),
+                decimals: 7,
+                initial_supply: 1_000,
+                max_supply: None,
+                metadata_uri: None,
+            }
+        }
+
+        #[test]
+        fn test_unlimited_by_default() {
+            let env = Env::default();
+            let (client, _, creator) = setup(&env);
+
+            assert_eq!(client.get_max_tokens(), 0);
+            for _ in 0..5 {
+                assert!(try_create(&env, &client, &creator).is_ok());
+            }
+            assert_eq!(client.get_full_state().token_count, 5);
+        }
+
+        #[test]
+        fn test_creation_rejected_at_limit() {
+            let env = Env::default();
+            let (client, admin, creator) = setup(&env);
+
+            client.set_max_tokens(&admin, &2);
+            assert!(try_create(&env, &client, &creator).is_ok());
+            assert!(try_create(&env, &client, &creator).is_ok());
+            assert_eq!(
+                try_create(&env, &client, &creator),
+                Err(Error::RegistryFull)
+            );
+            assert_eq!(client.get_full_state().token_count, 2);
+
+            client.set_max_tokens(&admin, &0);
+            assert!(try_create(&env, &client, &creator).is_ok());
+        }
+
+        #[test]
+        fn test_batch_crossing_limit_rejected_whole() {
+            let env = Env::default();
+            let (client, admin, creator) = setup(&env);
+
+            client.set_max_tokens(&admin, &3);
+            assert!(try_create(&env, &client, &creator).is_ok());
+
+            let three = vec![&env, params(&env), params(&env), params(&env)];
+            assert_eq!(
+                client.try_batch_create_tokens(&creator, &three, &210_000_000),
+                Err(Ok(Error::RegistryFull))
+            );
+            assert_eq!(client.get_full_state().token_count, 1);
+
+            let two = vec![&env, params(&env), params(&env)];
+            client.batch_create_tokens(&creator, &two, &140_000_000);
+            assert_eq!(client.get_full_state().token_count, 3);
+        }
+
+        #[test]
+        fn test_set_max_tokens_requires_admin() {
+            let env = Env::default();
+            let (client, _, creator) = setup(&env);
+
+            assert_eq!(
+                client.try_set_max_tokens(&creator, &1),
+                Err(Ok(Error::Unauthorized))
+            );
+        }
+    }
+
+    /// Tests for creator renames within the post-launch grace window.
+    mod rename_token {
+        use soroban_sdk::{
+            testutils::{Address as _, Ledger},
+            Address, Env, String,
+        };
+
+        use crate::test_helpers::{create_test_token, setup_factory};
+        use crate::token_creation::RENAME_WINDOW_SECS;
+        use crate::types::Error;
+        use crate::TokenFactoryClient;
+
+        const LAUNCH_TS: u64 = 5_000;
+
+        /// Set up a factory with tokens "TYPO" (index 0) and "OTHER" (index 1),
+        /// both created at `LAUNCH_TS`. Returns (client, creator).
+        fn setup(env: &Env) -> (TokenFactoryClient<'_>, Address) {
+            env.ledger().with_mut(|l| l.timestamp = LAUNCH_TS);
+            let (client, _, _) = setup_factory(env);
+
+            let creator = Address::generate(env);
+            for symbol in ["TYPO", "OTHER"] {
+                create_test_token(env, &client, &creator, symbol, 1_000);
+            }
+
+            (client, creator)
+        }
+
+        #[test]
+        fn test_rename_within_window() {
+            let env = Env::default();
+            let (client, creator) = setup(&env);
+            env.ledger()
+                .with_mut(|l| l.timestamp = LAUNCH_TS + RENAME_WINDOW_SECS);
+
+            client.rename_token(
+                &0,
+                &String::from_str(&env, "Typo Token"),
+                &String::from_str(&env, "TYP"),
+                &creator,
+            );
+
+            let info = client.get_token_info(&0);
+            assert_eq!(info.name, String::from_str(&env, "Typo Token"));
+            assert_eq!(info.symbol, String::from_str(&env, "TYP"));
+
+            // Keeping the token's own symbol is not a collision
+            client.rename_token(
+                &0,
+                &String::from_str(&env, "Typo Token v2"),
+                &String::from_str(&env, "TYP"),
+                &creator,
+            );
+        }
+
+        #[test]
+        fn test_symbol_collision_rejected() {
+            let env = Env::default();
+            let (client, creator) = setup(&env);
+
+            let result = client.try_rename_token(
+                &0,
+                &String::from_str(&env, "Typo Token"),
+                &String::from_str(&env, "OTHER"),
+                &creator,
+            );
+            assert_eq!(result, Err(Ok(Error::InvalidParameters)));
+            assert_eq!(
+                client.get_token_info(&0).symbol,
+                String::from_str(&env, "TYPO")
+            );
+        }
+
+        #[test]
+        fn test_rename_after_window_rejected() {
+            let env = Env::default();
+            let (client, creator) = setup(&env);
+            env.ledger()
+                .with_mut(|l| l.timestamp = LAUNCH_TS + RENAME_WINDOW_SECS + 1);
+
+            let result = client.try_rename_token(
+                &0,
+                &String::from_str(&env, "Typo Token"),
+                &String::from_str(&env, "TYP"),
+                &creator,
+            );
+            assert_eq!(result, Err(Ok(Error::Unauthorized)));
+        }
+
+        #[test]
+        fn test_rename_requires_creator_and_valid_lengths() {
+            let env = Env::default();
+            let (client, creator) = setup(&env);
+            let stranger = Address::generate(&env);
+
+            assert_eq!(
+                client.try_rename_token(
+                    &0,
+                    &String::from_str(&env, "Hijack"),
+                    &String::from_str(&env, "HJK"),
+                    &stranger,
+                ),
+                Err(Ok(Error::Unauthorized))
+            );
+            assert_eq!(
+                client.try_rename_token(
+                    &0,
+                    &String::from_str(&env, "Typo Token"),
+                    &String::from_str(&env, ""),
+                    &creator,
+                ),
+                Err(Ok(Error::InvalidTokenParams))
+            );
+            assert_eq!(
+                client.try_rename_token(
+                    &9,
+                    &String::from_str(&env, "Missing"),
+                    &String::from_str(&env, "MIS"),
+                    &creator,
+                ),
+                Err(Ok(Error::TokenNotFound))
+            );
+        }
+    }
+
+    /// Tests for permanent token retirement (`retire_token`).
+    mod token_retire {
+        use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+
+        use crate::test_helpers::{create_test_token, setup_factory};
+        use crate::types::Error;
+        use crate::TokenFactoryClient;
+
+        /// Set up a factory with two tokens and retire the first.
+        /// Returns (client, admin, creator, retired_index).
+        fn setup(env: &Env) -> (TokenFactoryClient<'_>, Address, Address, u32) {
+            let (client, admin, _) = setup_factory(env);
+
+            let creator = Address::generate(env);
+            for symbol in ["OLD", "NEW"] {
+                create_test_token(env, &client, &creator, symbol, 1_000_000);
+            }
+            client.retire_token(&admin, &0);
+
+            (client, admin, creator, 0u32)
+        }
+
+        #[test]
+        fn test_burns_rejected_on_retired_token() {
+            let env = Env::default();
+            let (client, admin, creator, retired) = setup(&env);
+
+            assert_eq!(
+                client.try_burn(&creator, &retired, &1),
+                Err(Ok(Error::TokenRetired))
+            );
+            assert_eq!(
+                client.try_admin_burn(&admin, &retired, &creator, &1),
+                Err(Ok(Error::TokenRetired))
+            );
+            assert_eq!(
+                client.try_batch_burn(&admin, &retired, &vec![&env, (creator.clone(), 1i128)]),
+                Err(Ok(Error::TokenRetired))
+            );
+            assert_eq!(client.get_token_info(&retired).total_burned, 0);
+        }
+
+        #[test]
+        fn test_mints_rejected_on_retired_token() {
+            let env = Env::default();
+            let (client, _, creator, retired) = setup(&env);
+
+            assert_eq!(
+                client.try_mint(&creator, &retired, &creator, &1),
+                Err(Ok(Error::TokenRetired))
+            );
+            assert_eq!(client.get_token_info(&retired).total_supply, 1_000_000);
+        }
+
+        #[test]
+        fn test_metadata_changes_rejected_on_retired_token() {
+            let env = Env::default();
+            let (client, _, creator, retired) = setup(&env);
+            let uri = String::from_str(&env, "ipfs://QmRetired");
+
+            assert_eq!(
+                client.try_set_metadata(&retired, &uri, &30_000_000),
+                Err(Ok(Error::TokenRetired))
+            );
+            assert_eq!(
+                client.try_set_token_metadata(&creator, &retired, &uri, &30_000_000),
+                Err(Ok(Error::TokenRetired))
+            );
+            assert_eq!(
+                client.try_rename_token(
+                    &retired,
+                    &String::from_str(&env, "Renamed"),
+                    &String::from_str(&env, "RNM"),
+                    &creator,
+                ),
+                Err(Ok(Error::TokenRetired))
+            );
+            assert_eq!(client.get_token_info(&retired).metadata_uri, None);
+        }
+
+        #[test]
+        fn test_other_tokens_unaffected() {
+            let env = Env::default();
+            let (client, _, creator, retired) = setup(&env);
+
+            assert!(client.is_token_retired(&retired));
+            assert!(!client.is_token_retired(&1));
+
+            client.burn(&creator, &1, &10);
+            client.mint(&creator, &1, &creator, &10);
+            assert_eq!(client.get_token_info(&1).total_burned, 10);
+            assert_eq!(client.get_token_info(&retired).total_supply, 1_000_000);
+        }
+
+        #[test]
+        fn test_retire_requires_admin_and_is_one_shot() {
+            let env = Env::default();
+            let (client, admin, creator, retired) = setup(&env);
+
+            assert_eq!(
+                client.try_retire_token(&creator, &1),
+                Err(Ok(Error::Unauthorized))
+            );
+            assert_eq!(
+                client.try_retire_token(&admin, &retired),
+                Err(Ok(Error::TokenRetired))
+            );
+            assert_eq!(
+                client.try_retire_token(&admin, &99),
+                Err(Ok(Error::TokenNotFound))
+            );
+        }
+    }
+
+    /// Tests for token tags and their configurable limits.
+    mod token_tags {
+        use soroban_sdk::{testutils::Address as _, vec, Address, Env, String, Vec};
+
+        use crate::test_helpers::setup_with_supply;
+        use crate::types::Error;
+
+        fn tags(env: &Env, names: &[&str]) -> Vec<String> {
+            let mut list = Vec::new(env);
+            for name in names {
+                list.push_back(String::from_str(env, name));
+            }
+            list
+        }
+
+        #[test]
+        fn test_set_replace_and_clear_tags() {
+            let env = Env::default();
+            let (client, _, creator, token_index) = setup_with_supply(&env, 1_000);
+
+            assert_eq!(client.get_token_tags(&token_index).len(), 0);
+
+            client.set_token_tags(&creator, &token_index, &tags(&env, &["defi", "gaming"]));
+            assert_eq!(
+                client.get_token_tags(&token_index),
+                tags(&env, &["defi", "gaming"])
+            );
+
+            client.set_token_tags(&creator, &token_index, &tags(&env, &["meme"]));
+            assert_eq!(client.get_token_tags(&token_index), tags(&env, &["meme"]));
+
+            client.set_token_tags(&creator, &token_index, &Vec::new(&env));
+            assert_eq!(client.get_token_tags(&token_index).len(), 0);
+        }
+
+        #[test]
+        fn test_too_many_tags() {
+            let env = Env::default();
+            let (client, _, creator, token_index) = setup_with_supply(&env, 1_000);
+
+            client.set_token_tags(
+                &creator,
+                &token_index,
+                &tags(&env, &["a", "b", "c", "d", "e"]),
+            );
+            assert_eq!(
+                client.try_set_token_tags(
+                    &creator,
+                    &token_index,
+                    &tags(&env, &["a", "b", "c", "d", "e", "f"])
+                ),
+                Err(Ok(Error::TooManyTags))
+            );
+            assert_eq!(client.get_token_tags(&token_index).len(), 5);
+        }
+
+        #[test]
+        fn test_invalid_tag_length() {
+            let env = Env::default();
+            let (client, _, creator, token_index) = setup_with_supply(&env, 1_000);
+            let long = "abcdefghijklmnopqrstuvwxyz0123456";
+
+            assert_eq!(
+                client.try_set_token_tags(&creator, &token_index, &tags(&env, &["ok", long])),
+                Err(Ok(Error::InvalidParameters))
+            );
+            assert_eq!(
+                client.try_set_token_tags(&creator, &token_index, &tags(&env, &[""])),
+                Err(Ok(Error::InvalidParameters))
+            );
+            client.set_token_tags(&creator, &token_index, &tags(&env, &[&long[..32]]));
+        }
+
+        #[test]
+        fn test_configured_limits() {
+            let env = Env::default();
+            let (client, admin, creator, token_index) = setup_with_supply(&env, 1_000);
+
+            assert_eq!(client.get_tag_limits(), (5, 32));
+            client.set_tag_limits(&admin, &Some(2), &Some(4));
+            assert_eq!(client.get_tag_limits(), (2, 4));
+
+            assert_eq!(
+                client.try_set_token_tags(&creator, &token_index, &tags(&env, &["a", "b", "c"])),
+                Err(Ok(Error::TooManyTags))
+            );
+            client.set_token_tags(&creator, &token_index, &tags(&env, &["defi"]));
+            assert_eq!(
+                client.try_set_token_tags(&creator, &token_index, &tags(&env, &["games"])),
+                Err(Ok(Error::InvalidParameters))
+            );
+        }
+
+        #[test]
+        fn test_tag_updates_and_limits_are_gated() {
+            let env = Env::default();
+            let (client, admin, creator, token_index) = setup_with_supply(&env, 1_000);
+            let stranger = Address::generate(&env);
+
+            assert_eq!(
+                client.try_set_token_tags(&stranger, &token_index, &vec![&env]),
+                Err(Ok(Error::Unauthorized))
+            );
+            assert_eq!(
+                client.try_set_token_tags(&creator, &9, &vec![&env]),
+                Err(Ok(Error::TokenNotFound))
+            );
+            assert_eq!(
+                client.try_set_tag_limits(&creator, &Some(3), &None),
+                Err(Ok(Error::Unauthorized))
+            );
+            assert_eq!(
+                client.try_set_tag_limits(&admin, &None, &None),
+                Err(Ok(Error::InvalidParameters))
+            );
+            assert_eq!(
+                client.try_set_tag_limits(&admin, &Some(0), &None),
+                Err(Ok(Error::InvalidParameters))
+            );
+        }
+    }
+
+    /// Tests for the admin-only token freeze.
+    mod token_freeze {
+        use soroban_sdk::{testutils::Address as _, Env};
+
+        use crate::test_helpers::setup_with_supply;
+        use crate::types::{DataKey, Error};
+
+        #[test]
+        fn test_freeze_blocks_burn_and_unfreeze_restores_it() {
+            let env = Env::default();
+            let (client, admin, creator, token_index) = setup_with_supply(&env, 1_000_000_000);
+
+            client.freeze_token(&admin, &token_index);
+            assert!(client.is_token_frozen(&token_index));
+
+            let result = client.try_burn(&creator, &token_index, &1_000);
+            assert_eq!(result, Err(Ok(Error::TokenFrozen)));
+
+            client.unfreeze_token(&admin, &token_index);
+            assert!(!client.is_token_frozen(&token_index));
+
+            client.burn(&creator, &token_index, &1_000);
+            let info = client.get_token_info(&token_index);
+            assert_eq!(info.total_supply, 1_000_000_000 - 1_000);
+            assert_eq!(info.total_burned, 1_000);
+        }
+
+        #[test]
+        fn test_freeze_flag_is_per_token_persistent_state() {
+            let env = Env::default();
+            let (client, admin, _, token_index) = setup_with_supply(&env, 1_000_000_000);
+
+            client.freeze_token(&admin, &token_index);
+
+            // Kept beside the token's other entries, not in the shared instance
+            env.as_contract(&client.address, || {
+                let key = DataKey::TokenFrozen(token_index);
+                assert!(env.storage().persistent().has(&key));
+                assert!(!env.storage().instance().has(&key));
+            });
+        }
+
+        #[test]
+        fn test_freeze_blocks_admin_burn() {
+            let env = Env::default();
+            let (client, admin, creator, token_index) = setup_with_supply(&env, 1_000_000_000);
+
+            client.freeze_token(&admin, &token_index);
+
+            let result = client.try_admin_burn(&admin, &token_index, &creator, &1_000);
+            assert_eq!(result, Err(Ok(Error::TokenFrozen)));
+        }
+
+        #[test]
+        fn test_freeze_requires_admin() {
+            let env = Env::default();
+            let (client, _admin, creator, token_index) = setup_with_supply(&env, 1_000_000_000);
+
+            // Even the token creator cannot freeze
+            let result = client.try_freeze_token(&creator, &token_index);
+            assert_eq!(result, Err(Ok(Error::Unauthorized)));
+            assert!(!client.is_token_frozen(&token_index));
+        }
+
+        #[test]
+        fn test_freeze_unknown_token_fails() {
+            let env = Env::default();
+            let (client, admin, _, _) = setup_with_supply(&env, 1_000_000_000);
+
+            let result = client.try_freeze_token(&admin, &99u32);
+            assert_eq!(result, Err(Ok(Error::TokenNotFound)));
+        }
+    }
+
+    /// Tests for creator-controlled token pauses and the admin override.
+    mod creator_pause {
+        use soroban_sdk::{testutils::Address as _, Address, Env};
+
+        use crate::test_helpers::setup_with_token;
+        use crate::types::Error;
+
+        #[test]
+        fn test_creator_pause_blocks_burns_until_unpaused() {
+            let env = Env::default();
+            let (client, _, creator, token_index) = setup_with_token(&env);
+
+            client.pause_token(&creator, &token_index);
+            assert!(client.is_token_paused(&token_index));
+            assert_eq!(
+                client.try_burn(&creator, &token_index, &100),
+                Err(Ok(Error::TokenPaused))
+            );
+
+            client.unpause_token(&creator, &token_index);
+            assert!(!client.is_token_paused(&token_index));
+            client.burn(&creator, &token_index, &100);
+            assert_eq!(client.get_token_info(&token_index).total_burned, 100);
+        }
+
+        #[test]
+        fn test_admin_can_unpause_creator_pause() {
+            let env = Env::default();
+            let (client, admin, creator, token_index) = setup_with_token(&env);
+
+            client.pause_token(&creator, &token_index);
+            client.unpause_token(&admin, &token_index);
+            assert!(!client.is_token_paused(&token_index));
+        }
+
+        #[test]
+        fn test_creator_cannot_lift_admin_pause() {
+            let env = Env::default();
+            let (client, admin, creator, token_index) = setup_with_token(&env);
+
+            client.pause_token(&admin, &token_index);
+            assert_eq!(
+                client.try_unpause_token(&creator, &token_index),
+                Err(Ok(Error::Unauthorized))
+            );
+            assert!(client.is_token_paused(&token_index));
+
+            // Once the admin lifts it, the creator regains control
+            client.unpause_token(&admin, &token_index);
+            client.pause_token(&creator, &token_index);
+            client.unpause_token(&creator, &token_index);
+            assert!(!client.is_token_paused(&token_index));
+        }
+
+        #[test]
+        fn test_stranger_cannot_pause() {
+            let env = Env::default();
+            let (client, _, _, token_index) = setup_with_token(&env);
+            let stranger = Address::generate(&env);
+
+            assert_eq!(
+                client.try_pause_token(&stranger, &token_index),
+                Err(Ok(Error::Unauthorized))
+            );
+            assert!(!client.is_token_paused(&token_index));
+        }
+    }
+
+    /// Tests for the default metadata URI fallback.
+    mod default_metadata_uri {
+        use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+        use crate::test_helpers::{create_test_token, setup_factory};
+        use crate::types::Error;
+        use crate::TokenFactoryClient;
+
+        const TOKEN_URI: &str = "ipfs://QmTokenOwnMetadata";
+        const DEFAULT_URI: &str = "ipfs://QmFactoryDefaultMetadata";
+
+        /// Set up a factory with token 0 carrying a URI and token 1 without one.
+        /// Returns (client, admin).
+        fn setup(env: &Env) -> (TokenFactoryClient<'_>, Address) {
+            let (client, admin, _) = setup_factory(env);
+
+            let creator = Address::generate(env);
+            client.create_token(
+                &creator,
+                &String::from_str(env, "WithUri"),
+                &String::from_str(env, "URI"),
+                &7u32,
+                &1_000i128,
+                &Some(String::from_str(env, TOKEN_URI)),
+                &100_000_000i128,
+            );
+            create_test_token(env, &client, &creator, "NOU", 1_000);
+
+            (client, admin)
+        }
+
+        #[test]
+        fn test_token_uri_takes_precedence() {
+            let env = Env::default();
+            let (client, admin) = setup(&env);
+            client.set_default_metadata_uri(&admin, &Some(String::from_str(&env, DEFAULT_URI)));
+
+            assert_eq!(
+                client.get_effective_metadata_uri(&0),
+                Some(String::from_str(&env, TOKEN_URI))
+            );
+        }
+
+        #[test]
+        fn test_default_used_when_token_has_no_uri() {
+            let env = Env::default();
+            let (client, admin) = setup(&env);
+            client.set_default_metadata_uri(&admin, &Some(String::from_str(&env, DEFAULT_URI)));
+
+            assert_eq!(
+                client.get_effective_metadata_uri(&1),
+                Some(String::from_str(&env, DEFAULT_URI))
+            );
+            // Stored token metadata is unchanged
+            assert_eq!(client.get_token_info(&1).metadata_uri, None);
+        }
+
+        #[test]
+        fn test_none_without_uri_or_default() {
+            let env = Env::default();
+            let (client, admin) = setup(&env);
+
+            assert_eq!(client.get_effective_metadata_uri(&1), None);
+
+            // Clearing a previously set default restores that behaviour
+            client.set_default_metadata_uri(&admin, &Some(String::from_str(&env, DEFAULT_URI)));
+            client.set_default_metadata_uri(&admin, &None);
+            assert_eq!(client.get_effective_metadata_uri(&1), None);
+            assert_eq!(client.get_effective_metadata_uri(&99), None);
+        }
+
+        #[test]
+        fn test_set_default_validation() {
+            let env = Env::default();
+            let (client, admin) = setup(&env);
+            let stranger = Address::generate(&env);
+            let uri = Some(String::from_str(&env, DEFAULT_URI));
+
+            assert_eq!(
+                client.try_set_default_metadata_uri(&stranger, &uri),
+                Err(Ok(Error::Unauthorized))
+            );
+
+            client.set_metadata_limits(&admin, &Some(8), &None);
+            assert_eq!(
+                client.try_set_default_metadata_uri(&admin, &uri),
+                Err(Ok(Error::InvalidParameters))
+            );
+        }
+    }
+
+    /// Tests for the require-metadata-at-creation flag.
+    mod require_metadata {
+        use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+
+        use crate::types::{Error, TokenCreationParams};
+
+        use crate::test_helpers::setup_factory;
+
+        fn params(env: &Env, metadata_uri: Option<String>) -> TokenCreationParams {
+            TokenCreationParams {
+                name: String::from_str(env, "Curated"),
+                symbol: String::from_str(env, "CUR"),
+                decimals: 7,
+                initial_supply: 1_000,
+                max_supply: None,
+                metadata_uri,
+            }
+        }
+
+        #[test]
+        fn test_flag_off_allows_missing_metadata() {
+            let env = Env::default();
+            let (client, _, _) = setup_factory(&env);
+            let creator = Address::generate(&env);
+
+            assert!(!client.get_require_metadata());
+            client.create_token(
+                &creator,
+                &String::from_str(&env, "Bare"),
+                &String::from_str(&env, "BARE"),
+                &7u32,
+                &1_000i128,
+                &None,
+                &70_000_000i128,
+            );
+            assert_eq!(client.get_full_state().token_count, 1);
+        }
+
+        #[test]
+        fn test_flag_on_rejects_missing_metadata() {
+            let env = Env::default();
+            let (client, admin, _) = setup_factory(&env);
+            let creator = Address::generate(&env);
+
+            client.set_require_metadata(&admin, &true);
+
+            let result = client.try_create_token(
+                &creator,
+                &String::from_str(&env, "Bare"),
+                &String::from_str(&env, "BARE"),
+                &7u32,
+                &1_000i128,
+                &None,
+                &70_000_000i128,
+            );
+            assert_eq!(result, Err(Ok(Error::InvalidParameters)));
+
+            let with_uri = params(&env, Some(String::from_str(&env, "ipfs://QmOk")));
+            let batch = vec![&env, with_uri.clone(), params(&env, None)];
+            assert_eq!(
+                client.try_batch_create_tokens(&creator, &batch, &300_000_000),
+                Err(Ok(Error::InvalidParameters))
+            );
+            assert_eq!(
+                client.try_batch_reveal(&creator, &batch, &300_000_000),
+                Err(Ok(Error::InvalidParameters))
+            );
+            assert_eq!(client.get_full_state().token_count, 0);
+        }
+
+        #[test]
+        fn test_flag_on_allows_metadata() {
+            let env = Env::default();
+            let (client, admin, _) = setup_factory(&env);
+            let creator = Address::generate(&env);
+            let uri = String::from_str(&env, "ipfs://QmOk");
+
+            client.set_require_metadata(&admin, &true);
+
+            let receipt = client.create_token(
+                &creator,
+                &String::from_str(&env, "Listed"),
+                &String::from_str(&env, "LST"),
+                &7u32,
+                &1_000i128,
+                &Some(uri.clone()),
+                &100_000_000i128,
+            );
+            assert_eq!(
+                client.get_token_info(&receipt.index).metadata_uri,
+                Some(uri.clone())
+            );
+
+            client.batch_create_tokens(
+                &creator,
+                &vec![&env, params(&env, Some(uri))],
+                &100_000_000,
+            );
+            assert_eq!(client.get_full_state().token_count, 2);
+        }
+
+        #[test]
+        fn test_set_require_metadata_requires_admin() {
+            let env = Env::default();
+            let (client, _, _) = setup_factory(&env);
+
+            assert_eq!(
+                client.try_set_require_metadata(&Address::generate(&env), &true),
+                Err(Ok(Error::Unauthorized))
+            );
+            assert!(!client.get_require_metadata());
+        }
+    }
+
+    /// Tests for freezing metadata on a token's first burn.
+    mod metadata_auto_freeze {
+        use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+
+        use crate::test_helpers::setup_with_supply;
+        use crate::types::Error;
+        use crate::TokenFactoryClient;
+
+        /// Set up a factory with one token carrying metadata.
+        /// Returns (client, admin, creator, token_index).
+        fn setup(env: &Env) -> (TokenFactoryClient<'_>, Address, Address, u32) {
+            let (client, admin, creator, token_index) = setup_with_supply(env, 1_000);
+
+            client.set_metadata(
+                &token_index,
+                &String::from_str(env, "ipfs://QmInitial"),
+                &30_000_000,
+            );
+
+            (client, admin, creator, token_index)
+        }
+
+        fn update(
+            env: &Env,
+            client: &TokenFactoryClient,
+            creator: &Address,
+            token_index: u32,
+        ) -> Result<(), Error> {
+            match client.try_update_metadata(
+                creator,
+                &token_index,
+                &String::from_str(env, "ipfs://QmNext"),
+            ) {
+                Ok(_) => Ok(()),
+                Err(Ok(err)) => Err(err),
+                Err(Err(_)) => panic!("unexpected host error"),
+            }
+        }
+
+        #[test]
+        fn test_metadata_frozen_after_first_burn() {
+            let env = Env::default();
+            let (client, admin, creator, token_index) = setup(&env);
+            client.set_auto_freeze_metadata_on_burn(&admin, &true);
+
+            assert!(update(&env, &client, &creator, token_index).is_ok());
+
+            client.burn(&creator, &token_index, &10);
+            assert_eq!(
+                update(&env, &client, &creator, token_index),
+                Err(Error::MetadataAlreadySet)
+            );
+
+            // Further burns are unaffected
+            client.burn(&creator, &token_index, &10);
+            assert_eq!(client.get_token_info(&token_index).total_burned, 20);
+        }
+
+        #[test]
+        fn test_batch_burn_freezes_metadata() {
+            let env = Env::default();
+            let (client, admin, creator, token_index) = setup(&env);
+            client.set_auto_freeze_metadata_on_burn(&admin, &true);
+
+            client.batch_burn(&admin, &token_index, &vec![&env, (creator.clone(), 5i128)]);
+            assert_eq!(
+                update(&env, &client, &creator, token_index),
+                Err(Error::MetadataAlreadySet)
+            );
+        }
+
+        #[test]
+        fn test_metadata_stays_mutable_when_disabled() {
+            let env = Env::default();
+            let (client, _, creator, token_index) = setup(&env);
+            assert!(!client.get_auto_freeze_metadata_on_burn());
+
+            client.burn(&creator, &token_index, &10);
+            assert!(update(&env, &client, &creator, token_index).is_ok());
+        }
+
+        #[test]
+        fn test_set_auto_freeze_requires_admin() {
+            let env = Env::default();
+            let (client, admin, creator, _) = setup(&env);
+
+            assert_eq!(
+                client.try_set_auto_freeze_metadata_on_burn(&creator, &true),
+                Err(Ok(Error::Unauthorized))
+            );
+
+            client.set_auto_freeze_metadata_on_burn(&admin, &true);
+            assert!(client.get_auto_freeze_metadata_on_burn());
+        }
+    }
+
+    /// Tests for the metadata edit window and `freeze_metadata`.
+    mod metadata_edit_window {
+        use soroban_sdk::{
+            testutils::{Address as _, Ledger},
+            Address, Env, String,
+        };
+
+        use crate::test_helpers::{create_test_token, setup_factory};
+        use crate::types::Error;
+        use crate::TokenFactoryClient;
+
+        const LAUNCH_TS: u64 = 10_000;
+        const WINDOW: u64 = 3_600;
+
+        /// Set up a factory with one token without metadata. Returns (client, creator, token_index).
+        fn setup(env: &Env) -> (TokenFactoryClient<'_>, Address, u32) {
+            env.ledger().with_mut(|l| l.timestamp = LAUNCH_TS);
+            let (client, _, _) = setup_factory(env);
+
+            let creator = Address::generate(env);
+            create_test_token(env, &client, &creator, "WIN", 1_000);
+
+            (client, creator, 0u32)
+        }
+
+        fn uri(env: &Env, s: &str) -> String {
+            String::from_str(env, s)
+        }
+
+        #[test]
+        fn test_edits_allowed_before_deadline() {
+            let env = Env::default();
+            let (client, creator, token_index) = setup(&env);
+            client.set_metadata_editable_until(&creator, &token_index, &Some(LAUNCH_TS + WINDOW));
+
+            client.set_token_metadata(
+                &creator,
+                &token_index,
+                &uri(&env, "ipfs://QmV1"),
+                &30_000_000,
+            );
+
+            env.ledger().with_mut(|l| l.timestamp = LAUNCH_TS + WINDOW);
+            assert_eq!(
+                client.update_metadata(&creator, &token_index, &uri(&env, "ipfs://QmV2")),
+                2
+            );
+            assert!(!client.is_metadata_locked(&token_index));
+        }
+
+        #[test]
+        fn test_edits_rejected_after_deadline() {
+            let env = Env::default();
+            let (client, creator, token_index) = setup(&env);
+            client.set_metadata_editable_until(&creator, &token_index, &Some(LAUNCH_TS + WINDOW));
+            client.set_token_metadata(
+                &creator,
+                &token_index,
+                &uri(&env, "ipfs://QmV1"),
+                &30_000_000,
+            );
+
+            env.ledger()
+                .with_mut(|l| l.timestamp = LAUNCH_TS + WINDOW + 1);
+            assert!(client.is_metadata_locked(&token_index));
+            assert_eq!(
+                client.try_update_metadata(&creator, &token_index, &uri(&env, "ipfs://QmV2")),
+                Err(Ok(Error::MetadataAlreadySet))
+            );
+
+            // The window cannot be reopened once closed
+            assert_eq!(
+                client.try_set_metadata_editable_until(&creator, &token_index, &None),
+                Err(Ok(Error::MetadataAlreadySet))
+            );
+        }
+
+        #[test]
+        fn test_first_set_rejected_after_deadline() {
+            let env = Env::default();
+            let (client, creator, token_index) = setup(&env);
+            client.set_metadata_editable_until(&creator, &token_index, &Some(LAUNCH_TS));
+
+            env.ledger().with_mut(|l| l.timestamp = LAUNCH_TS + 1);
+            assert_eq!(
+                client.try_set_metadata(&token_index, &uri(&env, "ipfs://QmLate"), &30_000_000),
+                Err(Ok(Error::MetadataAlreadySet))
+            );
+            assert_eq!(client.get_token_info(&token_index).metadata_uri, None);
+        }
+
+        #[test]
+        fn test_early_manual_freeze() {
+            let env = Env::default();
+            let (client, creator, token_index) = setup(&env);
+            client.set_metadata_editable_until(&creator, &token_index, &Some(LAUNCH_TS + WINDOW));
+            client.set_token_metadata(
+                &creator,
+                &token_index,
+                &uri(&env, "ipfs://QmV1"),
+                &30_000_000,
+            );
+
+            client.freeze_metadata(&creator, &token_index);
+
+            assert!(client.is_metadata_locked(&token_index));
+            assert_eq!(
+                client.try_update_metadata(&creator, &token_index, &uri(&env, "ipfs://QmV2")),
+                Err(Ok(Error::MetadataAlreadySet))
+            );
+            assert_eq!(
+                client.try_freeze_metadata(&creator, &token_index),
+                Err(Ok(Error::MetadataAlreadySet))
+            );
+        }
+
+        #[test]
+        fn test_only_creator_configures_window() {
+            let env = Env::default();
+            let (client, _, token_index) = setup(&env);
+            let stranger = Address::generate(&env);
+
+            assert_eq!(
+                client.try_set_metadata_editable_until(&stranger, &token_index, &Some(LAUNCH_TS)),
+                Err(Ok(Error::Unauthorized))
+            );
+            assert_eq!(
+                client.try_freeze_metadata(&stranger, &token_index),
+                Err(Ok(Error::Unauthorized))
+            );
+        }
+    }
+
+    /// Tests for waiving the metadata fee on URIs supplied at creation.
+    mod metadata_fee_waiver {
+        use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+
+        use crate::test_helpers::setup_factory;
+        use crate::types::{Error, TokenCreationParams};
+        use crate::TokenFactoryClient;
+
+        /// Create a token with a metadata URI, paying `fee`.
+        fn try_create_with_uri(
+            env: &Env,
+            client: &TokenFactoryClient,
+            fee: i128,
+        ) -> Result<(), Error> {
+            match client.try_create_token(
+                &Address::generate(env),
+                &String::from_str(env, "Listed"),
+                &String::from_str(env, "LST"),
+                &7u32,
+                &1_000i128,
+                &Some(String::from_str(env, "ipfs://QmListed")),
+                &fee,
+            ) {
+                Ok(_) => Ok(()),
+                Err(Ok(err)) => Err(err),
+                Err(Err(_)) => panic!("unexpected host error"),
+            }
+        }
+
+        #[test]
+        fn test_metadata_fee_charged_by_default() {
+            let env = Env::default();
+            let (client, _, _) = setup_factory(&env);
+
+            assert!(!client.get_waive_md_fee_at_create());
+            assert_eq!(
+                try_create_with_uri(&env, &client, 70_000_000),
+                Err(Error::InsufficientFee)
+            );
+            assert!(try_create_with_uri(&env, &client, 100_000_000).is_ok());
+        }
+
+        #[test]
+        fn test_waiver_accepts_base_fee_with_uri() {
+            let env = Env::default();
+            let (client, admin, _) = setup_factory(&env);
+
+            client.set_waive_md_fee_at_create(&admin, &true);
+            assert!(try_create_with_uri(&env, &client, 70_000_000).is_ok());
+            assert!(client.get_token_info(&0).metadata_uri.is_some());
+
+            // Turning it back off restores the metadata fee
+            client.set_waive_md_fee_at_create(&admin, &false);
+            assert_eq!(
+                try_create_with_uri(&env, &client, 70_000_000),
+                Err(Error::InsufficientFee)
+            );
+        }
+
+        #[test]
+        fn test_waiver_applies_to_batch_creation() {
+            let env = Env::default();
+            let (client, admin, _) = setup_factory(&env);
+            let creator = Address::generate(&env);
+            let params = TokenCreationParams {
+                name: String::from_str(&env, "BatchListed"),
+                symbol: String::from_str(&env, "BLS"),
+                decimals: 7,
+                initial_supply: 1_000,
+                max_supply: None,
+                metadata_uri: Some(String::from_str(&env, "ipfs://QmBatch")),
+            };
+            let tokens = vec![&env, params.clone(), params];
+
+            assert_eq!(
+                client.try_batch_create_tokens(&creator, &tokens, &140_000_000),
+                Err(Ok(Error::InsufficientFee))
+            );
+
+            client.set_waive_md_fee_at_create(&admin, &true);
+            client.batch_create_tokens(&creator, &tokens, &140_000_000);
+            assert_eq!(client.get_token_info(&1).creator, creator);
+        }
+
+        #[test]
+        fn test_waiver_respects_min_creation_fee() {
+            let env = Env::default();
+            let (client, admin, _) = setup_factory(&env);
+
+            client.set_waive_md_fee_at_create(&admin, &true);
+            client.set_min_creation_fee(&admin, &90_000_000);
+
+            assert_eq!(
+                try_create_with_uri(&env, &client, 70_000_000),
+                Err(Error::InsufficientFee)
+            );
+            assert!(try_create_with_uri(&env, &client, 90_000_000).is_ok());
+        }
+
+        #[test]
+        fn test_later_metadata_still_charged() {
+            let env = Env::default();
+            let (client, admin, _) = setup_factory(&env);
+            let creator = Address::generate(&env);
+
+            client.set_waive_md_fee_at_create(&admin, &true);
+            for symbol in ["LTA", "LTB"] {
+                client.create_token(
+                    &creator,
+                    &String::from_str(&env, "Later"),
+                    &String::from_str(&env, symbol),
+                    &7u32,
+                    &1_000i128,
+                    &None,
+                    &70_000_000i128,
+                );
+            }
+            let collected = client.get_collected_fees();
+            let uri = String::from_str(&env, "ipfs://QmLater");
+
+            assert_eq!(
+                client.try_set_metadata(&0, &uri, &29_999_999),
+                Err(Ok(Error::InsufficientFee))
+            );
+            client.set_metadata(&0, &uri, &30_000_000);
+
+            // Per-token override applies to the later-add path too
+            client.set_token_metadata_fee(&admin, &1, &Some(50_000_000));
+            assert_eq!(
+                client.try_set_token_metadata(&creator, &1, &uri, &30_000_000),
+                Err(Ok(Error::InsufficientFee))
+            );
+            client.set_token_metadata(&creator, &1, &uri, &50_000_000);
+
+            assert_eq!(client.get_collected_fees(), collected + 80_000_000);
+        }
+
+        #[test]
+        fn test_waiver_toggle_is_admin_only() {
+            let env = Env::default();
+            let (client, _, _) = setup_factory(&env);
+
+            assert_eq!(
+                client.try_set_waive_md_fee_at_create(&Address::generate(&env), &true),
+                Err(Ok(Error::Unauthorized))
+            );
+            assert!(!client.get_waive_md_fee_at_create());
+        }
+    }
+
+    /// Tests for the `is_metadata_frozen` query.
+    mod metadata_frozen {
+        use soroban_sdk::{
+            testutils::{Address as _, Ledger},
+            Address, Env, String,
+        };
+
+        use crate::test_helpers::setup_factory;
+        use crate::TokenFactoryClient;
+
+        /// Set up a factory with one token. Returns (client, creator).
+        fn setup(env: &Env) -> (TokenFactoryClient<'_>, Address) {
+            let (client, _, _) = setup_factory(env);
+
+            let creator = Address::generate(env);
+            client.create_token(
+                &creator,
+                &String::from_str(env, "Frozen"),
+                &String::from_str(env, "FRZ"),
+                &7u32,
+                &1_000_000i128,
+                &Some(String::from_str(env, "ipfs://meta")),
+                &100_000_000i128,
+            );
+
+            (client, creator)
+        }
+
+        #[test]
+        fn test_not_frozen_by_default() {
+            let env = Env::default();
+            let (client, _) = setup(&env);
+
+            assert!(!client.is_metadata_frozen(&0));
+            assert!(!client.is_metadata_frozen(&99));
+        }
+
+        #[test]
+        fn test_frozen_after_freeze() {
+            let env = Env::default();
+            let (client, creator) = setup(&env);
+
+            client.freeze_metadata(&creator, &0);
+
+            assert!(client.is_metadata_frozen(&0));
+            assert!(client.is_metadata_locked(&0));
+        }
+
+        #[test]
+        fn test_expired_window_is_locked_not_frozen() {
+            let env = Env::default();
+            let (client, creator) = setup(&env);
+
+            let deadline = env.ledger().timestamp() + 100;
+            client.set_metadata_editable_until(&creator, &0, &Some(deadline));
+            env.ledger().with_mut(|l| l.timestamp = deadline + 1);
+
+            assert!(client.is_metadata_locked(&0));
+            assert!(!client.is_metadata_frozen(&0));
+        }
+    }
+
+    /// Tests for metadata content-hash commitments.
+    mod metadata_hash {
+        use soroban_sdk::{testutils::Address as _, Address, BytesN, Env, String};
+
+        use crate::test_helpers::setup_with_token;
+        use crate::types::Error;
+        use crate::TokenFactoryClient;
+
+        /// Set up a factory with one token that has metadata set.
+        /// Returns (client, creator, token_index).
+        fn setup(env: &Env) -> (TokenFactoryClient<'_>, Address, u32) {
+            let (client, _, creator, token_index) = setup_with_token(env);
+
+            client.set_metadata(
+                &token_index,
+                &String::from_str(env, "ipfs://QmV1"),
+                &30_000_000,
+            );
+
+            (client, creator, token_index)
+        }
+
+        fn hash(env: &Env, byte: u8) -> BytesN<32> {
+            BytesN::from_array(env, &[byte; 32])
+        }
+
+        #[test]
+        fn test_set_and_read_hash() {
+            let env = Env::default();
+            let (client, creator, token_index) = setup(&env);
+
+            assert_eq!(client.get_metadata_hash(&token_index), None);
+
+            client.set_metadata_hash(&creator, &token_index, &Some(hash(&env, 1)));
+            assert_eq!(client.get_metadata_hash(&token_index), Some(hash(&env, 1)));
+
+            client.set_metadata_hash(&creator, &token_index, &None);
+            assert_eq!(client.get_metadata_hash(&token_index), None);
+        }
+
+        #[test]
+        fn test_uri_update_must_change_committed_hash() {
+            let env = Env::default();
+            let (client, creator, token_index) = setup(&env);
+            let v2 = String::from_str(&env, "ipfs://QmV2");
+
+            client.set_metadata_hash(&creator, &token_index, &Some(hash(&env, 1)));
+
+            assert_eq!(
+                client.try_update_metadata(&creator, &token_index, &v2),
+                Err(Ok(Error::InvalidParameters))
+            );
+            assert_eq!(
+                client.try_update_metadata_with_hash(&creator, &token_index, &v2, &hash(&env, 1)),
+                Err(Ok(Error::InvalidParameters))
+            );
+
+            let version =
+                client.update_metadata_with_hash(&creator, &token_index, &v2, &hash(&env, 2));
+            assert_eq!(version, 2);
+            assert_eq!(client.get_token_info(&token_index).metadata_uri, Some(v2));
+            assert_eq!(client.get_metadata_hash(&token_index), Some(hash(&env, 2)));
+        }
+
+        #[test]
+        fn test_update_without_committed_hash_unchanged() {
+            let env = Env::default();
+            let (client, creator, token_index) = setup(&env);
+
+            let version = client.update_metadata(
+                &creator,
+                &token_index,
+                &String::from_str(&env, "ipfs://QmV2"),
+            );
+            assert_eq!(version, 2);
+            assert_eq!(client.get_metadata_hash(&token_index), None);
+        }
+
+        #[test]
+        fn test_frozen_metadata_rejects_changes() {
+            let env = Env::default();
+            let (client, creator, token_index) = setup(&env);
+            let v2 = String::from_str(&env, "ipfs://QmV2");
+
+            client.set_metadata_hash(&creator, &token_index, &Some(hash(&env, 1)));
+            client.freeze_metadata(&creator, &token_index);
+
+            assert_eq!(
+                client.try_update_metadata(&creator, &token_index, &v2),
+                Err(Ok(Error::MetadataAlreadySet))
+            );
+            assert_eq!(
+                client.try_update_metadata_with_hash(&creator, &token_index, &v2, &hash(&env, 2)),
+                Err(Ok(Error::MetadataAlreadySet))
+            );
+            assert_eq!(
+                client.try_set_metadata_hash(&creator, &token_index, &Some(hash(&env, 2))),
+                Err(Ok(Error::MetadataAlreadySet))
+            );
+            assert_eq!(client.get_metadata_hash(&token_index), Some(hash(&env, 1)));
+        }
+
+        #[test]
+        fn test_set_hash_validation() {
+            let env = Env::default();
+            let (client, creator, token_index) = setup(&env);
+
+            assert_eq!(
+                client.try_set_metadata_hash(
+                    &Address::generate(&env),
+                    &token_index,
+                    &Some(hash(&env, 1))
+                ),
+                Err(Ok(Error::Unauthorized))
+            );
+            assert_eq!(
+                client.try_set_metadata_hash(&creator, &9, &Some(hash(&env, 1))),
+                Err(Ok(Error::TokenNotFound))
+            );
+
+            client.create_token(
+                &creator,
+                &String::from_str(&env, "Bare"),
+                &String::from_str(&env, "BARE"),
+                &7u32,
+                &1_000i128,
+                &None,
+                &70_000_000i128,
+            );
+            assert_eq!(
+                client.try_set_metadata_hash(&creator, &1, &Some(hash(&env, 1))),
+                Err(Ok(Error::MetadataNotSet))
+            );
+        }
+    }
+
+    /// Tests for admin-configurable metadata length limits.
+    mod metadata_limits {
+        use soroban_sdk::{testutils::Address as _, Env, String};
+
+        use crate::test_helpers::setup_with_supply;
+        use crate::types::Error;
+
+        fn uri_of_len(env: &Env, len: usize) -> String {
+            String::from_str(env, &"a".repeat(len))
+        }
+
+        #[test]
+        fn test_default_uri_limit_boundary() {
+            let env = Env::default();
+            let (client, _, creator, token_index) = setup_with_supply(&env, 1_000_000_000);
+
+            let too_long = client.try_set_token_metadata(
+                &creator,
+                &token_index,
+                &uri_of_len(&env, 257),
+                &30_000_000,
+            );
+            assert_eq!(too_long, Err(Ok(Error::InvalidParameters)));
+
+            client.set_token_metadata(&creator, &token_index, &uri_of_len(&env, 256), &30_000_000);
+            assert_eq!(client.get_token_info(&token_index).metadata_version, 1);
+        }
+
+        #[test]
+        fn test_tightened_limit_applies_to_updates() {
+            let env = Env::default();
+            let (client, admin, creator, token_index) = setup_with_supply(&env, 1_000_000_000);
+
+            client.set_token_metadata(&creator, &token_index, &uri_of_len(&env, 64), &30_000_000);
+            client.set_metadata_limits(&admin, &Some(32), &None);
+
+            let result = client.try_update_metadata(&creator, &token_index, &uri_of_len(&env, 33));
+            assert_eq!(result, Err(Ok(Error::InvalidParameters)));
+
+            assert_eq!(
+                client.update_metadata(&creator, &token_index, &uri_of_len(&env, 32)),
+                2
+            );
+        }
+
+        #[test]
+        fn test_limit_enforced_at_creation() {
+            let env = Env::default();
+            let (client, admin, creator, _) = setup_with_supply(&env, 1_000_000_000);
+
+            client.set_metadata_limits(&admin, &Some(16), &None);
+
+            let result = client.try_create_token(
+                &creator,
+                &String::from_str(&env, "Second"),
+                &String::from_str(&env, "SEC"),
+                &7u32,
+                &1_000i128,
+                &Some(uri_of_len(&env, 17)),
+                &100_000_000i128,
+            );
+            assert_eq!(result, Err(Ok(Error::InvalidParameters)));
+        }
+
+        #[test]
+        fn test_set_metadata_limits_validation() {
+            let env = Env::default();
+            let (client, admin, creator, _) = setup_with_supply(&env, 1_000_000_000);
+
+            assert_eq!(
+                client.try_set_metadata_limits(&creator, &Some(10), &None),
+                Err(Ok(Error::Unauthorized))
+            );
+            assert_eq!(
+                client.try_set_metadata_limits(&admin, &None, &None),
+                Err(Ok(Error::InvalidParameters))
+            );
+            assert_eq!(
+                client.try_set_metadata_limits(&admin, &Some(0), &None),
+                Err(Ok(Error::InvalidParameters))
+            );
+
+            client.set_metadata_limits(&admin, &Some(128), &Some(512));
+            let config = client.get_admin_config();
+            assert_eq!(config.max_metadata_uri_len, 128);
+            assert_eq!(config.max_description_len, 512);
+        }
+    }
+
+    /// Tests for the per-token metadata update cap.
+    mod metadata_update_cap {
+        use soroban_sdk::{testutils::Address as _, Address, Env, String};
+
+        use crate::test_helpers::setup_with_supply;
+        use crate::types::Error;
+        use crate::TokenFactoryClient;
+
+        /// Set up a factory with one token carrying metadata.
+        /// Returns (client, admin, creator, token_index).
+        fn setup(env: &Env) -> (TokenFactoryClient<'_>, Address, Address, u32) {
+            let (client, admin, creator, token_index) = setup_with_supply(env, 1_000);
+
+            client.set_metadata(
+                &token_index,
+                &String::from_str(env, "ipfs://QmInitial"),
+                &30_000_000,
+            );
+
+            (client, admin, creator, token_index)
+        }
+
+        fn uri(env: &Env, n: u32) -> String {
+            let uris = [
+                "ipfs://Qm1",
+                "ipfs://Qm2",
+                "ipfs://Qm3",
+                "ipfs://Qm4",
+                "ipfs://Qm5",
+            ];
+            String::from_str(env, uris[n as usize])
+        }
+
+        #[test]
+        fn test_updates_unlimited_by_default() {
+            let env = Env::default();
+            let (client, _, creator, token_index) = setup(&env);
+
+            assert_eq!(client.get_max_metadata_updates(), 0);
+            for n in 0..5 {
+                client.update_metadata(&creator, &token_index, &uri(&env, n));
+            }
+            assert_eq!(client.get_metadata_update_count(&token_index), 5);
+        }
+
+        #[test]
+        fn test_updates_rejected_past_cap() {
+            let env = Env::default();
+            let (client, admin, creator, token_index) = setup(&env);
+
+            client.set_max_metadata_updates(&admin, &2);
+            assert_eq!(client.get_metadata_update_count(&token_index), 0);
+
+            client.update_metadata(&creator, &token_index, &uri(&env, 0));
+            client.update_metadata(&creator, &token_index, &uri(&env, 1));
+            assert_eq!(
+                client.try_update_metadata(&creator, &token_index, &uri(&env, 2)),
+                Err(Ok(Error::MetadataAlreadySet))
+            );
+
+            let info = client.get_token_info(&token_index);
+            assert_eq!(info.metadata_uri, Some(uri(&env, 1)));
+            assert_eq!(info.metadata_version, 3);
+        }
+
+        #[test]
+        fn test_admin_reset_restores_updates() {
+            let env = Env::default();
+            let (client, admin, creator, token_index) = setup(&env);
+
+            client.set_max_metadata_updates(&admin, &1);
+            client.update_metadata(&creator, &token_index, &uri(&env, 0));
+            assert_eq!(
+                client.try_update_metadata(&creator, &token_index, &uri(&env, 1)),
+                Err(Ok(Error::MetadataAlreadySet))
+            );
+
+            client.reset_metadata_update_count(&admin, &token_index);
+            assert_eq!(client.get_metadata_update_count(&token_index), 0);
+            client.update_metadata(&creator, &token_index, &uri(&env, 1));
+        }
+
+        #[test]
+        fn test_cap_configuration_is_admin_only() {
+            let env = Env::default();
+            let (client, admin, creator, token_index) = setup(&env);
+
+            assert_eq!(
+                client.try_set_max_metadata_updates(&creator, &1),
+                Err(Ok(Error::Unauthorized))
+            );
+            assert_eq!(
+                client.try_reset_metadata_update_count(&creator, &token_index),
+                Err(Ok(Error::Unauthorized))
+            );
+            assert_eq!(
+                client.try_reset_metadata_update_count(&admin, &9),
+                Err(Ok(Error::TokenNotFound))
+            );
+        }
+    }
+
+    /// Tests for per-token metadata fee overrides.
+    mod token_metadata_fee {
+        use soroban_sdk::{testutils::Address as _, Address, Env};
+
+        use crate::test_helpers::{create_test_token, setup_factory};
+        use crate::types::Error;
+        use crate::TokenFactoryClient;
+
+        /// Set up a factory with two tokens. Returns (client, admin, creator).
+        fn setup(env: &Env) -> (TokenFactoryClient<'_>, Address, Address) {
+            let (client, admin, _) = setup_factory(env);
+
+            let creator = Address::generate(env);
+            for _ in 0..2 {
+                create_test_token(env, &client, &creator, "PRM", 1_000);
+            }
+
+            (client, admin, creator)
+        }
+
+        #[test]
+        fn test_override_applies_only_to_its_token() {
+            let env = Env::default();
+            let (client, admin, _) = setup(&env);
+
+            client.set_token_metadata_fee(&admin, &0, &Some(90_000_000));
+
+            assert_eq!(client.get_token_metadata_fee(&0), 90_000_000);
+            assert_eq!(client.get_token_metadata_fee(&1), 30_000_000);
+
+            // Global changes reach only tokens without an override
+            client.update_fees(&admin, &None, &Some(40_000_000));
+            assert_eq!(client.get_token_metadata_fee(&0), 90_000_000);
+            assert_eq!(client.get_token_metadata_fee(&1), 40_000_000);
+        }
+
+        #[test]
+        fn test_clearing_override_restores_global_fee() {
+            let env = Env::default();
+            let (client, admin, _) = setup(&env);
+
+            client.set_token_metadata_fee(&admin, &0, &Some(0));
+            assert_eq!(client.get_token_metadata_fee(&0), 0);
+
+            client.set_token_metadata_fee(&admin, &0, &None);
+            assert_eq!(client.get_token_metadata_fee(&0), 30_000_000);
+        }
+
+        #[test]
+        fn test_override_configuration_is_validated() {
+            let env = Env::default();
+            let (client, admin, creator) = setup(&env);
+
+            assert_eq!(
+                client.try_set_token_metadata_fee(&creator, &0, &Some(1)),
+                Err(Ok(Error::Unauthorized))
+            );
+            assert_eq!(
+                client.try_set_token_metadata_fee(&admin, &9, &Some(1)),
+                Err(Ok(Error::TokenNotFound))
+            );
+            assert_eq!(
+                client.try_set_token_metadata_fee(&admin, &0, &Some(-1)),
+                Err(Ok(Error::InvalidParameters))
+            );
+            assert_eq!(client.get_token_metadata_fee(&0), 30_000_000);
+        }
+    }
 }
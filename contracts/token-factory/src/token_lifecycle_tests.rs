@@ -51,6 +51,8 @@ fn setup_with_token(env: &Env) -> (TokenFactoryClient, Address, Address, u32) {
             is_paused: false,
             clawback_enabled: false,
             freeze_enabled: false,
+            max_burn_bps: None,
+            official: false,
         },
     );
     crate::storage::set_balance(env, token_index, &admin, 1_000_000);
@@ -89,6 +91,8 @@ fn test_snapshot_recorded_on_mint() {
             is_paused: false,
             clawback_enabled: false,
             freeze_enabled: false,
+            max_burn_bps: None,
+            official: false,
         },
     );
 
@@ -131,6 +135,8 @@ fn test_snapshot_recorded_on_burn() {
             is_paused: false,
             clawback_enabled: false,
             freeze_enabled: false,
+            max_burn_bps: None,
+            official: false,
         },
     );
     crate::storage::set_balance(&env, token_index, &admin, 1_000_000);
@@ -174,6 +180,8 @@ fn test_get_balance_at_historical_ledger() {
             is_paused: false,
             clawback_enabled: false,
             freeze_enabled: false,
+            max_burn_bps: None,
+            official: false,
         },
     );
 
@@ -230,6 +238,8 @@ fn test_get_supply_at_historical_ledger() {
             is_paused: false,
             clawback_enabled: false,
             freeze_enabled: false,
+            max_burn_bps: None,
+            official: false,
         },
     );
 
@@ -274,6 +284,8 @@ fn test_future_ledger_query_rejected() {
             is_paused: false,
             clawback_enabled: false,
             freeze_enabled: false,
+            max_burn_bps: None,
+            official: false,
         },
     );
 
@@ -334,6 +346,8 @@ fn test_multiple_holders_independent_snapshots() {
             is_paused: false,
             clawback_enabled: false,
             freeze_enabled: false,
+            max_burn_bps: None,
+            official: false,
         },
     );
 
@@ -380,6 +394,8 @@ fn test_snapshot_count_increments_per_operation() {
             is_paused: false,
             clawback_enabled: false,
             freeze_enabled: false,
+            max_burn_bps: None,
+            official: false,
         },
     );
 
@@ -419,6 +435,8 @@ fn test_supply_snapshot_after_burn() {
             is_paused: false,
             clawback_enabled: false,
             freeze_enabled: false,
+            max_burn_bps: None,
+            official: false,
         },
     );
     crate::storage::set_balance(&env, token_index, &admin, 1_000_000);
@@ -463,6 +481,8 @@ fn test_get_balance_snapshot_by_index() {
             is_paused: false,
             clawback_enabled: false,
             freeze_enabled: false,
+            max_burn_bps: None,
+            official: false,
         },
     );
 
@@ -511,6 +531,8 @@ fn test_get_supply_snapshot_by_index() {
             is_paused: false,
             clawback_enabled: false,
             freeze_enabled: false,
+            max_burn_bps: None,
+            official: false,
         },
     );
 
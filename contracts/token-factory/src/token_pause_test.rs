@@ -104,6 +104,7 @@ fn set_metadata_blocked_when_token_paused() {
     let result = client.set_metadata(
         &token_index,
         &soroban_sdk::String::from_str(&env, "ipfs://Qmtest"),
+        &50_i128,
     );
     assert_eq!(result, Err(crate::types::Error::TokenPaused));
 }
@@ -216,6 +217,7 @@ fn interaction_token_paused_blocks_metadata() {
     let result = client.set_metadata(
         &token_index,
         &soroban_sdk::String::from_str(&env, "ipfs://Qmtest"),
+        &50_i128,
     );
     assert_eq!(result, Err(crate::types::Error::TokenPaused));
 }
@@ -54,6 +54,8 @@ mod tests {
             is_paused: false,
             clawback_enabled: false,
             freeze_enabled,
+            max_burn_bps: None,
+            official: false,
         };
         env.as_contract(contract_id, || {
             env.storage()
@@ -172,6 +172,7 @@ pub fn withdraw_fees(
     amount: i128,
 ) -> Result<(), Error> {
     admin.require_auth();
+    storage::ensure_not_killed(env)?;
 
     // Verify admin
     let current_admin = storage::get_admin(env);
@@ -191,6 +192,28 @@ pub fn withdraw_fees(
     Ok(())
 }
 
+/// Add a charged creation fee to the collected-fee total
+///
+/// Emits `fee_threshold` when this fee takes the total from below the
+/// configured alert threshold to at or above it, so each crossing alerts
+/// once. Non-positive amounts (exempt creators) are ignored.
+pub fn add_collected_fee(env: &Env, amount: i128) -> Result<(), Error> {
+    if amount <= 0 {
+        return Ok(());
+    }
+
+    let previous = storage::get_collected_fees(env);
+    let total = previous.checked_add(amount).ok_or(Error::ArithmeticError)?;
+    storage::set_collected_fees(env, total);
+
+    let threshold = storage::get_fee_alert_threshold(env);
+    if threshold > 0 && previous < threshold && total >= threshold {
+        crate::events::emit_fee_threshold_crossed(env, threshold, total);
+    }
+
+    Ok(())
+}
+
 /// Add recipient to allowlist
 ///
 /// Allows an address to receive treasury withdrawals.
@@ -202,6 +225,7 @@ pub fn withdraw_fees(
 /// * `recipient` - Address to add to allowlist
 pub fn add_allowed_recipient(env: &Env, admin: &Address, recipient: &Address) -> Result<(), Error> {
     admin.require_auth();
+    storage::ensure_not_killed(env)?;
 
     let current_admin = storage::get_admin(env);
     if *admin != current_admin {
@@ -229,6 +253,7 @@ pub fn remove_allowed_recipient(
     recipient: &Address,
 ) -> Result<(), Error> {
     admin.require_auth();
+    storage::ensure_not_killed(env)?;
 
     let current_admin = storage::get_admin(env);
     if *admin != current_admin {
@@ -258,6 +283,7 @@ pub fn update_treasury_policy(
     allowlist_enabled: Option<bool>,
 ) -> Result<(), Error> {
     admin.require_auth();
+    storage::ensure_not_killed(env)?;
 
     let current_admin = storage::get_admin(env);
     if *admin != current_admin {
@@ -562,4 +588,790 @@ mod tests {
         let result = env.as_contract(&contract_id, || validate_withdrawal(&env, &recipient, -100));
         assert_eq!(result, Err(Error::InvalidAmount));
     }
+
+    /// Tests for `get_treasury_balance` against a Stellar asset fee token.
+    mod treasury_balance {
+        use soroban_sdk::{testutils::Address as _, token::StellarAssetClient, Address, Env};
+
+        use crate::test_helpers::setup_factory;
+        use crate::types::Error;
+
+        /// Register a Stellar asset and seed `holder` with `amount`.
+        fn seeded_asset(env: &Env, holder: &Address, amount: i128) -> Address {
+            let issuer = Address::generate(env);
+            let asset = env.register_stellar_asset_contract_v2(issuer).address();
+            StellarAssetClient::new(env, &asset).mint(holder, &amount);
+            asset
+        }
+
+        #[test]
+        fn test_reads_seeded_treasury_balance() {
+            let env = Env::default();
+            let (client, admin, treasury) = setup_factory(&env);
+            let fee_token = seeded_asset(&env, &treasury, 12_345_000);
+
+            client.set_fee_token(&admin, &fee_token);
+
+            assert_eq!(client.get_treasury_balance(), 12_345_000);
+        }
+
+        #[test]
+        fn test_tracks_balance_changes() {
+            let env = Env::default();
+            let (client, admin, treasury) = setup_factory(&env);
+            let fee_token = seeded_asset(&env, &treasury, 1_000);
+            client.set_fee_token(&admin, &fee_token);
+
+            StellarAssetClient::new(&env, &fee_token).mint(&treasury, &500);
+            assert_eq!(client.get_treasury_balance(), 1_500);
+        }
+
+        #[test]
+        fn test_requires_fee_token() {
+            let env = Env::default();
+            let (client, _, _) = setup_factory(&env);
+
+            assert_eq!(
+                client.try_get_treasury_balance(),
+                Err(Ok(Error::FeeTokenNotConfigured))
+            );
+        }
+
+        #[test]
+        fn test_set_fee_token_requires_admin() {
+            let env = Env::default();
+            let (client, _, treasury) = setup_factory(&env);
+            let fee_token = seeded_asset(&env, &treasury, 1);
+
+            assert_eq!(
+                client.try_set_fee_token(&treasury, &fee_token),
+                Err(Ok(Error::Unauthorized))
+            );
+        }
+    }
+
+    /// Tests for the separate penalty treasury.
+    mod penalty_treasury {
+        use soroban_sdk::{testutils::Address as _, Address, Env};
+
+        use crate::test_helpers::setup_factory;
+        use crate::types::Error;
+
+        #[test]
+        fn test_unset_falls_back_to_main_treasury() {
+            let env = Env::default();
+            let (client, _, treasury) = setup_factory(&env);
+
+            assert_eq!(client.get_penalty_treasury(), treasury);
+        }
+
+        #[test]
+        fn test_each_revenue_type_has_its_destination() {
+            let env = Env::default();
+            let (client, admin, treasury) = setup_factory(&env);
+            let penalties = Address::generate(&env);
+
+            client.set_penalty_treasury(&admin, &Some(penalties.clone()));
+
+            assert_eq!(client.get_penalty_treasury(), penalties);
+            assert_eq!(client.get_payment_info().treasury, treasury);
+            assert_eq!(client.get_treasury_status().current, treasury);
+        }
+
+        #[test]
+        fn test_clearing_restores_fallback() {
+            let env = Env::default();
+            let (client, admin, treasury) = setup_factory(&env);
+
+            client.set_penalty_treasury(&admin, &Some(Address::generate(&env)));
+            client.set_penalty_treasury(&admin, &None);
+
+            assert_eq!(client.get_penalty_treasury(), treasury);
+        }
+
+        #[test]
+        fn test_set_penalty_treasury_validation() {
+            let env = Env::default();
+            let (client, admin, _) = setup_factory(&env);
+            let stranger = Address::generate(&env);
+
+            assert_eq!(
+                client.try_set_penalty_treasury(&stranger, &Some(stranger.clone())),
+                Err(Ok(Error::Unauthorized))
+            );
+
+            client.set_require_separate_treasury(&admin, &true);
+            assert_eq!(
+                client.try_set_penalty_treasury(&admin, &Some(admin.clone())),
+                Err(Ok(Error::InvalidParameters))
+            );
+        }
+    }
+
+    /// Tests for the collected-fee alert threshold.
+    mod fee_threshold {
+        use soroban_sdk::{
+            testutils::{Address as _, Events},
+            vec, xdr, Address, Env, String, Symbol, TryFromVal, Val,
+        };
+
+        use crate::test_helpers::setup_factory;
+        use crate::types::{Error, TokenCreationParams};
+        use crate::TokenFactoryClient;
+
+        const FEE: i128 = 70_000_000;
+
+        fn create(env: &Env, client: &TokenFactoryClient) {
+            client.create_token(
+                &Address::generate(env),
+                &String::from_str(env, "Fees"),
+                &String::from_str(env, "FEE"),
+                &7u32,
+                &1_000i128,
+                &None,
+                &FEE,
+            );
+        }
+
+        /// Whether the last invocation emitted a `fee_threshold` event
+        /// reporting `total`; only that invocation's events are kept.
+        fn alerted_at(env: &Env, total: i128) -> bool {
+            env.events().all().events().iter().any(|event| {
+                let xdr::ContractEventBody::V0(body) = &event.body;
+                let name = body
+                    .topics
+                    .first()
+                    .and_then(|t| Symbol::try_from_val(env, t).ok());
+                let data = Val::try_from_val(env, &body.data).unwrap();
+                name == Some(Symbol::new(env, "fee_threshold"))
+                    && <(i128, i128)>::try_from_val(env, &data)
+                        .ok()
+                        .map(|(_, t)| t)
+                        == Some(total)
+            })
+        }
+
+        #[test]
+        fn test_crossing_in_one_collection() {
+            let env = Env::default();
+            let (client, admin, _) = setup_factory(&env);
+
+            client.set_fee_alert_threshold(&admin, &50_000_000);
+            create(&env, &client);
+            assert!(alerted_at(&env, FEE));
+            assert_eq!(client.get_collected_fees(), FEE);
+        }
+
+        #[test]
+        fn test_crossing_across_collections_alerts_once() {
+            let env = Env::default();
+            let (client, admin, _) = setup_factory(&env);
+
+            client.set_fee_alert_threshold(&admin, &(2 * FEE));
+
+            create(&env, &client);
+            assert!(!alerted_at(&env, FEE));
+            create(&env, &client);
+            assert!(alerted_at(&env, 2 * FEE));
+            create(&env, &client);
+            assert!(!alerted_at(&env, 3 * FEE));
+            assert_eq!(client.get_collected_fees(), 3 * FEE);
+        }
+
+        #[test]
+        fn test_batch_creation_counts_toward_total() {
+            let env = Env::default();
+            let (client, admin, _) = setup_factory(&env);
+            let creator = Address::generate(&env);
+
+            client.set_fee_alert_threshold(&admin, &(2 * FEE));
+            let params = TokenCreationParams {
+                name: String::from_str(&env, "Batch"),
+                symbol: String::from_str(&env, "BAT"),
+                decimals: 7,
+                initial_supply: 1_000,
+                max_supply: None,
+                metadata_uri: None,
+            };
+            client.batch_create_tokens(&creator, &vec![&env, params.clone(), params], &(2 * FEE));
+            assert!(alerted_at(&env, 2 * FEE));
+            assert_eq!(client.get_collected_fees(), 2 * FEE);
+        }
+
+        #[test]
+        fn test_no_alert_when_disabled() {
+            let env = Env::default();
+            let (client, _, _) = setup_factory(&env);
+
+            create(&env, &client);
+            assert!(!alerted_at(&env, FEE));
+            assert_eq!(client.get_fee_alert_threshold(), 0);
+            assert_eq!(client.get_collected_fees(), FEE);
+        }
+
+        #[test]
+        fn test_set_threshold_validation() {
+            let env = Env::default();
+            let (client, admin, _) = setup_factory(&env);
+
+            assert_eq!(
+                client.try_set_fee_alert_threshold(&Address::generate(&env), &1),
+                Err(Ok(Error::Unauthorized))
+            );
+            assert_eq!(
+                client.try_set_fee_alert_threshold(&admin, &-1),
+                Err(Ok(Error::InvalidParameters))
+            );
+            client.set_fee_alert_threshold(&admin, &5);
+            assert_eq!(client.get_fee_alert_threshold(), 5);
+        }
+    }
+
+    /// Tests for the combined `set_fees` entrypoint and the fee change log.
+    mod set_fees {
+        use soroban_sdk::{
+            symbol_short,
+            testutils::{Address as _, Events, Ledger},
+            xdr, Address, Env, Symbol, TryFromVal,
+        };
+
+        use crate::test_helpers::setup_factory;
+        use crate::types::Error;
+
+        fn fee_event_count(env: &Env) -> u32 {
+            let mut count = 0;
+            for event in env.events().all().events() {
+                let xdr::ContractEventBody::V0(body) = &event.body;
+                if Symbol::try_from_val(env, &body.topics[0]).ok()
+                    == Some(symbol_short!("fee_up_v1"))
+                {
+                    count += 1;
+                }
+            }
+            count
+        }
+
+        #[test]
+        fn test_set_fees_updates_both_with_single_audit_entry() {
+            let env = Env::default();
+            let (client, admin, _) = setup_factory(&env);
+            env.ledger().with_mut(|l| l.timestamp = 5_000);
+
+            client.set_fees(&100_000_000, &40_000_000, &admin);
+            assert_eq!(fee_event_count(&env), 1);
+
+            assert_eq!(client.get_base_fee(), 100_000_000);
+            assert_eq!(client.get_metadata_fee(), 40_000_000);
+
+            assert_eq!(client.get_fee_change_count(), 1);
+            let change = client.get_fee_change(&0).unwrap();
+            assert_eq!(change.old_base_fee, 70_000_000);
+            assert_eq!(change.old_metadata_fee, 30_000_000);
+            assert_eq!(change.new_base_fee, 100_000_000);
+            assert_eq!(change.new_metadata_fee, 40_000_000);
+            assert_eq!(change.changed_by, admin);
+            assert_eq!(change.timestamp, 5_000);
+        }
+
+        #[test]
+        fn test_set_fees_validation() {
+            let env = Env::default();
+            let (client, admin, _) = setup_factory(&env);
+            let stranger = Address::generate(&env);
+
+            assert_eq!(
+                client.try_set_fees(&1, &1, &stranger),
+                Err(Ok(Error::Unauthorized))
+            );
+            assert_eq!(
+                client.try_set_fees(&1, &-1, &admin),
+                Err(Ok(Error::InvalidParameters))
+            );
+
+            // Neither fee moved, nothing logged
+            assert_eq!(client.get_base_fee(), 70_000_000);
+            assert_eq!(client.get_metadata_fee(), 30_000_000);
+            assert_eq!(client.get_fee_change_count(), 0);
+        }
+
+        #[test]
+        fn test_update_fees_logs_partial_change() {
+            let env = Env::default();
+            let (client, admin, _) = setup_factory(&env);
+
+            client.update_fees(&admin, &Some(80_000_000), &None);
+
+            assert_eq!(client.get_fee_change_count(), 1);
+            let change = client.get_fee_change(&0).unwrap();
+            assert_eq!(change.new_base_fee, 80_000_000);
+            assert_eq!(change.new_metadata_fee, 30_000_000);
+            assert_eq!(client.get_fee_change(&1), None);
+        }
+    }
+
+    /// Tests for `get_payment_info`.
+    mod payment_info {
+        use soroban_sdk::{testutils::Address as _, Address, Env};
+
+        use crate::test_helpers::setup_factory;
+        use crate::types::Error;
+        use crate::{TokenFactory, TokenFactoryClient};
+
+        #[test]
+        fn test_payment_info_reflects_configuration() {
+            let env = Env::default();
+            let (client, admin, treasury) = setup_factory(&env);
+
+            let info = client.get_payment_info();
+            assert_eq!(info.fee_token, None);
+            assert_eq!(info.treasury, treasury);
+            assert_eq!(info.base_fee, 70_000_000);
+            assert_eq!(info.metadata_fee, 30_000_000);
+
+            let fee_token = Address::generate(&env);
+            client.set_fee_token(&admin, &fee_token);
+            client.update_fees(&admin, &Some(80_000_000), &Some(40_000_000));
+
+            let info = client.get_payment_info();
+            assert_eq!(info.fee_token, Some(fee_token));
+            assert_eq!(info.base_fee, 80_000_000);
+            assert_eq!(info.metadata_fee, 40_000_000);
+            assert_eq!(info.treasury, client.get_state().treasury);
+        }
+
+        #[test]
+        fn test_payment_info_requires_initialization() {
+            let env = Env::default();
+            let contract_id = env.register_contract(None, TokenFactory);
+            let client = TokenFactoryClient::new(&env, &contract_id);
+
+            assert_eq!(
+                client.try_get_payment_info(),
+                Err(Ok(Error::NotInitialized))
+            );
+        }
+    }
+
+    /// Tests for admin-managed creation fee exemptions.
+    mod fee_exemption {
+        use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+
+        use crate::test_helpers::setup_factory;
+        use crate::types::{Error, TokenCreationParams};
+        use crate::TokenFactoryClient;
+
+        fn try_create(
+            env: &Env,
+            client: &TokenFactoryClient,
+            creator: &Address,
+            fee: i128,
+        ) -> Result<i128, Error> {
+            match client.try_create_token(
+                creator,
+                &String::from_str(env, "ExemptToken"),
+                &String::from_str(env, "EXT"),
+                &7u32,
+                &1_000i128,
+                &None,
+                &fee,
+            ) {
+                Ok(Ok(receipt)) => Ok(receipt.fee_charged),
+                Ok(Err(_)) => panic!("unexpected conversion error"),
+                Err(Ok(err)) => Err(err),
+                Err(Err(_)) => panic!("unexpected host error"),
+            }
+        }
+
+        #[test]
+        fn test_exempt_creator_pays_nothing() {
+            let env = Env::default();
+            let (client, admin, _) = setup_factory(&env);
+            let partner = Address::generate(&env);
+
+            client.add_fee_exemption(&admin, &partner);
+            client.set_min_creation_fee(&admin, &50_000_000);
+            assert!(client.is_fee_exempt(&partner));
+
+            assert_eq!(try_create(&env, &client, &partner, 0), Ok(0));
+
+            let params = TokenCreationParams {
+                name: String::from_str(&env, "ExemptBatch"),
+                symbol: String::from_str(&env, "EXB"),
+                decimals: 7,
+                initial_supply: 1_000,
+                max_supply: None,
+                metadata_uri: Some(String::from_str(&env, "ipfs://QmExemptBatch")),
+            };
+            let tokens = vec![&env, params.clone(), params];
+            client.batch_create_tokens(&partner, &tokens, &0);
+            client.batch_reveal(&partner, &tokens, &0);
+        }
+
+        #[test]
+        fn test_non_exempt_creator_charged() {
+            let env = Env::default();
+            let (client, admin, _) = setup_factory(&env);
+            let partner = Address::generate(&env);
+            let creator = Address::generate(&env);
+            client.add_fee_exemption(&admin, &partner);
+
+            assert!(!client.is_fee_exempt(&creator));
+            assert_eq!(
+                try_create(&env, &client, &creator, 0),
+                Err(Error::InsufficientFee)
+            );
+            assert_eq!(
+                try_create(&env, &client, &creator, 70_000_000),
+                Ok(70_000_000)
+            );
+        }
+
+        #[test]
+        fn test_removing_exemption_restores_fees() {
+            let env = Env::default();
+            let (client, admin, _) = setup_factory(&env);
+            let partner = Address::generate(&env);
+
+            client.add_fee_exemption(&admin, &partner);
+            assert_eq!(try_create(&env, &client, &partner, 0), Ok(0));
+
+            client.remove_fee_exemption(&admin, &partner);
+            assert!(!client.is_fee_exempt(&partner));
+            assert_eq!(
+                try_create(&env, &client, &partner, 0),
+                Err(Error::InsufficientFee)
+            );
+
+            // Removing again is a no-op
+            client.remove_fee_exemption(&admin, &partner);
+        }
+
+        #[test]
+        fn test_only_admin_manages_exemptions() {
+            let env = Env::default();
+            let (client, _, _) = setup_factory(&env);
+            let stranger = Address::generate(&env);
+
+            assert_eq!(
+                client.try_add_fee_exemption(&stranger, &stranger),
+                Err(Ok(Error::Unauthorized))
+            );
+            assert_eq!(
+                client.try_remove_fee_exemption(&stranger, &stranger),
+                Err(Ok(Error::Unauthorized))
+            );
+            assert!(!client.is_fee_exempt(&stranger));
+        }
+    }
+
+    /// Tests for rounding creation fees up to a configured denomination.
+    mod fee_rounding {
+        use soroban_sdk::{testutils::Address as _, vec, Address, Env, String};
+
+        use crate::test_helpers::setup_factory;
+        use crate::types::{Error, TokenCreationParams};
+        use crate::TokenFactoryClient;
+
+        /// Attempt a creation paying `fee`, returning the contract error if any.
+        fn try_create(
+            env: &Env,
+            client: &TokenFactoryClient,
+            metadata: bool,
+            fee: i128,
+        ) -> Result<(), Error> {
+            let uri = metadata.then(|| String::from_str(env, "ipfs://meta"));
+            match client.try_create_token(
+                &Address::generate(env),
+                &String::from_str(env, "Rounded"),
+                &String::from_str(env, "RND"),
+                &7u32,
+                &1_000i128,
+                &uri,
+                &fee,
+            ) {
+                Ok(_) => Ok(()),
+                Err(Ok(err)) => Err(err),
+                Err(Err(_)) => panic!("unexpected host error"),
+            }
+        }
+
+        #[test]
+        fn test_unaligned_fee_rounds_up() {
+            let env = Env::default();
+            let (client, admin, _) = setup_factory(&env);
+
+            // 70_000_000 → 90_000_000; 100_000_000 with metadata → 120_000_000
+            client.set_fee_rounding_unit(&admin, &30_000_000);
+
+            assert_eq!(
+                try_create(&env, &client, false, 89_999_999),
+                Err(Error::InsufficientFee)
+            );
+            assert!(try_create(&env, &client, false, 90_000_000).is_ok());
+            assert_eq!(
+                try_create(&env, &client, true, 119_999_999),
+                Err(Error::InsufficientFee)
+            );
+            assert!(try_create(&env, &client, true, 120_000_000).is_ok());
+        }
+
+        #[test]
+        fn test_aligned_fee_unchanged() {
+            let env = Env::default();
+            let (client, admin, _) = setup_factory(&env);
+
+            client.set_fee_rounding_unit(&admin, &10_000_000);
+
+            assert!(try_create(&env, &client, false, 70_000_000).is_ok());
+            assert!(try_create(&env, &client, true, 100_000_000).is_ok());
+        }
+
+        #[test]
+        fn test_batch_fee_rounds_per_token() {
+            let env = Env::default();
+            let (client, admin, _) = setup_factory(&env);
+            let creator = Address::generate(&env);
+
+            client.set_fee_rounding_unit(&admin, &30_000_000);
+
+            let params = TokenCreationParams {
+                name: String::from_str(&env, "Batch"),
+                symbol: String::from_str(&env, "BAT"),
+                decimals: 7,
+                initial_supply: 1_000,
+                max_supply: None,
+                metadata_uri: None,
+            };
+            let tokens = vec![&env, params.clone(), params];
+
+            assert_eq!(
+                client.try_batch_create_tokens(&creator, &tokens, &140_000_000),
+                Err(Ok(Error::InsufficientFee))
+            );
+            assert!(client
+                .try_batch_create_tokens(&creator, &tokens, &180_000_000)
+                .is_ok());
+        }
+
+        #[test]
+        fn test_set_rounding_unit_validation() {
+            let env = Env::default();
+            let (client, admin, _) = setup_factory(&env);
+
+            assert_eq!(client.get_fee_rounding_unit(), 0);
+            assert_eq!(
+                client.try_set_fee_rounding_unit(&Address::generate(&env), &10),
+                Err(Ok(Error::Unauthorized))
+            );
+            assert_eq!(
+                client.try_set_fee_rounding_unit(&admin, &-1),
+                Err(Ok(Error::InvalidParameters))
+            );
+
+            client.set_fee_rounding_unit(&admin, &1_000_000);
+            assert_eq!(client.get_fee_rounding_unit(), 1_000_000);
+        }
+    }
+
+    /// Tests for the optional treasury/admin separation requirement.
+    mod separate_treasury {
+        use soroban_sdk::{testutils::Address as _, Env};
+
+        use crate::test_helpers::setup_factory;
+        use crate::types::Error;
+
+        #[test]
+        fn test_flag_off_allows_admin_as_treasury() {
+            let env = Env::default();
+            let (client, admin, _) = setup_factory(&env);
+
+            assert!(!client.get_require_separate_treasury());
+            client.propose_treasury(&admin, &admin);
+            client.apply_treasury();
+            assert_eq!(client.get_treasury_status().current, admin);
+        }
+
+        #[test]
+        fn test_flag_on_rejects_treasury_set_to_admin() {
+            let env = Env::default();
+            let (client, admin, treasury) = setup_factory(&env);
+
+            client.set_require_separate_treasury(&admin, &true);
+            assert_eq!(
+                client.try_propose_treasury(&admin, &admin),
+                Err(Ok(Error::InvalidParameters))
+            );
+            assert_eq!(client.get_treasury_status().current, treasury);
+        }
+
+        #[test]
+        fn test_flag_on_rejects_admin_set_to_treasury() {
+            let env = Env::default();
+            let (client, admin, treasury) = setup_factory(&env);
+
+            client.set_require_separate_treasury(&admin, &true);
+            assert_eq!(
+                client.try_transfer_admin(&admin, &treasury),
+                Err(Ok(Error::InvalidParameters))
+            );
+            assert_eq!(
+                client.try_propose_admin(&admin, &treasury),
+                Err(Ok(Error::InvalidParameters))
+            );
+        }
+
+        #[test]
+        fn test_flag_enabled_after_proposal_blocks_apply() {
+            let env = Env::default();
+            let (client, admin, treasury) = setup_factory(&env);
+
+            client.propose_treasury(&admin, &admin);
+            client.set_require_separate_treasury(&admin, &true);
+
+            assert_eq!(
+                client.try_apply_treasury(),
+                Err(Ok(Error::InvalidParameters))
+            );
+            assert_eq!(client.get_treasury_status().current, treasury);
+        }
+
+        #[test]
+        fn test_enable_rejected_while_treasury_is_admin() {
+            let env = Env::default();
+            let (client, admin, _) = setup_factory(&env);
+
+            client.propose_treasury(&admin, &admin);
+            client.apply_treasury();
+
+            assert_eq!(
+                client.try_set_require_separate_treasury(&admin, &true),
+                Err(Ok(Error::InvalidParameters))
+            );
+            assert!(!client.get_require_separate_treasury());
+        }
+
+        #[test]
+        fn test_set_flag_requires_admin() {
+            let env = Env::default();
+            let (client, _, treasury) = setup_factory(&env);
+
+            assert_eq!(
+                client.try_set_require_separate_treasury(&treasury, &true),
+                Err(Ok(Error::Unauthorized))
+            );
+        }
+    }
+
+    /// Tests for delayed treasury changes (`propose_treasury` / `apply_treasury`).
+    mod treasury_change_delay {
+        use soroban_sdk::{
+            testutils::{Address as _, Ledger},
+            Address, Env,
+        };
+
+        use crate::test_helpers::setup_factory;
+        use crate::types::Error;
+        use crate::TokenFactoryClient;
+
+        const DELAY: u64 = 86_400;
+        const START_TS: u64 = 10_000;
+
+        /// Returns (client, admin, treasury) with a one-day treasury change delay.
+        fn setup(env: &Env) -> (TokenFactoryClient<'_>, Address, Address) {
+            env.ledger().with_mut(|l| l.timestamp = START_TS);
+            let (client, admin, treasury) = setup_factory(env);
+            client.set_treasury_change_delay(&admin, &DELAY);
+
+            (client, admin, treasury)
+        }
+
+        #[test]
+        fn test_propose_records_pending_treasury() {
+            let env = Env::default();
+            let (client, admin, treasury) = setup(&env);
+            let new_treasury = Address::generate(&env);
+
+            let effective_at = client.propose_treasury(&admin, &new_treasury);
+            assert_eq!(effective_at, START_TS + DELAY);
+
+            let status = client.get_treasury_status();
+            assert_eq!(status.current, treasury);
+            assert_eq!(status.pending, Some(new_treasury));
+            assert_eq!(status.effective_at, Some(START_TS + DELAY));
+            assert_eq!(client.get_admin_config().treasury_change_delay, DELAY);
+        }
+
+        #[test]
+        fn test_apply_too_early_rejected() {
+            let env = Env::default();
+            let (client, admin, treasury) = setup(&env);
+            let new_treasury = Address::generate(&env);
+
+            client.propose_treasury(&admin, &new_treasury);
+            env.ledger()
+                .with_mut(|l| l.timestamp = START_TS + DELAY - 1);
+
+            assert_eq!(
+                client.try_apply_treasury(),
+                Err(Ok(Error::TimelockNotExpired))
+            );
+            assert_eq!(client.get_treasury_status().current, treasury);
+        }
+
+        #[test]
+        fn test_apply_after_delay() {
+            let env = Env::default();
+            let (client, admin, _) = setup(&env);
+            let new_treasury = Address::generate(&env);
+
+            client.propose_treasury(&admin, &new_treasury);
+            env.ledger().with_mut(|l| l.timestamp = START_TS + DELAY);
+            client.apply_treasury();
+
+            let status = client.get_treasury_status();
+            assert_eq!(status.current, new_treasury);
+            assert_eq!(status.pending, None);
+            assert_eq!(status.effective_at, None);
+            assert_eq!(client.get_admin_config().treasury, new_treasury);
+
+            // Nothing left to apply
+            assert_eq!(client.try_apply_treasury(), Err(Ok(Error::ChangeNotFound)));
+        }
+
+        #[test]
+        fn test_new_proposal_restarts_delay() {
+            let env = Env::default();
+            let (client, admin, _) = setup(&env);
+            let first = Address::generate(&env);
+            let second = Address::generate(&env);
+
+            client.propose_treasury(&admin, &first);
+            env.ledger().with_mut(|l| l.timestamp = START_TS + 100);
+            client.propose_treasury(&admin, &second);
+
+            let status = client.get_treasury_status();
+            assert_eq!(status.pending, Some(second));
+            assert_eq!(status.effective_at, Some(START_TS + 100 + DELAY));
+        }
+
+        #[test]
+        fn test_propose_validation() {
+            let env = Env::default();
+            let (client, admin, treasury) = setup(&env);
+            let stranger = Address::generate(&env);
+
+            assert_eq!(
+                client.try_propose_treasury(&stranger, &stranger),
+                Err(Ok(Error::Unauthorized))
+            );
+            assert_eq!(
+                client.try_set_treasury_change_delay(&stranger, &0),
+                Err(Ok(Error::Unauthorized))
+            );
+            assert_eq!(
+                client.try_propose_treasury(&admin, &treasury),
+                Err(Ok(Error::InvalidParameters))
+            );
+        }
+    }
 }
@@ -23,6 +23,98 @@ pub struct FactoryState {
     pub paused: bool,
 }
 
+/// Factory state extended with runtime counters and versions
+///
+/// Returned by `get_full_state`; `FactoryState` is left unchanged for
+/// existing integrations.
+///
+/// # Fields
+/// * `admin`, `treasury`, `base_fee`, `metadata_fee`, `paused` - As in `FactoryState`
+/// * `schema_version` - Storage layout version written at initialization
+/// * `contract_version` - Code version, bumped by `upgrade`
+/// * `token_count` - Number of tokens created
+/// * `burn_count` - Number of burns across all tokens
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FullState {
+    pub admin: Address,
+    pub treasury: Address,
+    pub base_fee: i128,
+    pub metadata_fee: i128,
+    pub paused: bool,
+    pub schema_version: u32,
+    pub contract_version: u32,
+    pub token_count: u32,
+    pub burn_count: u32,
+}
+
+/// Everything a wallet needs to pay for a token creation
+///
+/// Returned by `get_payment_info`.
+///
+/// # Fields
+/// * `fee_token` - Asset contract fees are paid in, if configured
+/// * `treasury` - Recipient of creation fees
+/// * `base_fee` - Fee for every token (in stroops)
+/// * `metadata_fee` - Extra fee when a metadata URI is supplied (in stroops)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PaymentInfo {
+    pub fee_token: Option<Address>,
+    pub treasury: Address,
+    pub base_fee: i128,
+    pub metadata_fee: i128,
+}
+
+/// Admin dashboard view of every admin-gated setting
+///
+/// Composition read returned by `get_admin_config`, bundling values that
+/// would otherwise require one call each.
+///
+/// # Fields
+/// * `admin` - Current admin address
+/// * `pending_admin` - Admin proposed via `propose_admin`, if any
+/// * `treasury` - Address receiving deployment fees
+/// * `base_fee` - Base fee for token deployment (in stroops)
+/// * `metadata_fee` - Additional fee for metadata inclusion (in stroops)
+/// * `paused` - Whether the contract is paused
+/// * `withdrawal_cap` - Treasury withdrawal cap per period (in stroops)
+/// * `withdrawal_period` - Treasury withdrawal period in seconds
+/// * `allowlist_enabled` - Whether the treasury recipient allowlist is enforced
+/// * `timelock_delay` - Delay applied to scheduled admin changes, in seconds
+/// * `timelock_enabled` - Whether scheduled changes are time-locked
+/// * `max_metadata_uri_len` - Maximum accepted metadata URI length in bytes
+/// * `max_description_len` - Maximum accepted description length in bytes
+/// * `min_initial_supply` - Smallest initial supply accepted at creation
+/// * `max_initial_supply` - Largest initial supply accepted at creation
+/// * `max_burn_records_per_token` - Cap on burn-log records per token (`None` = unlimited)
+/// * `default_metadata_uri` - Fallback URI for tokens without one
+/// * `fee_token` - Asset contract fees are denominated in, if configured
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AdminConfig {
+    pub admin: Address,
+    pub pending_admin: Option<Address>,
+    pub treasury: Address,
+    pub base_fee: i128,
+    pub metadata_fee: i128,
+    pub paused: bool,
+    pub withdrawal_cap: i128,
+    pub withdrawal_period: u64,
+    pub allowlist_enabled: bool,
+    pub timelock_delay: u64,
+    pub timelock_enabled: bool,
+    pub max_metadata_uri_len: u32,
+    pub max_description_len: u32,
+    pub min_initial_supply: i128,
+    pub max_initial_supply: i128,
+    pub max_burn_records_per_token: Option<u32>,
+    pub default_metadata_uri: Option<String>,
+    pub fee_token: Option<Address>,
+    pub treasury_change_delay: u64,
+    pub min_creation_fee: i128,
+}
+
 /// Contract metadata for factory identification
 ///
 /// Contains descriptive information about the token factory contract.
@@ -51,7 +143,7 @@ pub struct ContractMetadata {
 /// * `address` - The token's contract address
 /// * `creator` - Address that deployed the token
 /// * `name` - Token name (e.g., "My Token")
-/// * `symbol` - Token symbol (e.g., "MTK")
+/// * `symbol` - Token symbol (e.g., "MTK"), normalized by `normalize_symbol`
 /// * `decimals` - Number of decimal places (typically 7 for Stellar)
 /// * `total_supply` - Current circulating supply after burns
 /// * `initial_supply` - Initial supply at token creation
@@ -62,6 +154,7 @@ pub struct ContractMetadata {
 /// * `total_burned` - Cumulative amount of tokens burned
 /// * `burn_count` - Number of burn operations performed
 /// * `clawback_enabled` - Whether admin can burn from any address
+/// * `max_burn_bps` - Optional lifetime burn cap in basis points of `initial_supply`
 ///
 /// # Examples
 /// ```
@@ -88,6 +181,9 @@ pub struct TokenInfo {
     pub is_paused: bool,
     pub clawback_enabled: bool,
     pub freeze_enabled: bool,
+    pub max_burn_bps: Option<u32>,
+    /// Protocol-launched token: set when the admin creates it, or via `set_official`.
+    pub official: bool,
 }
 
 /// A historical record of a single metadata update.
@@ -107,6 +203,196 @@ pub struct MetadataRecord {
     pub updated_by: Address,
 }
 
+/// Outcome of a single `create_token` call.
+///
+/// # Fields
+/// * `token_address` - Address recorded for the new token
+/// * `index` - Registry index assigned to the token
+/// * `fee_charged` - Fee accepted from the creator, in stroops
+/// * `created_at` - Ledger timestamp of creation
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct CreationReceipt {
+    pub token_address: Address,
+    pub index: u32,
+    pub fee_charged: i128,
+    pub created_at: u64,
+}
+
+/// An audit entry for a change to the deployment fees.
+///
+/// # Fields
+/// * `old_base_fee` / `old_metadata_fee` - Fees before the change
+/// * `new_base_fee` / `new_metadata_fee` - Fees after the change
+/// * `changed_by` - Admin that made the change
+/// * `timestamp` - Ledger timestamp of the change
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeeChange {
+    pub old_base_fee: i128,
+    pub old_metadata_fee: i128,
+    pub new_base_fee: i128,
+    pub new_metadata_fee: i128,
+    pub changed_by: Address,
+    pub timestamp: u64,
+}
+
+/// A treasury change waiting out the treasury change delay.
+///
+/// # Fields
+/// * `treasury` - Proposed treasury address
+/// * `effective_at` - Earliest ledger timestamp at which it can be applied
+/// * `expires_at` - Timestamp after which the proposal is void (None = never)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct PendingTreasury {
+    pub treasury: Address,
+    pub effective_at: u64,
+    pub expires_at: Option<u64>,
+}
+
+/// Current treasury alongside any pending replacement.
+///
+/// # Fields
+/// * `current` - Treasury currently receiving fees
+/// * `pending` - Proposed treasury, if one is waiting
+/// * `effective_at` - When `pending` can be applied, if one is waiting
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TreasuryStatus {
+    pub current: Address,
+    pub pending: Option<Address>,
+    pub effective_at: Option<u64>,
+}
+
+/// Result of the `health` monitoring check.
+///
+/// # Fields
+/// * `initialized` - Admin and treasury are set
+/// * `paused` - Contract-wide pause flag
+/// * `counters_consistent` - Token counter and a sample of token supplies agree
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct HealthStatus {
+    pub initialized: bool,
+    pub paused: bool,
+    pub counters_consistent: bool,
+}
+
+/// Per-token burn figures for token pages, returned by `get_burn_summary`.
+///
+/// # Fields
+/// * `total_burned` - Cumulative amount burned
+/// * `burn_count` - Number of burns
+/// * `burned_bps` - `total_burned` as basis points of everything issued
+///   (initial supply plus minted)
+/// * `largest_burn` - Largest single burn amount (0 if none)
+/// * `last_burn_at` - Ledger timestamp of the most recent burn
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BurnSummary {
+    pub total_burned: i128,
+    pub burn_count: u32,
+    pub burned_bps: u32,
+    pub largest_burn: i128,
+    pub last_burn_at: Option<u64>,
+}
+
+/// Every stored per-token record in one response, for support and debugging.
+///
+/// Returned by the admin-only `dump_token`. Only fixed-size records are
+/// included so the read stays bounded; logs such as burn records and
+/// metadata history are summarized by their counts.
+///
+/// # Fields
+/// * `info` - Stored `TokenInfo`, with `is_paused` reflecting the pause flag
+/// * `effective_metadata_uri` - Token URI, or the factory default
+/// * `metadata_locked` / `metadata_editable_until` - Metadata edit state
+/// * `frozen` - Compliance freeze flag
+/// * `burn_count` / `total_burned` - Standalone burn trackers
+/// * `burn_record_count` - Burn-log entries written for the token
+/// * `total_minted` - Amount minted after creation
+/// * `largest_burn` / `last_burn_at` - Per-token burn stats
+/// * `holder_count` / `holder_reporter` - Reported holder figures
+/// * `tags` - Discovery tags (bounded by the tag limits)
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenDump {
+    pub info: TokenInfo,
+    pub effective_metadata_uri: Option<String>,
+    pub metadata_locked: bool,
+    pub metadata_editable_until: Option<u64>,
+    pub frozen: bool,
+    pub burn_count: u32,
+    pub total_burned: i128,
+    pub burn_record_count: u32,
+    pub total_minted: i128,
+    pub largest_burn: i128,
+    pub last_burn_at: Option<u64>,
+    pub holder_count: u32,
+    pub holder_reporter: Option<Address>,
+    pub tags: soroban_sdk::Vec<String>,
+}
+
+/// A single burn, appended to the global burn log.
+///
+/// Records are stored by a global, ascending index so every burn across all
+/// tokens can be replayed in order.
+///
+/// # Fields
+/// * `token_index` - Token the burn applied to
+/// * `from` - Address whose balance was reduced
+/// * `burned_by` - Address that authorized the burn (holder, admin or signer)
+/// * `amount` - Amount burned
+/// * `timestamp` - Ledger timestamp of the burn
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BurnRecord {
+    pub token_index: u32,
+    pub from: Address,
+    pub burned_by: Address,
+    pub amount: i128,
+    pub timestamp: u64,
+}
+
+/// A single post-creation mint, appended to the global mint log.
+///
+/// # Fields
+/// * `token_index` - Token the mint applied to
+/// * `to` - Address whose balance was increased
+/// * `amount` - Amount minted
+/// * `timestamp` - Ledger timestamp of the mint
+/// * `burn_record_count` - Length of the global burn log at mint time; orders
+///   the mint exactly against burns, even within one ledger
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MintRecord {
+    pub token_index: u32,
+    pub to: Address,
+    pub amount: i128,
+    pub timestamp: u64,
+    pub burn_record_count: u32,
+}
+
+/// One entry of a token's combined supply history.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum SupplyEvent {
+    Mint(MintRecord),
+    Burn(BurnRecord),
+}
+
+/// Position of one supply event in the global mint or burn log.
+///
+/// Entries of a token's supply ledger index; `get_token_ledger` resolves
+/// them to `SupplyEvent`s.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum SupplyEventRef {
+    Mint(u32),
+    Burn(u32),
+}
+
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct StreamInfo {
@@ -147,6 +433,19 @@ pub struct TokenCreationParams {
     pub metadata_uri: Option<String>,
 }
 
+/// Single-token creation parameters with optional decimals
+///
+/// `decimals: None` falls back to the configured default decimals.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct OptDecimalsTokenParams {
+    pub name: String,
+    pub symbol: String,
+    pub decimals: Option<u32>,
+    pub initial_supply: i128,
+    pub metadata_uri: Option<String>,
+}
+
 /// Timelock configuration
 #[contracttype]
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -299,6 +598,30 @@ pub enum CampaignStatus {
     Expired = 4,
 }
 
+/// How `units::from_base_units` rounds a remainder.
+///
+/// `Floor` rounds toward negative infinity, `Ceil` toward positive infinity
+/// and `HalfUp` to the nearest whole unit with ties away from zero.
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum RoundingMode {
+    Floor = 0,
+    Ceil = 1,
+    HalfUp = 2,
+}
+
+/// What a burn does with the burned amount.
+///
+/// `ReduceSupply` removes it from `total_supply`. `SendToDead` credits it to
+/// the given address and leaves supply unchanged, for tokens whose burns are
+/// transfers to an unspendable account. Both modes log a `BurnRecord`.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum BurnMode {
+    ReduceSupply,
+    SendToDead(Address),
+}
+
 // ─────────────────────────────────────────────────────────────────────────────
 // Liquidity Mining Types
 // ─────────────────────────────────────────────────────────────────────────────
@@ -573,6 +896,8 @@ pub enum DataKey {
     TokenPaused(u32),
     TotalBurned(u32),
     TokenByAddress(Address),
+    /// Index of the token registered at an address
+    TokenIndexByAddress(Address),
     Paused,
     TimelockConfig,
     PendingChange(u64),
@@ -632,6 +957,157 @@ pub enum DataKey {
     SupplySnapshotCount(u32),
     /// Individual supply snapshot: (token_index, snapshot_index)
     SupplySnapshot(u32, u32),
+    /// Compliance freeze flag for token_index; blocks burns while set
+    TokenFrozen(u32),
+    // Metadata length limits (admin-configurable)
+    MaxMetadataUriLen,
+    MaxDescriptionLen,
+    // Global burn log
+    BurnRecordCount,
+    BurnRecord(u32),
+    /// Replay-protection nonce for signed operations by an account
+    Nonce(Address),
+    // Initial supply bounds enforced at creation (admin-configurable)
+    MaxInitialSupply,
+    MinInitialSupply,
+    /// Optional cap on burn-log records written per token (admin-configurable)
+    MaxBurnRecordsPerToken,
+    /// Number of burn-log records written for token_index
+    TokenBurnRecordCount(u32),
+    /// Fallback metadata URI for tokens created without one (admin-configurable)
+    DefaultMetadataUri,
+    /// Cumulative amount minted after creation for token_index
+    TotalMinted(u32),
+    /// Timestamp after which metadata for token_index can no longer change
+    MetadataEditableUntil(u32),
+    /// Set once the creator freezes metadata for token_index
+    MetadataFrozen(u32),
+    /// Asset contract in which deployment fees are denominated
+    FeeToken,
+    /// Storage layout version written at initialization
+    SchemaVersion,
+    /// Largest single burn seen across all tokens
+    LargestBurn,
+    /// Extra mint authority for token_index, added by its creator
+    MintAuthority(u32, Address),
+    // Fee change audit log
+    FeeChangeCount,
+    FeeChange(u32),
+    /// Seconds a proposed treasury must wait before it can be applied
+    TreasuryChangeDelay,
+    /// Treasury proposed via propose_treasury, not yet applied
+    PendingTreasury,
+    /// Burn-log index of the burn tagged with a correlation id
+    BurnByCorrelation(u64),
+    /// Reported number of holders of token_index
+    HolderCount(u32),
+    /// Address allowed to report holder changes for token_index
+    HolderReporter(u32),
+    /// Number of burns across all tokens
+    GlobalBurnCount,
+    /// Largest single burn amount for token_index
+    TokenLargestBurn(u32),
+    /// Timestamp of the latest burn for token_index
+    LastBurnAt(u32),
+    /// Floor applied to every per-token creation fee
+    MinCreationFee,
+    /// Set for creators that pay no creation fees
+    FeeExempt(Address),
+    /// Cumulative amount burned and credited to an account for token_index
+    AccountBurned(u32, Address),
+    /// When set, admin burns credit the executor rather than the holder
+    CreditBurnExecutor,
+    /// When set, the treasury and admin must be different addresses
+    RequireSeparateTreasury,
+    /// Set once token_index has been permanently retired
+    TokenRetired(u32),
+    /// Decimals used when a creation call leaves them unspecified
+    DefaultDecimals,
+    /// Amount burned for token_index during UTC day `timestamp / 86_400`
+    DailyBurn(u32, u32),
+    /// Admin-configured cap on the number of tokens (0 = unlimited)
+    MaxTokens,
+    /// Seconds a pending admin/treasury proposal stays valid (0 = forever)
+    ProposalTtl,
+    /// Timestamp after which the pending admin proposal is void
+    PendingAdminExpiresAt,
+    /// When set, only allowlisted creators (and the admin) may create tokens
+    CreatorAllowlistEnabled,
+    /// Set for creators on the creation allowlist
+    AllowedCreator(Address),
+    /// Whether non-essential events are emitted (default: true)
+    EventsEnabled,
+    /// SHA-256 commitment to a token's off-chain metadata content
+    MetadataHash(u32),
+    /// Running total of creation fees collected
+    CollectedFees,
+    /// Collected-fee total that triggers a `fee_threshold` alert (0 = off)
+    FeeAlertThreshold,
+    /// Whether new tokens must be created with a metadata URI
+    RequireMetadata,
+    /// Set while a token's pause was placed by the factory admin
+    TokenAdminPaused(u32),
+    /// Burn-log index of a token's first record on a UTC day (token_index, day)
+    BurnDayIndex(u32, u32),
+    /// Where a token's burned amounts go (token_index)
+    BurnMode(u32),
+    /// Amount burned to a dead address rather than out of supply (token_index)
+    DeadBurned(u32),
+    /// Skip the metadata fee when the URI is supplied at creation
+    WaiveMetadataFeeAtCreation,
+    /// Whether an address is already in the distinct-creator index
+    CreatorSeen(Address),
+    /// Number of distinct creators
+    CreatorCount,
+    /// Distinct creator at a position in first-creation order
+    CreatorAt(u32),
+    /// Discovery tags attached to a token (token_index)
+    TokenTags(u32),
+    /// Maximum number of tags per token
+    MaxTagCount,
+    /// Maximum length of a single tag in bytes
+    MaxTagLen,
+    /// Burn total inherited from a migrated predecessor (token_index)
+    InheritedBurned(u32),
+    /// Metadata updates allowed per token (0 = unlimited)
+    MaxMetadataUpdates,
+    /// Metadata updates applied to a token since the last reset (token_index)
+    MetadataUpdateCount(u32),
+    /// Metadata fee override for a single token (token_index)
+    TokenMetadataFee(u32),
+    /// Freeze a token's metadata on its first burn (bool)
+    AutoFreezeMetadataOnBurn,
+    /// Destination for penalty and burn-fee revenue (Address)
+    PenaltyTreasury,
+    /// Only whitelisted accounts and the admin may burn (token_index)
+    BurnRestricted(u32),
+    /// Account allowed to burn a restricted token (token_index, account)
+    BurnWhitelist(u32, Address),
+    /// Number of burns across all tokens on one UTC day (day index)
+    DailyBurnCount(u32),
+    /// Creation fees are rounded up to a multiple of this unit (i128)
+    FeeRoundingUnit,
+    /// Fewest decimals a new token may have (u32)
+    MinDecimals,
+    // Global mint log
+    MintRecordCount,
+    MintRecord(u32),
+    /// Number of entries in a token's supply ledger index (token_index)
+    TokenLedgerCount(u32),
+    /// Mint or burn log position at a slot of a token's ledger (token_index, slot)
+    TokenLedgerEntry(u32, u32),
+    /// Emergency kill switch; blocks every mutation except its own reset (bool)
+    Killed,
+    /// Seconds after creation before holders may burn a token (u64)
+    MinAgeBeforeBurn,
+    /// Scheduled burn by id (BurnSchedule)
+    BurnSchedule(u64),
+    /// Next scheduled burn id (u64)
+    BurnScheduleCount,
+    /// Number of schedules created for a token (token_index)
+    BurnScheduleCountByToken(u32),
+    /// Schedule id at a position of a token's schedule list (token_index, position)
+    BurnSchedulesByToken(u32, u32),
 }
 
 /// A point-in-time record of a token holder's balance.
@@ -894,8 +1370,33 @@ impl Error {
     pub const BurnScheduleNotFound: Self = Self(81);
     pub const BurnScheduleLocked: Self = Self(82);
     pub const BurnScheduleAlreadyExecuted: Self = Self(83);
-    pub const BurnScheduleCancelled: Self = Self(84);
+    pub const BurnCancelled: Self = Self(84);
     pub const InvalidUnlockTime: Self = Self(85);
+    // Initialization errors
+    pub const NotInitialized: Self = Self(86);
+    // Token freeze errors
+    pub const TokenFrozen: Self = Self(87);
+    // Signed burn errors
+    pub const InvalidSignature: Self = Self(88);
+    pub const BadNonce: Self = Self(89);
+    // Burn cap errors
+    pub const BurnCapReached: Self = Self(90);
+    // Fee token errors
+    pub const FeeTokenNotConfigured: Self = Self(91);
+    // Token retirement errors
+    pub const TokenRetired: Self = Self(92);
+    // Registry capacity errors
+    pub const RegistryFull: Self = Self(93);
+    // Creator allowlist errors
+    pub const CreatorNotAllowed: Self = Self(94);
+    // Token tag errors
+    pub const TooManyTags: Self = Self(95);
+    // Accounting integrity errors
+    pub const SupplyInvariantViolated: Self = Self(96);
+    // Emergency kill switch errors
+    pub const ContractKilled: Self = Self(97);
+    // Burn age gate errors
+    pub const TokenTooNew: Self = Self(98);
 }
 
 impl From<Error> for soroban_sdk::Error {
@@ -1059,6 +1560,44 @@ pub struct PaginatedTokens {
     pub cursor: PaginationCursor,
 }
 
+/// Offset-paginated page of tokens
+///
+/// `contracttype` has no generics, so each paginated query gets a concrete
+/// page type with the same `items` / `next` / `total` shape.
+///
+/// # Fields
+/// * `items` - Tokens on this page in ascending index order
+/// * `next` - Offset to pass for the following page (None = last page)
+/// * `total` - Total number of entries across all pages
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct TokenPage {
+    pub items: soroban_sdk::Vec<TokenInfo>,
+    pub next: Option<u32>,
+    pub total: u32,
+}
+
+/// Offset-paginated page of the global burn log
+///
+/// Same shape as [`TokenPage`]; records are oldest first.
+#[contracttype]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct BurnRecordPage {
+    pub items: soroban_sdk::Vec<BurnRecord>,
+    pub next: Option<u32>,
+    pub total: u32,
+}
+
+/// Outcome of one entry of a best-effort batch burn
+#[contracttype]
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BatchBurnOutcome {
+    /// The burn was applied
+    Applied,
+    /// The burn was skipped; carries the code of the `Error` that rejected it
+    Rejected(u32),
+}
+
 /// Paginated vault result
 ///
 /// Contains a page of vaults and an optional cursor for fetching the next page.
@@ -0,0 +1,142 @@
+//! Conversions between whole-token amounts and base units.
+//!
+//! A token with `decimals = d` stores amounts as integers scaled by `10^d`.
+//! Scaling up is exact; scaling down divides by `10^d` and rounds according
+//! to a caller-chosen [`RoundingMode`], so fee and tax calculations can pick
+//! the direction that favours the protocol or the user explicitly.
+
+use crate::types::{Error, RoundingMode};
+
+/// Largest decimals value accepted, matching token creation.
+const MAX_DECIMALS: u32 = 18;
+
+fn scale(decimals: u32) -> Result<i128, Error> {
+    if decimals > MAX_DECIMALS {
+        return Err(Error::InvalidParameters);
+    }
+    10i128.checked_pow(decimals).ok_or(Error::ArithmeticError)
+}
+
+/// Convert a whole-token `amount` to base units (`amount * 10^decimals`).
+///
+/// # Errors
+/// * `Error::InvalidParameters` - `decimals` is above 18
+/// * `Error::ArithmeticError` - The result overflows `i128`
+pub fn to_base_units(amount: i128, decimals: u32) -> Result<i128, Error> {
+    amount
+        .checked_mul(scale(decimals)?)
+        .ok_or(Error::ArithmeticError)
+}
+
+/// Convert `base` units to whole tokens (`base / 10^decimals`), rounding
+/// any remainder according to `mode`.
+///
+/// # Errors
+/// * `Error::InvalidParameters` - `decimals` is above 18
+/// * `Error::ArithmeticError` - Rounding overflows `i128`
+pub fn from_base_units(base: i128, decimals: u32, mode: RoundingMode) -> Result<i128, Error> {
+    let scale = scale(decimals)?;
+    // Euclidean division: `floor` rounds toward -inf and 0 <= rem < scale
+    let floor = base
+        .checked_div_euclid(scale)
+        .ok_or(Error::ArithmeticError)?;
+    let rem = base
+        .checked_rem_euclid(scale)
+        .ok_or(Error::ArithmeticError)?;
+    if rem == 0 {
+        return Ok(floor);
+    }
+
+    let round_up = match mode {
+        RoundingMode::Floor => false,
+        RoundingMode::Ceil => true,
+        // rem < scale <= 10^18, so doubling cannot overflow. Ties move
+        // away from zero: up for positive values, down for negative ones.
+        RoundingMode::HalfUp => {
+            let twice = rem * 2;
+            if base >= 0 {
+                twice >= scale
+            } else {
+                twice > scale
+            }
+        }
+    };
+
+    if round_up {
+        floor.checked_add(1).ok_or(Error::ArithmeticError)
+    } else {
+        Ok(floor)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_base_units() {
+        assert_eq!(to_base_units(5, 7), Ok(50_000_000));
+        assert_eq!(to_base_units(5, 0), Ok(5));
+        assert_eq!(to_base_units(-2, 2), Ok(-200));
+        assert_eq!(to_base_units(1, 19), Err(Error::InvalidParameters));
+        assert_eq!(to_base_units(i128::MAX, 1), Err(Error::ArithmeticError));
+    }
+
+    #[test]
+    fn test_exact_values_ignore_mode() {
+        for mode in [
+            RoundingMode::Floor,
+            RoundingMode::Ceil,
+            RoundingMode::HalfUp,
+        ] {
+            assert_eq!(from_base_units(1_200, 2, mode), Ok(12));
+            assert_eq!(from_base_units(-1_200, 2, mode), Ok(-12));
+            assert_eq!(from_base_units(0, 18, mode), Ok(0));
+        }
+    }
+
+    #[test]
+    fn test_floor() {
+        assert_eq!(from_base_units(1_249, 2, RoundingMode::Floor), Ok(12));
+        assert_eq!(from_base_units(1_250, 2, RoundingMode::Floor), Ok(12));
+        assert_eq!(from_base_units(1_299, 2, RoundingMode::Floor), Ok(12));
+        assert_eq!(from_base_units(-1_201, 2, RoundingMode::Floor), Ok(-13));
+    }
+
+    #[test]
+    fn test_ceil() {
+        assert_eq!(from_base_units(1_201, 2, RoundingMode::Ceil), Ok(13));
+        assert_eq!(from_base_units(1_249, 2, RoundingMode::Ceil), Ok(13));
+        assert_eq!(from_base_units(-1_299, 2, RoundingMode::Ceil), Ok(-12));
+    }
+
+    #[test]
+    fn test_half_up() {
+        assert_eq!(from_base_units(1_249, 2, RoundingMode::HalfUp), Ok(12));
+        assert_eq!(from_base_units(1_250, 2, RoundingMode::HalfUp), Ok(13));
+        assert_eq!(from_base_units(1_251, 2, RoundingMode::HalfUp), Ok(13));
+        assert_eq!(from_base_units(-1_249, 2, RoundingMode::HalfUp), Ok(-12));
+        assert_eq!(from_base_units(-1_250, 2, RoundingMode::HalfUp), Ok(-13));
+        assert_eq!(from_base_units(-1_251, 2, RoundingMode::HalfUp), Ok(-13));
+    }
+
+    #[test]
+    fn test_from_base_units_bounds() {
+        assert_eq!(
+            from_base_units(1, 19, RoundingMode::Floor),
+            Err(Error::InvalidParameters)
+        );
+        assert_eq!(
+            from_base_units(i128::MAX, 0, RoundingMode::Ceil),
+            Ok(i128::MAX)
+        );
+        assert_eq!(
+            from_base_units(i128::MAX, 18, RoundingMode::Ceil),
+            Ok(i128::MAX / 1_000_000_000_000_000_000 + 1)
+        );
+        assert_eq!(
+            from_base_units(i128::MIN, 18, RoundingMode::Floor),
+            Ok(i128::MIN / 1_000_000_000_000_000_000 - 1)
+        );
+    }
+}
@@ -18,10 +18,10 @@
 //! All validation functions return `Result<(), Error>`. On validation failure,
 //! they return the first error encountered using fail-fast semantics.
 
-use soroban_sdk::Env;
+use soroban_sdk::{Env, String, Vec};
 
 use crate::storage;
-use crate::types::Error;
+use crate::types::{Error, HealthStatus};
 
 /// Validates that the admin address is set and valid.
 ///
@@ -162,6 +162,60 @@ pub fn validate_fees(env: &Env) -> Result<(), Error> {
     Ok(())
 }
 
+/// Validates a metadata URI against the configured maximum length.
+///
+/// The limit is read from `DataKey::MaxMetadataUriLen` (default 256 bytes)
+/// so operators can tighten it without a contract upgrade.
+///
+/// # Errors
+///
+/// * `Error::InvalidParameters` - URI is longer than the configured limit
+pub fn validate_metadata_uri(env: &Env, uri: &String) -> Result<(), Error> {
+    if uri.len() > storage::get_max_metadata_uri_len(env) {
+        return Err(Error::InvalidParameters);
+    }
+    Ok(())
+}
+
+/// Validates a description against the configured maximum length.
+///
+/// The limit is read from `DataKey::MaxDescriptionLen` (default 1024 bytes).
+///
+/// # Errors
+///
+/// * `Error::InvalidParameters` - Description is longer than the configured limit
+pub fn validate_description(env: &Env, description: &String) -> Result<(), Error> {
+    if description.len() > storage::get_max_description_len(env) {
+        return Err(Error::InvalidParameters);
+    }
+    Ok(())
+}
+
+/// Validates a token's tag list against the configured limits.
+///
+/// The count cap is read from `DataKey::MaxTagCount` (default 5) and the
+/// per-tag length cap from `DataKey::MaxTagLen` (default 32 bytes). The
+/// count is checked first so an oversized list fails the same way whatever
+/// its contents.
+///
+/// # Errors
+///
+/// * `Error::TooManyTags` - More tags than the configured count cap
+/// * `Error::InvalidParameters` - A tag is empty or longer than the length cap
+pub fn validate_tags(env: &Env, tags: &Vec<String>) -> Result<(), Error> {
+    if tags.len() > storage::get_max_tag_count(env) {
+        return Err(Error::TooManyTags);
+    }
+
+    let max_len = storage::get_max_tag_len(env);
+    for tag in tags.iter() {
+        if tag.is_empty() || tag.len() > max_len {
+            return Err(Error::InvalidParameters);
+        }
+    }
+    Ok(())
+}
+
 /// Validates that token_count is non-negative and matches actual stored tokens.
 ///
 /// This function ensures the token count consistency invariant is maintained.
@@ -206,7 +260,7 @@ pub fn validate_token_count(env: &Env) -> Result<(), Error> {
 
     // Count actual tokens by checking each index
     let mut actual_count = 0u32;
-    for i in 0..token_count {
+    for i in storage::valid_token_indices(env) {
         if storage::get_token_info(env, i).is_some() {
             actual_count += 1;
         }
@@ -276,3 +330,54 @@ pub fn validate_state(env: &Env) -> Result<(), Error> {
 
     Ok(())
 }
+
+/// Number of tokens inspected by [`health`].
+const HEALTH_SAMPLE_SIZE: u32 = 5;
+
+/// Cheap pass/fail summary of the core invariants for monitoring.
+///
+/// Unlike [`validate_state`], this never scans the whole registry: it
+/// checks up to [`HEALTH_SAMPLE_SIZE`] tokens spread evenly across it plus
+/// the newest one, and confirms nothing is stored past the token counter.
+/// A sampled token is consistent when its supply invariant holds and its
+/// supply is within `max_supply`.
+pub fn health(env: &Env) -> HealthStatus {
+    HealthStatus {
+        initialized: storage::has_admin(env) && storage::try_get_treasury(env).is_ok(),
+        paused: storage::is_paused(env),
+        counters_consistent: sampled_counters_consistent(env),
+    }
+}
+
+fn sampled_counters_consistent(env: &Env) -> bool {
+    let count = storage::get_token_count(env);
+    // First unassigned index must be empty
+    if storage::get_token_info(env, count).is_some() {
+        return false;
+    }
+    let Some(last) = storage::last_token_index(env) else {
+        return true;
+    };
+
+    let samples = count.min(HEALTH_SAMPLE_SIZE);
+    let step = count / samples;
+    for i in 0..samples {
+        if !token_consistent(env, storage::FIRST_TOKEN_INDEX + i * step) {
+            return false;
+        }
+    }
+    token_consistent(env, last)
+}
+
+fn token_consistent(env: &Env, token_index: u32) -> bool {
+    let info = match storage::get_token_info(env, token_index) {
+        Some(info) => info,
+        None => return false,
+    };
+    if let Some(max) = info.max_supply {
+        if info.total_supply > max {
+            return false;
+        }
+    }
+    crate::mint::verify_supply_invariant(env, token_index)
+}
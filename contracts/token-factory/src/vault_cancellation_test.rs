@@ -40,6 +40,8 @@ fn setup() -> (Env, Address, Address, Address, Address, Address) {
         is_paused: false,
         clawback_enabled: false,
         freeze_enabled: false,
+        max_burn_bps: None,
+        official: false,
     };
 
     env.as_contract(&contract_id, || {
@@ -121,11 +121,12 @@ export interface CreateTokensParams {
   total_fee_payment: bigint;        // i128
 }
 
-/** Matches `set_token_metadata(admin, token_index, metadata_uri)` */
+/** Matches `set_token_metadata(admin, token_index, metadata_uri, fee_payment)` */
 export interface SetTokenMetadataParams {
   admin: string;        // Address
   token_index: number;  // u32
   metadata_uri: string; // String
+  fee_payment: bigint;  // i128
 }
 
 /** Matches `update_metadata(admin, token_index, new_metadata_uri)` */
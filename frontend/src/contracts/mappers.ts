@@ -61,11 +61,12 @@ export const mappers = {
     i128(p.total_fee_payment),
   ],
 
-  /** set_token_metadata(admin, token_index, metadata_uri) */
+  /** set_token_metadata(admin, token_index, metadata_uri, fee_payment) */
   setTokenMetadata: (p: SetTokenMetadataParams): xdr.ScVal[] => [
     addr(p.admin),
     u32(p.token_index),
     str(p.metadata_uri),
+    i128(p.fee_payment),
   ],
 
   /** update_metadata(admin, token_index, new_metadata_uri) */